@@ -0,0 +1,136 @@
+use std::path::Path;
+use std::time::Instant;
+
+use miden_client::Client;
+use miden_client::account::component::{AccountComponent, BasicWallet};
+use miden_client::account::{
+    AccountBuilder,
+    AccountBuilderSchemaCommitmentExt,
+    AccountId,
+    AccountType,
+};
+use miden_client::auth::{AuthSchemeId, AuthSecretKey, AuthSingleSig};
+use miden_client::keystore::{FilesystemKeyStore, Keystore};
+use miden_client::transaction::{TransactionRequest, TransactionRequestBuilder};
+use rand::Rng;
+use rand_chacha::ChaCha20Rng;
+use rand_chacha::rand_core::SeedableRng;
+
+use crate::config::BenchConfig;
+use crate::metrics::{BenchmarkResult, measure_time_async};
+
+// ORCHESTRATOR
+// ================================================================================================
+
+/// Runs batch submission benchmarks (requires a running node).
+///
+/// Each iteration creates `accounts` new private wallets and deploys them with one transaction
+/// each, first one after the other with `submit_new_transaction` and then, on another set of new
+/// wallets, with a single `batch_execute` call. Using new accounts keeps the transactions
+/// independent, so no block advancement waits are needed between them.
+pub async fn run_batch_benchmarks(
+    client: &mut Client<FilesystemKeyStore>,
+    config: &BenchConfig,
+    accounts: usize,
+) -> anyhow::Result<Vec<BenchmarkResult>> {
+    let mut serial = BenchmarkResult::new(format!("serial ({accounts} txs)"));
+    let mut batch = BenchmarkResult::new(format!("batch ({accounts} txs)"));
+
+    for i in 0..config.iterations {
+        let iter_t = Instant::now();
+
+        let requests = create_deploy_requests(client, &config.store_path, accounts).await?;
+        let (results, serial_duration) = Box::pin(measure_time_async(|| async {
+            let mut results = Vec::with_capacity(requests.len());
+            for (account_id, tx_request) in requests {
+                results.push(client.submit_new_transaction(account_id, tx_request).await);
+            }
+            results
+        }))
+        .await;
+        ensure_all_submitted(results)?;
+        serial.add_iteration(serial_duration);
+
+        let requests = create_deploy_requests(client, &config.store_path, accounts).await?;
+        let (results, batch_duration) =
+            Box::pin(measure_time_async(|| async { client.batch_execute(requests).await })).await;
+        ensure_all_submitted(results?)?;
+        batch.add_iteration(batch_duration);
+
+        println!(
+            "  Iteration {}/{}: serial {:.2?}, batch {:.2?} (total: {:.2?})",
+            i + 1,
+            config.iterations,
+            serial_duration,
+            batch_duration,
+            iter_t.elapsed()
+        );
+    }
+
+    Ok(vec![
+        serial.with_metadata(format!(
+            "{accounts} account deployments submitted one by one with `submit_new_transaction`"
+        )),
+        batch.with_metadata(format!(
+            "{accounts} account deployments submitted with a single `batch_execute` call"
+        )),
+    ])
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Adds `accounts` new private wallets to the client, returning an empty transaction request
+/// for each of them. Executing the request deploys the wallet.
+async fn create_deploy_requests(
+    client: &mut Client<FilesystemKeyStore>,
+    store_path: &Path,
+    accounts: usize,
+) -> anyhow::Result<Vec<(AccountId, TransactionRequest)>> {
+    let keystore = FilesystemKeyStore::new(store_path.join("keystore"))?;
+    let mut requests = Vec::with_capacity(accounts);
+
+    for _ in 0..accounts {
+        let account_id = add_private_wallet(client, &keystore).await?;
+        requests.push((account_id, TransactionRequestBuilder::new().build()?));
+    }
+
+    Ok(requests)
+}
+
+/// Adds a new private wallet with a random seed to the client and stores its signing key in
+/// `keystore`. The wallet is deployed by its first transaction.
+pub(crate) async fn add_private_wallet(
+    client: &mut Client<FilesystemKeyStore>,
+    keystore: &FilesystemKeyStore,
+) -> anyhow::Result<AccountId> {
+    let mut seed = [0u8; 32];
+    rand::rng().fill(&mut seed);
+    let sk = AuthSecretKey::new_falcon512_poseidon2_with_rng(&mut ChaCha20Rng::from_seed(seed));
+
+    let wallet_component = AccountComponent::new(
+        BasicWallet::code().as_library().clone(),
+        vec![],
+        BasicWallet::component_metadata(),
+    )?;
+    let account = AccountBuilder::new(seed)
+        .with_auth_component(AuthSingleSig::new(
+            sk.public_key().to_commitment(),
+            AuthSchemeId::Falcon512Poseidon2,
+        ))
+        .account_type(AccountType::Private)
+        .with_component(wallet_component)
+        .build_with_schema_commitment()?;
+
+    keystore.add_key(&sk, account.id()).await?;
+    client.add_account(&account, false).await?;
+    Ok(account.id())
+}
+
+/// Fails if any of the transactions of a benchmark iteration could not be submitted.
+fn ensure_all_submitted<T, E: std::fmt::Debug>(results: Vec<Result<T, E>>) -> anyhow::Result<()> {
+    if let Some(err) = results.into_iter().find_map(Result::err) {
+        anyhow::bail!("Transaction submission failed: {err:?}");
+    }
+    Ok(())
+}
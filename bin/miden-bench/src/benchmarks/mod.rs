@@ -1 +1,3 @@
+pub mod batch;
+pub mod sync;
 pub mod transaction;
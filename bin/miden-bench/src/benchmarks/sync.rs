@@ -0,0 +1,165 @@
+use std::path::Path;
+use std::time::Instant;
+
+use miden_client::crypto::RandomCoin;
+use miden_client::keystore::FilesystemKeyStore;
+use miden_client::note::{NoteTag, NoteType};
+use miden_client::store::NoteFilter;
+use miden_client::testing::NoteBuilder;
+use miden_client::transaction::TransactionRequestBuilder;
+use miden_client::{Client, Felt};
+use rand::Rng;
+
+use crate::benchmarks::batch::add_private_wallet;
+use crate::config::{self, BenchConfig};
+use crate::deploy::wait_for_block_advancement;
+use crate::metrics::{BenchmarkResult, measure_time_async, peak_memory_growth, reset_peak_memory};
+
+/// Tag of the notes created to populate the chain for the first benchmark case. Each case uses the
+/// next tag, and the clients measured in a case only track its tag, so they receive the notes of
+/// the case and ignore the rest of the chain's notes.
+const SYNC_BENCH_NOTE_TAG: u32 = 0x5bec_0000;
+
+// ORCHESTRATOR
+// ================================================================================================
+
+/// Runs sync benchmarks (requires a running node), one case per entry of `notes`.
+///
+/// For each case, the chain is first populated with that many public notes spread over `blocks`
+/// new blocks, all carrying the tag of the case. Each iteration then creates a new client in a
+/// temporary store that tracks the tag and measures:
+///
+/// - **cold** - its first `sync_state`, which syncs from genesis and receives every note of the
+///   case.
+/// - **warm** - a second `sync_state` right after, with the store already at the chain tip.
+///
+/// The largest growth of the process' peak resident memory during a cold sync is reported along
+/// with it.
+pub async fn run_sync_benchmarks(
+    client: &mut Client<FilesystemKeyStore>,
+    config: &BenchConfig,
+    blocks: u32,
+    notes: &[usize],
+) -> anyhow::Result<Vec<BenchmarkResult>> {
+    let mut results = Vec::new();
+    for (case, &case_notes) in (0u32..).zip(notes) {
+        let tag = NoteTag::new(SYNC_BENCH_NOTE_TAG + case);
+        results.extend(Box::pin(run_sync_case(client, config, blocks, case_notes, tag)).await?);
+    }
+
+    Ok(results)
+}
+
+/// Populates the chain with `notes` notes carrying `tag` and measures the cold and warm syncs of
+/// new clients tracking it.
+async fn run_sync_case(
+    client: &mut Client<FilesystemKeyStore>,
+    config: &BenchConfig,
+    blocks: u32,
+    notes: usize,
+    tag: NoteTag,
+) -> anyhow::Result<Vec<BenchmarkResult>> {
+    println!("Populating the chain with {notes} notes over {blocks} blocks...");
+    Box::pin(populate_chain(client, &config.store_path, blocks, notes, tag)).await?;
+    let chain_height = client.get_sync_height().await?;
+    println!("Chain populated. Chain height: {chain_height}");
+
+    let mut cold = BenchmarkResult::new(format!("cold sync ({notes} notes, {blocks} blocks)"));
+    let mut warm = BenchmarkResult::new(format!("warm sync ({notes} notes)"));
+    let mut cold_peak_memory: Option<usize> = None;
+
+    for i in 0..config.iterations {
+        let iter_t = Instant::now();
+
+        let store_path = config.store_path.join(format!("sync-bench-{i}"));
+        std::fs::create_dir_all(&store_path)?;
+        let mut sync_client = config::create_client(&config.network, &store_path).await?;
+        sync_client.add_note_tag(tag).await?;
+
+        let memory_baseline = reset_peak_memory();
+        let (result, cold_duration) =
+            Box::pin(measure_time_async(|| async { sync_client.sync_state().await })).await;
+        result?;
+        cold.add_iteration(cold_duration);
+        if let Some(peak_memory) = memory_baseline.and_then(peak_memory_growth) {
+            cold_peak_memory = Some(cold_peak_memory.unwrap_or_default().max(peak_memory));
+        }
+
+        let (result, warm_duration) =
+            Box::pin(measure_time_async(|| async { sync_client.sync_state().await })).await;
+        result?;
+        warm.add_iteration(warm_duration);
+
+        let received_notes = sync_client.get_input_notes(NoteFilter::All).await?.len();
+        drop(sync_client);
+        std::fs::remove_dir_all(&store_path)?;
+
+        println!(
+            "  Iteration {}/{}: cold {:.2?}, warm {:.2?}, {received_notes} notes received (total: {:.2?})",
+            i + 1,
+            config.iterations,
+            cold_duration,
+            warm_duration,
+            iter_t.elapsed()
+        );
+    }
+
+    if let Some(peak_memory) = cold_peak_memory {
+        cold = cold.with_peak_memory(peak_memory);
+    }
+
+    Ok(vec![
+        cold.with_metadata(format!(
+            "first sync of a new client tracking {notes} notes spread over {blocks} blocks"
+        )),
+        warm.with_metadata("sync of a client that is already at the chain tip"),
+    ])
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Creates `notes` public notes tagged with `tag`, split evenly across `blocks` consecutive
+/// blocks. The notes of each block are created by a transaction of a new wallet, so the
+/// transactions don't depend on each other.
+async fn populate_chain(
+    client: &mut Client<FilesystemKeyStore>,
+    store_path: &Path,
+    blocks: u32,
+    notes: usize,
+    tag: NoteTag,
+) -> anyhow::Result<()> {
+    let keystore = FilesystemKeyStore::new(store_path.join("keystore"))?;
+    let notes_per_block = notes.div_ceil(blocks as usize);
+    let mut remaining = notes;
+
+    for block in 0..blocks {
+        let block_notes = remaining.min(notes_per_block);
+        if block_notes > 0 {
+            let account_id = add_private_wallet(client, &keystore).await?;
+
+            let output_notes = (0..block_notes)
+                .map(|_| {
+                    let coin_seed: [u64; 4] = rand::rng().random();
+                    NoteBuilder::new(
+                        account_id,
+                        RandomCoin::new(coin_seed.map(Felt::new_unchecked).into()),
+                    )
+                    .note_type(NoteType::Public)
+                    .tag(tag.into())
+                    .build()
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let tx_request =
+                TransactionRequestBuilder::new().own_output_notes(output_notes).build()?;
+
+            client.submit_new_transaction(account_id, tx_request).await?;
+            remaining -= block_notes;
+        }
+
+        wait_for_block_advancement(client).await?;
+        println!("  Block {}/{blocks}: {block_notes} notes", block + 1);
+    }
+
+    Ok(())
+}
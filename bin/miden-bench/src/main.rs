@@ -44,6 +44,11 @@ struct CliArgs {
 enum Command {
     /// Benchmark transaction operations: read all storage entries from account (requires node)
     Transaction(TransactionArgs),
+    /// Benchmark submitting many independent transactions serially and with `batch_execute`
+    /// (requires node)
+    Batch(BatchArgs),
+    /// Benchmark `sync_state` of new clients against a chain populated with notes (requires node)
+    Sync(SyncArgs),
     /// Deploy a public wallet with configurable storage to the network (requires node)
     Deploy(StorageArgs),
     /// Expand storage: fill entries in a specific map of a deployed account (requires node)
@@ -57,14 +62,16 @@ enum Command {
 impl Command {
     /// Returns whether the command needs the global startup sync against the network.
     ///
-    /// Only commands that read pre-existing chain state (deploy, expand, transaction)
+    /// Only commands that read pre-existing chain state (deploy, expand, transaction, batch, sync)
     /// require a synced client at startup. Import / export operate on a file or call
     /// their own RPC and do not benefit from the pre-sync.
     fn startup_mode(&self) -> StartupMode {
         match self {
-            Command::Deploy(_) | Command::Expand(_) | Command::Transaction(_) => {
-                StartupMode::Synced
-            },
+            Command::Deploy(_)
+            | Command::Expand(_)
+            | Command::Transaction(_)
+            | Command::Batch(_)
+            | Command::Sync(_) => StartupMode::Synced,
             Command::Import(_) | Command::Export(_) => StartupMode::Unsynced,
         }
     }
@@ -103,6 +110,34 @@ struct TransactionArgs {
     iterations: usize,
 }
 
+/// Batch benchmark options
+#[derive(Args, Clone)]
+struct BatchArgs {
+    /// Number of independent transactions submitted per iteration, one per new account
+    #[arg(short, long, default_value = "4")]
+    accounts: usize,
+
+    /// Number of benchmark iterations
+    #[arg(short, long, default_value_t = DEFAULT_ITERATION_COUNT)]
+    iterations: usize,
+}
+
+/// Sync benchmark options
+#[derive(Args, Clone)]
+struct SyncArgs {
+    /// Number of blocks the benchmark notes are spread over
+    #[arg(short, long, default_value = "10")]
+    blocks: u32,
+
+    /// Numbers of public notes created to populate the chain, one benchmark case per number
+    #[arg(long, value_delimiter = ',', default_values_t = [100, 500])]
+    notes: Vec<usize>,
+
+    /// Number of benchmark iterations
+    #[arg(short, long, default_value_t = DEFAULT_ITERATION_COUNT)]
+    iterations: usize,
+}
+
 /// Import an account from a `.mac` file or download a public account by ID.
 ///
 /// Exactly one of `--filename` or `--account-id` must be provided.
@@ -317,6 +352,47 @@ async fn dispatch_command(
                 },
             }
         },
+        Command::Batch(batch_args) => {
+            let start_time = Instant::now();
+            let config = BenchConfig::new(endpoint, batch_args.iterations, store_path);
+            let results = Box::pin(benchmarks::batch::run_batch_benchmarks(
+                client,
+                &config,
+                batch_args.accounts,
+            ))
+            .await;
+            let total_duration = start_time.elapsed();
+
+            match results {
+                Ok(results) => {
+                    report::print_results(&results, "Batch Benchmark", total_duration);
+                },
+                Err(e) => {
+                    panic!("Benchmark failed: {e:?}");
+                },
+            }
+        },
+        Command::Sync(sync_args) => {
+            let start_time = Instant::now();
+            let config = BenchConfig::new(endpoint, sync_args.iterations, store_path);
+            let results = Box::pin(benchmarks::sync::run_sync_benchmarks(
+                client,
+                &config,
+                sync_args.blocks,
+                &sync_args.notes,
+            ))
+            .await;
+            let total_duration = start_time.elapsed();
+
+            match results {
+                Ok(results) => {
+                    report::print_results(&results, "Sync Benchmark", total_duration);
+                },
+                Err(e) => {
+                    panic!("Benchmark failed: {e:?}");
+                },
+            }
+        },
         Command::Import(import_args) => {
             let result = match (import_args.filename, import_args.account_id) {
                 (Some(filename), None) => {
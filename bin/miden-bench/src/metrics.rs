@@ -11,6 +11,9 @@ pub struct BenchmarkResult {
     pub iterations: Vec<Duration>,
     /// Size of output (for serialization benchmarks)
     pub output_size: Option<usize>,
+    /// Growth of the peak resident memory of the process while the benchmark ran, in bytes (for
+    /// sync benchmarks)
+    pub peak_memory: Option<usize>,
     /// Additional metadata
     pub metadata: Option<String>,
 }
@@ -22,6 +25,7 @@ impl BenchmarkResult {
             name: name.into(),
             iterations: Vec::new(),
             output_size: None,
+            peak_memory: None,
             metadata: None,
         }
     }
@@ -37,6 +41,12 @@ impl BenchmarkResult {
         self
     }
 
+    /// Sets the peak memory
+    pub fn with_peak_memory(mut self, bytes: usize) -> Self {
+        self.peak_memory = Some(bytes);
+        self
+    }
+
     /// Sets metadata
     pub fn with_metadata(mut self, metadata: impl Into<String>) -> Self {
         self.metadata = Some(metadata.into());
@@ -74,3 +84,35 @@ where
     let duration = start.elapsed();
     (result, duration)
 }
+
+/// Resets the peak resident memory of the current process to its current resident memory,
+/// through `/proc/self/clear_refs`, and returns the latter in bytes.
+///
+/// Pass the returned baseline to [`peak_memory_growth`] to measure the memory used by the work in
+/// between, regardless of the peak reached earlier in the process. Returns `None` if the peak
+/// can't be reset, e.g. on platforms without procfs.
+pub fn reset_peak_memory() -> Option<usize> {
+    std::fs::write("/proc/self/clear_refs", "5").ok()?;
+    proc_status_bytes("VmRSS:")
+}
+
+/// Returns how much the peak resident memory of the current process grew over `baseline`, the
+/// value returned by [`reset_peak_memory`], in bytes.
+pub fn peak_memory_growth(baseline: usize) -> Option<usize> {
+    Some(proc_status_bytes("VmHWM:")?.saturating_sub(baseline))
+}
+
+/// Returns the memory entry of `/proc/self/status` starting with `prefix`, in bytes.
+fn proc_status_bytes(prefix: &str) -> Option<usize> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let kilobytes = status
+        .lines()
+        .find_map(|line| line.strip_prefix(prefix))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse::<usize>()
+        .ok()?;
+
+    Some(kilobytes * 1024)
+}
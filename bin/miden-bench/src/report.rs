@@ -41,6 +41,11 @@ pub fn print_results(results: &[BenchmarkResult], title: &str, total_duration: D
             row[0] = format!("{}\n  Output: {}", result.name, format_size(size));
         }
 
+        // Add peak memory info to the benchmark name if present
+        if let Some(bytes) = result.peak_memory {
+            row[0] = format!("{}\n  Peak memory growth: {}", row[0], format_size(bytes));
+        }
+
         table.add_row(row);
     }
 
@@ -46,6 +46,14 @@ fn deploy_help() {
     cmd.assert().success().stdout(contains("Deploy a public wallet"));
 }
 
+/// Tests that the sync subcommand help works
+#[test]
+fn sync_help() {
+    let mut cmd = cargo_bin_cmd!("miden-bench");
+    cmd.args(["sync", "--help"]);
+    cmd.assert().success().stdout(contains("Benchmark `sync_state`"));
+}
+
 /// Tests that the import subcommand help works
 #[test]
 fn import_help() {
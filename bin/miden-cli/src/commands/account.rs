@@ -6,19 +6,23 @@ use miden_client::account::{
     AccountCode,
     AccountId,
     AccountInterfaceExt,
+    AccountSummary,
     StorageSlotContent,
 };
 use miden_client::address::{Address, AddressInterface, NetworkId, RoutingParameters};
 use miden_client::asset::Asset;
+use miden_client::block::BlockNumber;
+use miden_client::keystore::{FilesystemKeyStore, Keystore};
 use miden_client::rpc::{GrpcClient, NodeRpcClient};
+use miden_client::store::AccountFilter;
 use miden_client::transaction::{AccountComponentInterface, AccountInterface};
 use miden_client::utils::base_units_to_tokens;
 use miden_client::{Client, PrettyPrint, ZERO};
 
 use crate::config::{CliConfig, RpcConfig};
 use crate::errors::CliError;
-use crate::utils::parse_account_id;
-use crate::{client_binary_name, create_dynamic_table};
+use crate::utils::{parse_account_id, read_passphrase};
+use crate::{CliClient, client_binary_name, create_dynamic_table};
 
 pub const DEFAULT_ACCOUNT_ID_KEY: &str = "default_account_id";
 
@@ -32,12 +36,22 @@ pub struct AccountCmd {
     /// List all accounts monitored by this client (default action).
     #[arg(short, long, group = "action")]
     list: bool,
+    /// When using --list, list the archived accounts instead.
+    #[arg(long, requires = "list")]
+    archived: bool,
     /// Show details of the account for the specified ID or hex prefix.
     #[arg(short, long, group = "action", value_name = "ID")]
     show: Option<String>,
     /// When using --show, include the account code in the output.
     #[arg(long, requires = "show")]
     with_code: bool,
+    /// When using --show, include the registered MASM source of the account code in the output.
+    #[arg(long, requires = "show")]
+    source: bool,
+    /// When using --show, display the account state as of the given block instead of the latest
+    /// one.
+    #[arg(long, value_name = "block_num", requires = "show")]
+    at_block: Option<u32>,
     /// Manages default account for transaction execution.
     ///
     /// If no ID is provided it will display the current default account ID.
@@ -45,10 +59,50 @@ pub struct AccountCmd {
     /// account to the provided ID.
     #[arg(short, long, group = "action", value_name = "ID")]
     default: Option<Option<String>>,
+    /// Encrypt the secret keys of the keystore with a passphrase.
+    ///
+    /// Once encrypted, the passphrase is prompted for whenever the keystore is opened, unless it
+    /// is provided through the `MIDEN_KEYSTORE_PASSPHRASE` environment variable.
+    #[arg(long, group = "action")]
+    encrypt_keystore: bool,
+    /// Archive the account with the specified ID or hex prefix.
+    ///
+    /// Archived accounts keep their state and history, but are hidden from the account list and
+    /// skipped by sync.
+    #[arg(long, group = "action", value_name = "ID")]
+    archive: Option<String>,
+    /// Restore the archived account with the specified ID or hex prefix.
+    #[arg(long, group = "action", value_name = "ID")]
+    unarchive: Option<String>,
+    /// Mark the account with the specified ID or hex prefix as watch-only.
+    ///
+    /// Watch-only accounts are still tracked and synced, but can't be used as the sender of a
+    /// transaction.
+    #[arg(long, group = "action", value_name = "ID")]
+    set_watch_only: Option<String>,
+    /// Clear the watch-only mark of the account with the specified ID or hex prefix.
+    #[arg(long, group = "action", value_name = "ID")]
+    clear_watch_only: Option<String>,
+    /// Export the secret keys of the account with the specified ID or hex prefix to a keystore
+    /// directory, such as: `--export-keys 0x1234567 ./account-keys`.
+    ///
+    /// Only the keys referenced by the account's authentication component are exported. The
+    /// directory can then be used as the keystore of another client; if it already holds a
+    /// keystore, the keys are added to it.
+    #[arg(long, group = "action", num_args = 2, value_names = ["ID", "PATH"])]
+    export_keys: Option<Vec<String>>,
 }
 
 impl AccountCmd {
-    pub async fn execute<AUTH>(&self, mut client: Client<AUTH>) -> Result<(), CliError> {
+    pub async fn execute(&self, client: CliClient) -> Result<(), CliError> {
+        if let Some(args) = &self.export_keys {
+            let account_id = parse_account_id(&client, &args[0]).await?;
+            let exported = client.export_keystore_for_account(account_id, &args[1]).await?;
+            println!("Exported {exported} secret key(s) of account {account_id} to {}", args[1]);
+            return Ok(());
+        }
+
+        let mut client = client.into_inner();
         let cli_config = CliConfig::load()?;
         match self {
             AccountCmd {
@@ -58,7 +112,15 @@ impl AccountCmd {
                 ..
             } => {
                 let account_id = parse_account_id(&client, id).await?;
-                show_account(&client, account_id, &cli_config.rpc, self.with_code).await?;
+                show_account(
+                    &mut client,
+                    account_id,
+                    &cli_config.rpc,
+                    self.with_code,
+                    self.source,
+                    self.at_block.map(BlockNumber::from),
+                )
+                .await?;
             },
             AccountCmd {
                 list: false,
@@ -94,19 +156,73 @@ impl AccountCmd {
                     },
                 }
             },
+            AccountCmd { encrypt_keystore: true, .. } => {
+                encrypt_keystore(&cli_config)?;
+            },
+            AccountCmd { archive: Some(id), .. } => {
+                let account_id = parse_account_id(&client, id).await?;
+                client.archive_account(account_id).await?;
+                println!("Account {account_id} archived.");
+            },
+            AccountCmd { unarchive: Some(id), .. } => {
+                let account_id = parse_account_id(&client, id).await?;
+                client.unarchive_account(account_id).await?;
+                println!("Account {account_id} unarchived.");
+            },
+            AccountCmd { set_watch_only: Some(id), .. } => {
+                let account_id = parse_account_id(&client, id).await?;
+                client.set_account_watch_only(account_id, true).await?;
+                println!("Account {account_id} marked as watch-only.");
+            },
+            AccountCmd { clear_watch_only: Some(id), .. } => {
+                let account_id = parse_account_id(&client, id).await?;
+                client.set_account_watch_only(account_id, false).await?;
+                println!("Account {account_id} is no longer watch-only.");
+            },
             _ => {
-                list_accounts(client).await?;
+                let filter = if self.archived {
+                    AccountFilter::Archived
+                } else {
+                    AccountFilter::Active
+                };
+                list_accounts(client, filter).await?;
             },
         }
         Ok(())
     }
 }
 
+// ENCRYPT KEYSTORE
+// ================================================================================================
+
+fn encrypt_keystore(cli_config: &CliConfig) -> Result<(), CliError> {
+    let keys_directory = &cli_config.secret_keys_directory;
+    if FilesystemKeyStore::is_encrypted(keys_directory) {
+        return Err(CliError::Input(format!(
+            "the keystore at {} is already encrypted",
+            keys_directory.display()
+        )));
+    }
+
+    let passphrase = read_passphrase("New keystore passphrase: ")?;
+    if passphrase.is_empty() {
+        return Err(CliError::Input("the passphrase can't be empty".to_string()));
+    }
+    if read_passphrase("Confirm passphrase: ")? != passphrase {
+        return Err(CliError::Input("the passphrases don't match".to_string()));
+    }
+
+    FilesystemKeyStore::encrypt_keystore(keys_directory.clone(), &passphrase)
+        .map_err(CliError::KeyStore)?;
+    println!("Keystore at {} encrypted successfully.", keys_directory.display());
+    Ok(())
+}
+
 // LIST ACCOUNTS
 // ================================================================================================
 
-async fn list_accounts<AUTH>(client: Client<AUTH>) -> Result<(), CliError> {
-    let accounts = client.get_account_headers().await?;
+async fn list_accounts<AUTH>(client: Client<AUTH>, filter: AccountFilter) -> Result<(), CliError> {
+    let accounts = client.get_filtered_account_headers(filter).await?;
 
     let mut table = create_dynamic_table(&["Account ID", "Kind", "Type", "Nonce", "Status"]);
     for (acc, _acc_seed) in &accounts {
@@ -133,13 +249,19 @@ async fn list_accounts<AUTH>(client: Client<AUTH>) -> Result<(), CliError> {
 // SHOW ACCOUNT
 // ================================================================================================
 
-async fn show_account<AUTH>(
-    client: &Client<AUTH>,
+async fn show_account<AUTH: Keystore + Sync>(
+    client: &mut Client<AUTH>,
     account_id: AccountId,
     rpc_config: &RpcConfig,
     with_code: bool,
+    with_source: bool,
+    at_block: Option<BlockNumber>,
 ) -> Result<(), CliError> {
-    let account = if let Some(account) = client.get_account(account_id).await? {
+    let mut summary = None;
+    let account = if let Some(block_num) = at_block {
+        client.get_account_at_block(account_id, block_num).await?
+    } else if let Some(account) = client.get_account(account_id).await? {
+        summary = Some(client.account_summary(account_id).await?);
         account
     } else {
         println!("Account {account_id} is not tracked by the client. Fetching from the network...");
@@ -160,7 +282,7 @@ async fn show_account<AUTH>(
     let token_symbol = faucet_component_from_account(&account)
         .ok()
         .map(|faucet| faucet.symbol().to_string());
-    print_summary_table(&account, network_id, token_symbol.as_deref());
+    print_summary_table(&account, network_id, token_symbol.as_deref(), summary.as_ref());
 
     // Vault Table
     {
@@ -173,12 +295,32 @@ async fn show_account<AUTH>(
                 Asset::Fungible(fungible_asset) => {
                     let faucet_id = fungible_asset.faucet_id();
                     let amount_u64 = fungible_asset.amount().as_u64();
-                    let (faucet, amount) = match get_faucet_component(client, faucet_id).await {
+                    let (faucet, decimals) = match get_faucet_component(client, faucet_id).await {
                         Ok(faucet_component) => (
                             faucet_component.symbol().to_string(),
-                            base_units_to_tokens(amount_u64, faucet_component.decimals()),
+                            Some(faucet_component.decimals()),
+                        ),
+                        Err(_) => (faucet_id.prefix().to_hex(), None),
+                    };
+                    let format_amount = |amount: u64| match decimals {
+                        Some(decimals) => base_units_to_tokens(amount, decimals),
+                        None => amount.to_string(),
+                    };
+
+                    // Pending transactions may have changed the balance since the last committed
+                    // state of the account.
+                    let committed = summary
+                        .as_ref()
+                        .and_then(|summary| summary.balances.get(&faucet_id))
+                        .map(|balance| balance.committed)
+                        .filter(|committed| *committed != amount_u64);
+                    let amount = match committed {
+                        Some(committed) => format!(
+                            "{} (committed: {})",
+                            format_amount(amount_u64),
+                            format_amount(committed)
                         ),
-                        Err(_) => (faucet_id.prefix().to_hex(), amount_u64.to_string()),
+                        None => format_amount(amount_u64),
                     };
                     ("Fungible Asset", faucet, amount)
                 },
@@ -235,14 +377,34 @@ async fn show_account<AUTH>(
         println!("{table}");
     }
 
+    // Account code source
+    if with_source {
+        match client.get_account_code_source(account_id).await? {
+            Some(source) => {
+                println!("Source: \n");
+
+                let mut table = create_dynamic_table(&["Source"]);
+                table.add_row(vec![&source]);
+                println!("{table}");
+            },
+            None => println!("No MASM source is registered for the code of account {account_id}."),
+        }
+    }
+
     Ok(())
 }
 
 // HELPERS
 // ================================================================================================
 
-/// Prints a summary table with account information.
-fn print_summary_table(account: &Account, network_id: NetworkId, token_symbol: Option<&str>) {
+/// Prints a summary table with account information. The pending transactions and consumable notes
+/// of the account are included when its `summary` is provided.
+fn print_summary_table(
+    account: &Account,
+    network_id: NetworkId,
+    token_symbol: Option<&str>,
+    summary: Option<&AccountSummary>,
+) {
     let mut table = create_dynamic_table(&["Account Information"]);
     table
         .load_preset(presets::UTF8_HORIZONTAL_ONLY)
@@ -256,6 +418,7 @@ fn print_summary_table(account: &Account, network_id: NetworkId, token_symbol: O
     ]);
     table.add_row(vec![Cell::new("Kind"), Cell::new(account_kind_display_name(token_symbol))]);
     table.add_row(vec![Cell::new("Type"), Cell::new(account.id().account_type().to_string())]);
+    table.add_row(vec![Cell::new("Components"), Cell::new(account_components_display(account))]);
     table.add_row(vec![
         Cell::new("Code Commitment"),
         Cell::new(account.code().commitment().to_string()),
@@ -269,6 +432,16 @@ fn print_summary_table(account: &Account, network_id: NetworkId, token_symbol: O
         Cell::new("Nonce"),
         Cell::new(account.nonce().as_canonical_u64().to_string()),
     ]);
+    if let Some(summary) = summary {
+        table.add_row(vec![
+            Cell::new("Pending Transactions"),
+            Cell::new(summary.pending_transactions.to_string()),
+        ]);
+        table.add_row(vec![
+            Cell::new("Consumable Notes"),
+            Cell::new(summary.consumable_notes.to_string()),
+        ]);
+    }
 
     println!("{table}\n");
 }
@@ -313,6 +486,17 @@ fn account_kind_display_name(token_symbol: Option<&str>) -> String {
     }
 }
 
+/// Returns the names of the components installed on the account (wallet, faucet, authentication
+/// and custom components), one per line.
+fn account_components_display(account: &Account) -> String {
+    AccountInterface::from_account(account)
+        .components()
+        .iter()
+        .map(AccountComponentInterface::name)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 /// Returns `true` if the account code exposes the [`BasicWallet`] component interface.
 ///
 /// Takes the [`AccountCode`] rather than the full [`Account`] so callers can avoid loading the
@@ -1,7 +1,14 @@
 use clap::ValueEnum;
 use miden_client::Client;
 use miden_client::account::AccountId;
-use miden_client::address::{Address, AddressId, AddressInterface, NetworkId, RoutingParameters};
+use miden_client::address::{
+    Address,
+    AddressId,
+    AddressInterface,
+    NetworkId,
+    RoutingParameters,
+    address_to_uri,
+};
 
 use crate::errors::CliError;
 use crate::utils::parse_account_id;
@@ -28,6 +35,17 @@ impl From<CliAddressInterface> for AddressInterface {
 pub enum AddressSubCommand {
     /// List all addresses an account can be referenced by
     List { account_id: Option<String> },
+    /// Show the addresses of an account.
+    ///
+    /// With `--uri`, each address is printed on its own line as a
+    /// `miden:<ADDRESS>?network=<NETWORK>` URI, e.g. to be shared through a QR code.
+    Show {
+        /// Account whose addresses to show
+        account_id: String,
+        /// Print the addresses as `miden:` URIs
+        #[arg(long)]
+        uri: bool,
+    },
     /// Add a previously-encoded address to an account.
     ///
     /// To produce the bech32 `ADDRESS` argument from its fields, see the `encode` subcommand.
@@ -37,6 +55,19 @@ pub enum AddressSubCommand {
         /// Bech32-encoded address to track
         address: String,
     },
+    /// Create a new address for an account and start tracking it, along with its note tag.
+    ///
+    /// The interface must be implemented by the account (e.g. faucets can't use `basic-wallet`).
+    New {
+        /// Account to create the address for
+        account_id: String,
+        /// Interface the address exposes.
+        #[arg(long, value_enum)]
+        interface: CliAddressInterface,
+        /// Optional tag length
+        #[arg(long)]
+        tag_len: Option<u8>,
+    },
     /// Remove the given address
     Remove {
         /// Account that owns the address to remove
@@ -72,9 +103,18 @@ impl AddressCmd {
             Some(AddressSubCommand::List { account_id: Some(account_id) }) => {
                 list_account_addresses(client, account_id, network_id).await?;
             },
+            Some(AddressSubCommand::Show { account_id, uri: true }) => {
+                show_address_uris(client, account_id, &network_id).await?;
+            },
+            Some(AddressSubCommand::Show { account_id, uri: false }) => {
+                list_account_addresses(client, account_id, network_id).await?;
+            },
             Some(AddressSubCommand::Add { account_id, address }) => {
                 add_address(client, account_id.clone(), address.clone(), network_id).await?;
             },
+            Some(AddressSubCommand::New { account_id, interface, tag_len }) => {
+                new_address(client, account_id, interface.clone(), *tag_len, network_id).await?;
+            },
             Some(AddressSubCommand::Remove { account_id, address }) => {
                 remove_address(client, account_id.clone(), address.clone(), network_id).await?;
             },
@@ -98,31 +138,34 @@ fn print_account_addresses(account_id: &String, addresses: &Vec<Address>, networ
     let mut table = create_dynamic_table(&["Address", "Interface"]);
     for address in addresses {
         let address_bech32 = address.encode(network_id.clone());
-        let interface = match address.interface() {
-            Some(interface) => interface.to_string(),
-            None => "Unspecified".to_string(),
-        };
-        table.add_row(vec![address_bech32, interface]);
+        table.add_row(vec![address_bech32, address_interface(address)]);
     }
 
     println!("{table}");
 }
 
+fn address_interface(address: &Address) -> String {
+    match address.interface() {
+        Some(interface) => interface.to_string(),
+        None => "Unspecified".to_string(),
+    }
+}
+
 async fn list_all_addresses<AUTH>(
     client: Client<AUTH>,
     network_id: NetworkId,
 ) -> Result<(), CliError> {
     println!("Listing addresses for all accounts:\n");
-    let accounts = client.get_account_headers().await?;
-    for (acc_header, _) in accounts {
-        let addresses = client
-            .account_reader(acc_header.id())
-            .addresses()
-            .await
-            .expect("account is expected to exist if retrieved");
-        print_account_addresses(&acc_header.id().to_string(), &addresses, &network_id);
-        println!();
+    let mut table = create_dynamic_table(&["Account ID", "Address", "Interface"]);
+    for (address, account_id) in client.list_all_addresses().await? {
+        table.add_row(vec![
+            account_id.to_hex(),
+            address.encode(network_id.clone()),
+            address_interface(&address),
+        ]);
     }
+
+    println!("{table}");
     Ok(())
 }
 
@@ -140,6 +183,22 @@ async fn list_account_addresses<AUTH>(
     Ok(())
 }
 
+async fn show_address_uris<AUTH>(
+    client: Client<AUTH>,
+    account_id: &String,
+    network_id: &NetworkId,
+) -> Result<(), CliError> {
+    let id = parse_account_id(&client, account_id).await?;
+    let addresses = client.account_reader(id).addresses().await.map_err(|_| {
+        CliError::Input(format!("The account with id `{account_id}` does not exist"))
+    })?;
+
+    for address in addresses {
+        println!("{}", address_to_uri(&address, network_id));
+    }
+    Ok(())
+}
+
 async fn add_address<AUTH>(
     mut client: Client<AUTH>,
     account_id: String,
@@ -156,6 +215,25 @@ async fn add_address<AUTH>(
     Ok(())
 }
 
+async fn new_address<AUTH>(
+    mut client: Client<AUTH>,
+    account_id: &str,
+    interface: CliAddressInterface,
+    tag_len: Option<u8>,
+    network_id: NetworkId,
+) -> Result<(), CliError> {
+    let account_id = parse_account_id(&client, account_id).await?;
+    let routing_params = build_routing_parameters(interface, tag_len)?;
+    let address = client.new_address(account_id, routing_params).await?;
+
+    println!(
+        "Address created: {} - Account Id {account_id} - Note tag: {}",
+        address.encode(network_id),
+        address.to_note_tag()
+    );
+    Ok(())
+}
+
 async fn remove_address<AUTH>(
     mut client: Client<AUTH>,
     account_id: String,
@@ -180,19 +258,27 @@ async fn encode_address<AUTH>(
     network_id: NetworkId,
 ) -> Result<(), CliError> {
     let account_id = parse_account_id(&client, account_id).await?;
-    let interface = interface.into();
-    let routing_params = match tag_len {
-        Some(tag_len) => RoutingParameters::new(interface)
-            .with_note_tag_len(tag_len)
-            .map_err(|e| CliError::Address(e, String::new()))?,
-        None => RoutingParameters::new(interface),
-    };
+    let routing_params = build_routing_parameters(interface, tag_len)?;
     let address = Address::new(account_id).with_routing_parameters(routing_params);
 
     println!("{}", address.encode(network_id));
     Ok(())
 }
 
+/// Builds the routing parameters for the given interface, with an optional note tag length.
+fn build_routing_parameters(
+    interface: CliAddressInterface,
+    tag_len: Option<u8>,
+) -> Result<RoutingParameters, CliError> {
+    let routing_params = RoutingParameters::new(interface.into());
+    match tag_len {
+        Some(tag_len) => routing_params
+            .with_note_tag_len(tag_len)
+            .map_err(|e| CliError::Address(e, String::new())),
+        None => Ok(routing_params),
+    }
+}
+
 /// Decodes a bech32 address and verifies it encodes the expected account ID and network.
 fn decode_account_address(
     encoded: &str,
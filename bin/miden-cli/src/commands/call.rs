@@ -82,8 +82,10 @@ impl CallCmd {
         // it at compile time to resolve `call.<digest>` to a known procedure — otherwise it
         // emits a "phantom target" warning. Dynamic linking provides that resolution without
         // embedding the library bytes in the script.
-        let linked_builder =
-            client.code_builder().with_dynamically_linked_library(package.mast.as_ref())?;
+        let linked_builder: CodeBuilder = client
+            .code_builder()
+            .with_dynamically_linked_library(package.mast.as_ref())?
+            .into();
 
         // 1) Read-only execution to get return values. If `result_count` is unknown we skip
         // the drop sequence and let `print_output_stack` auto-detect results from the stack.
@@ -2,12 +2,13 @@ use std::collections::BTreeMap;
 use std::fs;
 #[cfg(feature = "dap")]
 use std::net::SocketAddr;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use clap::Parser;
-use miden_client::account::AccountId;
+use miden_client::account::{Account, AccountFile, AccountId};
 use miden_client::keystore::Keystore;
-use miden_client::transaction::{ForeignAccount, TransactionScript};
+use miden_client::transaction::{ForeignAccount, TransactionRequestBuilder, TransactionScript};
+use miden_client::utils::Deserializable;
 use miden_client::vm::{AdviceInputs, MIN_STACK_DEPTH};
 use miden_client::{Client, Felt, Word};
 use serde::{Deserialize, Deserializer, Serialize, de};
@@ -17,6 +18,7 @@ use crate::utils::{
     get_input_acc_id_by_prefix_or_default,
     print_executed_program_stack,
     print_executed_program_stack_hex_words,
+    print_executed_transaction,
 };
 
 // EXEC COMMAND
@@ -29,6 +31,12 @@ pub struct ExecCmd {
     #[arg(short = 'a', long = "account")]
     account_id: Option<String>,
 
+    /// Path to a serialized account or account file to execute the program against, instead of
+    /// the stored state of the account. The account doesn't need to be tracked by the client,
+    /// and the results aren't persisted
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["account_id", "simulate"])]
+    account_file: Option<PathBuf>,
+
     /// Path to script's source code to be executed
     #[arg(long, short)]
     script_path: String,
@@ -57,10 +65,16 @@ pub struct ExecCmd {
     #[arg(long, default_value_t = false)]
     hex_words: bool,
 
+    /// Run the script as a full transaction without submitting it, and print its effects
+    /// (output notes, account changes and the final account commitment) instead of the output
+    /// stack
+    #[arg(long, default_value_t = false, conflicts_with = "hex_words")]
+    simulate: bool,
+
     /// Start a DAP debug adapter server on the given address (e.g. "127.0.0.1:4711")
     /// and wait for a DAP client to connect before executing.
     #[cfg(feature = "dap")]
-    #[arg(long = "start-debug-adapter")]
+    #[arg(long = "start-debug-adapter", conflicts_with = "account_file")]
     start_debug_adapter: Option<SocketAddr>,
 }
 
@@ -77,8 +91,12 @@ impl ExecCmd {
             ));
         }
 
-        let account_id =
-            get_input_acc_id_by_prefix_or_default(&client, self.account_id.clone()).await?;
+        let account_state = self.account_file.as_deref().map(read_account_state).transpose()?;
+        let account_id = if let Some(account) = &account_state {
+            account.id()
+        } else {
+            get_input_acc_id_by_prefix_or_default(&client, self.account_id.clone()).await?
+        };
 
         let inputs = match &self.inputs_path {
             Some(input_file) => {
@@ -96,6 +114,11 @@ impl ExecCmd {
             None => vec![],
         };
 
+        if self.simulate {
+            let tx_script = client.code_builder().compile_tx_script(script_path.as_path())?;
+            return simulate_program(&mut client, account_id, tx_script, inputs).await;
+        }
+
         let advice_inputs = AdviceInputs::default().with_map(inputs);
 
         // Pass the path rather than the source string so the assembler's source manager
@@ -104,10 +127,28 @@ impl ExecCmd {
         // highlight the current line or open the file.
         let tx_script = client.code_builder().compile_tx_script(script_path.as_path())?;
 
-        let output_stack =
-            self.execute_program(&mut client, account_id, tx_script, advice_inputs).await?;
+        let output_stack = if let Some(account) = account_state {
+            client
+                .execute_program_with_account_state(
+                    account,
+                    tx_script,
+                    advice_inputs,
+                    BTreeMap::new(),
+                )
+                .await
+                .map_err(|err| {
+                    CliError::Exec(err.into(), "error executing the program".to_string())
+                })?
+        } else {
+            self.execute_program(&mut client, account_id, tx_script, advice_inputs).await?
+        };
 
         println!("Program executed successfully");
+        if self.account_file.is_some() {
+            println!(
+                "The program ran against the account state from the file; nothing was persisted"
+            );
+        }
         if self.hex_words {
             print_executed_program_stack_hex_words(&output_stack);
         } else {
@@ -167,6 +208,52 @@ impl ExecCmd {
     }
 }
 
+/// Reads the account state to execute a program against from a file holding either an
+/// [`AccountFile`] or a serialized [`Account`].
+fn read_account_state(path: &Path) -> Result<Account, CliError> {
+    let bytes = fs::read(path)?;
+    if let Ok(AccountFile { account, .. }) = AccountFile::read_from_bytes(&bytes) {
+        return Ok(account);
+    }
+
+    Account::read_from_bytes(&bytes).map_err(|err| {
+        CliError::Exec(
+            err.into(),
+            format!("failed to read an account from the file at path {}", path.display()),
+        )
+    })
+}
+
+/// Executes the transaction script as a transaction against the specified account without
+/// submitting it, and prints the resulting effects.
+async fn simulate_program<AUTH: Keystore + Sync + 'static>(
+    client: &mut Client<AUTH>,
+    account_id: AccountId,
+    tx_script: TransactionScript,
+    inputs: Vec<(Word, Vec<Felt>)>,
+) -> Result<(), CliError> {
+    let transaction_request = TransactionRequestBuilder::new()
+        .custom_script(tx_script)
+        .extend_advice_map(inputs)
+        .build()
+        .map_err(|err| {
+            CliError::Exec(err.into(), "error building the transaction request".to_string())
+        })?;
+
+    let transaction_result = client
+        .simulate_transaction(account_id, transaction_request)
+        .await
+        .map_err(|err| CliError::Exec(err.into(), "error simulating the program".to_string()))?;
+
+    println!("Program simulated successfully");
+    print_executed_transaction(client, transaction_result.executed_transaction()).await?;
+    println!(
+        "Final account commitment: {}",
+        transaction_result.final_account_commitment().to_hex()
+    );
+    Ok(())
+}
+
 // SOURCE FILE RELOADING
 // ================================================================================================
 
@@ -11,16 +11,18 @@ use tracing::info;
 
 use crate::errors::CliError;
 use crate::utils::parse_account_id;
-use crate::{FilesystemKeyStore, Parser, get_output_note_with_id_prefix};
+use crate::{FilesystemKeyStore, Parser};
 
 #[derive(Debug, Parser, Clone)]
 #[command(about = "Export client output notes, or account data")]
 pub struct ExportCmd {
-    /// ID (or a valid prefix) of the output note or account to export.
-    #[clap()]
-    id: String,
+    /// IDs (or valid prefixes) of the output notes or account to export. When more than one note
+    /// is given, all notes are written to a single note set file.
+    #[clap(required = true, num_args = 1..)]
+    ids: Vec<String>,
 
-    /// Desired filename for the binary file. Defaults to the note ID if not provided.
+    /// Desired filename for the binary file. Defaults to the note ID (or `notes.mns` when
+    /// exporting multiple notes) if not provided.
     #[arg(short, long)]
     filename: Option<PathBuf>,
 
@@ -61,9 +63,18 @@ impl ExportCmd {
         keystore: FilesystemKeyStore,
     ) -> Result<(), CliError> {
         if self.account {
-            export_account(&client, &keystore, self.id.as_str(), self.filename.clone()).await?;
+            let [account_id] = self.ids.as_slice() else {
+                return Err(CliError::Export(
+                    "Only one account can be exported at a time".to_string(),
+                ));
+            };
+            export_account(&client, &keystore, account_id, self.filename.clone()).await?;
         } else if let Some(export_type) = &self.export_type {
-            export_note(&mut client, self.id.as_str(), self.filename.clone(), export_type).await?;
+            if let [note_id] = self.ids.as_slice() {
+                export_note(&mut client, note_id, self.filename.clone(), export_type).await?;
+            } else {
+                export_notes(&client, &self.ids, self.filename.clone(), export_type).await?;
+            }
         } else {
             return Err(CliError::Export(
                 "Export type is required when exporting a note".to_string(),
@@ -122,16 +133,15 @@ async fn export_note<AUTH: Keystore + Sync>(
     filename: Option<PathBuf>,
     export_type: &ExportType,
 ) -> Result<File, CliError> {
-    let note_id = get_output_note_with_id_prefix(client, note_id)
+    let note_id = client
+        .resolve_note_by_prefix(note_id)
         .await
-        .map_err(|err| CliError::Export(err.to_string()))?
-        .id();
+        .map_err(|err| CliError::Export(err.to_string()))?;
 
     let output_note = client
-        .get_output_notes(miden_client::store::NoteFilter::Unique(note_id))
+        .get_output_note(note_id)
         .await?
-        .pop()
-        .expect("should have an output note");
+        .ok_or_else(|| CliError::Export(format!("note {note_id} is not an output note")))?;
 
     let note_file = output_note
         .into_note_file(&export_type.into())
@@ -151,3 +161,38 @@ async fn export_note<AUTH: Keystore + Sync>(
     println!("Successfully exported note {note_id}");
     Ok(file)
 }
+
+// EXPORT NOTE SET
+// ================================================================================================
+
+async fn export_notes<AUTH: Keystore + Sync>(
+    client: &Client<AUTH>,
+    note_ids: &[String],
+    filename: Option<PathBuf>,
+    export_type: &ExportType,
+) -> Result<File, CliError> {
+    let mut resolved_ids = Vec::with_capacity(note_ids.len());
+    for note_id in note_ids {
+        let note_id = client
+            .resolve_note_by_prefix(note_id)
+            .await
+            .map_err(|err| CliError::Export(err.to_string()))?;
+        resolved_ids.push(note_id);
+    }
+
+    let note_set = client.export_notes(&resolved_ids, &export_type.into()).await?;
+
+    let file_path = if let Some(filename) = filename {
+        filename
+    } else {
+        let current_dir = std::env::current_dir()?;
+        current_dir.join("notes.mns")
+    };
+
+    info!("Writing file to {}", file_path.to_string_lossy());
+    let mut file = File::create(file_path)?;
+    file.write_all(&note_set).map_err(CliError::IO)?;
+
+    println!("Successfully exported {} notes", resolved_ids.len());
+    Ok(file)
+}
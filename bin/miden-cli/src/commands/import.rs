@@ -1,16 +1,16 @@
-use std::fs::{self, File};
-use std::io::Read;
+use std::fs;
 use std::path::PathBuf;
 
+use miden_client::Client;
 use miden_client::account::{AccountFile, AccountId};
 use miden_client::keystore::Keystore;
-use miden_client::note::NoteFile;
+use miden_client::note::{NoteFile, NoteFileSet};
 use miden_client::utils::Deserializable;
-use miden_client::{Client, ClientError};
 use tracing::info;
 
 use crate::commands::account::{account_code_has_basic_wallet, set_default_account_if_unset};
 use crate::errors::CliError;
+use crate::utils::parse_account_id;
 use crate::{FilesystemKeyStore, Parser};
 
 #[derive(Debug, Parser, Clone)]
@@ -22,6 +22,9 @@ pub struct ImportCmd {
     /// Only relevant for accounts. If set, the account will be overwritten if it already exists.
     #[arg(short, long, default_value_t = false)]
     overwrite: bool,
+    /// IDs (hex or bech32) of public accounts to fetch from the network and start tracking.
+    #[arg(long = "account-id", value_name = "ACCOUNT_ID")]
+    account_ids: Vec<String>,
 }
 
 impl ImportCmd {
@@ -32,15 +35,24 @@ impl ImportCmd {
     ) -> Result<(), CliError> {
         validate_paths(&self.filenames)?;
         for filename in &self.filenames {
-            let note_file = read_note_file(filename.clone());
+            let contents = fs::read(filename)?;
+            let note_commitments = if let Ok(note_file) = NoteFile::read_from_bytes(&contents) {
+                Some(client.import_notes(&[note_file]).await?)
+            } else if is_note_set(&contents) {
+                Some(client.import_note_set(&contents).await?)
+            } else {
+                None
+            };
 
-            if let Ok(note_file) = note_file {
-                match client.import_notes(&[note_file]).await?.first() {
-                    Some(commitment) => println!(
+            if let Some(note_commitments) = note_commitments {
+                if note_commitments.is_empty() {
+                    println!("Notes were already up to date; nothing to import.");
+                }
+                for commitment in note_commitments {
+                    println!(
                         "Successfully imported note with details commitment {}",
                         commitment.to_hex()
-                    ),
-                    None => println!("Note was already up to date; nothing to import."),
+                    );
                 }
             } else {
                 info!(
@@ -62,6 +74,11 @@ impl ImportCmd {
                 }
             }
         }
+
+        for account_id in &self.account_ids {
+            let account_id = parse_account_id(&client, account_id).await?;
+            import_public_account(&mut client, account_id).await?;
+        }
         Ok(())
     }
 }
@@ -94,15 +111,31 @@ async fn import_account<AUTH>(
     Ok(account_id)
 }
 
+/// Fetches a public account from the network and starts tracking it.
+async fn import_public_account<AUTH: Keystore + Sync + 'static>(
+    client: &mut Client<AUTH>,
+    account_id: AccountId,
+) -> Result<(), CliError> {
+    info!("Fetching public account {account_id} from the network...");
+    client.import_account_by_id(account_id).await?;
+
+    println!("Successfully imported account {account_id}");
+
+    if let Some(code) = client.get_account_code(account_id).await?
+        && account_code_has_basic_wallet(account_id, &code)
+    {
+        set_default_account_if_unset(client, account_id).await?;
+    }
+
+    Ok(())
+}
+
 // IMPORT NOTE
 // ================================================================================================
 
-fn read_note_file(filename: PathBuf) -> Result<NoteFile, CliError> {
-    let mut contents = vec![];
-    let mut _file = File::open(filename).and_then(|mut f| f.read_to_end(&mut contents))?;
-
-    NoteFile::read_from_bytes(&contents)
-        .map_err(|err| ClientError::DataDeserializationError(err).into())
+/// Returns whether the provided bytes hold a serialized [`NoteFileSet`].
+fn is_note_set(contents: &[u8]) -> bool {
+    NoteFileSet::read_from_bytes(contents).is_ok()
 }
 
 // HELPERS
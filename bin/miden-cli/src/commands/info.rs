@@ -5,4 +5,8 @@ pub struct InfoCmd {
     /// Display detailed RPC node status information.
     #[arg(short = 'r', long = "rpc-status")]
     pub rpc_status: bool,
+
+    /// List the faucets whose assets appear in the tracked accounts and notes.
+    #[arg(short = 'f', long = "faucets")]
+    pub faucets: bool,
 }
@@ -17,6 +17,7 @@ use crate::config::{
     get_local_miden_dir,
 };
 use crate::errors::CliError;
+use crate::utils::RngSeedFile;
 
 // COMPONENT PACKAGES
 // ================================================================================================
@@ -118,10 +119,18 @@ pub struct InitCmd {
     /// Maximum number of blocks the client can be behind the network.
     #[clap(long)]
     block_delta: Option<u32>,
+
+    /// Hex-encoded 32-byte seed for the client's RNG, e.g. restored from the `rng_seed.toml` file
+    /// of another installation. Replaying the same commands then generates the same keys and
+    /// serial numbers. If not set, a random seed is generated when the client is first used.
+    #[arg(long, value_name = "hex_seed")]
+    rng_seed: Option<String>,
 }
 
 impl InitCmd {
     pub fn execute(&self) -> Result<(), CliError> {
+        let rng_seed = self.rng_seed.as_deref().map(RngSeedFile::parse_seed).transpose()?;
+
         // Determine target directory based on flags
         let (target_miden_dir, config_type) = if self.local {
             (get_local_miden_dir()?, "local")
@@ -220,6 +229,11 @@ impl InitCmd {
         };
         write_packages_files(&resolved_package_dir)?;
 
+        if let Some(seed) = rng_seed {
+            let rng_seed_path = config_dir.join(&cli_config.rng_seed_filepath);
+            RngSeedFile::new(seed).write(&rng_seed_path)?;
+        }
+
         file_handle.write_all(config_as_toml_string.as_bytes()).map_err(|err| {
             CliError::Config("failed to write config file".to_string().into(), err.to_string())
         })?;
@@ -11,6 +11,7 @@ pub mod network_note_status;
 pub mod new_account;
 pub mod new_transactions;
 pub mod notes;
+pub mod store;
 pub mod sync;
 pub mod tags;
 pub mod transactions;
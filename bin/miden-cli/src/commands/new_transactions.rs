@@ -4,13 +4,7 @@ use std::sync::Arc;
 use clap::{Parser, Subcommand, ValueEnum};
 use miden_client::account::AccountId;
 use miden_client::keystore::Keystore;
-use miden_client::note::{
-    BlockNumber,
-    Note,
-    NoteType as MidenNoteType,
-    SwapNote,
-    get_input_note_with_id_prefix,
-};
+use miden_client::note::{BlockNumber, Note, NoteType as MidenNoteType, SwapNote};
 use miden_client::store::NoteRecordError;
 use miden_client::transaction::{
     PaymentNoteDescription,
@@ -20,7 +14,7 @@ use miden_client::transaction::{
     TransactionRequest,
     TransactionRequestBuilder,
 };
-use miden_client::{Client, RemoteTransactionProver};
+use miden_client::{Client, ClientError, RemoteTransactionProver};
 use tracing::info;
 
 use crate::config::CliConfig;
@@ -65,6 +59,11 @@ pub struct MintCmd {
     #[arg(long, default_value_t = false)]
     force: bool,
 
+    /// Number of blocks after the reference block after which the transaction expires. If not
+    /// set, the transaction doesn't expire.
+    #[arg(long, value_name = "BLOCKS")]
+    expire_in: Option<u32>,
+
     /// Flag to delegate proving to the remote prover specified in the config file.
     #[arg(long, default_value_t = false)]
     delegate_proving: bool,
@@ -82,7 +81,7 @@ impl MintCmd {
 
         let target_account_id = parse_account_id(&client, self.target_account_id.as_str()).await?;
 
-        let transaction_request = TransactionRequestBuilder::new()
+        let transaction_request = transaction_request_builder(self.expire_in)
             .build_mint_fungible_asset(
                 fungible_asset,
                 target_account_id,
@@ -136,6 +135,11 @@ pub struct SendCmd {
     #[arg(short = 'i', long)]
     timelock_height: Option<u32>,
 
+    /// Number of blocks after the reference block after which the transaction expires. If not
+    /// set, the transaction doesn't expire.
+    #[arg(long, value_name = "BLOCKS")]
+    expire_in: Option<u32>,
+
     /// Flag to delegate proving to the remote prover specified in the config file
     #[arg(long, default_value_t = false)]
     delegate_proving: bool,
@@ -173,7 +177,7 @@ impl SendCmd {
                 payment_description.with_timelock_height(BlockNumber::from(timelock_height));
         }
 
-        let transaction_request = TransactionRequestBuilder::new()
+        let transaction_request = transaction_request_builder(self.expire_in)
             .build_pay_to_id(payment_description, (&self.note_type).into(), client.rng())
             .map_err(|err| {
                 CliError::Transaction(err.into(), "Failed to build payment transaction".to_string())
@@ -295,6 +299,16 @@ pub struct ConsumeNotesCmd {
     /// Flag to delegate proving to the remote prover specified in the config file.
     #[arg(long, default_value_t = false)]
     delegate_proving: bool,
+
+    /// Consume every note the account can consume, splitting them across as many transactions
+    /// as needed to stay within the per-transaction input note limit.
+    #[arg(long, default_value_t = false, conflicts_with_all = ["list_of_notes", "delegate_proving"])]
+    all: bool,
+
+    /// With `--all`, only consume notes carrying an asset issued by this faucet (ID, hex prefix
+    /// or address).
+    #[arg(long, requires = "all")]
+    faucet: Option<String>,
 }
 
 impl ConsumeNotesCmd {
@@ -304,6 +318,10 @@ impl ConsumeNotesCmd {
     ) -> Result<(), CliError> {
         let force = self.force;
 
+        if self.all {
+            return self.consume_all(client).await;
+        }
+
         let mut input_notes = Vec::new();
 
         for note_id in &self.list_of_notes {
@@ -353,6 +371,64 @@ impl ConsumeNotesCmd {
         )
         .await
     }
+
+    async fn consume_all<AUTH: Keystore + Sync + 'static>(
+        &self,
+        mut client: Client<AUTH>,
+    ) -> Result<(), CliError> {
+        let account_id =
+            get_input_acc_id_by_prefix_or_default(&client, self.account_id.clone()).await?;
+        let faucet_filter = match &self.faucet {
+            Some(faucet) => Some(parse_account_id(&client, faucet).await?),
+            None => None,
+        };
+
+        if !self.force {
+            println!(
+                "\nConsume all consumable notes for {account_id}? Each transaction is submitted without further confirmation (y/N)"
+            );
+            let mut proceed_str: String = String::new();
+            io::stdin().read_line(&mut proceed_str).expect("Should read line");
+
+            if proceed_str.trim().to_lowercase() != "y" {
+                println!("Transaction was cancelled.");
+                return Ok(());
+            }
+        }
+
+        println!("Consuming notes...");
+        let transaction_ids = match client
+            .consume_all_consumable_notes(account_id, faucet_filter)
+            .await
+        {
+            Ok(transaction_ids) => transaction_ids,
+            Err(err) => {
+                if let ClientError::NoteConsumptionIncomplete { submitted_transactions, .. } = &err
+                {
+                    println!(
+                        "Created {} transaction(s) before failing:",
+                        submitted_transactions.len()
+                    );
+                    for transaction_id in submitted_transactions {
+                        println!("\t- {transaction_id}");
+                    }
+                }
+                return Err(err.into());
+            },
+        };
+
+        if transaction_ids.is_empty() {
+            println!("Did not find any consumable notes for {account_id}.");
+            return Ok(());
+        }
+
+        println!("Successfully created {} transaction(s):", transaction_ids.len());
+        for transaction_id in &transaction_ids {
+            println!("\t- {transaction_id}");
+        }
+
+        Ok(())
+    }
 }
 
 // PSWAP COMMANDS
@@ -557,18 +633,28 @@ impl PswapCancelCmd {
 // HELPERS
 // ================================================================================================
 
+/// Returns a new [`TransactionRequestBuilder`], setting the transaction's expiration if
+/// `expire_in` is provided.
+fn transaction_request_builder(expire_in: Option<u32>) -> TransactionRequestBuilder {
+    let builder = TransactionRequestBuilder::new();
+    match expire_in {
+        Some(delta_blocks) => builder.with_expiration(delta_blocks),
+        None => builder,
+    }
+}
+
 /// Resolves a note ID prefix to a fully-qualified [`Note`].
 async fn resolve_input_note<AUTH: Keystore + Sync>(
     client: &Client<AUTH>,
     note_id_prefix: &str,
 ) -> Result<Note, CliError> {
-    let note_record = get_input_note_with_id_prefix(client, note_id_prefix)
-        .await
-        .map_err(|_| {
-            CliError::Input(format!(
-                "Input note ID {note_id_prefix} is neither a valid Note ID nor a prefix of a known Note ID"
-            ))
-        })?;
+    let not_found = || {
+        CliError::Input(format!(
+            "Input note ID {note_id_prefix} is neither a valid Note ID nor a prefix of a known Note ID"
+        ))
+    };
+    let note_id = client.resolve_note_by_prefix(note_id_prefix).await.map_err(|_| not_found())?;
+    let note_record = client.get_input_note(note_id).await?.ok_or_else(not_found)?;
 
     note_record.try_into().map_err(|err: NoteRecordError| {
         CliError::Transaction(err.into(), "Failed to convert note record".to_string())
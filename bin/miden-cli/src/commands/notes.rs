@@ -2,6 +2,7 @@ use clap::ValueEnum;
 use comfy_table::{Attribute, Cell, ContentArrangement, Table, presets};
 use miden_client::address::Address;
 use miden_client::asset::Asset;
+use miden_client::block::BlockNumber;
 use miden_client::keystore::Keystore;
 use miden_client::note::{
     Note,
@@ -9,15 +10,15 @@ use miden_client::note::{
     NoteConsumptionStatus,
     NoteMetadata,
     NoteStorage,
+    Nullifier,
     StandardNote,
-    get_input_note_with_id_prefix,
 };
 use miden_client::store::{InputNoteRecord, NoteFilter as ClientNoteFilter, OutputNoteRecord};
 use miden_client::{Client, ClientError, IdPrefixFetchError, PrettyPrint};
 
 use crate::errors::CliError;
-use crate::utils::{load_faucet_metadata_resolver, parse_account_id};
-use crate::{Parser, create_dynamic_table, get_output_note_with_id_prefix};
+use crate::utils::{FaucetMetadataResolver, load_faucet_metadata_resolver, parse_account_id};
+use crate::{Parser, create_dynamic_table};
 
 #[derive(Clone, Debug, ValueEnum)]
 pub enum NoteFilter {
@@ -50,9 +51,18 @@ pub struct NotesCmd {
     /// List notes with the specified filter. If no filter is provided, all notes will be listed.
     #[arg(short, long, group = "action", default_missing_value="all", num_args=0..=1, value_name = "filter")]
     list: Option<NoteFilter>,
+    /// (only has effect on `--list all`) Only list notes created or consumed after this block.
+    #[arg(long, value_name = "block_num", requires = "list")]
+    since: Option<u32>,
+    /// List output notes created by this client that haven't been consumed yet.
+    #[arg(long, group = "action")]
+    outgoing_pending: bool,
     /// Show note with the specified ID.
     #[arg(short, long, group = "action", value_name = "note_id")]
     show: Option<String>,
+    /// Show the state transitions of the input note with the specified ID, oldest first.
+    #[arg(long, group = "action", value_name = "note_id")]
+    history: Option<String>,
     /// When using --show, include the note code in the output.
     #[arg(long, requires = "show")]
     with_code: bool,
@@ -69,6 +79,41 @@ pub struct NotesCmd {
     /// Fetched notes for tracked note tags will be added to the store.
     #[arg(long, group = "action")]
     fetch: bool,
+    /// Check on-chain whether the specified nullifiers (as hex strings) have been consumed.
+    /// The notes don't need to be tracked by the client.
+    #[arg(long, group = "action", num_args = 1.., value_name = "nullifier")]
+    nullifier_status: Option<Vec<String>>,
+    /// Delete consumed input notes to free up space. Requires `--before`.
+    #[arg(long, group = "action", requires = "before")]
+    prune: bool,
+    /// (only has effect on `--prune`) Only notes consumed before this block are deleted.
+    #[arg(long, value_name = "block_num", requires = "prune")]
+    before: Option<u32>,
+    /// Pin the note with the specified ID, so that `--prune` never deletes it.
+    #[arg(long, group = "action", value_name = "note_id")]
+    pin: Option<String>,
+    /// Unpin the note with the specified ID, so that `--prune` can delete it again.
+    #[arg(long, group = "action", value_name = "note_id")]
+    unpin: Option<String>,
+    /// Consume the smallest consumable notes of a faucet into the specified account in a single
+    /// transaction, compacting its dust notes. Requires `--faucet`.
+    #[arg(long, group = "action", value_name = "account_id", requires = "faucet")]
+    coalesce: Option<String>,
+    /// (only has effect on `--coalesce`) Faucet whose notes are coalesced.
+    #[arg(long, value_name = "faucet_id", requires = "coalesce")]
+    faucet: Option<String>,
+    /// (only has effect on `--coalesce`) Maximum number of notes to consume. Defaults to the
+    /// protocol's limit of input notes per transaction.
+    #[arg(long, value_name = "max_notes", requires = "coalesce")]
+    max_notes: Option<usize>,
+    /// List the unspent SWAP notes that the specified account could fill, along with the assets
+    /// they offer and request.
+    #[arg(long, group = "action", value_name = "account_id")]
+    swaps: Option<String>,
+    /// List the P2IDE notes sent by the specified account that it can still recall, because the
+    /// recipient hasn't consumed them yet.
+    #[arg(long, group = "action", value_name = "account_id")]
+    recallable: Option<String>,
 }
 
 impl NotesCmd {
@@ -80,6 +125,18 @@ impl NotesCmd {
             NotesCmd { list: Some(NoteFilter::Consumable), .. } => {
                 list_consumable_notes(client, None).await?;
             },
+            NotesCmd {
+                list: Some(NoteFilter::All),
+                since: Some(since),
+                ..
+            } => {
+                list_recent_notes(client, (*since).into()).await?;
+            },
+            NotesCmd { list: Some(_), since: Some(_), .. } => {
+                return Err(CliError::Input(
+                    "--since can only be used when listing all notes".to_string(),
+                ));
+            },
             NotesCmd { list: Some(filter), .. } => {
                 list_notes(
                     client,
@@ -87,9 +144,15 @@ impl NotesCmd {
                 )
                 .await?;
             },
+            NotesCmd { outgoing_pending: true, .. } => {
+                list_outgoing_pending_notes(client).await?;
+            },
             NotesCmd { show: Some(id), .. } => {
                 show_note(&mut client, id.to_owned(), self.with_code).await?;
             },
+            NotesCmd { history: Some(id), .. } => {
+                show_note_history(&client, id).await?;
+            },
             NotesCmd { send: Some(args), .. } => {
                 let note_id = &args[0];
                 let address = &args[1];
@@ -98,6 +161,37 @@ impl NotesCmd {
             NotesCmd { fetch: true, .. } => {
                 fetch(&mut client).await?;
             },
+            NotesCmd { nullifier_status: Some(nullifiers), .. } => {
+                nullifier_status(&client, nullifiers).await?;
+            },
+            NotesCmd { prune: true, before: Some(before), .. } => {
+                let removed = client.prune_consumed_notes((*before).into()).await?;
+                println!("Removed {removed} consumed notes.");
+            },
+            NotesCmd { pin: Some(id), .. } => {
+                set_note_pinned(&mut client, id, true).await?;
+            },
+            NotesCmd { unpin: Some(id), .. } => {
+                set_note_pinned(&mut client, id, false).await?;
+            },
+            NotesCmd {
+                coalesce: Some(account_id),
+                faucet: Some(faucet_id),
+                ..
+            } => {
+                let account_id = parse_account_id(&client, account_id).await?;
+                let faucet_id = parse_account_id(&client, faucet_id).await?;
+                let transaction_id = client
+                    .coalesce_dust(account_id, faucet_id, self.max_notes.unwrap_or(usize::MAX))
+                    .await?;
+                println!("Coalesced dust notes in transaction {transaction_id}.");
+            },
+            NotesCmd { swaps: Some(account_id), .. } => {
+                list_swap_notes(&mut client, account_id).await?;
+            },
+            NotesCmd { recallable: Some(account_id), .. } => {
+                list_recallable_notes(&client, account_id).await?;
+            },
             _ => {
                 list_notes(client, ClientNoteFilter::All).await?;
             },
@@ -144,6 +238,57 @@ async fn list_notes<AUTH: Keystore + Sync>(
     Ok(())
 }
 
+/// Lists the notes created or consumed after `since`, excluding that block.
+async fn list_recent_notes<AUTH: Keystore + Sync>(
+    client: Client<AUTH>,
+    since: BlockNumber,
+) -> Result<(), CliError> {
+    let mut input_notes = client.get_input_notes(ClientNoteFilter::CreatedAfter(since)).await?;
+    for note in client.get_input_notes(ClientNoteFilter::ConsumedAfter(since)).await? {
+        if !input_notes
+            .iter()
+            .any(|existing| existing.details_commitment() == note.details_commitment())
+        {
+            input_notes.push(note);
+        }
+    }
+
+    let mut output_notes = client.get_output_notes(ClientNoteFilter::CreatedAfter(since)).await?;
+    for note in client.get_output_notes(ClientNoteFilter::ConsumedAfter(since)).await? {
+        if !output_notes.iter().any(|existing| existing.id() == note.id()) {
+            output_notes.push(note);
+        }
+    }
+
+    print_notes_summary(
+        input_notes.iter().map(|note| note_summary(Some(note), None)),
+        "Input Notes",
+    );
+    print_notes_summary(
+        output_notes.iter().map(|note| note_summary(None, Some(note))),
+        "Output Notes",
+    );
+
+    Ok(())
+}
+
+// LIST OUTGOING PENDING NOTES
+// ================================================================================================
+async fn list_outgoing_pending_notes<AUTH: Keystore + Sync>(
+    client: Client<AUTH>,
+) -> Result<(), CliError> {
+    let output_notes = client
+        .get_unconsumed_output_notes()
+        .await?
+        .into_iter()
+        .map(|output_note_record| note_summary(None, Some(&output_note_record)))
+        .collect::<Vec<CliNoteSummary>>();
+
+    print_notes_summary(output_notes, "Pending Output Notes");
+
+    Ok(())
+}
+
 // SHOW NOTE
 // ================================================================================================
 #[allow(clippy::too_many_lines)]
@@ -152,41 +297,22 @@ async fn show_note<AUTH: Keystore + Sync>(
     note_id: String,
     with_code: bool,
 ) -> Result<(), CliError> {
-    let input_note_record = get_input_note_with_id_prefix(client, &note_id).await;
-    let output_note_record = get_output_note_with_id_prefix(client, &note_id).await;
-
-    // If we don't find an input note nor an output note return an error
-    if matches!(input_note_record, Err(IdPrefixFetchError::NoMatch(_)))
-        && matches!(output_note_record, Err(IdPrefixFetchError::NoMatch(_)))
-    {
-        return Err(CliError::Import(
-            "The specified note ID hex prefix did not match any note".to_string(),
-        ));
-    }
-
-    // If either one of the two match with multiple notes return an error
-    if matches!(input_note_record, Err(IdPrefixFetchError::MultipleMatches(_)))
-        || matches!(output_note_record, Err(IdPrefixFetchError::MultipleMatches(_)))
-    {
-        return Err(CliError::Import(
-            "The specified note ID hex prefix matched with more than one note.".to_string(),
-        ));
-    }
-
-    let input_note_record = input_note_record.ok();
-    let output_note_record = output_note_record.ok();
-
-    // If we match one note as the input note and another one as the output note return an error
-    match (&input_note_record, &output_note_record) {
-        (Some(input_record), Some(output_record))
-            if input_record.id() != Some(output_record.id()) =>
-        {
+    let note_id = match client.resolve_note_by_prefix(&note_id).await {
+        Ok(note_id) => note_id,
+        Err(IdPrefixFetchError::NoMatch(_)) => {
+            return Err(CliError::Import(
+                "The specified note ID hex prefix did not match any note".to_string(),
+            ));
+        },
+        Err(IdPrefixFetchError::MultipleMatches(_)) => {
             return Err(CliError::Import(
                 "The specified note ID hex prefix matched with more than one note.".to_string(),
             ));
         },
-        _ => {},
-    }
+    };
+
+    let input_note_record = client.get_input_note(note_id).await?;
+    let output_note_record = client.get_output_note(note_id).await?;
 
     let mut table = create_dynamic_table(&["Note Information"]);
     table
@@ -317,6 +443,54 @@ async fn show_note<AUTH: Keystore + Sync>(
     Ok(())
 }
 
+// PIN NOTE
+// ================================================================================================
+async fn set_note_pinned<AUTH: Keystore + Sync>(
+    client: &mut Client<AUTH>,
+    note_id: &str,
+    pinned: bool,
+) -> Result<(), CliError> {
+    let note_id = client
+        .resolve_note_by_prefix(note_id)
+        .await
+        .map_err(|e| CliError::Input(format!("note not found: {e}")))?;
+
+    client.set_note_pinned(note_id, pinned).await?;
+    if pinned {
+        println!("Note {note_id} pinned.");
+    } else {
+        println!("Note {note_id} unpinned.");
+    }
+    Ok(())
+}
+
+// SHOW NOTE HISTORY
+// ================================================================================================
+async fn show_note_history<AUTH: Keystore + Sync>(
+    client: &Client<AUTH>,
+    note_id: &str,
+) -> Result<(), CliError> {
+    let note_record = resolve_input_note_record(client, note_id).await?;
+
+    let history = client.get_note_state_history(note_record.details_commitment()).await?;
+    if history.is_empty() {
+        println!("No state transitions recorded for note {note_id}.");
+        return Ok(());
+    }
+
+    let mut table = create_dynamic_table(&["State", "Block", "Timestamp"]);
+    for transition in &history {
+        table.add_row(vec![
+            transition.state().to_string(),
+            transition.block_num().to_string(),
+            transition.timestamp().to_string(),
+        ]);
+    }
+
+    println!("{table}");
+    Ok(())
+}
+
 // LIST CONSUMABLE INPUT NOTES
 // ================================================================================================
 async fn list_consumable_notes<AUTH: Keystore + Sync>(
@@ -332,6 +506,74 @@ async fn list_consumable_notes<AUTH: Keystore + Sync>(
     Ok(())
 }
 
+// LIST SWAP NOTES
+// ================================================================================================
+async fn list_swap_notes<AUTH: Keystore + Sync>(
+    client: &mut Client<AUTH>,
+    account_id: &str,
+) -> Result<(), CliError> {
+    let account_id = parse_account_id(client, account_id).await?;
+    let swap_notes = client.get_swap_notes(account_id).await?;
+
+    let resolver = load_faucet_metadata_resolver()?;
+    let mut table = create_dynamic_table(&["Note ID", "Sender", "Offered", "Requested"]);
+    for (note, swap_data) in swap_notes {
+        // SWAP notes are only returned when their sender is known, so id() is Some.
+        let note_id_hex = note.id().map_or_else(|| "<unknown>".to_string(), |id| id.to_hex());
+        let offered = format_swap_asset(client, &resolver, &swap_data.offered_asset()).await?;
+        let requested = format_swap_asset(client, &resolver, &swap_data.requested_asset()).await?;
+        table.add_row(vec![note_id_hex, swap_data.account_id().to_hex(), offered, requested]);
+    }
+    println!("{table}");
+
+    Ok(())
+}
+
+/// Formats an asset of a swap as `<amount> <faucet>`.
+async fn format_swap_asset<AUTH: Keystore + Sync>(
+    client: &mut Client<AUTH>,
+    resolver: &FaucetMetadataResolver,
+    asset: &Asset,
+) -> Result<String, CliError> {
+    match asset {
+        Asset::Fungible(fungible_asset) => {
+            let (faucet, amount) = resolver.format_fungible_asset(client, fungible_asset).await?;
+            Ok(format!("{amount} {faucet}"))
+        },
+        Asset::NonFungible(non_fungible_asset) => {
+            Ok(format!("1 {}", non_fungible_asset.faucet_id().prefix().to_hex()))
+        },
+    }
+}
+
+// LIST RECALLABLE NOTES
+// ================================================================================================
+async fn list_recallable_notes<AUTH: Keystore + Sync>(
+    client: &Client<AUTH>,
+    account_id: &str,
+) -> Result<(), CliError> {
+    let account_id = parse_account_id(client, account_id).await?;
+    let recallable_notes = client.recallable_notes(account_id).await?;
+    let sync_height = client.get_sync_height().await?;
+
+    let mut table = create_dynamic_table(&["Note ID", "State", "Reclaim Height", "Recallable Now"]);
+    for note in recallable_notes {
+        // Recallable notes always have a reclaim height.
+        let Some(reclaim_height) = note.reclaim_height() else {
+            continue;
+        };
+        table.add_row(vec![
+            note.id().to_hex(),
+            note.state().to_string(),
+            reclaim_height.to_string(),
+            (sync_height >= reclaim_height).to_string(),
+        ]);
+    }
+    println!("{table}");
+
+    Ok(())
+}
+
 // SEND
 // ================================================================================================
 
@@ -341,9 +583,7 @@ async fn send<AUTH: Keystore + Sync>(
     note_id: &str,
     address: &str,
 ) -> Result<(), CliError> {
-    let note_record = get_input_note_with_id_prefix(client, note_id)
-        .await
-        .map_err(|e| CliError::Input(format!("note not found: {e}")))?;
+    let note_record = resolve_input_note_record(client, note_id).await?;
 
     let block_hint = note_record.inclusion_proof().map(|proof| proof.location().block_num());
     let note: Note = note_record
@@ -379,8 +619,56 @@ where
     Ok(())
 }
 
+// NULLIFIER STATUS
+// ================================================================================================
+
+/// Prints whether each of the provided nullifiers has been consumed on-chain, and at which block.
+async fn nullifier_status<AUTH: Keystore + Sync>(
+    client: &Client<AUTH>,
+    nullifiers: &[String],
+) -> Result<(), CliError> {
+    let nullifiers = nullifiers
+        .iter()
+        .map(|nullifier| {
+            Nullifier::from_hex(nullifier).map_err(|err| {
+                CliError::Parse(err.into(), format!("failed to parse nullifier {nullifier}"))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let statuses = client.check_nullifiers_onchain(&nullifiers).await?;
+
+    let mut table = create_dynamic_table(&["Nullifier", "Status", "Consumed At Block"]);
+    for (nullifier, block_num) in statuses {
+        let (status, block) = match block_num {
+            Some(block_num) => ("Spent", block_num.to_string()),
+            None => ("Unspent", "-".to_string()),
+        };
+        table.add_row(vec![nullifier.to_hex(), status.to_string(), block]);
+    }
+    println!("{table}");
+
+    Ok(())
+}
+
 // HELPERS
 // ================================================================================================
+/// Returns the input note whose ID starts with `note_id_prefix`.
+async fn resolve_input_note_record<AUTH: Keystore + Sync>(
+    client: &Client<AUTH>,
+    note_id_prefix: &str,
+) -> Result<InputNoteRecord, CliError> {
+    let note_id = client
+        .resolve_note_by_prefix(note_id_prefix)
+        .await
+        .map_err(|e| CliError::Input(format!("note not found: {e}")))?;
+
+    client
+        .get_input_note(note_id)
+        .await?
+        .ok_or_else(|| CliError::Input(format!("note not found: {note_id} is not an input note")))
+}
+
 fn print_notes_summary<I>(notes: I, header: &str)
 where
     I: IntoIterator<Item = CliNoteSummary>,
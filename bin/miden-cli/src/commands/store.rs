@@ -0,0 +1,41 @@
+use clap::{Parser, Subcommand};
+use miden_client::Client;
+
+use crate::errors::CliError;
+
+#[derive(Debug, Subcommand, Clone)]
+pub enum StoreSubCommand {
+    /// Check the consistency of the data in the client's store
+    Check,
+}
+
+#[derive(Debug, Parser, Clone)]
+#[command(about = "Inspect the client's store")]
+pub struct StoreCmd {
+    #[clap(subcommand)]
+    command: StoreSubCommand,
+}
+
+impl StoreCmd {
+    pub async fn execute<AUTH>(&self, client: Client<AUTH>) -> Result<(), CliError> {
+        match self.command {
+            StoreSubCommand::Check => check_store(&client).await,
+        }
+    }
+}
+
+/// Runs the store integrity checks and prints the issues found, failing if there are any.
+async fn check_store<AUTH>(client: &Client<AUTH>) -> Result<(), CliError> {
+    let report = client.check_store_integrity().await?;
+    if report.is_healthy() {
+        println!("Store {} is healthy.", client.store_identifier());
+        return Ok(());
+    }
+
+    println!("Store {} has integrity issues:", client.store_identifier());
+    for issue in report.issues() {
+        println!("  - {issue}");
+    }
+
+    Err(CliError::StoreIntegrity(report.issues().len()))
+}
@@ -1,28 +1,238 @@
-use clap::Parser;
-use miden_client::Client;
+use std::io;
+use std::time::Duration;
+
+use clap::{Parser, Subcommand};
 use miden_client::keystore::Keystore;
+use miden_client::rpc::RpcError;
+use miden_client::sync::SyncSummary;
+use miden_client::{Client, ClientError};
 
+use crate::create_dynamic_table;
 use crate::errors::CliError;
 
+#[derive(Debug, Subcommand, Clone)]
+pub enum SyncSubCommand {
+    /// Show the most recent sync points and the time elapsed between them
+    History {
+        /// Maximum number of sync points to show
+        #[arg(short, long, default_value_t = 20)]
+        limit: usize,
+    },
+    /// Fetch and store the block headers (and their MMR authentication paths) in a range, so
+    /// that proofs against those blocks can be verified offline
+    FetchHeaders {
+        /// First block of the range (inclusive)
+        #[arg(long)]
+        from: u32,
+        /// Last block of the range (inclusive). Must be lower than the current sync height
+        #[arg(long)]
+        to: u32,
+    },
+    /// Roll the local chain state back to a previous sync point, so that the following syncs
+    /// fetch the updates after it again. Transactions committed after it become pending again
+    Rollback {
+        /// Block number of the sync point to roll back to (see `sync history`)
+        #[arg(long)]
+        block: u32,
+        /// Do not prompt for confirmation before rolling back
+        #[arg(long, default_value_t = false)]
+        force: bool,
+    },
+    /// Roll the local chain state back to the sync point before the last sync
+    Undo {
+        /// Do not prompt for confirmation before rolling back
+        #[arg(long, default_value_t = false)]
+        force: bool,
+    },
+}
+
 #[derive(Debug, Parser, Clone)]
 #[command(about = "Sync this client with the latest state of the Miden network")]
-pub struct SyncCmd {}
+pub struct SyncCmd {
+    #[clap(subcommand)]
+    command: Option<SyncSubCommand>,
+
+    /// Fetch the next sync update from the node and print what it would change, without
+    /// applying it. Network requests are still made, but no local state is updated.
+    #[arg(long, default_value_t = false, conflicts_with = "follow")]
+    dry_run: bool,
+
+    /// Keep syncing until interrupted (Ctrl+C), printing a one-line summary after each sync
+    #[arg(long, default_value_t = false)]
+    follow: bool,
+
+    /// Seconds to wait between syncs when following
+    #[arg(long, default_value_t = 10, requires = "follow", value_parser = clap::value_parser!(u64).range(1..))]
+    interval: u64,
+}
 
 impl SyncCmd {
     pub async fn execute<AUTH: Keystore + Sync + 'static>(
         &self,
         mut client: Client<AUTH>,
     ) -> Result<(), CliError> {
-        let new_details = client.sync_state().await?;
+        match &self.command {
+            Some(SyncSubCommand::History { limit }) => {
+                return print_sync_history(&client, *limit).await;
+            },
+            Some(SyncSubCommand::FetchHeaders { from, to }) => {
+                client.fetch_block_range((*from).into(), (*to).into()).await?;
+                println!("Stored block headers {from} to {to}");
+                return Ok(());
+            },
+            Some(SyncSubCommand::Rollback { block, force }) => {
+                if !*force && !confirm_rollback(&format!("block {block}"))? {
+                    return Ok(());
+                }
+                client.rollback_to_block((*block).into()).await?;
+                println!("Rolled back to block {block}");
+                return Ok(());
+            },
+            Some(SyncSubCommand::Undo { force }) => {
+                if !*force && !confirm_rollback("the sync point before the last sync")? {
+                    return Ok(());
+                }
+                match client.undo_last_sync().await? {
+                    Some(block_num) => println!("Rolled back to block {block_num}"),
+                    None => println!("No previous sync point to roll back to."),
+                }
+                return Ok(());
+            },
+            None => {},
+        }
+
+        if self.dry_run {
+            let preview = client.preview_sync().await?;
+
+            println!("Dry run: nothing was applied to the local state.");
+            println!("State would be synced to block {}", preview.block_num);
+            print_sync_summary(&preview);
+            return Ok(());
+        }
+
+        if self.follow {
+            return follow_sync(&mut client, Duration::from_secs(self.interval)).await;
+        }
+
+        // Long catch-ups are synced in several steps; report each one before the chain tip.
+        let new_details = client
+            .sync_state_with_progress(|block_num, chain_tip| {
+                if block_num < chain_tip {
+                    println!("Synced to block {block_num} of {chain_tip}...");
+                }
+            })
+            .await?;
 
         println!("State synced to block {}", new_details.block_num);
-        println!("New public notes: {}", new_details.new_public_notes.len());
-        println!("New private notes: {}", new_details.new_private_notes.len());
-        println!("Committed notes: {}", new_details.committed_notes.len());
-        println!("Tracked notes consumed: {}", new_details.consumed_notes.len());
-        println!("Tracked accounts updated: {}", new_details.updated_accounts.len());
-        println!("Locked accounts: {}", new_details.locked_accounts.len());
-        println!("Committed transactions: {}", new_details.committed_transactions.len());
+        print_sync_summary(&new_details);
         Ok(())
     }
 }
+
+/// Prints the number of notes, accounts and transactions affected by a sync.
+fn print_sync_summary(summary: &SyncSummary) {
+    println!("New public notes: {}", summary.new_public_notes.len());
+    println!("New private notes: {}", summary.new_private_notes.len());
+    println!("Committed notes: {}", summary.committed_notes.len());
+    println!("Tracked notes consumed: {}", summary.consumed_notes.len());
+    println!("Tracked accounts updated: {}", summary.updated_accounts.len());
+    println!("Locked accounts: {}", summary.locked_accounts.len());
+    println!("Committed transactions: {}", summary.committed_transactions.len());
+}
+
+/// Asks the user to confirm rolling the local state back to `target`.
+fn confirm_rollback(target: &str) -> Result<bool, CliError> {
+    println!(
+        "Roll the local state back to {target}? Transactions committed after it will be marked as pending until the next sync. (y/N)"
+    );
+    let mut proceed_str = String::new();
+    io::stdin().read_line(&mut proceed_str)?;
+    if proceed_str.trim().to_lowercase() != "y" {
+        println!("Rollback cancelled.");
+        return Ok(false);
+    }
+    Ok(true)
+}
+
+/// Syncs repeatedly, waiting `interval` between syncs, until Ctrl+C is pressed.
+///
+/// Syncs that fail because the node couldn't be reached are reported and retried on the next
+/// tick; any other error stops following.
+async fn follow_sync<AUTH: Keystore + Sync + 'static>(
+    client: &mut Client<AUTH>,
+    interval: Duration,
+) -> Result<(), CliError> {
+    println!("Syncing every {}s. Press Ctrl+C to stop.", interval.as_secs());
+
+    let shutdown = tokio::signal::ctrl_c();
+    tokio::pin!(shutdown);
+
+    let mut delay = Duration::ZERO;
+    loop {
+        // A sync that is in progress is allowed to finish; Ctrl+C is handled between syncs,
+        // including before the first one.
+        tokio::select! {
+            biased;
+            _ = &mut shutdown => break,
+            () = tokio::time::sleep(delay) => {},
+        }
+        delay = interval;
+
+        match client.sync_state().await {
+            Ok(summary) => println!("{}", format_sync_line(&summary)),
+            Err(err) if is_transient_sync_error(&err) => {
+                eprintln!("Sync failed, retrying in {}s: {err}", interval.as_secs());
+            },
+            Err(err) => return Err(err.into()),
+        }
+    }
+
+    println!("Stopped following sync.");
+    Ok(())
+}
+
+/// Returns whether a sync failed because the node couldn't be reached or didn't answer in time,
+/// so the next sync may succeed.
+fn is_transient_sync_error(err: &ClientError) -> bool {
+    matches!(
+        err,
+        ClientError::Timeout(_)
+            | ClientError::RpcError(RpcError::ConnectionError(_) | RpcError::RequestError { .. })
+    )
+}
+
+/// Formats a sync summary as a single line, for use when following.
+fn format_sync_line(summary: &SyncSummary) -> String {
+    format!(
+        "block {}: {} new notes, {} committed notes, {} consumed notes, {} committed transactions",
+        summary.block_num,
+        summary.new_public_notes.len() + summary.new_private_notes.len(),
+        summary.committed_notes.len(),
+        summary.consumed_notes.len(),
+        summary.committed_transactions.len(),
+    )
+}
+
+/// Prints the most recent sync points, newest first, along with the seconds elapsed since the
+/// previous sync point.
+async fn print_sync_history<AUTH: Keystore + Sync + 'static>(
+    client: &Client<AUTH>,
+    limit: usize,
+) -> Result<(), CliError> {
+    let history = client.get_sync_history(limit).await?;
+    if history.is_empty() {
+        println!("No syncs recorded yet.");
+        return Ok(());
+    }
+
+    let mut table = create_dynamic_table(&["Block", "Timestamp", "Since Previous (s)"]);
+    for (index, (block_num, timestamp)) in history.iter().enumerate() {
+        let interval = history.get(index + 1).map_or("-".to_string(), |(_, previous)| {
+            timestamp.saturating_sub(*previous).to_string()
+        });
+        table.add_row(vec![block_num.to_string(), timestamp.to_string(), interval]);
+    }
+
+    println!("{table}");
+    Ok(())
+}
@@ -19,6 +19,10 @@ pub struct TagsCmd {
     /// Removes a tag from the list of tags monitored by this client.
     #[arg(short, long, group = "action", value_name = "tag")]
     remove: Option<u32>,
+
+    /// Removes the tags that are no longer needed to receive notes.
+    #[arg(short, long, group = "action")]
+    prune: bool,
 }
 
 impl TagsCmd {
@@ -30,6 +34,9 @@ impl TagsCmd {
             TagsCmd { remove: Some(tag), .. } => {
                 remove_tag(client, *tag).await?;
             },
+            TagsCmd { prune: true, .. } => {
+                prune_tags(client).await?;
+            },
             _ => {
                 list_tags(client).await?;
             },
@@ -41,11 +48,11 @@ impl TagsCmd {
 // HELPERS
 // ================================================================================================
 async fn list_tags<AUTH>(client: Client<AUTH>) -> Result<(), CliError> {
-    let mut table = create_dynamic_table(&["Tag", "Source"]);
+    let mut table = create_dynamic_table(&["Tag", "Source", "Notes"]);
 
-    let tags = client.get_note_tags().await?;
+    let tags = client.note_tags_with_note_counts().await?;
 
-    for tag in tags {
+    for (tag, note_count) in tags {
         let source = match tag.source {
             miden_client::sync::NoteTagSource::Account(account_id) => {
                 format!("Account({})", account_id.to_hex())
@@ -59,7 +66,7 @@ async fn list_tags<AUTH>(client: Client<AUTH>) -> Result<(), CliError> {
             },
         };
 
-        table.add_row(vec![tag.tag.to_string(), source]);
+        table.add_row(vec![tag.tag.to_string(), source, note_count.to_string()]);
     }
 
     println!("\n{table}");
@@ -80,3 +87,9 @@ async fn remove_tag<AUTH>(mut client: Client<AUTH>, tag: u32) -> Result<(), CliE
     println!("Tag {tag} removed");
     Ok(())
 }
+
+async fn prune_tags<AUTH>(mut client: Client<AUTH>) -> Result<(), CliError> {
+    let removed = client.prune_unused_note_tags().await?;
+    println!("Removed {removed} unused tag(s)");
+    Ok(())
+}
@@ -1,7 +1,10 @@
-use miden_client::Client;
+use std::path::PathBuf;
+
 use miden_client::keystore::Keystore;
 use miden_client::store::TransactionFilter;
-use miden_client::transaction::TransactionRecord;
+use miden_client::transaction::{TransactionId, TransactionRecord, TransactionStatus};
+use miden_client::utils::Serializable;
+use miden_client::{Client, Word};
 
 use crate::errors::CliError;
 use crate::{Parser, create_dynamic_table};
@@ -12,33 +15,165 @@ pub struct TransactionCmd {
     /// List currently tracked transactions.
     #[arg(short, long, group = "action")]
     list: bool,
+
+    /// Only list transactions with the given local category.
+    #[arg(long)]
+    category: Option<String>,
+
+    /// Only list transactions that failed to execute. Failures are only recorded when
+    /// `record_failed_transactions` is enabled in the client configuration.
+    #[arg(long, conflicts_with = "category")]
+    failed: bool,
+
+    /// Show details of the transaction with the given ID, including the notes it consumed and
+    /// created.
+    #[arg(short, long, group = "action", value_name = "ID")]
+    show: Option<String>,
+
+    /// Export the retained proof of the transaction with the given ID. Proofs are only available
+    /// when `retain_proofs` is enabled in the client configuration.
+    #[arg(long, group = "action", value_name = "ID")]
+    proof: Option<String>,
+
+    /// Submit the pending transaction with the given ID to the network again, using its retained
+    /// proof. Useful when the node dropped the transaction without committing it.
+    #[arg(long, group = "action", value_name = "ID")]
+    resubmit: Option<String>,
+
+    /// File the exported proof is written to. Defaults to `<ID>.proof` in the current directory.
+    #[arg(long, requires = "proof")]
+    out: Option<PathBuf>,
 }
 
 impl TransactionCmd {
     pub async fn execute<AUTH: Keystore + Sync + 'static>(
         &self,
-        client: Client<AUTH>,
+        mut client: Client<AUTH>,
     ) -> Result<(), CliError> {
-        list_transactions(client).await?;
+        if let Some(transaction_id) = &self.show {
+            return show_transaction(&client, transaction_id).await;
+        }
+
+        if let Some(transaction_id) = &self.proof {
+            return export_transaction_proof(&client, transaction_id, self.out.clone()).await;
+        }
+
+        if let Some(transaction_id) = &self.resubmit {
+            let transaction_id = parse_transaction_id(transaction_id)?;
+            let block_num = client.resubmit_transaction(transaction_id).await?;
+            println!(
+                "Resubmitted transaction {transaction_id}. The node's chain tip is block {block_num}."
+            );
+            return Ok(());
+        }
+
+        let filter = if self.failed {
+            TransactionFilter::Failed
+        } else {
+            self.category
+                .clone()
+                .map_or(TransactionFilter::All, TransactionFilter::Category)
+        };
+
+        list_transactions(client, filter).await?;
         Ok(())
     }
 }
 
+// SHOW TRANSACTION
+// ================================================================================================
+async fn show_transaction<AUTH: Keystore + Sync + 'static>(
+    client: &Client<AUTH>,
+    transaction_id: &str,
+) -> Result<(), CliError> {
+    let transaction_id = parse_transaction_id(transaction_id)?;
+
+    let transaction = client
+        .get_transactions(TransactionFilter::Ids(vec![transaction_id]))
+        .await?
+        .pop()
+        .ok_or_else(|| CliError::Input(format!("Transaction {transaction_id} not found")))?;
+    let category = client.get_transaction_category(transaction_id).await?;
+    print_transactions_summary([(&transaction, category)]);
+
+    let (input_notes, output_notes) = client.get_transaction_notes(transaction_id).await?;
+
+    let mut table = create_dynamic_table(&["Note ID", "Direction", "State"]);
+    for note in &input_notes {
+        table.add_row(vec![
+            note.id()
+                .map_or_else(|| note.details_commitment().to_string(), |id| id.to_string()),
+            "Consumed".to_string(),
+            note.state().to_string(),
+        ]);
+    }
+    for note in &output_notes {
+        table.add_row(vec![note.id().to_string(), "Created".to_string(), note.state().to_string()]);
+    }
+
+    println!("{table}");
+    Ok(())
+}
+
+// EXPORT TRANSACTION PROOF
+// ================================================================================================
+async fn export_transaction_proof<AUTH: Keystore + Sync + 'static>(
+    client: &Client<AUTH>,
+    transaction_id: &str,
+    out: Option<PathBuf>,
+) -> Result<(), CliError> {
+    let transaction_id = parse_transaction_id(transaction_id)?;
+
+    let proven_transaction =
+        client.get_transaction_proof(transaction_id).await?.ok_or_else(|| {
+            CliError::Export(format!(
+                "No proof retained for transaction {transaction_id}; enable `retain_proofs` in \
+                 the client configuration to store proofs of submitted transactions"
+            ))
+        })?;
+
+    let file_path = if let Some(out) = out {
+        out
+    } else {
+        std::env::current_dir()?.join(format!("{transaction_id}.proof"))
+    };
+    std::fs::write(&file_path, proven_transaction.to_bytes())?;
+
+    println!(
+        "Successfully exported proof of transaction {transaction_id} to {}",
+        file_path.display()
+    );
+    Ok(())
+}
+
 // LIST TRANSACTIONS
 // ================================================================================================
 async fn list_transactions<AUTH: Keystore + Sync + 'static>(
     client: Client<AUTH>,
+    filter: TransactionFilter,
 ) -> Result<(), CliError> {
-    let transactions = client.get_transactions(TransactionFilter::All).await?;
-    print_transactions_summary(&transactions);
+    let transactions = client.get_transactions(filter).await?;
+
+    let mut categories = Vec::with_capacity(transactions.len());
+    for tx in &transactions {
+        categories.push(client.get_transaction_category(tx.id).await?);
+    }
+
+    print_transactions_summary(transactions.iter().zip(categories));
     Ok(())
 }
 
 // HELPERS
 // ================================================================================================
+fn parse_transaction_id(transaction_id: &str) -> Result<TransactionId, CliError> {
+    Word::try_from(transaction_id)
+        .map(TransactionId::from_raw)
+        .map_err(|err| CliError::Input(format!("Invalid transaction ID: {err}")))
+}
+
 fn print_transactions_summary<'a, I>(executed_transactions: I)
 where
-    I: IntoIterator<Item = &'a TransactionRecord>,
+    I: IntoIterator<Item = (&'a TransactionRecord, Option<String>)>,
 {
     let mut table = create_dynamic_table(&[
         "ID",
@@ -47,18 +182,38 @@ where
         "Script Root",
         "Input Notes Count",
         "Output Notes Count",
+        "Cycles",
+        "Category",
     ]);
 
-    for tx in executed_transactions {
+    for (tx, category) in executed_transactions {
         table.add_row(vec![
             tx.id.to_string(),
-            tx.status.to_string(),
+            format_status(&tx.status),
             tx.details.account_id.to_string(),
             tx.script.as_ref().map_or("-".to_string(), |x| x.root().to_string()),
             tx.details.input_note_nullifiers.len().to_string(),
             tx.details.output_notes.num_notes().to_string(),
+            tx.details.execution_cycles.map_or("-".to_string(), |cycles| cycles.to_string()),
+            category.unwrap_or_else(|| "-".to_string()),
         ]);
     }
 
     println!("{table}");
 }
+
+/// Formats a transaction status for display, shortening the reason of failed transactions so
+/// they fit in the table.
+fn format_status(status: &TransactionStatus) -> String {
+    const MAX_DISPLAYED_REASON_LEN: usize = 60;
+
+    match status {
+        TransactionStatus::Failed { reason }
+            if reason.chars().count() > MAX_DISPLAYED_REASON_LEN =>
+        {
+            let truncated: String = reason.chars().take(MAX_DISPLAYED_REASON_LEN).collect();
+            format!("Failed ({truncated}...)")
+        },
+        _ => status.to_string(),
+    }
+}
@@ -22,6 +22,7 @@ pub const TOKEN_SYMBOL_MAP_FILENAME: &str = "token_symbol_map.toml";
 pub const DEFAULT_PACKAGES_DIR: &str = "packages";
 pub const STORE_FILENAME: &str = "store.sqlite3";
 pub const KEYSTORE_DIRECTORY: &str = "keystore";
+pub const RNG_SEED_FILENAME: &str = "rng_seed.toml";
 pub const DEFAULT_REMOTE_PROVER_TIMEOUT: Duration = Duration::from_secs(20);
 
 /// Returns the global miden directory path.
@@ -91,6 +92,20 @@ pub struct CliConfig {
     pub note_transport: Option<NoteTransportConfig>,
     /// Timeout for the remote prover requests.
     pub remote_prover_timeout: Duration,
+    /// Whether proven transactions are persisted in the store when submitted.
+    #[serde(default)]
+    pub retain_proofs: bool,
+    /// Whether transactions that fail to execute are recorded in the store.
+    #[serde(default)]
+    pub record_failed_transactions: bool,
+    /// Path to the file containing the seed of the client's RNG. Backing this file up allows
+    /// reproducing the generated keys and serial numbers on another machine.
+    #[serde(default = "default_rng_seed_filepath")]
+    pub rng_seed_filepath: PathBuf,
+}
+
+fn default_rng_seed_filepath() -> PathBuf {
+    PathBuf::from(RNG_SEED_FILENAME)
 }
 
 // Make `ClientConfig` a provider itself for composability.
@@ -131,6 +146,9 @@ impl Default for CliConfig {
             max_block_number_delta: None,
             note_transport: None,
             remote_prover_timeout: DEFAULT_REMOTE_PROVER_TIMEOUT,
+            retain_proofs: false,
+            record_failed_transactions: false,
+            rng_seed_filepath: default_rng_seed_filepath(),
         }
     }
 }
@@ -228,6 +246,7 @@ impl CliConfig {
         Self::resolve_relative_path(&mut cli_config.secret_keys_directory, miden_dir);
         Self::resolve_relative_path(&mut cli_config.token_symbol_map_filepath, miden_dir);
         Self::resolve_relative_path(&mut cli_config.package_directory, miden_dir);
+        Self::resolve_relative_path(&mut cli_config.rng_seed_filepath, miden_dir);
 
         Ok(cli_config)
     }
@@ -334,6 +353,9 @@ impl CliConfig {
     /// use [`CliClient::new()`](crate::CliClient::new) instead, which uses this method
     /// internally.
     ///
+    /// If the RPC endpoint is set in the environment (see [`Endpoint::from_env`]), it overrides
+    /// the endpoint in the configuration file.
+    ///
     /// # Returns
     ///
     /// A configured [`CliConfig`] instance.
@@ -344,6 +366,7 @@ impl CliConfig {
     /// - [`CliError::ConfigNotFound`](crate::errors::CliError::ConfigNotFound) if neither local nor
     ///   global config file exists
     /// - [`CliError::Config`](crate::errors::CliError::Config) if configuration file parsing fails
+    ///   or the RPC endpoint environment variables are invalid
     ///
     /// Note: If a local config file exists but has parse errors, the error is returned
     /// immediately without falling back to global config.
@@ -363,6 +386,21 @@ impl CliConfig {
     /// # }
     /// ```
     pub fn load() -> Result<Self, CliError> {
+        let mut config = Self::load_from_dirs()?;
+
+        // The RPC endpoint set in the environment overrides the one in the config file.
+        let env_endpoint = Endpoint::from_env().map_err(|err| {
+            CliError::Config("RPC endpoint environment variables".to_string().into(), err)
+        })?;
+        if let Some(endpoint) = env_endpoint {
+            config.rpc.endpoint = CliEndpoint(endpoint);
+        }
+
+        Ok(config)
+    }
+
+    /// Loads the configuration file from the local directory, falling back to the global one.
+    fn load_from_dirs() -> Result<Self, CliError> {
         // Try local first
         match Self::from_local_dir() {
             Ok(config) => Ok(config),
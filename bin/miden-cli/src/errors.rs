@@ -100,6 +100,12 @@ pub enum CliError {
     #[error("parse error: {1}")]
     #[diagnostic(code(cli::parse_error), help("Check the inputs."))]
     Parse(#[source] SourceError, String),
+    #[error("store integrity check found {0} issue(s)")]
+    #[diagnostic(
+        code(cli::store_integrity_error),
+        help("Restore the store from a backup, or create a new one and sync it from scratch.")
+    )]
+    StoreIntegrity(usize),
     #[error("script builder error")]
     #[diagnostic(code(cli::script_builder_error))]
     CodeBuilder(#[from] CodeBuilderError),
@@ -9,11 +9,13 @@ use miden_client::store::NoteFilter;
 
 use super::config::CliConfig;
 use crate::commands::account::DEFAULT_ACCOUNT_ID_KEY;
+use crate::create_dynamic_table;
 use crate::errors::CliError;
 
 pub async fn print_client_info<AUTH: Keystore + Sync + 'static>(
     client: &Client<AUTH>,
     show_rpc_status: bool,
+    show_faucets: bool,
 ) -> Result<(), CliError> {
     let config = CliConfig::load()?;
 
@@ -33,6 +35,10 @@ pub async fn print_client_info<AUTH: Keystore + Sync + 'static>(
     print_config_stats(&config)?;
     print_client_stats(client).await?;
 
+    if show_faucets {
+        print_known_faucets(client).await?;
+    }
+
     if show_rpc_status {
         print_rpc_status(&config).await?;
     }
@@ -46,6 +52,16 @@ async fn print_client_stats<AUTH: Keystore + Sync + 'static>(
     client: &Client<AUTH>,
 ) -> Result<(), CliError> {
     println!("Block number: {}", client.get_sync_height().await?);
+    match client.sync_lag().await {
+        Ok(lag) => {
+            println!(
+                "Blocks behind chain tip: {} (chain tip: {})",
+                lag.blocks_behind(),
+                lag.chain_tip
+            );
+        },
+        Err(err) => println!("Blocks behind chain tip: - (failed to fetch chain tip: {err})"),
+    }
     println!("Tracked accounts: {}", client.get_account_headers().await?.len());
     println!("Expected notes: {}", client.get_input_notes(NoteFilter::Expected).await?.len());
     println!(
@@ -58,6 +74,22 @@ async fn print_client_stats<AUTH: Keystore + Sync + 'static>(
     Ok(())
 }
 
+async fn print_known_faucets<AUTH: Keystore + Sync + 'static>(
+    client: &Client<AUTH>,
+) -> Result<(), CliError> {
+    println!("\n--- Known Faucets ---");
+    let mut table = create_dynamic_table(&["Faucet ID", "Symbol", "Decimals"]);
+    for faucet_id in client.known_faucets().await? {
+        let (symbol, decimals) = match client.get_faucet_metadata(faucet_id).await? {
+            Some(metadata) => (metadata.symbol, metadata.decimals.to_string()),
+            None => ("-".to_string(), "-".to_string()),
+        };
+        table.add_row(vec![faucet_id.to_hex(), symbol, decimals]);
+    }
+    println!("{table}");
+    Ok(())
+}
+
 fn print_config_stats(config: &CliConfig) -> Result<(), CliError> {
     println!("Node address: {}", config.rpc.endpoint.0.host());
     let store_len = fs::metadata(config.store_filepath.clone())?.len();
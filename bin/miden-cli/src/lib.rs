@@ -1,16 +1,19 @@
 use std::env;
 use std::ffi::OsString;
 use std::ops::{Deref, DerefMut};
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use clap::{Parser, Subcommand};
 use comfy_table::{Attribute, Cell, ContentArrangement, Table, presets};
 use errors::CliError;
-use miden_client::account::AccountHeader;
+use miden_client::Word;
+use miden_client::account::AccountId;
+use miden_client::auth::AuthMethod;
 use miden_client::builder::ClientBuilder;
 use miden_client::keystore::{FilesystemKeyStore, Keystore};
 use miden_client::note_transport::grpc::GrpcNoteTransportClient;
-use miden_client::store::{NoteFilter as ClientNoteFilter, OutputNoteRecord};
+use miden_client::rpc::Endpoint;
 use miden_client_sqlite_store::ClientBuilderSqliteExt;
 
 mod commands;
@@ -26,11 +29,12 @@ use commands::network_note_status::NetworkNoteStatusCmd;
 use commands::new_account::{NewAccountCmd, NewWalletCmd};
 use commands::new_transactions::{ConsumeNotesCmd, MintCmd, PswapCmd, SendCmd, SwapCmd};
 use commands::notes::NotesCmd;
+use commands::store::StoreCmd;
 use commands::sync::SyncCmd;
 use commands::tags::TagsCmd;
 use commands::transactions::TransactionCmd;
 
-use self::utils::config_file_exists;
+use self::utils::{RngSeedFile, config_file_exists, load_keystore};
 use crate::commands::address::AddressCmd;
 
 pub type CliKeyStore = FilesystemKeyStore;
@@ -134,8 +138,19 @@ impl CliClient {
         debug_mode: miden_client::DebugMode,
     ) -> Result<Self, CliError> {
         // Create keystore
-        let keystore =
-            CliKeyStore::new(config.secret_keys_directory.clone()).map_err(CliError::KeyStore)?;
+        let keystore = load_keystore(&config.secret_keys_directory)?;
+
+        Self::from_config_with_keystore(config, keystore, debug_mode).await
+    }
+
+    /// Creates a new `CliClient` from the given configuration, using an already opened keystore
+    /// as the authenticator.
+    async fn from_config_with_keystore(
+        config: CliConfig,
+        keystore: CliKeyStore,
+        debug_mode: miden_client::DebugMode,
+    ) -> Result<Self, CliError> {
+        let configured_network_id = Endpoint::from(&config.rpc.endpoint).to_network_id();
 
         // Build client with the provided configuration
         let mut builder = ClientBuilder::new()
@@ -143,7 +158,15 @@ impl CliClient {
             .grpc_client(&config.rpc.endpoint.clone().into(), Some(config.rpc.timeout_ms))
             .authenticator(Arc::new(keystore))
             .in_debug_mode(debug_mode)
-            .tx_discard_delta(Some(TX_DISCARD_DELTA));
+            .tx_discard_delta(Some(TX_DISCARD_DELTA))
+            .retain_proofs(config.retain_proofs)
+            .record_failed_transactions(config.record_failed_transactions);
+
+        // Seed the RNG from the seed file set up by `init --rng-seed`, so the generated keys and
+        // serial numbers can be reproduced from a backup of it
+        if let Some(seed) = RngSeedFile::next_session_seed(&config.rng_seed_filepath)? {
+            builder = builder.with_rng_seed(seed);
+        }
 
         // Add optional max_block_number_delta
         if let Some(delta) = config.max_block_number_delta {
@@ -158,7 +181,14 @@ impl CliClient {
         }
 
         // Build and return the wrapped client
-        let client = builder.build().await.map_err(CliError::from)?;
+        let mut client = builder.build().await.map_err(CliError::from)?;
+
+        // The cached network ID is only dropped when the configured endpoint points to a
+        // different network, e.g. after switching from testnet to devnet.
+        if client.network_id().await? != configured_network_id {
+            client.reset_network_id().await?;
+        }
+
         Ok(CliClient(client))
     }
 
@@ -241,6 +271,57 @@ impl CliClient {
         Self::from_config(config, debug_mode).await
     }
 
+    /// Exports the secret keys of the specified account to the keystore directory at `path`.
+    ///
+    /// Only the keys whose public key commitments are referenced by the account's authentication
+    /// component are exported, so a single account can be migrated to another client without
+    /// copying the whole keystore. The directory is created if it doesn't exist, and the keys are
+    /// written unencrypted along with their account mapping, so it can be opened as a
+    /// [`FilesystemKeyStore`] by the destination client.
+    ///
+    /// Returns the number of exported keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CliError`] if:
+    /// - The account isn't tracked by the client
+    /// - A key referenced by the account's authentication component isn't in the keystore
+    /// - The keys can't be read from the keystore or written to `path`
+    pub async fn export_keystore_for_account(
+        &self,
+        account_id: AccountId,
+        path: impl Into<PathBuf>,
+    ) -> Result<usize, CliError> {
+        let keystore = self.authenticator().ok_or_else(|| {
+            CliError::Export("the client wasn't configured with a keystore".to_string())
+        })?;
+
+        let interface = self.account_interface(account_id).await?;
+        let mut secret_keys = Vec::new();
+        for commitment in interface.auth().iter().flat_map(AuthMethod::get_public_key_commitments) {
+            let Some(secret_key) =
+                keystore.get_key(commitment).await.map_err(CliError::KeyStore)?
+            else {
+                return Err(CliError::Export(format!(
+                    "secret key for public key commitment {} of account {account_id} not found \
+                     in the keystore",
+                    Word::from(commitment).to_hex()
+                )));
+            };
+            secret_keys.push(secret_key);
+        }
+
+        let export_keystore = FilesystemKeyStore::new(path.into()).map_err(CliError::KeyStore)?;
+        for secret_key in &secret_keys {
+            export_keystore
+                .add_key(secret_key, account_id)
+                .await
+                .map_err(CliError::KeyStore)?;
+        }
+
+        Ok(secret_keys.len())
+    }
+
     /// Unwraps the `CliClient` to get the inner `Client<CliKeyStore>`.
     ///
     /// This consumes the `CliClient` and returns the underlying client.
@@ -391,6 +472,7 @@ pub enum Command {
     ClearConfig(ClearConfigCmd),
     Notes(NotesCmd),
     Sync(SyncCmd),
+    Store(StoreCmd),
     /// View a summary of the current client state.
     Info(InfoCmd),
     Tags(TagsCmd),
@@ -443,27 +525,39 @@ impl Cli {
         let cli_config = CliConfig::load()?;
 
         // Create keystore for commands that need it
-        let keystore = CliKeyStore::new(cli_config.secret_keys_directory.clone())
-            .map_err(CliError::KeyStore)?;
+        let keystore = load_keystore(&cli_config.secret_keys_directory)?;
 
         // Create the client
-        let cli_client = CliClient::from_config(cli_config, in_debug_mode).await?;
+        let cli_client =
+            CliClient::from_config_with_keystore(cli_config, keystore.clone(), in_debug_mode)
+                .await?;
+
+        // Account commands run on the `CliClient`, as exporting an account's keys needs its
+        // keystore
+        if let Command::Account(account) = &self.action {
+            return account.execute(cli_client).await;
+        }
 
         // Extract the inner client for command execution
         let client = cli_client.into_inner();
 
         // Execute CLI command
         match &self.action {
-            Command::Account(account) => account.execute(client).await,
             Command::NewWallet(new_wallet) => Box::pin(new_wallet.execute(client, keystore)).await,
             Command::NewAccount(new_account) => {
                 Box::pin(new_account.execute(client, keystore)).await
             },
             Command::Import(import) => import.execute(client, keystore).await,
-            Command::Init(_) | Command::ClearConfig(_) | Command::NetworkNoteStatus(_) => Ok(()), /* Already handled earlier */
-            Command::Info(info_cmd) => info::print_client_info(&client, info_cmd.rpc_status).await,
+            Command::Init(_)
+            | Command::ClearConfig(_)
+            | Command::NetworkNoteStatus(_)
+            | Command::Account(_) => Ok(()), // Already handled earlier
+            Command::Info(info_cmd) => {
+                info::print_client_info(&client, info_cmd.rpc_status, info_cmd.faucets).await
+            },
             Command::Notes(notes) => Box::pin(notes.execute(client)).await,
             Command::Sync(sync) => sync.execute(client).await,
+            Command::Store(store) => store.execute(client).await,
             Command::Tags(tags) => tags.execute(client).await,
             Command::Address(addresses) => addresses.execute(client).await,
             Command::Transaction(transaction) => transaction.execute(client).await,
@@ -498,97 +592,3 @@ pub fn create_dynamic_table(headers: &[&str]) -> Table {
 
     table
 }
-
-/// Returns the client output note whose ID starts with `note_id_prefix`.
-///
-/// # Errors
-///
-/// - Returns [`IdPrefixFetchError::NoMatch`](miden_client::IdPrefixFetchError::NoMatch) if we were
-///   unable to find any note where `note_id_prefix` is a prefix of its ID.
-/// - Returns [`IdPrefixFetchError::MultipleMatches`](miden_client::IdPrefixFetchError::MultipleMatches)
-///   if there were more than one note found where `note_id_prefix` is a prefix of its ID.
-pub(crate) async fn get_output_note_with_id_prefix<AUTH: Keystore + Sync>(
-    client: &miden_client::Client<AUTH>,
-    note_id_prefix: &str,
-) -> Result<OutputNoteRecord, miden_client::IdPrefixFetchError> {
-    let mut output_note_records = client
-        .get_output_notes(ClientNoteFilter::All)
-        .await
-        .map_err(|err| {
-            tracing::error!("Error when fetching all notes from the store: {err}");
-            miden_client::IdPrefixFetchError::NoMatch(
-                format!("note ID prefix {note_id_prefix}").to_string(),
-            )
-        })?
-        .into_iter()
-        .filter(|note_record| note_record.id().to_hex().starts_with(note_id_prefix))
-        .collect::<Vec<_>>();
-
-    if output_note_records.is_empty() {
-        return Err(miden_client::IdPrefixFetchError::NoMatch(
-            format!("note ID prefix {note_id_prefix}").to_string(),
-        ));
-    }
-    if output_note_records.len() > 1 {
-        let output_note_record_ids =
-            output_note_records.iter().map(OutputNoteRecord::id).collect::<Vec<_>>();
-        tracing::error!(
-            "Multiple notes found for the prefix {}: {:?}",
-            note_id_prefix,
-            output_note_record_ids
-        );
-        return Err(miden_client::IdPrefixFetchError::MultipleMatches(
-            format!("note ID prefix {note_id_prefix}").to_string(),
-        ));
-    }
-
-    Ok(output_note_records
-        .pop()
-        .expect("input_note_records should always have one element"))
-}
-
-/// Returns the client account whose ID starts with `account_id_prefix`.
-///
-/// # Errors
-///
-/// - Returns [`IdPrefixFetchError::NoMatch`](miden_client::IdPrefixFetchError::NoMatch) if we were
-///   unable to find any account where `account_id_prefix` is a prefix of its ID.
-/// - Returns [`IdPrefixFetchError::MultipleMatches`](miden_client::IdPrefixFetchError::MultipleMatches)
-///   if there were more than one account found where `account_id_prefix` is a prefix of its ID.
-async fn get_account_with_id_prefix<AUTH>(
-    client: &miden_client::Client<AUTH>,
-    account_id_prefix: &str,
-) -> Result<AccountHeader, miden_client::IdPrefixFetchError> {
-    let mut accounts = client
-        .get_account_headers()
-        .await
-        .map_err(|err| {
-            tracing::error!("Error when fetching all accounts from the store: {err}");
-            miden_client::IdPrefixFetchError::NoMatch(
-                format!("account ID prefix {account_id_prefix}").to_string(),
-            )
-        })?
-        .into_iter()
-        .filter(|(account_header, _)| account_header.id().to_hex().starts_with(account_id_prefix))
-        .map(|(acc, _)| acc)
-        .collect::<Vec<_>>();
-
-    if accounts.is_empty() {
-        return Err(miden_client::IdPrefixFetchError::NoMatch(
-            format!("account ID prefix {account_id_prefix}").to_string(),
-        ));
-    }
-    if accounts.len() > 1 {
-        let account_ids = accounts.iter().map(AccountHeader::id).collect::<Vec<_>>();
-        tracing::error!(
-            "Multiple accounts found for the prefix {}: {:?}",
-            account_id_prefix,
-            account_ids
-        );
-        return Err(miden_client::IdPrefixFetchError::MultipleMatches(
-            format!("account ID prefix {account_id_prefix}").to_string(),
-        ));
-    }
-
-    Ok(accounts.pop().expect("account_ids should always have one element"))
-}
@@ -1,16 +1,19 @@
 use std::collections::{BTreeMap, BTreeSet};
-use std::path::PathBuf;
+use std::env;
+use std::path::{Path, PathBuf};
 
 use miden_client::account::{AccountId, FaucetMetadata};
 use miden_client::address::{Address, AddressId};
 use miden_client::asset::{FungibleAsset, NonFungibleDeltaAction};
+use miden_client::crypto::Rpo256;
+use miden_client::keystore::FilesystemKeyStore;
 use miden_client::transaction::{ExecutedTransaction, InputNote};
-use miden_client::utils::{base_units_to_tokens, tokens_to_base_units};
+use miden_client::utils::{Serializable, base_units_to_tokens, tokens_to_base_units};
 use miden_client::vm::MIN_STACK_DEPTH;
 use miden_client::{Client, Felt, WORD_SIZE, Word};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-use super::{CLIENT_CONFIG_FILE_NAME, create_dynamic_table, get_account_with_id_prefix};
+use super::{CLIENT_CONFIG_FILE_NAME, create_dynamic_table};
 use crate::commands::account::DEFAULT_ACCOUNT_ID_KEY;
 use crate::config::{CliConfig, get_global_miden_dir, get_local_miden_dir};
 use crate::errors::CliError;
@@ -63,10 +66,12 @@ pub(crate) async fn parse_account_id<AUTH>(
             return Ok(account_id);
         }
 
-        Ok(get_account_with_id_prefix(client, account_id)
-        .await
-        .map_err(|_| CliError::Input(format!("Input account ID {account_id} is neither a valid Account ID nor a hex prefix of a known Account ID")))?
-        .id())
+        client.resolve_account_by_prefix(account_id).await.map_err(|_| {
+            CliError::Input(format!(
+                "Input account ID {account_id} is neither a valid Account ID nor a hex prefix of \
+                 a known Account ID"
+            ))
+        })
     } else {
         let address = Address::decode(account_id)
             .map_err(|err| CliError::Input(format!("error parsing bech32 address: {err}")))?
@@ -94,6 +99,30 @@ pub(super) fn config_file_exists() -> Result<bool, CliError> {
     Ok(global_miden_dir.join(CLIENT_CONFIG_FILE_NAME).exists())
 }
 
+/// Environment variable holding the passphrase of an encrypted keystore. When unset, the
+/// passphrase is prompted for.
+pub const KEYSTORE_PASSPHRASE_ENV_VAR: &str = "MIDEN_KEYSTORE_PASSPHRASE";
+
+/// Opens the keystore in `keys_directory`, asking for its passphrase if it is encrypted.
+pub fn load_keystore(keys_directory: &Path) -> Result<FilesystemKeyStore, CliError> {
+    if !FilesystemKeyStore::is_encrypted(keys_directory) {
+        return FilesystemKeyStore::new(keys_directory.to_path_buf()).map_err(CliError::KeyStore);
+    }
+
+    let passphrase = match env::var(KEYSTORE_PASSPHRASE_ENV_VAR) {
+        Ok(passphrase) => passphrase,
+        Err(_) => read_passphrase("Keystore passphrase: ")?,
+    };
+    FilesystemKeyStore::new_encrypted(keys_directory.to_path_buf(), &passphrase)
+        .map_err(CliError::KeyStore)
+}
+
+/// Prompts for a passphrase without echoing it to the terminal.
+pub fn read_passphrase(prompt: &str) -> Result<String, CliError> {
+    rpassword::prompt_password(prompt)
+        .map_err(|err| CliError::Input(format!("failed to read passphrase: {err}")))
+}
+
 /// Returns the faucet metadata resolver using the config file.
 pub fn load_faucet_metadata_resolver() -> Result<FaucetMetadataResolver, CliError> {
     let config = CliConfig::load()?;
@@ -339,8 +368,7 @@ impl FaucetMetadataResolver {
             return Ok(Some(FaucetMetadata { symbol, decimals }));
         }
         // 2) settings store
-        let setting_key = faucet_metadata_setting_key(faucet_id);
-        Ok(client.get_setting::<FaucetMetadata>(setting_key).await?)
+        Ok(client.get_faucet_metadata(faucet_id).await?)
     }
 
     /// Looks up `(symbol, decimals)` for a faucet, walking TOML → settings store → RPC fetch.
@@ -355,10 +383,12 @@ impl FaucetMetadataResolver {
             return Ok(Some(meta));
         }
         // 3) RPC fetch
-        let setting_key = faucet_metadata_setting_key(faucet_id);
         match client.fetch_remote_token_metadata(faucet_id).await {
             Ok(Some(meta)) => {
-                if let Err(err) = client.set_setting(setting_key, meta.clone()).await {
+                if let Err(err) = client
+                    .register_faucet_metadata(faucet_id, meta.symbol.clone(), meta.decimals)
+                    .await
+                {
                     tracing::warn!(
                         "failed to persist faucet metadata for {}: {err}",
                         faucet_id.to_hex(),
@@ -397,7 +427,8 @@ impl FaucetMetadataResolver {
     ///   `<FAUCET_ADDRESS>` is the faucet address.
     /// - `<AMOUNT>::<TOKEN_SYMBOL>` where `<AMOUNT>` is a decimal number representing the quantity
     ///   of the token (specified to the precision allowed by the token's decimals), and
-    ///   `<TOKEN_SYMBOL>` is a symbol tracked in the token symbol map file.
+    ///   `<TOKEN_SYMBOL>` is a symbol tracked in the token symbol map file or registered in the
+    ///   client's faucet metadata.
     ///
     /// Some examples of valid `arg` values are `100::mlcl1qru2e5yvx40ndgqqqzusrryr0ucyd0uj`,
     /// `100::0xabcdef0123456789` and `1.23::TST`.
@@ -408,7 +439,7 @@ impl FaucetMetadataResolver {
     /// - The provided `arg` doesn't match one of the expected formats.
     /// - A faucet ID was provided but the amount isn't in base units.
     /// - The amount has more than the allowed number of decimals.
-    /// - The token symbol isn't present in the token symbol map file.
+    /// - The token symbol isn't present in the token symbol map file nor registered in the client.
     pub async fn parse_fungible_asset<AUTH>(
         &self,
         client: &Client<AUTH>,
@@ -424,14 +455,25 @@ impl FaucetMetadataResolver {
                 .map_err(|err| CliError::Parse(err.into(), "Failed to parse u64".to_string()))?;
             (id, amount)
         } else {
-            let entry = self.toml.get(asset).ok_or(CliError::Config(
-                "Token symbol not found in the map file".to_string().into(),
-                asset.to_string(),
-            ))?;
-            let amount = tokens_to_base_units(amount, entry.decimals).map_err(|err| {
+            let (faucet_id, decimals) = match self.toml.get(asset) {
+                Some(entry) => (entry.account_id, entry.decimals),
+                None => {
+                    let faucet_id = client.resolve_faucet(asset).await?.ok_or(CliError::Config(
+                        "Token symbol not found in the map file".to_string().into(),
+                        asset.to_string(),
+                    ))?;
+                    let metadata =
+                        client.get_faucet_metadata(faucet_id).await?.ok_or(CliError::Config(
+                            "Faucet metadata not found".to_string().into(),
+                            faucet_id.to_hex(),
+                        ))?;
+                    (faucet_id, metadata.decimals)
+                },
+            };
+            let amount = tokens_to_base_units(amount, decimals).map_err(|err| {
                 CliError::Parse(err.into(), "Failed to parse tokens to base units".to_string())
             })?;
-            (entry.account_id, amount)
+            (faucet_id, amount)
         };
 
         FungibleAsset::new(faucet_id, amount).map_err(CliError::Asset)
@@ -445,14 +487,6 @@ impl FaucetMetadataResolver {
     }
 }
 
-/// Settings key prefix under which faucet display metadata is persisted.
-const FAUCET_METADATA_SETTING_PREFIX: &str = "faucet_metadata:";
-
-/// Returns the settings-store key under which the metadata for `faucet_id` is persisted.
-fn faucet_metadata_setting_key(faucet_id: AccountId) -> String {
-    format!("{FAUCET_METADATA_SETTING_PREFIX}{}", faucet_id.to_hex())
-}
-
 /// Parses an `id` string from the TOML as a bech32 address.
 fn parse_id_string(id: &str) -> Result<AccountId, String> {
     let (_, address) = Address::decode(id)
@@ -462,3 +496,107 @@ fn parse_id_string(id: &str) -> Result<AccountId, String> {
     }
     Err(format!("address `{id}` does not encode an account ID"))
 }
+
+// RNG SEED FILE
+// ================================================================================================
+
+/// Contents of the file that persists the seed of the client's RNG, set up with
+/// `init --rng-seed`.
+///
+/// Seeding every CLI invocation with the same value would make them generate the same keys and
+/// serial numbers, so each client session is seeded with a value derived from the persisted `seed`
+/// and a `session` counter that is bumped whenever a client is created. Restoring the seed on
+/// another machine and replaying the same commands regenerates the same keys. Without a seed file,
+/// the client's RNG is seeded randomly.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RngSeedFile {
+    /// Hex-encoded 32-byte seed.
+    seed: String,
+    /// Number of client sessions seeded so far.
+    session: u64,
+}
+
+impl RngSeedFile {
+    /// Creates the contents of a seed file for `seed`, with no session seeded yet.
+    pub fn new(seed: [u8; 32]) -> Self {
+        Self { seed: hex::encode(seed), session: 0 }
+    }
+
+    /// Parses a hex-encoded 32-byte seed, with or without a `0x` prefix.
+    pub fn parse_seed(seed: &str) -> Result<[u8; 32], CliError> {
+        let seed = seed.strip_prefix("0x").unwrap_or(seed);
+        let mut bytes = [0u8; 32];
+        hex::decode_to_slice(seed, &mut bytes).map_err(|err| {
+            CliError::InvalidArgument(format!("RNG seed must be 32 hex-encoded bytes: {err}"))
+        })?;
+        Ok(bytes)
+    }
+
+    /// Writes the seed file to `path`, readable only by the current user on Unix.
+    ///
+    /// The contents are written to a temporary file that then replaces `path`, so the seed file
+    /// is never left partially written.
+    pub fn write(&self, path: &Path) -> Result<(), CliError> {
+        use std::io::Write;
+
+        let contents = toml::to_string(self).map_err(|err| {
+            CliError::Config(Box::new(err), "Failed to serialize RNG seed file".to_string())
+        })?;
+
+        let temp_path = path.with_extension("toml.tmp");
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        options
+            .open(&temp_path)
+            .and_then(|mut file| {
+                file.write_all(contents.as_bytes())?;
+                file.sync_all()
+            })
+            .and_then(|()| std::fs::rename(&temp_path, path))
+            .map_err(|err| {
+                CliError::Config(Box::new(err), "Failed to write RNG seed file".to_string())
+            })
+    }
+
+    /// Returns the RNG seed of the next client session, or `None` if there is no seed file at
+    /// `path`.
+    ///
+    /// The bumped session counter is persisted before the seed is returned, while holding an
+    /// exclusive lock, so that concurrent invocations never get the same seed.
+    pub fn next_session_seed(path: &Path) -> Result<Option<[u8; 32]>, CliError> {
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let lock_error = |err: std::io::Error| {
+            CliError::Config(Box::new(err), "Failed to lock RNG seed file".to_string())
+        };
+        // The lock is released when the file is dropped.
+        let lock_file = std::fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path.with_extension("toml.lock"))
+            .map_err(lock_error)?;
+        lock_file.lock().map_err(lock_error)?;
+
+        let content = std::fs::read_to_string(path).map_err(|err| {
+            CliError::Config(Box::new(err), "Failed to read RNG seed file".to_string())
+        })?;
+        let mut seed_file: Self = toml::from_str(&content).map_err(|err| {
+            CliError::Config(Box::new(err), "Failed to parse RNG seed file".to_string())
+        })?;
+
+        let seed = Self::parse_seed(&seed_file.seed)?;
+        let session = seed_file.session;
+        seed_file.session += 1;
+        seed_file.write(path)?;
+
+        let mut preimage = seed.to_vec();
+        preimage.extend_from_slice(&session.to_le_bytes());
+        let session_seed = Rpo256::hash(&preimage).to_bytes();
+        Ok(Some(session_seed.try_into().expect("a word is serialized into 32 bytes")))
+    }
+}
@@ -103,6 +103,55 @@ fn init_with_params() {
     init_cmd.current_dir(&temp_dir).assert().failure();
 }
 
+#[test]
+fn init_with_rng_seed_persists_the_seed() {
+    const SEED: &str = "0707070707070707070707070707070707070707070707070707070707070707";
+
+    let temp_dir = temp_dir().join(format!("cli-test-{}", rand::rng().random::<u64>()));
+    std::fs::create_dir_all(&temp_dir).unwrap();
+
+    // Invalid seeds are rejected before anything is written.
+    let mut init_cmd = cargo_bin_cmd!("miden-client");
+    init_cmd.args(["init", "--local", "--rng-seed", "0x1234"]);
+    init_cmd.current_dir(&temp_dir).assert().failure();
+    assert!(!temp_dir.join(MIDEN_DIR).exists());
+
+    let mut init_cmd = cargo_bin_cmd!("miden-client");
+    init_cmd.args(["init", "--local", "--network", "localhost", "--rng-seed", SEED]);
+    init_cmd.current_dir(&temp_dir).assert().success();
+
+    let seed_file_path = temp_dir.join(MIDEN_DIR).join("rng_seed.toml");
+    let seed_file = std::fs::read_to_string(&seed_file_path).unwrap();
+    assert!(seed_file.contains(SEED));
+    assert!(seed_file.contains("session = 0"));
+
+    // Every client session is seeded with a different value derived from the seed.
+    let mut account_cmd = cargo_bin_cmd!("miden-client");
+    account_cmd.args(["account"]);
+    account_cmd.current_dir(&temp_dir).assert().success();
+
+    let seed_file = std::fs::read_to_string(&seed_file_path).unwrap();
+    assert!(seed_file.contains(SEED));
+    assert!(seed_file.contains("session = 1"));
+}
+
+#[test]
+fn init_without_rng_seed_keeps_random_seeding() {
+    let temp_dir = temp_dir().join(format!("cli-test-{}", rand::rng().random::<u64>()));
+    std::fs::create_dir_all(&temp_dir).unwrap();
+
+    let mut init_cmd = cargo_bin_cmd!("miden-client");
+    init_cmd.args(["init", "--local", "--network", "localhost"]);
+    init_cmd.current_dir(&temp_dir).assert().success();
+
+    let mut account_cmd = cargo_bin_cmd!("miden-client");
+    account_cmd.args(["account"]);
+    account_cmd.current_dir(&temp_dir).assert().success();
+
+    // Sessions are only seeded deterministically after `init --rng-seed`.
+    assert!(!temp_dir.join(MIDEN_DIR).join("rng_seed.toml").exists());
+}
+
 #[test]
 #[serial_test::file_serial]
 fn silent_initialization_uses_default_values() {
@@ -741,6 +790,47 @@ async fn cli_export_import_account() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn cli_export_import_account_keys() -> Result<()> {
+    const KEYS_DIRECTORY: &str = "exported-keys";
+
+    let (_, temp_dir_1, _) = init_cli();
+    let (_, temp_dir_2, _) = init_cli();
+
+    let wallet_id = new_wallet_cli(&temp_dir_1, AccountType::Private);
+    let other_wallet_id = new_wallet_cli(&temp_dir_1, AccountType::Private);
+
+    // Export the keys of a single account
+    let mut export_cmd = cargo_bin_cmd!("miden-client");
+    export_cmd.args(["account", "--export-keys", &wallet_id, KEYS_DIRECTORY]);
+    export_cmd.current_dir(&temp_dir_1).assert().success();
+
+    let wallet_id = AccountId::from_hex(&wallet_id)?;
+    let other_wallet_id = AccountId::from_hex(&other_wallet_id)?;
+    let source_keystore = FilesystemKeyStore::new(temp_dir_1.join(MIDEN_DIR).join("keystore"))?;
+    let exported_keystore = FilesystemKeyStore::new(temp_dir_1.join(KEYS_DIRECTORY))?;
+
+    // Only the keys of the exported account are in the exported keystore
+    let exported_keys = exported_keystore.get_keys_for_account(&wallet_id).await?;
+    assert!(!exported_keys.is_empty());
+    assert!(exported_keystore.get_account_key_commitments(&other_wallet_id).await.is_err());
+
+    // Import the exported keys into the keystore of the second client
+    let target_keystore = FilesystemKeyStore::new(temp_dir_2.join(MIDEN_DIR).join("keystore"))?;
+    for key in &exported_keys {
+        target_keystore.add_key(key, wallet_id).await?;
+    }
+
+    let source_commitments = source_keystore.get_account_key_commitments(&wallet_id).await?;
+    let imported_commitments = target_keystore.get_account_key_commitments(&wallet_id).await?;
+    assert_eq!(imported_commitments, source_commitments);
+    for commitment in imported_commitments {
+        assert!(target_keystore.get_key_sync(commitment)?.is_some());
+    }
+
+    Ok(())
+}
+
 #[test]
 fn cli_empty_commands() {
     let temp_dir = init_cli().1;
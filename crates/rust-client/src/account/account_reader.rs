@@ -56,10 +56,13 @@ impl AccountReader {
     // HEADER ACCESS
     // --------------------------------------------------------------------------------------------
 
-    /// Retrieves the current account nonce.
+    /// Retrieves the current account nonce. Unlike the other header accessors, this only reads
+    /// the nonce from the store.
     pub async fn nonce(&self) -> Result<Felt, ClientError> {
-        let (header, _) = self.header().await?;
-        Ok(header.nonce())
+        self.store
+            .get_account_nonce(self.account_id)
+            .await?
+            .ok_or(ClientError::AccountDataNotFound(self.account_id))
     }
 
     /// Retrieves the account commitment (hash of the full state).
@@ -183,4 +186,40 @@ impl AccountReader {
             .await
             .map_err(ClientError::StoreError)
     }
+
+    /// Retrieves the values and Merkle witnesses of multiple keys of a storage map slot, in the
+    /// same order as `keys`.
+    ///
+    /// This is cheaper than calling [`Self::get_storage_map_witness`] for each key, as the slot is
+    /// only looked up once.
+    ///
+    /// # Errors
+    /// Returns an error if the slot is not found or is not a map.
+    pub async fn get_storage_map_witnesses(
+        &self,
+        slot_name: impl Into<StorageSlotName>,
+        keys: &[StorageMapKey],
+    ) -> Result<Vec<(Word, StorageMapWitness)>, ClientError> {
+        self.store
+            .get_account_map_items(self.account_id, slot_name.into(), keys)
+            .await
+            .map_err(ClientError::StoreError)
+    }
+
+    /// Retrieves the keys of a storage map slot by name, without loading the map values.
+    ///
+    /// Keys are returned in ascending order of their hex encoding, so a large map can be paged
+    /// through consistently while it doesn't change.
+    ///
+    /// # Errors
+    /// Returns an error if the slot is not found or is not a map.
+    pub async fn storage_map_keys(
+        &self,
+        slot_name: impl Into<StorageSlotName>,
+    ) -> Result<Vec<StorageMapKey>, ClientError> {
+        self.store
+            .get_storage_map_keys(self.account_id, slot_name.into())
+            .await
+            .map_err(ClientError::StoreError)
+    }
 }
@@ -32,10 +32,11 @@
 //!
 //! For more details on accounts, refer to the [Account] documentation.
 
+use alloc::collections::BTreeMap;
+use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 
-use miden_protocol::Felt;
 use miden_protocol::account::auth::PublicKey;
 pub use miden_protocol::account::delta::AccountUpdateDetails;
 pub use miden_protocol::account::{
@@ -71,10 +72,14 @@ pub use miden_protocol::account::{
     StorageSlotName,
     StorageSlotType,
 };
+use miden_protocol::address::RoutingParameters;
 pub use miden_protocol::address::{Address, AddressInterface, AddressType, NetworkId};
-use miden_protocol::asset::AssetVault;
+use miden_protocol::asset::{Asset, AssetVault};
+use miden_protocol::block::BlockNumber;
 pub use miden_protocol::errors::{AccountIdError, AddressError, NetworkIdError};
 use miden_protocol::note::NoteTag;
+use miden_protocol::{EMPTY_WORD, Felt};
+use miden_tx::auth::TransactionAuthenticator;
 use miden_tx::utils::serde::{
     ByteReader,
     ByteWriter,
@@ -129,12 +134,22 @@ pub use miden_standards::account::metadata::{
 use miden_standards::account::wallets::BasicWallet;
 
 use super::Client;
+use crate::address::address_to_uri;
 use crate::asset::TokenSymbol;
-use crate::errors::ClientError;
+use crate::errors::{ClientError, IdPrefixFetchError};
+use crate::rpc::AccountStateAt;
 use crate::rpc::domain::account::GetAccountRequest;
 use crate::rpc::node::{EndpointError, GetAccountError};
-use crate::store::{AccountStatus, AccountStorageFilter, ClientAccountType};
+use crate::store::{
+    AccountFilter,
+    AccountStatus,
+    AccountStorageFilter,
+    ClientAccountType,
+    InputNoteRecord,
+    TransactionFilter,
+};
 use crate::sync::NoteTagRecord;
+use crate::transaction::{TransactionDetails, TransactionStatus};
 
 pub mod component {
     pub const MIDEN_PACKAGE_EXTENSION: &str = "masp";
@@ -296,6 +311,15 @@ impl<AUTH> Client<AUTH> {
                     self.store.add_note_tag(note_tag_record).await?;
                 }
 
+                // Register the source of the code if it was compiled with the client's code
+                // builder, without replacing one registered by the user.
+                let code_commitment = account.code().commitment();
+                if let Some(source) = self.component_sources.source_for(account.code())
+                    && self.get_account_code_source_by_commitment(code_commitment).await?.is_none()
+                {
+                    self.register_account_code_source(code_commitment, source).await?;
+                }
+
                 Ok(())
             },
             Some(tracked_account) => {
@@ -380,6 +404,42 @@ impl<AUTH> Client<AUTH> {
         self.add_account_inner(&account, ClientAccountType::Watched, true).await
     }
 
+    /// Imports a native [`Account`] together with its [`Address`], input notes and note tags in a
+    /// single store transaction, so that a restored wallet is either imported as a whole or not at
+    /// all.
+    ///
+    /// The note tag derived from `address` is tracked even if it isn't part of `tags`.
+    ///
+    /// # Errors
+    /// - If the account is new but it does not contain the seed.
+    /// - If the account is already tracked.
+    /// - If any part of the bundle fails to be stored, in which case nothing is imported.
+    pub async fn import_account_bundle(
+        &mut self,
+        account: &Account,
+        address: Address,
+        notes: &[InputNoteRecord],
+        tags: &[NoteTagRecord],
+    ) -> Result<(), ClientError> {
+        if account.is_new() && account.seed().is_none() {
+            return Err(ClientError::AddNewAccountWithoutSeed);
+        }
+
+        if self.store.get_account_header(account.id()).await?.is_some() {
+            return Err(ClientError::AccountAlreadyTracked(account.id()));
+        }
+
+        let address_tag = NoteTagRecord::with_account_source(address.to_note_tag(), account.id());
+        let mut tags = tags.to_vec();
+        if !tags.contains(&address_tag) {
+            tags.push(address_tag);
+        }
+
+        self.store.import_account_bundle(account, address, notes, &tags).await?;
+
+        Ok(())
+    }
+
     /// Fetches a public [`Account`] from the network, returning a typed error when the account
     /// doesn't exist on chain or is private.
     async fn fetch_public_account(&self, account_id: AccountId) -> Result<Account, ClientError> {
@@ -476,6 +536,54 @@ impl<AUTH> Client<AUTH> {
         }
     }
 
+    /// Returns `address` as a `miden:<bech32>?network=<network>` URI for the network the client
+    /// is connected to, e.g. to share it through a QR code.
+    ///
+    /// Use [`parse_address_uri`](crate::address::parse_address_uri) to parse it back.
+    pub async fn address_uri(&self, address: &Address) -> Result<String, ClientError> {
+        let network_id = self.network_id().await?;
+        Ok(address_to_uri(address, &network_id))
+    }
+
+    /// Builds a new [`Address`] for the account with the provided [`RoutingParameters`], and
+    /// tracks it through [`Client::add_address`] so its derived [`NoteTag`] is registered.
+    ///
+    /// The interface carried by `routing` is validated against the account's code, so an address
+    /// can't advertise procedures the account doesn't expose (e.g. a
+    /// [`AddressInterface::BasicWallet`] address for a faucet).
+    ///
+    /// # Errors
+    /// - If the account is not tracked by the client.
+    /// - If the account doesn't implement the interface requested in `routing`.
+    /// - If the address is already being tracked.
+    pub async fn new_address(
+        &mut self,
+        account_id: AccountId,
+        routing: RoutingParameters,
+    ) -> Result<Address, ClientError> {
+        let address = Address::new(account_id).with_routing_parameters(routing);
+
+        if let Some(interface) = address.interface() {
+            let code = self
+                .store
+                .get_account_code(account_id)
+                .await?
+                .ok_or(ClientError::AccountDataNotFound(account_id))?;
+            let account_interface = AccountInterface::from_code(account_id, Vec::new(), &code);
+
+            let missing_basic_wallet = matches!(interface, AddressInterface::BasicWallet)
+                && !account_interface
+                    .components()
+                    .contains(&AccountComponentInterface::BasicWallet);
+            if missing_basic_wallet {
+                return Err(ClientError::IncompatibleAddressInterface { account_id, interface });
+            }
+        }
+
+        self.add_address(address.clone(), account_id).await?;
+        Ok(address)
+    }
+
     /// Removes an [`Address`] from the associated [`AccountId`], alongside its derived [`NoteTag`].
     /// If no address was tracked for the given account, this is a no-op.
     pub async fn remove_address(
@@ -530,8 +638,8 @@ impl<AUTH> Client<AUTH> {
         self.store.get_account_code(account_id).await.map_err(ClientError::StoreError)
     }
 
-    /// Returns a list of [`AccountHeader`] of all accounts stored in the database along with their
-    /// statuses.
+    /// Returns a list of [`AccountHeader`] of all non-archived accounts stored in the database
+    /// along with their statuses.
     ///
     /// Said accounts' state is the state after the last performed sync.
     pub async fn get_account_headers(
@@ -540,6 +648,63 @@ impl<AUTH> Client<AUTH> {
         self.store.get_account_headers().await.map_err(Into::into)
     }
 
+    /// Returns a list of [`AccountHeader`] of the accounts matching `filter` along with their
+    /// statuses.
+    pub async fn get_filtered_account_headers(
+        &self,
+        filter: AccountFilter,
+    ) -> Result<Vec<(AccountHeader, AccountStatus)>, ClientError> {
+        self.store.get_filtered_account_headers(filter).await.map_err(Into::into)
+    }
+
+    /// Returns the ID of the account tracked by the client whose ID starts with
+    /// `account_id_prefix`. Archived accounts are included, so that they can still be referenced.
+    ///
+    /// # Errors
+    ///
+    /// - Returns [`IdPrefixFetchError::NoMatch`] if no tracked account ID starts with
+    ///   `account_id_prefix`.
+    /// - Returns [`IdPrefixFetchError::MultipleMatches`] if more than one tracked account ID starts
+    ///   with `account_id_prefix`.
+    pub async fn resolve_account_by_prefix(
+        &self,
+        account_id_prefix: &str,
+    ) -> Result<AccountId, IdPrefixFetchError> {
+        let no_match =
+            || IdPrefixFetchError::NoMatch(format!("account ID prefix {account_id_prefix}"));
+
+        let mut account_ids = self
+            .store
+            .get_filtered_account_headers(AccountFilter::All)
+            .await
+            .map_err(|err| {
+                tracing::error!("Error when fetching all accounts from the store: {err}");
+                no_match()
+            })?
+            .into_iter()
+            .map(|(header, _)| header.id())
+            .filter(|id| id.to_hex().starts_with(account_id_prefix))
+            .collect::<Vec<_>>();
+
+        if account_ids.len() > 1 {
+            tracing::error!(
+                "Multiple accounts found for the prefix {account_id_prefix}: {account_ids:?}"
+            );
+            return Err(IdPrefixFetchError::MultipleMatches(format!(
+                "account ID prefix {account_id_prefix}"
+            )));
+        }
+
+        account_ids.pop().ok_or_else(no_match)
+    }
+
+    /// Returns every tracked [`Address`] along with the ID of the account it belongs to.
+    ///
+    /// Addresses are grouped by account and returned in a stable order.
+    pub async fn list_all_addresses(&self) -> Result<Vec<(Address, AccountId)>, ClientError> {
+        self.store.get_all_addresses().await.map_err(Into::into)
+    }
+
     /// Retrieves the full [`Account`] object from the store, returning `None` if not found.
     ///
     /// This method loads the complete account state including vault, storage, and code.
@@ -566,6 +731,81 @@ impl<AUTH> Client<AUTH> {
             .ok_or(ClientError::AccountDataNotFound(account_id))
     }
 
+    /// Retrieves the full [`Account`] state as it was at block `block_num`.
+    ///
+    /// - For public accounts, the state is fetched from the node at the requested block.
+    /// - For private accounts, the state is rebuilt from the store: the latest transaction of the
+    ///   account committed at or before `block_num` determines which of the locally tracked states
+    ///   (latest or historical) is returned.
+    ///
+    /// # Errors
+    /// - [`ClientError::AccountStateNotAvailable`] if the state of a private account at that block
+    ///   cannot be determined, e.g. no transaction of the account was committed at or before the
+    ///   block, the block is past the sync height, or the historical state was pruned.
+    /// - [`ClientError::AccountNotFoundOnChain`] if a public account doesn't exist on chain.
+    pub async fn get_account_at_block(
+        &mut self,
+        account_id: AccountId,
+        block_num: BlockNumber,
+    ) -> Result<Account, ClientError> {
+        if account_id.is_public() {
+            let fetched_account = self
+                .rpc_api
+                .get_account_details_at(account_id, AccountStateAt::Block(block_num))
+                .await
+                .map_err(|err| match err.endpoint_error() {
+                    Some(EndpointError::GetAccount(GetAccountError::AccountNotFound)) => {
+                        ClientError::AccountNotFoundOnChain(account_id)
+                    },
+                    _ => ClientError::RpcError(err),
+                })?;
+
+            return fetched_account.ok_or(ClientError::AccountIsPrivate(account_id));
+        }
+
+        let not_available = ClientError::AccountStateNotAvailable { account_id, block_num };
+        if block_num > self.store.get_sync_height().await? {
+            return Err(not_available);
+        }
+
+        let committed: Vec<(BlockNumber, TransactionDetails)> = self
+            .store
+            .get_transactions(TransactionFilter::All)
+            .await?
+            .into_iter()
+            .filter(|record| record.details.account_id == account_id)
+            .filter_map(|record| match record.status {
+                TransactionStatus::Committed { block_number, .. } if block_number <= block_num => {
+                    Some((block_number, record.details))
+                },
+                _ => None,
+            })
+            .collect();
+
+        let Some(last_block) = committed.iter().map(|(block_number, _)| *block_number).max() else {
+            return Err(not_available);
+        };
+
+        // Several transactions of the account may have been committed in the same block; the
+        // resulting state is the one that no other transaction in that block started from.
+        let last_block_details: Vec<&TransactionDetails> = committed
+            .iter()
+            .filter(|(block_number, _)| *block_number == last_block)
+            .map(|(_, details)| details)
+            .collect();
+        let Some(commitment) = last_block_details
+            .iter()
+            .map(|details| details.final_account_state)
+            .find(|state| {
+                !last_block_details.iter().any(|details| details.init_account_state == *state)
+            })
+        else {
+            return Err(not_available);
+        };
+
+        self.store.get_account_by_commitment(commitment).await?.ok_or(not_available)
+    }
+
     /// Creates an [`AccountReader`] for lazy access to account data.
     ///
     /// The `AccountReader` provides lazy access to account state - each method call
@@ -589,6 +829,40 @@ impl<AUTH> Client<AUTH> {
         AccountReader::new(self.store.clone(), account_id)
     }
 
+    /// Archives an account, so that the client stops following it without losing its state or
+    /// history.
+    ///
+    /// Archived accounts are not listed by [`Client::get_account_headers`] (use
+    /// [`Client::get_filtered_account_headers`] with [`AccountFilter::Archived`] to list them) and
+    /// are skipped by sync. They can still be loaded by ID.
+    pub async fn archive_account(&mut self, account_id: AccountId) -> Result<(), ClientError> {
+        self.store.archive_account(account_id).await.map_err(Into::into)
+    }
+
+    /// Restores an archived account, so that it's followed by sync again.
+    ///
+    /// Account updates committed on chain while the account was archived are not fetched
+    /// retroactively, as sync only follows the changes made after its current sync height.
+    pub async fn unarchive_account(&mut self, account_id: AccountId) -> Result<(), ClientError> {
+        self.store.unarchive_account(account_id).await.map_err(Into::into)
+    }
+
+    /// Marks the account as watch-only, or clears the mark.
+    ///
+    /// Watch-only accounts are still tracked and synced, but transactions with them as the sender
+    /// are rejected early with [`ClientError::WatchOnlyAccount`], instead of failing during
+    /// execution because the client doesn't hold their keys.
+    pub async fn set_account_watch_only(
+        &mut self,
+        account_id: AccountId,
+        watch_only: bool,
+    ) -> Result<(), ClientError> {
+        self.store
+            .set_account_watch_only(account_id, watch_only)
+            .await
+            .map_err(Into::into)
+    }
+
     /// Prunes historical account states for the specified account up to the given nonce.
     ///
     /// Deletes all historical entries with `replaced_at_nonce <= up_to_nonce` and any
@@ -605,6 +879,121 @@ impl<AUTH> Client<AUTH> {
     }
 }
 
+// ACCOUNT SUMMARY
+// ================================================================================================
+
+/// An overview of an account's state, as returned by [`Client::account_summary`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountSummary {
+    /// The ID of the account.
+    pub account_id: AccountId,
+    /// The nonce of the latest local state of the account.
+    pub nonce: Felt,
+    /// The fungible balances of the account, by faucet ID.
+    pub balances: BTreeMap<AccountId, FaucetBalance>,
+    /// The number of transactions of the account that haven't been committed yet.
+    pub pending_transactions: usize,
+    /// The number of committed notes the account can consume.
+    pub consumable_notes: usize,
+}
+
+/// The balance an account holds of a fungible asset, as reported by [`AccountSummary`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FaucetBalance {
+    /// The balance in the last state of the account that isn't the result of a pending
+    /// transaction.
+    pub committed: u64,
+    /// The balance in the latest local state of the account, which includes the changes made by
+    /// its pending transactions. This is the balance new transactions can spend.
+    pub available: u64,
+}
+
+impl<AUTH> Client<AUTH>
+where
+    AUTH: TransactionAuthenticator + Sync,
+{
+    /// Returns an [`AccountSummary`] with the nonce, the fungible balances, the number of pending
+    /// transactions and the number of consumable notes of the account.
+    ///
+    /// The account header and vault are read without loading the full account. The committed
+    /// state of the account is only loaded when it has pending transactions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ClientError::AccountDataNotFound`] if the account isn't tracked, or if it has
+    /// pending transactions and the state they started from is no longer tracked.
+    pub async fn account_summary(
+        &self,
+        account_id: AccountId,
+    ) -> Result<AccountSummary, ClientError> {
+        let nonce = self
+            .store
+            .get_account_nonce(account_id)
+            .await?
+            .ok_or(ClientError::AccountDataNotFound(account_id))?;
+        let available = fungible_balances(&self.store.get_account_vault(account_id).await?);
+
+        let pending: Vec<TransactionDetails> = self
+            .store
+            .get_transactions(TransactionFilter::Uncommitted)
+            .await?
+            .into_iter()
+            .filter(|record| record.details.account_id == account_id)
+            .map(|record| record.details)
+            .collect();
+
+        // The pending transactions form a chain of states; the committed state is the one the
+        // chain starts from, which no other pending transaction produced. New accounts start from
+        // an empty commitment, as they have no committed state yet.
+        let committed_commitment = pending
+            .iter()
+            .map(|details| details.init_account_state)
+            .find(|state| !pending.iter().any(|details| details.final_account_state == *state));
+        let committed = match committed_commitment {
+            Some(commitment) if commitment == EMPTY_WORD => BTreeMap::new(),
+            Some(commitment) => {
+                let account = self
+                    .store
+                    .get_account_by_commitment(commitment)
+                    .await?
+                    .ok_or(ClientError::AccountDataNotFound(account_id))?;
+                fungible_balances(account.vault())
+            },
+            None => available.clone(),
+        };
+
+        let mut balances = BTreeMap::new();
+        for (faucet_id, amount) in &available {
+            balances.entry(*faucet_id).or_default().available = *amount;
+        }
+        for (faucet_id, amount) in &committed {
+            balances.entry(*faucet_id).or_default().committed = *amount;
+        }
+
+        let consumable_notes = self.get_consumable_notes(Some(account_id)).await?.len();
+
+        Ok(AccountSummary {
+            account_id,
+            nonce,
+            balances,
+            pending_transactions: pending.len(),
+            consumable_notes,
+        })
+    }
+}
+
+/// Returns the total amount of each fungible asset held in the vault, by faucet ID.
+fn fungible_balances(vault: &AssetVault) -> BTreeMap<AccountId, u64> {
+    let mut balances = BTreeMap::new();
+    for asset in vault.assets() {
+        if let Asset::Fungible(fungible_asset) = asset {
+            let balance = balances.entry(fungible_asset.faucet_id()).or_insert(0u64);
+            *balance = balance.saturating_add(u64::from(fungible_asset.amount()));
+        }
+    }
+    balances
+}
+
 // UTILITY FUNCTIONS
 // ================================================================================================
 
@@ -0,0 +1,171 @@
+//! Provides types for working with addresses within the Miden network.
+//!
+//! Besides the protocol's address types, the module provides helpers to share an address as a
+//! URI, e.g. encoded in a QR code:
+//!
+//! ```text
+//! miden:<bech32 address>?network=<network>
+//! ```
+//!
+//! The `network` parameter names the network the address is meant for: `mainnet`, `testnet`,
+//! `devnet`, or the bech32 prefix of a custom network. See [`address_to_uri`] and
+//! [`parse_address_uri`].
+
+use alloc::format;
+use alloc::string::{String, ToString};
+
+pub use miden_protocol::address::{
+    Address,
+    AddressId,
+    AddressInterface,
+    CustomNetworkId,
+    NetworkId,
+    RoutingParameters,
+};
+
+use crate::errors::ClientError;
+
+/// Scheme of the URIs built by [`address_to_uri`].
+pub const ADDRESS_URI_SCHEME: &str = "miden";
+
+/// Name of the URI query parameter holding the network of the address.
+const NETWORK_PARAM: &str = "network";
+
+/// Encodes `address` for `network_id` as a `miden:<bech32>?network=<network>` URI.
+pub fn address_to_uri(address: &Address, network_id: &NetworkId) -> String {
+    format!(
+        "{ADDRESS_URI_SCHEME}:{}?{NETWORK_PARAM}={}",
+        address.encode(network_id.clone()),
+        network_name(network_id)
+    )
+}
+
+/// Parses an address URI built by [`address_to_uri`].
+///
+/// Returns the address along with the network named by the URI's `network` parameter, or `None`
+/// if the URI has no such parameter. Unknown query parameters are ignored.
+///
+/// # Errors
+///
+/// Returns [`ClientError::InvalidAddressUri`] if the URI doesn't use the `miden` scheme, the
+/// address is not valid bech32, or the `network` parameter is unknown or doesn't match the
+/// network encoded in the address.
+pub fn parse_address_uri(uri: &str) -> Result<(Address, Option<NetworkId>), ClientError> {
+    let invalid = |reason: String| ClientError::InvalidAddressUri(uri.to_string(), reason);
+
+    let rest = uri
+        .strip_prefix(ADDRESS_URI_SCHEME)
+        .and_then(|rest| rest.strip_prefix(':'))
+        .ok_or_else(|| invalid(format!("expected the `{ADDRESS_URI_SCHEME}:` scheme")))?;
+    let (encoded, query) = rest.split_once('?').unwrap_or((rest, ""));
+
+    let (encoded_network_id, address) =
+        Address::decode(encoded).map_err(|err| invalid(err.to_string()))?;
+
+    let mut network_id = None;
+    for param in query.split('&').filter(|param| !param.is_empty()) {
+        let Some((NETWORK_PARAM, value)) = param.split_once('=') else {
+            continue;
+        };
+        let named_network_id = parse_network_name(value)
+            .ok_or_else(|| invalid(format!("unknown network `{value}`")))?;
+        if named_network_id != encoded_network_id {
+            return Err(invalid(format!(
+                "network `{value}` does not match the address network `{encoded_network_id}`"
+            )));
+        }
+        network_id = Some(named_network_id);
+    }
+
+    Ok((address, network_id))
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the name used for `network_id` in address URIs.
+fn network_name(network_id: &NetworkId) -> String {
+    match network_id {
+        NetworkId::Mainnet => "mainnet".to_string(),
+        NetworkId::Testnet => "testnet".to_string(),
+        NetworkId::Devnet => "devnet".to_string(),
+        custom => custom.to_string(),
+    }
+}
+
+/// Parses a network name produced by [`network_name`].
+fn parse_network_name(name: &str) -> Option<NetworkId> {
+    match name {
+        "mainnet" => Some(NetworkId::Mainnet),
+        "testnet" => Some(NetworkId::Testnet),
+        "devnet" => Some(NetworkId::Devnet),
+        custom => NetworkId::new(custom).ok(),
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+    use alloc::string::String;
+
+    use miden_protocol::account::AccountId;
+    use miden_protocol::testing::account_id::ACCOUNT_ID_REGULAR_PRIVATE_ACCOUNT_UPDATABLE_CODE;
+
+    use super::{Address, NetworkId, address_to_uri, parse_address_uri};
+    use crate::ClientError;
+
+    fn test_address() -> Address {
+        Address::new(
+            AccountId::try_from(ACCOUNT_ID_REGULAR_PRIVATE_ACCOUNT_UPDATABLE_CODE).unwrap(),
+        )
+    }
+
+    #[test]
+    fn address_uri_round_trips_across_networks() {
+        let address = test_address();
+        let networks = [
+            NetworkId::Mainnet,
+            NetworkId::Testnet,
+            NetworkId::Devnet,
+            NetworkId::new("mlcl").unwrap(),
+        ];
+
+        for network_id in networks {
+            let uri = address_to_uri(&address, &network_id);
+            assert!(uri.starts_with("miden:"));
+
+            let (parsed, parsed_network_id) = parse_address_uri(&uri).unwrap();
+            assert_eq!(parsed, address);
+            assert_eq!(parsed_network_id, Some(network_id));
+        }
+
+        let uri = address_to_uri(&address, &NetworkId::Testnet);
+        assert!(uri.ends_with("?network=testnet"));
+    }
+
+    #[test]
+    fn address_uri_without_network_parameter() {
+        let address = test_address();
+        let uri = format!("miden:{}", address.encode(NetworkId::Testnet));
+
+        assert_eq!(parse_address_uri(&uri).unwrap(), (address, None));
+    }
+
+    #[test]
+    fn invalid_address_uris_are_rejected() {
+        let encoded = test_address().encode(NetworkId::Testnet);
+        let invalid_uris: [String; 4] = [
+            format!("bitcoin:{encoded}?network=testnet"),
+            format!("miden:{encoded}?network=devnet"),
+            format!("miden:{encoded}?network=not-a-network"),
+            "miden:not-an-address?network=testnet".into(),
+        ];
+
+        for uri in invalid_uris {
+            assert!(matches!(parse_address_uri(&uri), Err(ClientError::InvalidAddressUri(..))));
+        }
+    }
+}
@@ -5,21 +5,31 @@ use alloc::vec::Vec;
 
 use miden_protocol::assembly::{DefaultSourceManager, SourceManagerSync};
 use miden_protocol::block::BlockNumber;
+use miden_protocol::crypto::hash::rpo::Rpo256;
 use miden_protocol::crypto::rand::RandomCoin;
-use miden_protocol::{Felt, MAX_TX_EXECUTION_CYCLES, MIN_TX_EXECUTION_CYCLES};
+use miden_protocol::{MAX_TX_EXECUTION_CYCLES, MIN_TX_EXECUTION_CYCLES};
+use miden_tx::auth::TransactionAuthenticator;
 use miden_tx::{ExecutionOptions, LocalTransactionProver};
 use rand::Rng;
 
 #[cfg(any(feature = "tonic", feature = "std"))]
 use crate::alloc::string::ToString;
+use crate::code_builder::CompiledComponentSources;
 #[cfg(feature = "std")]
 use crate::keystore::FilesystemKeyStore;
-use crate::keystore::Keystore;
+use crate::keystore::{CompositeAuthenticator, Keystore};
+use crate::note::ScreenerPolicy;
 use crate::note_transport::NoteTransportClient;
 use crate::pswap::PswapTransactionObserver;
-use crate::rpc::{Endpoint, NodeRpcClient};
+use crate::rpc::{Endpoint, LoggingRpcClient, NodeRpcClient};
 use crate::store::{Store, StoreError};
+use crate::sync::{BlockVerifier, PruningPolicy};
+use crate::transaction::foreign_account_cache::{
+    DEFAULT_FOREIGN_ACCOUNT_CACHE_CAPACITY,
+    ForeignAccountCache,
+};
 use crate::transaction::{TransactionObserver, TransactionProver};
+use crate::utils::RwLock;
 use crate::{Client, ClientError, ClientRng, ClientRngBox, DebugMode, grpc_support};
 
 // CONSTANTS
@@ -32,6 +42,14 @@ const TX_DISCARD_DELTA: u32 = 20;
 const IRRELEVANT_BLOCK_PRUNE_INTERVAL: u32 = 1;
 /// Whether the client should cache the current Partial MMR in memory by default.
 const CACHE_PARTIAL_MMR_IN_MEMORY: bool = false;
+/// Whether foreign account inputs fetched for FPI are cached in memory by default.
+const CACHE_FOREIGN_ACCOUNT_INPUTS: bool = true;
+/// Whether proven transactions are persisted in the store by default.
+const RETAIN_PROOFS: bool = false;
+/// Whether transactions that fail to execute are recorded in the store by default.
+const RECORD_FAILED_TRANSACTIONS: bool = false;
+/// The default number of synced blocks after which the cached fee parameters are refreshed.
+const FEE_PARAMETERS_REFRESH_INTERVAL: u32 = 100;
 
 pub use grpc_support::*;
 
@@ -78,14 +96,16 @@ pub trait StoreFactory {
 ///
 /// - **RPC client** ([`NodeRpcClient`]): Provides connectivity to the Miden node for submitting
 ///   transactions, syncing state, and fetching account/note data. Configure via
-///   [`rpc()`](Self::rpc) or [`grpc_client()`](Self::grpc_client).
+///   [`rpc()`](Self::rpc) or [`grpc_client()`](Self::grpc_client). TLS settings of the gRPC client,
+///   such as a custom root CA, are configured via [`grpc_tls()`](Self::grpc_tls).
 ///
 /// - **Store** ([`Store`]): Provides persistence for accounts, notes, and transaction history.
 ///   Configure via [`store()`](Self::store).
 ///
 /// - **RNG** ([`FeltRng`](miden_protocol::crypto::rand::FeltRng)): Provides randomness for
 ///   generating keys, serial numbers, and other cryptographic operations. If not provided, a random
-///   seed-based RNG is created automatically. Configure via [`rng()`](Self::rng).
+///   seed-based RNG is created automatically. Configure via [`rng()`](Self::rng), or seed the
+///   default RNG for reproducible key derivation via [`with_rng_seed()`](Self::with_rng_seed).
 ///
 /// - **Authenticator** ([`TransactionAuthenticator`](miden_tx::auth::TransactionAuthenticator)):
 ///   Handles transaction signing when signatures are requested from within the VM. Configure via
@@ -108,16 +128,43 @@ pub trait StoreFactory {
 ///   rebuilding it from store. Disabled by default. Configure via
 ///   [`cache_partial_mmr_in_memory()`](Self::cache_partial_mmr_in_memory).
 ///
+/// - **Foreign account cache**: Reuses foreign account proofs fetched for the current sync height
+///   across transactions. Enabled by default. Configure via
+///   [`cache_foreign_account_inputs()`](Self::cache_foreign_account_inputs).
+///
+/// - **Proof retention**: Persists proven transactions in the store when they are submitted.
+///   Disabled by default. Configure via [`retain_proofs()`](Self::retain_proofs).
+///
+/// - **Failed transaction records**: Persists transactions that fail to execute locally, along with
+///   the error that caused the failure. Disabled by default. Configure via
+///   [`record_failed_transactions()`](Self::record_failed_transactions).
+///
 /// - **Max block number delta**: Maximum number of blocks the client can be behind the network for
 ///   transactions and account proofs to be considered valid. Configure via
 ///   [`max_block_number_delta()`](Self::max_block_number_delta).
+///
+/// - **Note screener policy** ([`ScreenerPolicy`]): Optional policy that overrides or extends which
+///   untracked notes are tracked during sync. Configure via
+///   [`note_screener_policy()`](Self::note_screener_policy).
+///
+/// - **Block verifier** ([`BlockVerifier`]): Optional hook that can reject block headers received
+///   from the node before they're stored. Configure via [`block_verifier()`](Self::block_verifier).
 pub struct ClientBuilder<AUTH> {
-    /// An optional custom RPC client. If provided, this takes precedence over `rpc_endpoint`.
+    /// An optional custom RPC client. If provided, this takes precedence over `grpc_config`.
     rpc_api: Option<Arc<dyn NodeRpcClient>>,
+    /// Configuration for lazy gRPC client initialization (used by network constructors and
+    /// [`grpc_client()`](Self::grpc_client)).
+    #[cfg(feature = "tonic")]
+    grpc_config: Option<GrpcClientConfig>,
+    /// Optional TLS settings for the gRPC client created from `grpc_config`.
+    #[cfg(feature = "tonic")]
+    grpc_tls: Option<crate::rpc::GrpcTlsConfig>,
     /// An optional store provided by the user.
     pub store: Option<StoreBuilder>,
     /// An optional RNG provided by the user.
     rng: Option<ClientRngBox>,
+    /// An optional seed for the client's default RNG. Ignored if `rng` is set.
+    rng_seed: Option<[u8; 32]>,
     /// The authenticator provided by the user.
     authenticator: Option<Arc<AUTH>>,
     /// A flag to enable debug mode.
@@ -128,8 +175,20 @@ pub struct ClientBuilder<AUTH> {
     /// Number of synced blocks between automatic pruning runs for irrelevant block data.
     /// If `None`, automatic irrelevant-block pruning is disabled.
     irrelevant_block_prune_interval: Option<u32>,
+    /// An optional policy for pruning stale data after state syncs.
+    pruning_policy: Option<PruningPolicy>,
     /// Whether the current Partial MMR should be cached in memory between sync-related operations.
     cache_partial_mmr_in_memory: bool,
+    /// Whether foreign account inputs fetched for FPI should be cached in memory.
+    cache_foreign_account_inputs: bool,
+    /// Whether proven transactions should be persisted in the store when submitted.
+    retain_proofs: bool,
+    /// Whether transactions that fail to execute should be recorded in the store.
+    record_failed_transactions: bool,
+    /// Number of synced blocks after which the cached fee parameters are fetched again.
+    fee_parameters_refresh_interval: u32,
+    /// Whether the requests made through the RPC client should be logged.
+    rpc_logging: bool,
     /// Maximum number of blocks the client can be behind the network for transactions and account
     /// proofs to be considered valid.
     max_block_number_delta: Option<u32>,
@@ -144,25 +203,42 @@ pub struct ClientBuilder<AUTH> {
     endpoint: Option<Endpoint>,
     /// An optional shared source manager for MASM source information.
     source_manager: Option<Arc<dyn SourceManagerSync>>,
+    /// An optional policy for the client's note screener.
+    note_screener_policy: Option<Arc<dyn ScreenerPolicy>>,
+    /// An optional verifier for the block headers received from the node.
+    block_verifier: Option<Arc<dyn BlockVerifier>>,
 }
 
 impl<AUTH> Default for ClientBuilder<AUTH> {
     fn default() -> Self {
         Self {
             rpc_api: None,
+            #[cfg(feature = "tonic")]
+            grpc_config: None,
+            #[cfg(feature = "tonic")]
+            grpc_tls: None,
             store: None,
             rng: None,
+            rng_seed: None,
             authenticator: None,
             in_debug_mode: DebugMode::Disabled,
             tx_discard_delta: Some(TX_DISCARD_DELTA),
             irrelevant_block_prune_interval: Some(IRRELEVANT_BLOCK_PRUNE_INTERVAL),
+            pruning_policy: None,
             cache_partial_mmr_in_memory: CACHE_PARTIAL_MMR_IN_MEMORY,
+            cache_foreign_account_inputs: CACHE_FOREIGN_ACCOUNT_INPUTS,
+            retain_proofs: RETAIN_PROOFS,
+            record_failed_transactions: RECORD_FAILED_TRANSACTIONS,
+            fee_parameters_refresh_interval: FEE_PARAMETERS_REFRESH_INTERVAL,
+            rpc_logging: false,
             max_block_number_delta: None,
             note_transport_api: None,
             note_transport_config: None,
             tx_prover: None,
             endpoint: None,
             source_manager: None,
+            note_screener_policy: None,
+            block_verifier: None,
         }
     }
 }
@@ -204,10 +280,10 @@ where
     pub fn for_testnet() -> Self {
         let endpoint = Endpoint::testnet();
         Self {
-            rpc_api: Some(Arc::new(crate::rpc::GrpcClient::new(
-                &endpoint,
-                DEFAULT_GRPC_TIMEOUT_MS,
-            ))),
+            grpc_config: Some(GrpcClientConfig {
+                endpoint: endpoint.clone(),
+                timeout_ms: DEFAULT_GRPC_TIMEOUT_MS,
+            }),
             tx_prover: Some(Arc::new(RemoteTransactionProver::new(
                 TESTNET_PROVER_ENDPOINT.to_string(),
             ))),
@@ -248,10 +324,10 @@ where
     pub fn for_devnet() -> Self {
         let endpoint = Endpoint::devnet();
         Self {
-            rpc_api: Some(Arc::new(crate::rpc::GrpcClient::new(
-                &endpoint,
-                DEFAULT_GRPC_TIMEOUT_MS,
-            ))),
+            grpc_config: Some(GrpcClientConfig {
+                endpoint: endpoint.clone(),
+                timeout_ms: DEFAULT_GRPC_TIMEOUT_MS,
+            }),
             tx_prover: Some(Arc::new(RemoteTransactionProver::new(
                 DEVNET_PROVER_ENDPOINT.to_string(),
             ))),
@@ -292,10 +368,10 @@ where
     pub fn for_localhost() -> Self {
         let endpoint = Endpoint::localhost();
         Self {
-            rpc_api: Some(Arc::new(crate::rpc::GrpcClient::new(
-                &endpoint,
-                DEFAULT_GRPC_TIMEOUT_MS,
-            ))),
+            grpc_config: Some(GrpcClientConfig {
+                endpoint: endpoint.clone(),
+                timeout_ms: DEFAULT_GRPC_TIMEOUT_MS,
+            }),
             endpoint: Some(endpoint),
             ..Self::default()
         }
@@ -323,20 +399,68 @@ where
     #[must_use]
     pub fn rpc(mut self, client: Arc<dyn NodeRpcClient>) -> Self {
         self.rpc_api = Some(client);
+        #[cfg(feature = "tonic")]
+        self.grpc_config = None;
+        self
+    }
+
+    /// Wraps the RPC client in a [`LoggingRpcClient`], which logs every request made to the node,
+    /// with its latency and the size of its result, at `debug` level.
+    ///
+    /// Applies to the client passed to [`rpc()`](Self::rpc) as well as to the gRPC client created
+    /// on [`build()`](Self::build).
+    #[must_use]
+    pub fn with_rpc_logging(mut self) -> Self {
+        self.rpc_logging = true;
         self
     }
 
     /// Sets a gRPC client from the endpoint and optional timeout.
+    ///
+    /// The client is created when [`build()`](Self::build) is called, so TLS settings passed to
+    /// [`grpc_tls()`](Self::grpc_tls) apply to it regardless of the call order.
     #[must_use]
     #[cfg(feature = "tonic")]
     pub fn grpc_client(mut self, endpoint: &crate::rpc::Endpoint, timeout_ms: Option<u64>) -> Self {
-        self.rpc_api = Some(Arc::new(crate::rpc::GrpcClient::new(
-            endpoint,
-            timeout_ms.unwrap_or(DEFAULT_GRPC_TIMEOUT_MS),
-        )));
+        self.rpc_api = None;
+        self.grpc_config = Some(GrpcClientConfig {
+            endpoint: endpoint.clone(),
+            timeout_ms: timeout_ms.unwrap_or(DEFAULT_GRPC_TIMEOUT_MS),
+        });
+        self
+    }
+
+    /// Sets the TLS settings (custom root CA, client certificate) of the gRPC client configured
+    /// through [`grpc_client()`](Self::grpc_client) or one of the network constructors.
+    ///
+    /// Has no effect on a client passed to [`rpc()`](Self::rpc), nor on `wasm32` targets, where
+    /// TLS is negotiated by the browser.
+    #[must_use]
+    #[cfg(feature = "tonic")]
+    pub fn grpc_tls(mut self, tls_config: crate::rpc::GrpcTlsConfig) -> Self {
+        self.grpc_tls = Some(tls_config);
         self
     }
 
+    /// Sets a gRPC client from the endpoint configured in the environment (see
+    /// [`Endpoint::from_env`](crate::rpc::Endpoint::from_env)) and optional timeout.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environment doesn't define an endpoint or it can't be parsed.
+    #[cfg(all(feature = "tonic", feature = "std"))]
+    pub fn grpc_from_env(self, timeout_ms: Option<u64>) -> Result<Self, ClientError> {
+        let endpoint = Endpoint::from_env()
+            .map_err(ClientError::ClientInitializationError)?
+            .ok_or_else(|| {
+                ClientError::ClientInitializationError(
+                    "no RPC endpoint is set in the environment".to_string(),
+                )
+            })?;
+
+        Ok(self.grpc_client(&endpoint, timeout_ms))
+    }
+
     /// Provide a store to be used by the client.
     #[must_use]
     pub fn store(mut self, store: Arc<dyn Store>) -> Self {
@@ -345,9 +469,35 @@ where
     }
 
     /// Optionally provide a custom RNG.
+    ///
+    /// This overrides any seed set with [`with_rng_seed()`](Self::with_rng_seed).
     #[must_use]
     pub fn rng(mut self, rng: ClientRngBox) -> Self {
         self.rng = Some(rng);
+        self.rng_seed = None;
+        self
+    }
+
+    /// Seeds the client's default RNG with `seed`, overriding any RNG set with
+    /// [`rng()`](Self::rng).
+    ///
+    /// Clients built with the same seed draw the same sequence of random values, so the keys and
+    /// note serial numbers they generate can be reproduced by performing the same operations in
+    /// the same order. If no seed is provided, a random one is generated; in both cases it can be
+    /// read back with [`Client::rng_seed`].
+    ///
+    /// # Security
+    ///
+    /// The seed is as sensitive as the secret keys derived from it and must be stored
+    /// accordingly. A seed must never be used by more than one running client: two clients seeded
+    /// alike generate the same keys and serial numbers, making their accounts controllable by each
+    /// other and their notes linkable. To keep restarting a client from the same backup
+    /// reproducible, derive a distinct seed for each session from the backed-up one instead of
+    /// reusing it.
+    #[must_use]
+    pub fn with_rng_seed(mut self, seed: [u8; 32]) -> Self {
+        self.rng_seed = Some(seed);
+        self.rng = None;
         self
     }
 
@@ -405,6 +555,27 @@ where
         self
     }
 
+    /// Sets the [`PruningPolicy`] the client applies after state syncs to delete consumed notes,
+    /// irrelevant blocks and MMR authentication nodes it no longer needs.
+    ///
+    /// By default, no pruning policy is set.
+    #[must_use]
+    pub fn pruning_policy(mut self, policy: PruningPolicy) -> Self {
+        self.pruning_policy = Some(policy);
+        self
+    }
+
+    /// Sets the number of synced blocks after which the fee parameters cached by
+    /// [`Client::get_fee_parameters`] are fetched again from the node.
+    ///
+    /// By default, the interval is set to `FEE_PARAMETERS_REFRESH_INTERVAL` (100 blocks). `0`
+    /// fetches them on every call.
+    #[must_use]
+    pub fn fee_parameters_refresh_interval(mut self, interval: u32) -> Self {
+        self.fee_parameters_refresh_interval = interval;
+        self
+    }
+
     /// Enables or disables the in-memory Partial MMR cache.
     ///
     /// When enabled, the client reuses the current Partial MMR between sync and pruning
@@ -416,10 +587,52 @@ where
         self
     }
 
+    /// Enables or disables the in-memory foreign account cache.
+    ///
+    /// When enabled, the account proofs fetched for foreign procedure invocation are reused by
+    /// later transactions executed at the same sync height, instead of being requested from the
+    /// node again. Entries are dropped once the sync height moves past the block they were
+    /// fetched at.
+    #[must_use]
+    pub fn cache_foreign_account_inputs(mut self, enabled: bool) -> Self {
+        self.cache_foreign_account_inputs = enabled;
+        self
+    }
+
+    /// Enables or disables the retention of proven transactions.
+    ///
+    /// When enabled, every proven transaction submitted by the client is persisted in the store
+    /// and can be retrieved later with [`Client::get_transaction_proof`]. Proofs are large, so
+    /// this is disabled by default.
+    #[must_use]
+    pub fn retain_proofs(mut self, enabled: bool) -> Self {
+        self.retain_proofs = enabled;
+        self
+    }
+
+    /// Enables or disables the recording of failed transaction executions.
+    ///
+    /// When enabled, every transaction that fails to execute locally is persisted in the store
+    /// with a [`TransactionStatus::Failed`](crate::transaction::TransactionStatus::Failed) status
+    /// holding the (truncated) error message. Failed transactions can be retrieved with
+    /// [`TransactionFilter::Failed`](crate::store::TransactionFilter::Failed). Disabled by default.
+    #[must_use]
+    pub fn record_failed_transactions(mut self, enabled: bool) -> Self {
+        self.record_failed_transactions = enabled;
+        self
+    }
+
     /// Sets the number of blocks after which pending transactions are considered stale and
     /// discarded.
     ///
-    /// This is an alias for [`tx_discard_delta`](Self::tx_discard_delta).
+    /// The "graceful blocks" are the blocks a submitted transaction is given to be included in a
+    /// block before the client gives up on it: once the sync height passes the submission height
+    /// by more than `delta` blocks, the pending transaction is discarded locally. `None` keeps
+    /// pending transactions indefinitely.
+    ///
+    /// This is the setting's former name, kept as an alias for
+    /// [`tx_discard_delta`](Self::tx_discard_delta): it sets the same value, so whichever of the
+    /// two is called last wins.
     #[deprecated(since = "0.10.0", note = "Use `tx_discard_delta` instead")]
     #[must_use]
     pub fn tx_graceful_blocks(mut self, delta: Option<u32>) -> Self {
@@ -427,6 +640,22 @@ where
         self
     }
 
+    /// Sets the [`ScreenerPolicy`] used during sync to decide whether untracked notes should be
+    /// tracked. If not set, [`DefaultScreenerPolicy`](crate::note::DefaultScreenerPolicy) is used.
+    #[must_use]
+    pub fn note_screener_policy(mut self, policy: Arc<dyn ScreenerPolicy>) -> Self {
+        self.note_screener_policy = Some(policy);
+        self
+    }
+
+    /// Sets the [`BlockVerifier`] consulted before storing block headers received from the node.
+    /// If not set, every header is accepted.
+    #[must_use]
+    pub fn block_verifier(mut self, verifier: Arc<dyn BlockVerifier>) -> Self {
+        self.block_verifier = Some(verifier);
+        self
+    }
+
     /// Sets a custom note transport client directly.
     #[must_use]
     pub fn note_transport(mut self, client: Arc<dyn NoteTransportClient>) -> Self {
@@ -459,14 +688,29 @@ where
     /// - Returns an error if the store cannot be instantiated.
     #[allow(clippy::unused_async, unused_mut)]
     pub async fn build(mut self) -> Result<Client<AUTH>, ClientError> {
-        // Determine the RPC client to use.
-        let rpc_api: Arc<dyn NodeRpcClient> = if let Some(client) = self.rpc_api {
+        // Determine the RPC client to use: prefer explicit client, fall back to config (tonic only)
+        #[cfg(feature = "tonic")]
+        if self.rpc_api.is_none()
+            && let Some(config) = self.grpc_config
+        {
+            let mut client = crate::rpc::GrpcClient::new(&config.endpoint, config.timeout_ms);
+            if let Some(tls_config) = self.grpc_tls {
+                client = client.with_tls_config(tls_config);
+            }
+
+            self.rpc_api = Some(Arc::new(client) as Arc<dyn NodeRpcClient>);
+        }
+
+        let mut rpc_api: Arc<dyn NodeRpcClient> = if let Some(client) = self.rpc_api {
             client
         } else {
             return Err(ClientError::ClientInitializationError(
                 "RPC client is required. Call `.rpc(...)` or `.grpc_client(...)`.".into(),
             ));
         };
+        if self.rpc_logging {
+            rpc_api = Arc::new(LoggingRpcClient::new(rpc_api));
+        }
 
         // Ensure a store was provided.
         let store = if let Some(store_builder) = self.store {
@@ -480,13 +724,13 @@ where
             ));
         };
 
-        // Use the provided RNG, or create a default one.
-        let rng = if let Some(user_rng) = self.rng {
-            user_rng
+        // Use the provided RNG, or create a default one from the provided or a random seed.
+        let (rng, rng_seed) = if let Some(user_rng) = self.rng {
+            (user_rng, None)
         } else {
-            let mut seed_rng = rand::rng();
-            let coin_seed: [u64; 4] = seed_rng.random();
-            Box::new(RandomCoin::new(coin_seed.map(Felt::new_unchecked).into()))
+            let seed = self.rng_seed.unwrap_or_else(|| rand::rng().random());
+            let rng: ClientRngBox = Box::new(RandomCoin::new(Rpo256::hash(&seed)));
+            (rng, Some(seed))
         };
 
         // Set default prover if not provided
@@ -508,6 +752,9 @@ where
             rpc_api.set_rpc_limits(limits).await;
         }
 
+        // Reuse the persisted network ID, if any, to avoid fetching it again.
+        let network_id = store.get_network_id().await?;
+
         // Initialize note transport: prefer explicit client, fall back to config (tonic only)
         #[cfg(feature = "tonic")]
         if self.note_transport_api.is_none()
@@ -531,10 +778,12 @@ where
         Ok(Client {
             store,
             rng: ClientRng::new(rng),
+            rng_seed,
             rpc_api,
             tx_prover,
             authenticator: self.authenticator,
             source_manager,
+            component_sources: CompiledComponentSources::default(),
             exec_options: ExecutionOptions::new(
                 Some(MAX_TX_EXECUTION_CYCLES),
                 MIN_TX_EXECUTION_CYCLES,
@@ -546,15 +795,73 @@ where
             tx_discard_delta: self.tx_discard_delta,
             irrelevant_block_prune_interval: self.irrelevant_block_prune_interval,
             last_irrelevant_block_prune_sync_height: None,
+            pruning_policy: self.pruning_policy,
+            last_pruning_sync_height: None,
             max_block_number_delta: self.max_block_number_delta,
             note_transport_api: self.note_transport_api.clone(),
             cache_partial_mmr_in_memory: self.cache_partial_mmr_in_memory,
             partial_mmr: None,
+            foreign_account_cache: self.cache_foreign_account_inputs.then(|| {
+                RwLock::new(ForeignAccountCache::new(DEFAULT_FOREIGN_ACCOUNT_CACHE_CAPACITY))
+            }),
+            retain_proofs: self.retain_proofs,
+            record_failed_transactions: self.record_failed_transactions,
+            network_id: RwLock::new(network_id),
+            fee_parameters: RwLock::new(None),
+            fee_parameters_refresh_interval: self.fee_parameters_refresh_interval,
+            genesis_verified: false,
             transaction_observers,
+            note_screener_policy: self.note_screener_policy,
+            block_verifier: self.block_verifier,
         })
     }
 }
 
+// COMPOSITE AUTHENTICATOR CONVENIENCE METHOD
+// ================================================================================================
+
+/// Convenience method for [`ClientBuilder`] when using a [`CompositeAuthenticator`] as the
+/// authenticator.
+impl<K> ClientBuilder<CompositeAuthenticator<K>>
+where
+    K: Keystore,
+{
+    /// Registers an additional signing backend (e.g. an HSM or a hardware wallet) on the
+    /// [`CompositeAuthenticator`] set with [`authenticator()`](Self::authenticator). Signature
+    /// requests are routed to the backend that holds the requested key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no authenticator was set before calling this method.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let client = ClientBuilder::new()
+    ///     .rpc(rpc_client)
+    ///     .store(store)
+    ///     .authenticator(Arc::new(CompositeAuthenticator::new(keystore)))
+    ///     .add_authenticator(Arc::new(hardware_wallet))?
+    ///     .build()
+    ///     .await?;
+    /// ```
+    pub fn add_authenticator<A>(self, authenticator: Arc<A>) -> Result<Self, ClientError>
+    where
+        A: TransactionAuthenticator + Send + Sync + 'static,
+    {
+        let composite = self.authenticator.as_ref().ok_or_else(|| {
+            ClientError::ClientInitializationError(
+                "an authenticator must be set with `.authenticator(...)` before adding signing \
+                 backends to it"
+                    .into(),
+            )
+        })?;
+        composite.add_authenticator(authenticator);
+
+        Ok(self)
+    }
+}
+
 // FILESYSTEM KEYSTORE CONVENIENCE METHOD
 // ================================================================================================
 
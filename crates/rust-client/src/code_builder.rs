@@ -0,0 +1,216 @@
+//! Provides [`ClientCodeBuilder`], the code builder returned by [`Client::code_builder`].
+
+use alloc::collections::VecDeque;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::ops::{Deref, DerefMut};
+
+use miden_protocol::account::{
+    AccountCode,
+    AccountComponent,
+    AccountComponentCode,
+    AccountComponentMetadata,
+    AccountProcedureRoot,
+};
+use miden_protocol::assembly::{Library, Parse};
+use miden_protocol::note::NoteScript;
+use miden_protocol::transaction::TransactionScript;
+use miden_standards::code_builder::CodeBuilder;
+use miden_standards::errors::CodeBuilderError;
+
+#[cfg(doc)]
+use crate::Client;
+use crate::utils::RwLock;
+
+// COMPILED COMPONENT SOURCES
+// ================================================================================================
+
+/// Maximum number of compiled component sources a client keeps. Once reached, the sources of the
+/// oldest components are dropped first.
+const MAX_COMPILED_COMPONENT_SOURCES: usize = 64;
+
+/// MASM source of an account component compiled with [`ClientCodeBuilder`], along with the roots
+/// of the procedures it exports.
+pub(crate) struct CompiledComponentSource {
+    procedures: Vec<AccountProcedureRoot>,
+    source: String,
+}
+
+/// Sources of the account components compiled with a client's code builders, shared between the
+/// client and the builders it hands out.
+///
+/// Each client owns its own set, which keeps at most [`MAX_COMPILED_COMPONENT_SOURCES`] entries.
+#[derive(Clone, Default)]
+pub(crate) struct CompiledComponentSources(Arc<RwLock<VecDeque<CompiledComponentSource>>>);
+
+impl CompiledComponentSources {
+    fn record(&self, component_code: &AccountComponentCode, component_path: &str, source: String) {
+        // The metadata and storage slots don't affect the exported procedures, so a bare
+        // component is enough to read them.
+        let Ok(component) = AccountComponent::new(
+            component_code.clone(),
+            Vec::new(),
+            AccountComponentMetadata::new(component_path),
+        ) else {
+            return;
+        };
+
+        // A component without procedures would match the code of every account.
+        let procedures: Vec<AccountProcedureRoot> =
+            component.procedures().map(|(root, _)| root).collect();
+        if procedures.is_empty() {
+            return;
+        }
+
+        let mut sources = self.0.write();
+        if sources.len() == MAX_COMPILED_COMPONENT_SOURCES {
+            sources.pop_front();
+        }
+        sources.push_back(CompiledComponentSource { procedures, source });
+    }
+
+    /// Returns the sources of the compiled components that make up `code`, joined in the order
+    /// they were compiled, or `None` if none of them do.
+    pub(crate) fn source_for(&self, code: &AccountCode) -> Option<String> {
+        let sources = self.0.read();
+        let matching: Vec<&str> = sources
+            .iter()
+            .filter(|component| {
+                !component.procedures.is_empty()
+                    && component.procedures.iter().all(|root| code.procedures().contains(root))
+            })
+            .map(|component| component.source.as_str())
+            .collect();
+
+        (!matching.is_empty()).then(|| matching.join("\n"))
+    }
+}
+
+// CLIENT CODE BUILDER
+// ================================================================================================
+
+/// A [`CodeBuilder`] bound to the client's source manager.
+///
+/// Besides compiling code, the builder keeps the MASM source of the account components it
+/// compiles. When an account built from those components is added to the client, the source is
+/// registered for the account's code so it can be retrieved with
+/// [`Client::get_account_code_source`].
+///
+/// The builder dereferences to [`CodeBuilder`], and its consuming methods mirror those of
+/// [`CodeBuilder`], so it can be used in its place. It converts into a [`CodeBuilder`] with
+/// [`From`].
+#[derive(Clone)]
+pub struct ClientCodeBuilder {
+    inner: CodeBuilder,
+    component_sources: CompiledComponentSources,
+}
+
+impl ClientCodeBuilder {
+    pub(crate) fn new(inner: CodeBuilder, component_sources: CompiledComponentSources) -> Self {
+        Self { inner, component_sources }
+    }
+
+    /// See [`CodeBuilder::with_warnings_as_errors`].
+    #[must_use]
+    pub fn with_warnings_as_errors(self, yes: bool) -> Self {
+        Self {
+            inner: self.inner.with_warnings_as_errors(yes),
+            component_sources: self.component_sources,
+        }
+    }
+
+    /// See [`CodeBuilder::with_linked_module`].
+    pub fn with_linked_module(
+        self,
+        module_path: impl AsRef<str>,
+        module_code: impl Parse,
+    ) -> Result<Self, CodeBuilderError> {
+        Ok(Self {
+            inner: self.inner.with_linked_module(module_path, module_code)?,
+            component_sources: self.component_sources,
+        })
+    }
+
+    /// See [`CodeBuilder::with_statically_linked_library`].
+    pub fn with_statically_linked_library(
+        self,
+        library: impl AsRef<Library>,
+    ) -> Result<Self, CodeBuilderError> {
+        Ok(Self {
+            inner: self.inner.with_statically_linked_library(library)?,
+            component_sources: self.component_sources,
+        })
+    }
+
+    /// See [`CodeBuilder::with_dynamically_linked_library`].
+    pub fn with_dynamically_linked_library(
+        self,
+        library: impl AsRef<Library>,
+    ) -> Result<Self, CodeBuilderError> {
+        Ok(Self {
+            inner: self.inner.with_dynamically_linked_library(library)?,
+            component_sources: self.component_sources,
+        })
+    }
+
+    /// Compiles the account component code in `component_code` under `component_path`, keeping
+    /// its source for the accounts built from it.
+    ///
+    /// See [`CodeBuilder::compile_component_code`].
+    pub fn compile_component_code(
+        self,
+        component_path: impl AsRef<str>,
+        component_code: impl AsRef<str>,
+    ) -> Result<AccountComponentCode, CodeBuilderError> {
+        let component_path = component_path.as_ref();
+        let source = component_code.as_ref().to_string();
+
+        let code = self.inner.compile_component_code(component_path, source.as_str())?;
+        self.component_sources.record(&code, component_path, source);
+
+        Ok(code)
+    }
+
+    /// See [`CodeBuilder::compile_tx_script`].
+    pub fn compile_tx_script(
+        self,
+        tx_script: impl Parse,
+    ) -> Result<TransactionScript, CodeBuilderError> {
+        self.inner.compile_tx_script(tx_script)
+    }
+
+    /// See [`CodeBuilder::compile_note_script`].
+    pub fn compile_note_script(
+        self,
+        note_script: impl Parse,
+    ) -> Result<NoteScript, CodeBuilderError> {
+        self.inner.compile_note_script(note_script)
+    }
+
+    /// Returns the underlying [`CodeBuilder`]. Account components compiled with it don't have
+    /// their source kept.
+    pub fn into_inner(self) -> CodeBuilder {
+        self.inner
+    }
+}
+
+impl Deref for ClientCodeBuilder {
+    type Target = CodeBuilder;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl DerefMut for ClientCodeBuilder {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl From<ClientCodeBuilder> for CodeBuilder {
+    fn from(builder: ClientCodeBuilder) -> Self {
+        builder.inner
+    }
+}
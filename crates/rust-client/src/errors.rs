@@ -4,7 +4,9 @@ use alloc::vec::Vec;
 use core::fmt;
 
 use miden_protocol::Word;
-use miden_protocol::account::AccountId;
+use miden_protocol::account::{AccountId, StorageSlotName};
+use miden_protocol::address::AddressInterface;
+use miden_protocol::block::BlockNumber;
 use miden_protocol::crypto::merkle::MerkleError;
 pub use miden_protocol::errors::{AccountError, AccountIdError, AssetError, NetworkIdError};
 use miden_protocol::errors::{
@@ -16,6 +18,7 @@ use miden_protocol::errors::{
     TransactionScriptError,
 };
 use miden_protocol::note::NoteId;
+use miden_protocol::transaction::TransactionId;
 use miden_standards::account::interface::AccountInterfaceError;
 // RE-EXPORTS
 // ================================================================================================
@@ -74,6 +77,8 @@ const TROUBLESHOOTING_DOC: &str =
 pub enum ClientError {
     #[error("address {0} is already being tracked")]
     AddressAlreadyTracked(String),
+    #[error("invalid address URI `{0}`: {1}")]
+    InvalidAddressUri(String, String),
     #[error("account with id {0} is already being tracked")]
     AccountAlreadyTracked(AccountId),
     #[error("account error")]
@@ -100,8 +105,22 @@ pub enum ClientError {
     AccountNonceTooLow,
     #[error("asset error")]
     AssetError(#[from] AssetError),
+    #[error("account {account_id} does not implement the {interface} address interface")]
+    IncompatibleAddressInterface {
+        account_id: AccountId,
+        interface: AddressInterface,
+    },
     #[error("account data wasn't found for account id {0}")]
     AccountDataNotFound(AccountId),
+    #[error("state of account {account_id} at block {block_num} is not available")]
+    AccountStateNotAvailable {
+        account_id: AccountId,
+        block_num: BlockNumber,
+    },
+    #[error(
+        "the vault of account {0} cannot be synced on its own because other parts of its state changed on chain"
+    )]
+    AccountVaultSyncIncomplete(AccountId),
     #[error(transparent)]
     BatchBuilder(#[from] BatchBuilderError),
     #[error("data store error")]
@@ -124,6 +143,34 @@ pub enum ClientError {
     InvalidPartialMmrForest,
     #[error("chain validation error: {0}")]
     ChainValidationError(String),
+    #[error(
+        "state sync made no progress past block {block_num} while the node's chain tip is {chain_tip}"
+    )]
+    SyncStalled {
+        block_num: BlockNumber,
+        chain_tip: BlockNumber,
+    },
+    #[error(
+        "the node serves genesis block {actual}, but the store was created for genesis {expected}"
+    )]
+    GenesisMismatch { expected: Word, actual: Word },
+    #[error("block header {block_num} was rejected by the block verifier: {reason}")]
+    BlockVerificationFailed { block_num: BlockNumber, reason: String },
+    #[error(
+        "invalid block range {from}..={to}: the range must be ordered and end before the sync height {sync_height}"
+    )]
+    InvalidBlockRange {
+        from: BlockNumber,
+        to: BlockNumber,
+        sync_height: BlockNumber,
+    },
+    #[error("cannot verify the inclusion of note {note_id}: {reason}")]
+    NoteInclusionDataMissing { note_id: NoteId, reason: String },
+    #[error("cannot resubmit transaction {transaction_id}: {reason}")]
+    TransactionNotResubmittable {
+        transaction_id: TransactionId,
+        reason: String,
+    },
     #[error(
         "cannot track a new account without its seed; the seed is required to validate the account ID's correctness"
     )]
@@ -148,6 +195,16 @@ pub enum ClientError {
         "account {0} has no notes available to consume; sync the client or check that notes targeting this account exist"
     )]
     NoConsumableNoteForAccount(AccountId),
+    #[error(
+        "consuming the notes of account {account_id} failed after submitting {} transaction(s)",
+        submitted_transactions.len()
+    )]
+    NoteConsumptionIncomplete {
+        account_id: AccountId,
+        submitted_transactions: Vec<TransactionId>,
+        #[source]
+        source: Box<ClientError>,
+    },
     #[error("RPC error")]
     RpcError(#[from] RpcError),
     #[error(
@@ -158,6 +215,15 @@ pub enum ClientError {
     NoteScreenerError(#[from] NoteScreenerError),
     #[error("storage error")]
     StoreError(#[from] StoreError),
+    #[error(
+        "storage map {slot_name} of account {account_id} cannot be synced on its own because other parts of the account's state changed on chain"
+    )]
+    StorageMapSyncIncomplete {
+        account_id: AccountId,
+        slot_name: StorageSlotName,
+    },
+    #[error("account {0} is watch-only and cannot be used as a transaction sender")]
+    WatchOnlyAccount(AccountId),
     #[error("transaction execution failed")]
     TransactionExecutorError(#[from] TransactionExecutorError),
     #[error("invalid transaction input")]
@@ -172,6 +238,8 @@ pub enum ClientError {
     TransactionScriptError(#[source] TransactionScriptError),
     #[error("client initialization error: {0}")]
     ClientInitializationError(String),
+    #[error("operation timed out after {0:?}")]
+    Timeout(core::time::Duration),
     #[error("expected full account data for account {0}, but only partial data is available")]
     AccountRecordNotFull(AccountId),
     #[error("expected partial account data for account {0}, but full data was found")]
@@ -196,6 +264,11 @@ pub enum ClientError {
         #[source]
         source: Box<ClientError>,
     },
+    #[error(
+        "transaction for account {0} was skipped because an earlier transaction for the same \
+         account failed"
+    )]
+    PrecedingTransactionFailed(AccountId),
     /// Generic carrier for feature-specific errors raised by an observer
     /// or domain module. Keeps `ClientError` free of per-feature variants;
     /// each feature provides its own `From<MyFeatureError> for ClientError`
@@ -267,6 +340,29 @@ impl From<&ClientError> for Option<ErrorHint> {
                           or re-export the account from a more up-to-date source.".to_string(),
                 docs_url: Some(TROUBLESHOOTING_DOC),
             }),
+            ClientError::AccountVaultSyncIncomplete(account_id) => Some(ErrorHint {
+                message: format!(
+                    "The storage or code of account {account_id} changed along with its vault. \
+                     Run `sync` to fetch the full account state from the network."
+                ),
+                docs_url: Some(TROUBLESHOOTING_DOC),
+            }),
+            ClientError::StorageMapSyncIncomplete { account_id, .. } => Some(ErrorHint {
+                message: format!(
+                    "Other storage slots, the vault or the code of account {account_id} changed \
+                     along with the storage map. Run `sync` to fetch the full account state from \
+                     the network."
+                ),
+                docs_url: Some(TROUBLESHOOTING_DOC),
+            }),
+            ClientError::WatchOnlyAccount(account_id) => Some(ErrorHint {
+                message: format!(
+                    "Account {account_id} is marked as watch-only. If the client holds its keys, \
+                     clear the mark with `account --clear-watch-only {account_id}` before using \
+                     it as a transaction sender."
+                ),
+                docs_url: Some(TROUBLESHOOTING_DOC),
+            }),
             ClientError::NoConsumableNoteForAccount(account_id) => Some(ErrorHint {
                 message: format!(
                     "No notes were found that account {account_id} can consume. \
@@ -285,6 +381,30 @@ impl From<&ClientError> for Option<ErrorHint> {
                           Ensure your client version is compatible with the node version.".to_string(),
                 docs_url: Some(TROUBLESHOOTING_DOC),
             }),
+            ClientError::GenesisMismatch { .. } => Some(ErrorHint {
+                message: "The configured node belongs to a different network than the local \
+                          store. Point the client to the node the store was created with, or use \
+                          a new store for this network.".to_string(),
+                docs_url: Some(TROUBLESHOOTING_DOC),
+            }),
+            ClientError::BlockVerificationFailed { .. } => Some(ErrorHint {
+                message: "The node served a block header that failed verification. It may be \
+                          misbehaving or on a fork; consider syncing against a different node."
+                    .to_string(),
+                docs_url: Some(TROUBLESHOOTING_DOC),
+            }),
+            ClientError::NoteInclusionDataMissing { .. } => Some(ErrorHint {
+                message: "The chain data needed to verify the note is not stored locally. Run \
+                          `sync` to fetch it, or use `Client::fetch_block_range` for blocks the \
+                          client no longer tracks.".to_string(),
+                docs_url: Some(TROUBLESHOOTING_DOC),
+            }),
+            ClientError::TransactionNotResubmittable { .. } => Some(ErrorHint {
+                message: "Only pending transactions whose proof was retained can be resubmitted. \
+                          Enable `retain_proofs` to keep the proofs of submitted transactions, \
+                          and execute the transaction again if it expired.".to_string(),
+                docs_url: Some(TROUBLESHOOTING_DOC),
+            }),
             ClientError::AddNewAccountWithoutSeed => Some(ErrorHint {
                 message: "New accounts require a seed to derive their initial state. \
                           Use `Client::new_account()` which generates the seed automatically, \
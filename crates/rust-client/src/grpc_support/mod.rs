@@ -1,6 +1,7 @@
 use alloc::string::String;
 
 pub use crate::RemoteTransactionProver;
+use crate::rpc::Endpoint;
 
 /// Default remote prover endpoint for testnet.
 pub const TESTNET_PROVER_ENDPOINT: &str = "https://tx-prover.testnet.miden.io";
@@ -11,6 +12,15 @@ pub const DEVNET_PROVER_ENDPOINT: &str = "https://tx-prover.devnet.miden.io";
 /// Default timeout in milliseconds for gRPC connections (10 seconds).
 pub const DEFAULT_GRPC_TIMEOUT_MS: u64 = 10_000;
 
+/// Configuration for lazy gRPC client initialization.
+///
+/// Deferring the creation of the `GrpcClient` until `build()` is called lets settings that apply
+/// to it, such as TLS, be configured in any order relative to the endpoint.
+pub struct GrpcClientConfig {
+    pub endpoint: Endpoint,
+    pub timeout_ms: u64,
+}
+
 /// Configuration for lazy note transport initialization.
 ///
 /// Since `GrpcNoteTransportClient::connect()` is async, this struct allows us to defer
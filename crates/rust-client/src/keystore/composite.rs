@@ -0,0 +1,199 @@
+use alloc::boxed::Box;
+use alloc::collections::BTreeSet;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use miden_protocol::account::AccountId;
+use miden_protocol::account::auth::{AuthSecretKey, PublicKey, PublicKeyCommitment, Signature};
+use miden_tx::AuthenticationError;
+use miden_tx::auth::{SigningInputs, TransactionAuthenticator};
+
+use super::{KeyStoreError, Keystore};
+use crate::utils::RwLock;
+
+// COMPOSITE AUTHENTICATOR
+// ================================================================================================
+
+/// An authenticator that routes each signature request to the backend holding the requested key.
+///
+/// Keys are managed through a primary [`Keystore`], which also signs for the keys it holds.
+/// Additional signing backends (e.g. HSMs or hardware wallets) can be registered with
+/// [`CompositeAuthenticator::add_authenticator`]; they only need to implement
+/// [`TransactionAuthenticator`]. When a signature is requested, the keystore is asked first and
+/// then every backend in the order they were added, and the first one that knows the public key
+/// signs.
+///
+/// All [`Keystore`] methods are delegated to the primary keystore, so keys held only by the
+/// additional backends aren't listed by them.
+pub struct CompositeAuthenticator<K> {
+    /// Keystore used to manage keys, which also signs for the keys it holds.
+    keystore: K,
+    /// Additional signing backends, in the order they're asked for a key.
+    authenticators: RwLock<Vec<Arc<dyn SigningBackend>>>,
+}
+
+impl<K> CompositeAuthenticator<K>
+where
+    K: Keystore,
+{
+    /// Creates a new [`CompositeAuthenticator`] with the provided keystore and no additional
+    /// signing backends.
+    pub fn new(keystore: K) -> Self {
+        Self {
+            keystore,
+            authenticators: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// Registers an additional signing backend. It's asked for keys after the keystore and the
+    /// backends registered before it.
+    pub fn add_authenticator<A>(&self, authenticator: Arc<A>)
+    where
+        A: TransactionAuthenticator + Send + Sync + 'static,
+    {
+        self.authenticators.write().push(authenticator);
+    }
+
+    /// Returns the keystore used to manage keys.
+    pub fn keystore(&self) -> &K {
+        &self.keystore
+    }
+
+    /// Returns the additional backend that holds the public key with the given commitment, if any.
+    async fn find_authenticator(
+        &self,
+        pub_key_commitment: PublicKeyCommitment,
+    ) -> Option<(Arc<dyn SigningBackend>, Arc<PublicKey>)> {
+        let authenticators = self.authenticators.read().clone();
+        for authenticator in authenticators {
+            if let Some(public_key) = authenticator.public_key(pub_key_commitment).await {
+                return Some((authenticator, public_key));
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K> From<super::FilesystemKeyStore> for CompositeAuthenticator<K>
+where
+    K: Keystore + From<super::FilesystemKeyStore>,
+{
+    fn from(keystore: super::FilesystemKeyStore) -> Self {
+        Self::new(K::from(keystore))
+    }
+}
+
+impl<K> TransactionAuthenticator for CompositeAuthenticator<K>
+where
+    K: Keystore + Sync,
+{
+    /// Gets a signature over a message from the first backend that holds the given public key.
+    ///
+    /// # Errors
+    /// If no backend holds the public key, [`AuthenticationError::UnknownPublicKey`] is returned.
+    async fn get_signature(
+        &self,
+        pub_key: PublicKeyCommitment,
+        signing_info: &SigningInputs,
+    ) -> Result<Signature, AuthenticationError> {
+        if self.keystore.get_public_key(pub_key).await.is_some() {
+            return self.keystore.get_signature(pub_key, signing_info).await;
+        }
+
+        match self.find_authenticator(pub_key).await {
+            Some((authenticator, _)) => authenticator.sign(pub_key, signing_info).await,
+            None => Err(AuthenticationError::UnknownPublicKey(pub_key)),
+        }
+    }
+
+    async fn get_public_key(
+        &self,
+        pub_key_commitment: PublicKeyCommitment,
+    ) -> Option<Arc<PublicKey>> {
+        if let Some(public_key) = self.keystore.get_public_key(pub_key_commitment).await {
+            return Some(public_key);
+        }
+
+        self.find_authenticator(pub_key_commitment)
+            .await
+            .map(|(_, public_key)| public_key)
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+impl<K> Keystore for CompositeAuthenticator<K>
+where
+    K: Keystore + Send + Sync,
+{
+    async fn add_key(
+        &self,
+        key: &AuthSecretKey,
+        account_id: AccountId,
+    ) -> Result<(), KeyStoreError> {
+        self.keystore.add_key(key, account_id).await
+    }
+
+    async fn remove_key(&self, pub_key: PublicKeyCommitment) -> Result<(), KeyStoreError> {
+        self.keystore.remove_key(pub_key).await
+    }
+
+    async fn get_key(
+        &self,
+        pub_key: PublicKeyCommitment,
+    ) -> Result<Option<AuthSecretKey>, KeyStoreError> {
+        self.keystore.get_key(pub_key).await
+    }
+
+    async fn get_account_key_commitments(
+        &self,
+        account_id: &AccountId,
+    ) -> Result<BTreeSet<PublicKeyCommitment>, KeyStoreError> {
+        self.keystore.get_account_key_commitments(account_id).await
+    }
+
+    async fn get_account_id_by_key_commitment(
+        &self,
+        pub_key_commitment: PublicKeyCommitment,
+    ) -> Result<Option<AccountId>, KeyStoreError> {
+        self.keystore.get_account_id_by_key_commitment(pub_key_commitment).await
+    }
+}
+
+// SIGNING BACKEND
+// ================================================================================================
+
+/// Object-safe counterpart of [`TransactionAuthenticator`], so that backends of different types
+/// can be stored together.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+trait SigningBackend: Send + Sync {
+    async fn public_key(&self, pub_key_commitment: PublicKeyCommitment) -> Option<Arc<PublicKey>>;
+
+    async fn sign(
+        &self,
+        pub_key: PublicKeyCommitment,
+        signing_info: &SigningInputs,
+    ) -> Result<Signature, AuthenticationError>;
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+impl<A> SigningBackend for A
+where
+    A: TransactionAuthenticator + Send + Sync,
+{
+    async fn public_key(&self, pub_key_commitment: PublicKeyCommitment) -> Option<Arc<PublicKey>> {
+        self.get_public_key(pub_key_commitment).await
+    }
+
+    async fn sign(
+        &self,
+        pub_key: PublicKeyCommitment,
+        signing_info: &SigningInputs,
+    ) -> Result<Signature, AuthenticationError> {
+        self.get_signature(pub_key, signing_info).await
+    }
+}
@@ -1,11 +1,16 @@
 use alloc::boxed::Box;
 use alloc::collections::{BTreeMap, BTreeSet};
 use alloc::string::String;
+use alloc::vec::Vec;
 use std::fs;
 use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+use argon2::Argon2;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
 use miden_protocol::Word;
 use miden_protocol::account::AccountId;
 use miden_protocol::account::auth::{AuthSecretKey, PublicKey, PublicKeyCommitment, Signature};
@@ -14,6 +19,7 @@ use miden_tx::auth::{SigningInputs, TransactionAuthenticator};
 use miden_tx::utils::serde::{Deserializable, Serializable};
 use miden_tx::utils::sync::RwLock;
 use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
 
 use super::{KeyStoreError, Keystore};
 
@@ -141,6 +147,111 @@ impl KeyIndex {
     }
 }
 
+// ENCRYPTION
+// ================================================================================================
+
+const ENCRYPTION_FILE_NAME: &str = "keystore_encryption.json";
+const ENCRYPTION_VERSION: u32 = 1;
+
+/// Prefix of encrypted key files, used to tell them apart from plaintext ones.
+const ENCRYPTED_KEY_MAGIC: &[u8; 4] = b"MKE1";
+/// Plaintext sealed in the encryption file to check the passphrase when opening the keystore.
+const PASSPHRASE_CHECK: &[u8] = b"miden-keystore";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+
+/// The structure of the file that marks a keystore directory as encrypted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EncryptionInfo {
+    version: u32,
+    /// Hex-encoded salt used to derive the encryption key from the passphrase.
+    salt: String,
+    /// Hex-encoded [`PASSPHRASE_CHECK`], sealed with the derived key.
+    check: String,
+}
+
+impl EncryptionInfo {
+    /// Loads the encryption info from disk, returning `None` if the keystore isn't encrypted.
+    fn read_from_file(keys_directory: &Path) -> Result<Option<Self>, KeyStoreError> {
+        let path = keys_directory.join(ENCRYPTION_FILE_NAME);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let contents =
+            fs::read_to_string(&path).map_err(keystore_error("error reading encryption file"))?;
+        serde_json::from_str(&contents).map(Some).map_err(|err| {
+            KeyStoreError::DecodingError(format!("error parsing encryption file: {err:?}"))
+        })
+    }
+
+    fn write_to_file(&self, keys_directory: &Path) -> Result<(), KeyStoreError> {
+        let contents = serde_json::to_string_pretty(self).map_err(|err| {
+            KeyStoreError::StorageError(format!("error serializing encryption file: {err:?}"))
+        })?;
+        fs::write(keys_directory.join(ENCRYPTION_FILE_NAME), contents)
+            .map_err(keystore_error("error writing encryption file"))
+    }
+}
+
+/// Seals and opens key files with XChaCha20-Poly1305, using a key derived from the keystore
+/// passphrase with Argon2id. The derived key is zeroized when the cipher is dropped.
+#[derive(Clone)]
+struct KeyCipher {
+    key: Zeroizing<[u8; 32]>,
+}
+
+impl core::fmt::Debug for KeyCipher {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("KeyCipher").finish_non_exhaustive()
+    }
+}
+
+impl KeyCipher {
+    fn derive(passphrase: &str, salt: &[u8]) -> Result<Self, KeyStoreError> {
+        let mut key = Zeroizing::new([0u8; 32]);
+        Argon2::default()
+            .hash_password_into(passphrase.as_bytes(), salt, key.as_mut_slice())
+            .map_err(|err| {
+                KeyStoreError::StorageError(format!("error deriving encryption key: {err}"))
+            })?;
+        Ok(Self { key })
+    }
+
+    fn aead(&self) -> XChaCha20Poly1305 {
+        XChaCha20Poly1305::new(Key::from_slice(self.key.as_slice()))
+    }
+
+    /// Returns `nonce || ciphertext`.
+    fn seal(&self, plaintext: &[u8]) -> Result<Vec<u8>, KeyStoreError> {
+        let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .aead()
+            .encrypt(&nonce, plaintext)
+            .map_err(|_| KeyStoreError::StorageError("error encrypting secret key".into()))?;
+
+        let mut sealed = nonce.to_vec();
+        sealed.extend_from_slice(&ciphertext);
+        Ok(sealed)
+    }
+
+    /// Returns the decrypted contents of `sealed`, which are zeroized when dropped.
+    fn open(&self, sealed: &[u8]) -> Result<Zeroizing<Vec<u8>>, KeyStoreError> {
+        if sealed.len() < NONCE_LEN {
+            return Err(KeyStoreError::DecodingError("encrypted data is too short".into()));
+        }
+        let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+        self.aead()
+            .decrypt(XNonce::from_slice(nonce), ciphertext)
+            .map(Zeroizing::new)
+            .map_err(|_| {
+                KeyStoreError::DecodingError(
+                    "error decrypting data: wrong passphrase or corrupted file".into(),
+                )
+            })
+    }
+}
+
 // FILESYSTEM KEYSTORE
 // ================================================================================================
 
@@ -149,12 +260,17 @@ impl KeyIndex {
 /// and the contents of the file are the serialized public and secret key.
 ///
 /// Account-to-key mappings are stored in a separate JSON index file.
+///
+/// Key files can optionally be encrypted at rest with a passphrase (see
+/// [`FilesystemKeyStore::new_encrypted`]). The account-to-key index is not encrypted.
 #[derive(Debug)]
 pub struct FilesystemKeyStore {
     /// The directory where the keys are stored and read from.
     pub keys_directory: PathBuf,
     /// The in-memory index of account-to-key mappings.
     index: RwLock<KeyIndex>,
+    /// The cipher used to seal key files, if the keystore is encrypted.
+    cipher: Option<KeyCipher>,
 }
 
 impl Clone for FilesystemKeyStore {
@@ -163,13 +279,118 @@ impl Clone for FilesystemKeyStore {
         Self {
             keys_directory: self.keys_directory.clone(),
             index: RwLock::new(index),
+            cipher: self.cipher.clone(),
         }
     }
 }
 
 impl FilesystemKeyStore {
     /// Creates a [`FilesystemKeyStore`] on a specific directory.
+    ///
+    /// Returns an error if the keystore in the directory is encrypted. Use
+    /// [`FilesystemKeyStore::new_encrypted`] to open it instead.
     pub fn new(keys_directory: PathBuf) -> Result<Self, KeyStoreError> {
+        if Self::is_encrypted(&keys_directory) {
+            return Err(KeyStoreError::StorageError(format!(
+                "keystore at {} is encrypted and requires a passphrase",
+                keys_directory.display()
+            )));
+        }
+
+        Self::open(keys_directory, None)
+    }
+
+    /// Creates a [`FilesystemKeyStore`] on a specific directory whose key files are encrypted
+    /// with a key derived from `passphrase`.
+    ///
+    /// If the directory doesn't contain a keystore yet, a new encrypted keystore is created.
+    /// Existing plaintext keystores must be migrated first with
+    /// [`FilesystemKeyStore::encrypt_keystore`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the passphrase doesn't match the one the keystore was encrypted with,
+    /// or if the directory contains plaintext keys.
+    pub fn new_encrypted(keys_directory: PathBuf, passphrase: &str) -> Result<Self, KeyStoreError> {
+        if !keys_directory.exists() {
+            fs::create_dir_all(&keys_directory)
+                .map_err(keystore_error("error creating keys directory"))?;
+        }
+
+        let info = match EncryptionInfo::read_from_file(&keys_directory)? {
+            Some(info) => info,
+            None => {
+                if !key_file_paths(&keys_directory)?.is_empty() {
+                    return Err(KeyStoreError::StorageError(
+                        "keystore contains unencrypted keys, migrate it with `encrypt_keystore`"
+                            .into(),
+                    ));
+                }
+                init_encryption(&keys_directory, passphrase)?
+            },
+        };
+
+        let salt = hex::decode(&info.salt).map_err(|err| {
+            KeyStoreError::DecodingError(format!("error decoding keystore salt: {err}"))
+        })?;
+        let check = hex::decode(&info.check).map_err(|err| {
+            KeyStoreError::DecodingError(format!("error decoding keystore check: {err}"))
+        })?;
+
+        let cipher = KeyCipher::derive(passphrase, &salt)?;
+        if !cipher
+            .open(&check)
+            .is_ok_and(|plaintext| plaintext.as_slice() == PASSPHRASE_CHECK)
+        {
+            return Err(KeyStoreError::DecodingError("invalid keystore passphrase".into()));
+        }
+
+        let keystore = Self::open(keys_directory, Some(cipher))?;
+        // Finish any interrupted migration by sealing keys that were left in plaintext.
+        keystore.seal_plaintext_keys()?;
+        Ok(keystore)
+    }
+
+    /// Encrypts an existing plaintext keystore with a key derived from `passphrase`, and returns
+    /// the encrypted keystore.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the keystore is already encrypted.
+    pub fn encrypt_keystore(
+        keys_directory: PathBuf,
+        passphrase: &str,
+    ) -> Result<Self, KeyStoreError> {
+        if Self::is_encrypted(&keys_directory) {
+            return Err(KeyStoreError::StorageError(format!(
+                "keystore at {} is already encrypted",
+                keys_directory.display()
+            )));
+        }
+
+        if !keys_directory.exists() {
+            fs::create_dir_all(&keys_directory)
+                .map_err(keystore_error("error creating keys directory"))?;
+        }
+
+        // Make sure every key can be read before touching anything on disk.
+        for path in key_file_paths(&keys_directory)? {
+            let bytes = Zeroizing::new(
+                fs::read(&path).map_err(keystore_error("error reading secret key file"))?,
+            );
+            read_secret_key(&bytes)?;
+        }
+
+        init_encryption(&keys_directory, passphrase)?;
+        Self::new_encrypted(keys_directory, passphrase)
+    }
+
+    /// Returns whether the keystore in `keys_directory` is encrypted.
+    pub fn is_encrypted(keys_directory: &Path) -> bool {
+        keys_directory.join(ENCRYPTION_FILE_NAME).exists()
+    }
+
+    fn open(keys_directory: PathBuf, cipher: Option<KeyCipher>) -> Result<Self, KeyStoreError> {
         if !keys_directory.exists() {
             fs::create_dir_all(&keys_directory)
                 .map_err(keystore_error("error creating keys directory"))?;
@@ -180,16 +401,41 @@ impl FilesystemKeyStore {
         Ok(FilesystemKeyStore {
             keys_directory,
             index: RwLock::new(index),
+            cipher,
         })
     }
 
+    /// Encrypts the key files that are still stored in plaintext.
+    fn seal_plaintext_keys(&self) -> Result<(), KeyStoreError> {
+        let Some(cipher) = &self.cipher else {
+            return Ok(());
+        };
+
+        for path in key_file_paths(&self.keys_directory)? {
+            let bytes = Zeroizing::new(
+                fs::read(&path).map_err(keystore_error("error reading secret key file"))?,
+            );
+            if bytes.starts_with(ENCRYPTED_KEY_MAGIC) {
+                continue;
+            }
+            let key = read_secret_key(&bytes)?;
+            write_secret_key_file(&path, &seal_secret_key(cipher, &key)?)?;
+        }
+
+        Ok(())
+    }
+
     /// Adds a secret key to the keystore without updating account mappings.
     ///
     /// This is an internal method. Use [`Keystore::add_key`] instead.
     fn add_key_without_account(&self, key: &AuthSecretKey) -> Result<(), KeyStoreError> {
         let pub_key_commitment = key.public_key().to_commitment();
         let file_path = key_file_path(&self.keys_directory, pub_key_commitment);
-        write_secret_key_file(&file_path, key)
+        let contents = match &self.cipher {
+            Some(cipher) => seal_secret_key(cipher, key)?,
+            None => Zeroizing::new(key.to_bytes()),
+        };
+        write_secret_key_file(&file_path, &contents)
     }
 
     /// Retrieves a secret key from the keystore given the commitment of a public key.
@@ -200,12 +446,24 @@ impl FilesystemKeyStore {
         let file_path = key_file_path(&self.keys_directory, pub_key);
         match fs::read(&file_path) {
             Ok(bytes) => {
-                let key = AuthSecretKey::read_from_bytes(&bytes).map_err(|err| {
-                    KeyStoreError::DecodingError(format!(
-                        "error reading secret key from file: {err:?}"
-                    ))
-                })?;
-                Ok(Some(key))
+                let bytes = Zeroizing::new(bytes);
+                let bytes = match (&self.cipher, bytes.strip_prefix(ENCRYPTED_KEY_MAGIC)) {
+                    (Some(cipher), Some(sealed)) => cipher.open(sealed)?,
+                    (None, Some(_)) => {
+                        return Err(KeyStoreError::DecodingError(
+                            "secret key file is encrypted".into(),
+                        ));
+                    },
+                    // Plaintext files are sealed when an encrypted keystore is opened, so one
+                    // showing up afterwards was not written by this keystore.
+                    (Some(_), None) => {
+                        return Err(KeyStoreError::DecodingError(
+                            "secret key file is not encrypted".into(),
+                        ));
+                    },
+                    (None, None) => bytes,
+                };
+                Ok(Some(read_secret_key(&bytes)?))
             },
             Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
             Err(e) => Err(keystore_error("error reading secret key file")(e)),
@@ -337,9 +595,9 @@ fn key_file_path(keys_directory: &Path, pub_key_commitment: PublicKeyCommitment)
     keys_directory.join(filename)
 }
 
-/// Writes an [`AuthSecretKey`] into a file with restrictive permissions (0600 on Unix).
+/// Writes the contents of a secret key file with restrictive permissions (0600 on Unix).
 #[cfg(unix)]
-fn write_secret_key_file(file_path: &Path, key: &AuthSecretKey) -> Result<(), KeyStoreError> {
+fn write_secret_key_file(file_path: &Path, contents: &[u8]) -> Result<(), KeyStoreError> {
     use std::io::Write;
     use std::os::unix::fs::OpenOptionsExt;
     let mut file = fs::OpenOptions::new()
@@ -349,15 +607,68 @@ fn write_secret_key_file(file_path: &Path, key: &AuthSecretKey) -> Result<(), Ke
         .mode(0o600)
         .open(file_path)
         .map_err(keystore_error("error writing secret key file"))?;
-    file.write_all(&key.to_bytes())
+    file.write_all(contents)
         .map_err(keystore_error("error writing secret key file"))
 }
 
-/// Writes an [`AuthSecretKey`] into a file.
+/// Writes the contents of a secret key file.
 // TODO: on Windows, set restrictive ACLs to limit access to the current user.
 #[cfg(not(unix))]
-fn write_secret_key_file(file_path: &Path, key: &AuthSecretKey) -> Result<(), KeyStoreError> {
-    fs::write(file_path, key.to_bytes()).map_err(keystore_error("error writing secret key file"))
+fn write_secret_key_file(file_path: &Path, contents: &[u8]) -> Result<(), KeyStoreError> {
+    fs::write(file_path, contents).map_err(keystore_error("error writing secret key file"))
+}
+
+/// Returns the paths of all secret key files in the keystore directory.
+fn key_file_paths(keys_directory: &Path) -> Result<Vec<PathBuf>, KeyStoreError> {
+    let entries =
+        fs::read_dir(keys_directory).map_err(keystore_error("error reading keys directory"))?;
+
+    let mut paths = Vec::new();
+    for entry in entries {
+        let path = entry.map_err(keystore_error("error reading keys directory"))?.path();
+        let is_key_file = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| Word::try_from(name).is_ok());
+        if is_key_file && path.is_file() {
+            paths.push(path);
+        }
+    }
+    Ok(paths)
+}
+
+fn read_secret_key(bytes: &[u8]) -> Result<AuthSecretKey, KeyStoreError> {
+    AuthSecretKey::read_from_bytes(bytes).map_err(|err| {
+        KeyStoreError::DecodingError(format!("error reading secret key from file: {err:?}"))
+    })
+}
+
+/// Returns the contents of an encrypted secret key file.
+fn seal_secret_key(
+    cipher: &KeyCipher,
+    key: &AuthSecretKey,
+) -> Result<Zeroizing<Vec<u8>>, KeyStoreError> {
+    let mut contents = ENCRYPTED_KEY_MAGIC.to_vec();
+    contents.extend_from_slice(&cipher.seal(&Zeroizing::new(key.to_bytes()))?);
+    Ok(Zeroizing::new(contents))
+}
+
+/// Generates a new salt for `passphrase` and marks the keystore directory as encrypted.
+fn init_encryption(
+    keys_directory: &Path,
+    passphrase: &str,
+) -> Result<EncryptionInfo, KeyStoreError> {
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+
+    let cipher = KeyCipher::derive(passphrase, &salt)?;
+    let info = EncryptionInfo {
+        version: ENCRYPTION_VERSION,
+        salt: hex::encode(salt),
+        check: hex::encode(cipher.seal(PASSPHRASE_CHECK)?),
+    };
+    info.write_to_file(keys_directory)?;
+    Ok(info)
 }
 
 fn keystore_error(context: &str) -> impl FnOnce(std::io::Error) -> KeyStoreError {
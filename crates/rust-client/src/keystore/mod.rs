@@ -83,6 +83,9 @@ pub trait Keystore: TransactionAuthenticator {
     }
 }
 
+mod composite;
+pub use composite::CompositeAuthenticator;
+
 #[cfg(feature = "std")]
 mod fs_keystore;
 #[cfg(feature = "std")]
@@ -119,6 +119,7 @@ use alloc::boxed::Box;
 extern crate std;
 
 pub mod account;
+pub mod address;
 pub mod grpc_support;
 pub mod keystore;
 pub mod note;
@@ -132,6 +133,7 @@ pub mod transaction;
 pub mod utils;
 
 pub mod builder;
+mod code_builder;
 
 #[cfg(feature = "testing")]
 mod test_utils;
@@ -170,6 +172,8 @@ pub mod assembly {
         Path,
     };
     pub use miden_standards::code_builder::CodeBuilder;
+
+    pub use crate::code_builder::ClientCodeBuilder;
 }
 
 /// Provides types and utilities for working with assets within the Miden network.
@@ -236,7 +240,7 @@ pub mod auth {
 
 /// Provides types for working with blocks within the Miden network.
 pub mod block {
-    pub use miden_protocol::block::{BlockHeader, BlockNumber};
+    pub use miden_protocol::block::{BlockHeader, BlockNumber, FeeParameters};
 }
 
 /// Provides cryptographic types and utilities used within the Miden rollup
@@ -281,18 +285,6 @@ pub mod crypto {
     pub use miden_protocol::crypto::rand::{FeltRng, RandomCoin};
 }
 
-/// Provides types for working with addresses within the Miden network.
-pub mod address {
-    pub use miden_protocol::address::{
-        Address,
-        AddressId,
-        AddressInterface,
-        CustomNetworkId,
-        NetworkId,
-        RoutingParameters,
-    };
-}
-
 /// Provides types for working with the virtual machine within the Miden network.
 pub mod vm {
     pub use miden_protocol::vm::{
@@ -347,7 +339,7 @@ pub mod testing {
 use alloc::sync::Arc;
 use alloc::vec::Vec;
 
-use miden_protocol::block::BlockNumber;
+use miden_protocol::block::{BlockNumber, FeeParameters};
 use miden_protocol::crypto::merkle::mmr::PartialMmr;
 use miden_protocol::crypto::rand::FeltRng;
 use miden_tx::auth::TransactionAuthenticator;
@@ -357,6 +349,8 @@ use store::Store;
 
 use crate::note_transport::NoteTransportClient;
 use crate::transaction::TransactionProver;
+use crate::transaction::foreign_account_cache::ForeignAccountCache;
+use crate::utils::RwLock;
 
 // MIDEN CLIENT
 // ================================================================================================
@@ -374,6 +368,8 @@ pub struct Client<AUTH> {
     /// An instance of [`FeltRng`] which provides randomness tools for generating new keys,
     /// serial numbers, etc.
     rng: ClientRng,
+    /// The seed the default RNG was created from, or `None` if a custom RNG was provided.
+    rng_seed: Option<[u8; 32]>,
     /// An instance of [`NodeRpcClient`] which provides a way for the client to connect to the
     /// Miden node.
     rpc_api: Arc<dyn NodeRpcClient>,
@@ -385,6 +381,9 @@ pub struct Client<AUTH> {
     authenticator: Option<Arc<AUTH>>,
     /// Shared source manager used to retain MASM source information for assembled programs.
     source_manager: Arc<dyn SourceManagerSync>,
+    /// MASM sources of the account components compiled with [`Client::code_builder`], registered
+    /// for the code of the accounts built from them when those are added.
+    component_sources: code_builder::CompiledComponentSources,
     /// Options that control the transaction executor's runtime behaviour (e.g. debug mode).
     exec_options: ExecutionOptions,
     /// Number of blocks after which pending transactions are considered stale and discarded.
@@ -393,6 +392,10 @@ pub struct Client<AUTH> {
     irrelevant_block_prune_interval: Option<u32>,
     /// Sync height at which the last automatic irrelevant-block prune completed.
     last_irrelevant_block_prune_sync_height: Option<BlockNumber>,
+    /// Policy for the automatic pruning of stale data run after state syncs.
+    pruning_policy: Option<sync::PruningPolicy>,
+    /// Sync height at which the pruning policy last ran.
+    last_pruning_sync_height: Option<BlockNumber>,
     /// Maximum number of blocks the client can be behind the network for transactions and account
     /// proofs to be considered valid.
     max_block_number_delta: Option<u32>,
@@ -404,9 +407,29 @@ pub struct Client<AUTH> {
     /// Cached [`PartialMmr`] for the chain's MMR. Lazily built from the store and kept in sync
     /// across sync/prune operations. `None` forces a rebuild on next access.
     partial_mmr: Option<CachedPartialMmr>,
+    /// Cache of the foreign account data fetched for FPI, keyed by account and block. `None` if
+    /// caching was disabled when building the client.
+    foreign_account_cache: Option<RwLock<ForeignAccountCache>>,
+    /// Whether proven transactions are persisted in the store when submitted.
+    retain_proofs: bool,
+    /// Whether transactions that fail to execute are recorded in the store.
+    record_failed_transactions: bool,
+    /// Network ID of the node the client is connected to, cached after it's first requested.
+    network_id: RwLock<Option<address::NetworkId>>,
+    /// Fee parameters of the node, cached along with the sync height at which they were fetched.
+    fee_parameters: RwLock<Option<(FeeParameters, BlockNumber)>>,
+    /// Number of synced blocks after which the cached fee parameters are fetched again.
+    fee_parameters_refresh_interval: u32,
+    /// Whether the node was checked to serve the genesis block the store was created for.
+    genesis_verified: bool,
     /// Observers fired by `apply_transaction`. See
     /// [`Client::with_transaction_observer`].
     transaction_observers: Vec<Arc<dyn transaction::TransactionObserver>>,
+    /// Policy used by the client's [`note::NoteScreener`] to decide whether untracked notes
+    /// received during sync should be tracked.
+    note_screener_policy: Option<Arc<dyn note::ScreenerPolicy>>,
+    /// Verifier consulted before storing block headers received from the node.
+    block_verifier: Option<Arc<dyn sync::BlockVerifier>>,
 }
 
 /// Cached [`PartialMmr`] with a two-part freshness fingerprint:
@@ -460,14 +483,25 @@ where
         self.exec_options.enable_debugging()
     }
 
-    /// Returns an instance of the `CodeBuilder`
-    pub fn code_builder(&self) -> assembly::CodeBuilder {
-        assembly::CodeBuilder::with_source_manager(self.source_manager.clone())
+    /// Returns an instance of the [`ClientCodeBuilder`](assembly::ClientCodeBuilder).
+    ///
+    /// The MASM source of the account components compiled with it is registered for the code of
+    /// the accounts built from them once they're added to the client. See
+    /// [`Client::get_account_code_source`].
+    pub fn code_builder(&self) -> assembly::ClientCodeBuilder {
+        assembly::ClientCodeBuilder::new(
+            assembly::CodeBuilder::with_source_manager(self.source_manager.clone()),
+            self.component_sources.clone(),
+        )
     }
 
     /// Returns an instance of [`note::NoteScreener`] configured for this client.
     pub fn note_screener(&self) -> note::NoteScreener {
-        note::NoteScreener::new(self.store.clone(), self.rpc_api.clone())
+        let note_screener = note::NoteScreener::new(self.store.clone(), self.rpc_api.clone());
+        match &self.note_screener_policy {
+            Some(policy) => note_screener.with_policy(policy.clone()),
+            None => note_screener,
+        }
     }
 
     /// Returns a reference to the client's random number generator. This can be used to generate
@@ -476,6 +510,16 @@ where
         &mut self.rng
     }
 
+    /// Returns the seed the client's RNG was created from, or `None` if the client was built with
+    /// a custom RNG (see [`ClientBuilder::rng`](crate::builder::ClientBuilder::rng)).
+    ///
+    /// Passing this seed to
+    /// [`ClientBuilder::with_rng_seed`](crate::builder::ClientBuilder::with_rng_seed) makes a new
+    /// client draw the same random values, so it must be kept as secret as the client's keys.
+    pub fn rng_seed(&self) -> Option<[u8; 32]> {
+        self.rng_seed
+    }
+
     pub fn prover(&self) -> Arc<dyn TransactionProver + Send + Sync> {
         self.tx_prover.clone()
     }
@@ -498,6 +542,14 @@ impl<AUTH> Client<AUTH> {
         self.store.identifier()
     }
 
+    /// Checks the consistency of the data in the client's store.
+    ///
+    /// See [`Store::check_integrity`] for the checks performed. Issues are returned in the
+    /// [`store::IntegrityReport`] rather than as errors.
+    pub async fn check_store_integrity(&self) -> Result<store::IntegrityReport, ClientError> {
+        self.store.check_integrity().await.map_err(Into::into)
+    }
+
     /// Registers a [`transaction::TransactionObserver`]. Per-observer failures are logged.
     pub fn with_transaction_observer(
         &mut self,
@@ -506,9 +558,114 @@ impl<AUTH> Client<AUTH> {
         self.transaction_observers.push(observer);
     }
 
+    /// Sets the timeout applied to RPC calls that don't have an operation-specific timeout.
+    ///
+    /// Clients built with
+    /// [`ClientBuilder::grpc_client`](crate::builder::ClientBuilder::grpc_client) default to
+    /// [`DEFAULT_GRPC_TIMEOUT_MS`](crate::grpc_support::DEFAULT_GRPC_TIMEOUT_MS) unless a timeout
+    /// was provided when building.
+    pub fn set_rpc_timeout(&mut self, timeout: core::time::Duration) {
+        self.rpc_api.set_default_timeout(timeout);
+    }
+
+    /// Overrides the timeout of RPC calls to the provided endpoint, e.g. to give large state syncs
+    /// more time than quick block header fetches. Calling this again for the same endpoint
+    /// replaces the previous override.
+    pub fn with_operation_timeout(
+        &mut self,
+        endpoint: rpc::RpcEndpoint,
+        timeout: core::time::Duration,
+    ) {
+        self.rpc_api.set_operation_timeout(endpoint, Some(timeout));
+    }
+
+    /// Replaces the client's RPC client, e.g. to switch to another node of the same network at
+    /// runtime without rebuilding the client.
+    ///
+    /// If the store already holds the genesis block, the new node must serve the same genesis
+    /// block. The RPC limits and the network ID are then fetched from the new node and persisted,
+    /// and the cached fee parameters are dropped so they're fetched again. Timeouts and RPC
+    /// logging configured when building the client aren't applied to the new RPC client.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ClientError::GenesisMismatch`] if the new node serves a different genesis block
+    /// than the one in the store. The client keeps using its previous RPC client on any error.
+    pub async fn set_rpc_client(
+        &mut self,
+        rpc_api: Arc<dyn NodeRpcClient>,
+    ) -> Result<(), ClientError> {
+        let stored_genesis = self.store.get_block_header_by_num(BlockNumber::GENESIS).await?;
+        if let Some((genesis, _)) = &stored_genesis {
+            let expected = genesis.commitment();
+            let actual = rpc_api.get_genesis_commitment().await?;
+            if actual != expected {
+                return Err(ClientError::GenesisMismatch { expected, actual });
+            }
+            rpc_api.set_genesis_commitment(expected).await?;
+        }
+
+        let limits = rpc_api.get_rpc_limits().await?;
+        let network_id = rpc_api.get_network_id().await?;
+        self.store.set_rpc_limits(limits).await?;
+        self.store.set_network_id(network_id.clone()).await?;
+
+        // Without a stored genesis block, the next sync fetches it from the new node.
+        self.genesis_verified = stored_genesis.is_some();
+        self.rpc_api = rpc_api;
+        self.network_id.write().replace(network_id);
+        self.fee_parameters.write().take();
+        Ok(())
+    }
+
     /// Returns the network ID of the node the client is connected to.
+    ///
+    /// The network ID is fetched from the RPC client the first time it's requested, then cached in
+    /// memory and persisted in the store so later calls (and clients built on the same store)
+    /// don't need to fetch it again. It's used to encode [`address::Address`]es with the
+    /// network's bech32 prefix. Call [`Client::reset_network_id`] after switching to a node of a
+    /// different network.
     pub async fn network_id(&self) -> Result<address::NetworkId, ClientError> {
-        Ok(self.rpc_api.get_network_id().await?)
+        if let Some(network_id) = self.network_id.read().clone() {
+            return Ok(network_id);
+        }
+
+        let network_id = self.rpc_api.get_network_id().await?;
+        self.store.set_network_id(network_id.clone()).await?;
+        self.network_id.write().replace(network_id.clone());
+        Ok(network_id)
+    }
+
+    /// Forgets the cached network ID, both in memory and in the store, so it's fetched again from
+    /// the RPC client on the next [`Client::network_id`] call.
+    ///
+    /// This should only be needed after the client's RPC endpoint changes to a node of a
+    /// different network.
+    pub async fn reset_network_id(&mut self) -> Result<(), ClientError> {
+        self.store.remove_network_id().await?;
+        self.network_id.write().take();
+        Ok(())
+    }
+
+    /// Returns the fee parameters of the node the client is connected to.
+    ///
+    /// The parameters are fetched from the RPC client the first time they're requested and cached
+    /// in memory. Since fees can change, they're fetched again once the client's sync height has
+    /// advanced by the refresh interval set with
+    /// [`ClientBuilder::fee_parameters_refresh_interval`](crate::builder::ClientBuilder::fee_parameters_refresh_interval)
+    /// since they were cached.
+    pub async fn get_fee_parameters(&self) -> Result<FeeParameters, ClientError> {
+        let sync_height = self.store.get_sync_height().await?;
+        if let Some((fee_parameters, fetched_at)) = self.fee_parameters.read().clone()
+            && sync_height.as_u32()
+                < fetched_at.as_u32().saturating_add(self.fee_parameters_refresh_interval)
+        {
+            return Ok(fee_parameters);
+        }
+
+        let fee_parameters = self.rpc_api.get_fee_parameters().await?;
+        self.fee_parameters.write().replace((fee_parameters.clone(), sync_height));
+        Ok(fee_parameters)
     }
 
     // TEST HELPERS
@@ -528,6 +685,16 @@ impl<AUTH> Client<AUTH> {
     pub fn test_has_cached_partial_mmr(&self) -> bool {
         self.partial_mmr.is_some()
     }
+
+    #[cfg(any(test, feature = "testing"))]
+    pub async fn test_consume_all_consumable_notes_in_batches(
+        &mut self,
+        account_id: account::AccountId,
+        faucet_filter: Option<account::AccountId>,
+        notes_per_tx: usize,
+    ) -> Result<Vec<transaction::TransactionId>, ClientError> {
+        self.consume_notes_in_batches(account_id, faucet_filter, notes_per_tx).await
+    }
 }
 
 // CLIENT RNG
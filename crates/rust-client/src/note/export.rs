@@ -0,0 +1,122 @@
+//! Provides multi-note export methods.
+//!
+//! Notes are exported as a [`NoteFileSet`]: a versioned container of [`NoteFile`]s that can be
+//! written to a single file and imported back with [`Client::import_note_set()`].
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::vec::Vec;
+
+use miden_protocol::note::{NoteFile, NoteId};
+use miden_tx::auth::TransactionAuthenticator;
+use miden_tx::utils::serde::{
+    ByteReader,
+    ByteWriter,
+    Deserializable,
+    DeserializationError,
+    Serializable,
+};
+
+use crate::store::{NoteExportType, NoteFilter, StoreError};
+use crate::{Client, ClientError};
+
+// NOTE FILE SET
+// ================================================================================================
+
+/// Magic bytes that prefix a serialized [`NoteFileSet`], used to tell it apart from a single
+/// serialized [`NoteFile`].
+const NOTE_FILE_SET_MAGIC: [u8; 4] = *b"mnst";
+
+/// A versioned container holding multiple [`NoteFile`]s, used to share several notes at once.
+#[derive(Clone, Debug)]
+pub struct NoteFileSet {
+    note_files: Vec<NoteFile>,
+}
+
+impl NoteFileSet {
+    /// Current version of the serialized format.
+    pub const VERSION: u8 = 1;
+
+    pub fn new(note_files: Vec<NoteFile>) -> Self {
+        Self { note_files }
+    }
+
+    /// Returns the note files contained in the set.
+    pub fn note_files(&self) -> &[NoteFile] {
+        &self.note_files
+    }
+
+    /// Consumes the set, returning the contained note files.
+    pub fn into_note_files(self) -> Vec<NoteFile> {
+        self.note_files
+    }
+}
+
+impl Serializable for NoteFileSet {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_bytes(&NOTE_FILE_SET_MAGIC);
+        target.write_u8(Self::VERSION);
+        self.note_files.write_into(target);
+    }
+}
+
+impl Deserializable for NoteFileSet {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let magic: [u8; 4] = source.read_array()?;
+        if magic != NOTE_FILE_SET_MAGIC {
+            return Err(DeserializationError::InvalidValue(
+                "data is not a serialized note file set".into(),
+            ));
+        }
+
+        let version = source.read_u8()?;
+        if version != Self::VERSION {
+            return Err(DeserializationError::InvalidValue(format!(
+                "unsupported note file set version: {version}"
+            )));
+        }
+
+        let note_files = Vec::<NoteFile>::read_from(source)?;
+        Ok(Self { note_files })
+    }
+}
+
+/// Note exporting methods.
+impl<AUTH> Client<AUTH>
+where
+    AUTH: TransactionAuthenticator + Sync,
+{
+    /// Exports the output notes with the provided IDs as a serialized [`NoteFileSet`].
+    ///
+    /// Each note is converted into a [`NoteFile`] of the requested `export_type`. The returned
+    /// bytes can be imported in a single call with [`Client::import_note_set()`].
+    ///
+    /// # Errors
+    ///
+    /// - Returns a [`StoreError::NoteNotFound`] if any of the notes is not tracked as an output
+    ///   note.
+    /// - Returns a [`ClientError::NoteRecordConversionError`] if a note doesn't hold enough
+    ///   information for the requested export type (e.g. no inclusion proof for
+    ///   [`NoteExportType::NoteWithProof`]).
+    pub async fn export_notes(
+        &self,
+        note_ids: &[NoteId],
+        export_type: &NoteExportType,
+    ) -> Result<Vec<u8>, ClientError> {
+        let output_notes = self
+            .store
+            .get_output_notes(NoteFilter::List(note_ids.to_vec()))
+            .await?
+            .into_iter()
+            .map(|note| (note.id(), note))
+            .collect::<BTreeMap<_, _>>();
+
+        // Keep the caller's ordering and fail on the first note that isn't tracked.
+        let mut note_files = Vec::with_capacity(note_ids.len());
+        for note_id in note_ids {
+            let note = output_notes.get(note_id).ok_or(StoreError::NoteNotFound(*note_id))?;
+            note_files.push(note.clone().into_note_file(export_type)?);
+        }
+
+        Ok(NoteFileSet::new(note_files).to_bytes())
+    }
+}
@@ -25,7 +25,9 @@ use miden_protocol::note::{
     NoteTag,
 };
 use miden_tx::auth::TransactionAuthenticator;
+use miden_tx::utils::serde::Deserializable;
 
+use super::NoteFileSet;
 use crate::rpc::RpcError;
 use crate::rpc::domain::note::FetchedNote;
 use crate::store::input_note_states::ExpectedNoteState;
@@ -164,6 +166,25 @@ where
         Ok(imported_commitments)
     }
 
+    /// Imports all the notes contained in a serialized [`NoteFileSet`], as produced by
+    /// [`Client::export_notes()`].
+    ///
+    /// The notes are imported through [`Client::import_notes()`], so the whole set is applied
+    /// atomically: either every note is imported or none is.
+    ///
+    /// # Errors
+    ///
+    /// - Returns a [`ClientError::DataDeserializationError`] if the bytes are not a valid
+    ///   [`NoteFileSet`] (including sets written with an unsupported format version).
+    /// - Any error returned by [`Client::import_notes()`].
+    pub async fn import_note_set(
+        &mut self,
+        bytes: &[u8],
+    ) -> Result<Vec<NoteDetailsCommitment>, ClientError> {
+        let note_set = NoteFileSet::read_from_bytes(bytes)?;
+        self.import_notes(note_set.note_files()).await
+    }
+
     // HELPERS
     // ================================================================================================
 
@@ -12,8 +12,7 @@
 //! - Retrieve input notes and output notes.
 //! - Determine the consumability of notes using the [`NoteScreener`].
 //! - Compile note scripts from source code with `compile_note_script`.
-//! - Retrieve an input note by a prefix of its ID using the helper function
-//!   [`get_input_note_with_id_prefix`].
+//! - Resolve the ID of a tracked note from a prefix of it with [`Client::resolve_note_by_prefix`].
 //!
 //! ## Example
 //!
@@ -22,7 +21,7 @@
 //!     auth::TransactionAuthenticator,
 //!     Client,
 //!     crypto::FeltRng,
-//!     note::{NoteScreener, get_input_note_with_id_prefix},
+//!     note::NoteScreener,
 //!     store::NoteFilter,
 //! };
 //! use miden_protocol::account::AccountId;
@@ -38,14 +37,11 @@
 //!     println!("Note consumability: {:?}", consumability);
 //! }
 //!
-//! // Retrieve an input note by a partial ID match
+//! // Resolve a note ID from a partial ID match
 //! let note_prefix = "0x70b7ec";
-//! match get_input_note_with_id_prefix(client, note_prefix).await {
-//!     Ok(note) => println!(
-//!         "Found note with matching prefix: {}",
-//!         note.id().expect("note matched by ID prefix has an ID").to_hex()
-//!     ),
-//!     Err(err) => println!("Error retrieving note: {err:?}"),
+//! match client.resolve_note_by_prefix(note_prefix).await {
+//!     Ok(note_id) => println!("Found note with matching prefix: {}", note_id.to_hex()),
+//!     Err(err) => println!("Error resolving note: {err:?}"),
 //! }
 //!
 //! // Compile the note script
@@ -60,22 +56,36 @@
 //! For more details on the API and error handling, see the documentation for the specific functions
 //! and types in this module.
 
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::ToString;
 use alloc::vec::Vec;
+use alloc::{format, vec};
 
 use miden_protocol::account::AccountId;
+use miden_protocol::transaction::PartialBlockchain;
 use miden_tx::auth::TransactionAuthenticator;
 
-use crate::store::{InputNoteRecord, NoteFilter, OutputNoteRecord};
+use crate::store::{
+    InputNoteRecord,
+    NoteFilter,
+    NoteStateTransition,
+    OutputNoteRecord,
+    StoreError,
+};
+use crate::transaction::SwapTransactionData;
 use crate::{Client, ClientError, IdPrefixFetchError};
 
+mod export;
 mod import;
 mod note_reader;
 mod note_screener;
 mod note_update_tracker;
+mod p2ide;
 
 // RE-EXPORTS
 // ================================================================================================
 
+pub use export::NoteFileSet;
 pub use miden_protocol::block::BlockNumber;
 pub use miden_protocol::errors::NoteError;
 pub use miden_protocol::note::{
@@ -123,7 +133,15 @@ pub use miden_standards::note::{
 };
 pub use miden_tx::{FailedNote, NoteConsumptionInfo};
 pub use note_reader::InputNoteReader;
-pub use note_screener::{NoteConsumability, NoteScreener, NoteScreenerError};
+pub use note_screener::{
+    DefaultScreenerPolicy,
+    NoteConsumability,
+    NoteConsumptionRequirements,
+    NoteScreener,
+    NoteScreenerError,
+    ScreenerPolicy,
+    ScreeningDecision,
+};
 pub use note_update_tracker::{
     InputNoteUpdate,
     NoteConsumption,
@@ -131,6 +149,7 @@ pub use note_update_tracker::{
     NoteUpdateType,
     OutputNoteUpdate,
 };
+pub(crate) use p2ide::P2ideConditions;
 
 /// Note retrieval methods.
 impl<AUTH> Client<AUTH>
@@ -208,6 +227,66 @@ where
         self.note_screener().can_consume(&note.try_into()?).await.map_err(Into::into)
     }
 
+    /// Returns what consuming the input note with the provided ID requires: whether its script
+    /// needs note arguments, its P2IDE timelock and reclaim heights, and the tracked accounts
+    /// that may consume it along with their [`NoteConsumptionStatus`].
+    ///
+    /// # Errors
+    ///
+    /// - Returns [`StoreError::NoteNotFound`] if the note isn't tracked as an input note.
+    /// - Returns an error if the note's metadata isn't known yet, or the consumption checks fail.
+    pub async fn note_consumption_requirements(
+        &self,
+        note_id: NoteId,
+    ) -> Result<NoteConsumptionRequirements, ClientError> {
+        let note: Note = self
+            .get_input_note(note_id)
+            .await?
+            .ok_or(StoreError::NoteNotFound(note_id))?
+            .try_into()?;
+        let consumability = self.note_screener().can_consume(&note).await?;
+
+        Ok(NoteConsumptionRequirements::new(&note, consumability))
+    }
+
+    /// Returns the unspent SWAP notes tracked by the client that `account_id` could fill, along
+    /// with their decoded swap terms.
+    ///
+    /// A note is returned if it was built from the SWAP script, its sender is known and differs
+    /// from `account_id`, and its swap terms can be decoded (see
+    /// [`SwapTransactionData::from_swap_note`]). SWAP notes whose terms can't be decoded are
+    /// skipped with a warning. The returned [`SwapTransactionData`] holds the
+    /// note's sender, the asset it offers and the asset it requests in exchange.
+    ///
+    /// Whether the account currently holds the requested asset isn't checked; use
+    /// [`Client::get_note_consumability`] for that.
+    pub async fn get_swap_notes(
+        &self,
+        account_id: AccountId,
+    ) -> Result<Vec<(InputNoteRecord, SwapTransactionData)>, ClientError> {
+        let swap_notes = self
+            .store
+            .get_input_notes(NoteFilter::Unspent)
+            .await?
+            .into_iter()
+            .filter_map(|note| {
+                let sender = note.metadata()?.sender();
+                if sender == account_id {
+                    return None;
+                }
+                match SwapTransactionData::from_swap_note(sender, note.details()) {
+                    Ok(swap_data) => swap_data.map(|swap_data| (note, swap_data)),
+                    Err(err) => {
+                        tracing::warn!(note_id = ?note.id(), %err, "skipping malformed SWAP note");
+                        None
+                    },
+                }
+            })
+            .collect();
+
+        Ok(swap_notes)
+    }
+
     /// Retrieves the input note given a [`NoteId`]. Returns `None` if the note is not found.
     pub async fn get_input_note(
         &self,
@@ -216,6 +295,67 @@ where
         Ok(self.store.get_input_notes(NoteFilter::Unique(note_id)).await?.pop())
     }
 
+    /// Retrieves the states the input note with the provided details commitment went through,
+    /// oldest first. See
+    /// [`Store::get_note_state_history`](crate::store::Store::get_note_state_history) for details.
+    pub async fn get_note_state_history(
+        &self,
+        details_commitment: NoteDetailsCommitment,
+    ) -> Result<Vec<NoteStateTransition>, ClientError> {
+        self.store.get_note_state_history(details_commitment).await.map_err(Into::into)
+    }
+
+    /// Verifies the inclusion proof of the input note with the provided ID against the chain data
+    /// stored locally, without relying on the state the note was given during sync.
+    ///
+    /// The note's Merkle path is checked against the note root of the block header it references,
+    /// and the header's commitment against the chain MMR at the current sync height. Returns
+    /// `false` if either check fails.
+    ///
+    /// # Errors
+    ///
+    /// - Returns [`StoreError::NoteNotFound`] if the note isn't tracked.
+    /// - Returns [`ClientError::NoteInclusionDataMissing`] if the note has no inclusion proof, or
+    ///   if the referenced block header or its MMR authentication path isn't stored.
+    pub async fn verify_note_inclusion(&self, note_id: NoteId) -> Result<bool, ClientError> {
+        let missing_data =
+            |reason: String| ClientError::NoteInclusionDataMissing { note_id, reason };
+
+        let note = self.get_input_note(note_id).await?.ok_or(StoreError::NoteNotFound(note_id))?;
+        let inclusion_proof = note
+            .inclusion_proof()
+            .ok_or_else(|| missing_data("the note has no inclusion proof".to_string()))?;
+        let block_num = inclusion_proof.location().block_num();
+
+        let (block_header, _) = self
+            .store
+            .get_block_header_by_num(block_num)
+            .await?
+            .ok_or_else(|| missing_data(format!("block header {block_num} is not stored")))?;
+
+        let partial_mmr = self.get_current_partial_mmr().await?;
+        if !partial_mmr.is_tracked(block_num.as_usize()) {
+            return Err(missing_data(format!(
+                "the MMR authentication path of block {block_num} is not stored"
+            )));
+        }
+
+        let note_path_is_valid = inclusion_proof
+            .note_path()
+            .verify(
+                inclusion_proof.location().block_note_tree_index().into(),
+                note_id.as_word(),
+                &block_header.note_root(),
+            )
+            .is_ok();
+        if !note_path_is_valid {
+            return Ok(false);
+        }
+
+        // Building the partial blockchain checks the header's commitment against the MMR.
+        Ok(PartialBlockchain::new(partial_mmr, vec![block_header]).is_ok())
+    }
+
     // OUTPUT NOTE DATA RETRIEVAL
     // --------------------------------------------------------------------------------------------
 
@@ -235,6 +375,142 @@ where
         Ok(self.store.get_output_notes(NoteFilter::Unique(note_id)).await?.pop())
     }
 
+    /// Retrieves the output note with the provided recipient digest. Returns `None` if no output
+    /// note is tracked for that recipient.
+    ///
+    /// This is useful to match an output note created by this client against a note received
+    /// elsewhere, since both share the same recipient.
+    pub async fn get_output_note_by_recipient(
+        &self,
+        recipient_digest: Word,
+    ) -> Result<Option<OutputNoteRecord>, ClientError> {
+        self.store
+            .get_output_note_by_recipient(recipient_digest)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Returns the output notes created by this client that haven't been consumed yet.
+    ///
+    /// A note stops being returned once its nullifier is observed during sync, which makes this
+    /// useful to track sent payments that the recipient hasn't claimed.
+    pub async fn get_unconsumed_output_notes(&self) -> Result<Vec<OutputNoteRecord>, ClientError> {
+        self.store.get_unconsumed_output_notes().await.map_err(Into::into)
+    }
+
+    /// Returns the output notes sent by `account_id` that it can still recall: P2IDE notes with a
+    /// reclaim height (see [`OutputNoteRecord::reclaim_height`]) that the recipient hasn't
+    /// consumed as of the last sync.
+    ///
+    /// The sender can consume a returned note back once the chain reaches its reclaim height.
+    pub async fn recallable_notes(
+        &self,
+        account_id: AccountId,
+    ) -> Result<Vec<OutputNoteRecord>, ClientError> {
+        Ok(self
+            .store
+            .get_unconsumed_output_notes()
+            .await?
+            .into_iter()
+            .filter(|note| {
+                note.metadata().sender() == account_id && note.reclaim_height().is_some()
+            })
+            .collect())
+    }
+
+    /// Deletes the input notes that were consumed before block `before`, along with the note
+    /// scripts no longer referenced by any input note.
+    ///
+    /// Pinned notes (see [`Client::set_note_pinned`]) and notes referenced by uncommitted
+    /// transactions are kept. Returns the number of input notes removed.
+    pub async fn prune_consumed_notes(&self, before: BlockNumber) -> Result<usize, ClientError> {
+        Ok(self.store.prune_consumed_notes(before).await?)
+    }
+
+    /// Pins the note with the provided ID, or unpins it. Pinned notes are never removed by
+    /// [`Client::prune_consumed_notes`], regardless of how long ago they were consumed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client doesn't track an input or output note with the provided ID.
+    pub async fn set_note_pinned(
+        &mut self,
+        note_id: NoteId,
+        pinned: bool,
+    ) -> Result<(), ClientError> {
+        self.store.set_note_pinned(note_id, pinned).await.map_err(Into::into)
+    }
+
+    // ID PREFIX RESOLUTION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the note tracked by the client whose ID starts with `note_id_prefix`.
+    ///
+    /// Both input and output notes are searched, and a note tracked as both counts as a single
+    /// match.
+    ///
+    /// # Errors
+    ///
+    /// - Returns [`IdPrefixFetchError::NoMatch`] if no tracked note ID starts with
+    ///   `note_id_prefix`.
+    /// - Returns [`IdPrefixFetchError::MultipleMatches`] if more than one tracked note ID starts
+    ///   with `note_id_prefix`.
+    pub async fn resolve_note_by_prefix(
+        &self,
+        note_id_prefix: &str,
+    ) -> Result<NoteId, IdPrefixFetchError> {
+        let no_match = || IdPrefixFetchError::NoMatch(format!("note ID prefix {note_id_prefix}"));
+
+        let input_notes = self.store.get_input_notes(NoteFilter::All).await.map_err(|err| {
+            tracing::error!("Error when fetching all notes from the store: {err}");
+            no_match()
+        })?;
+        let output_notes = self.store.get_output_notes(NoteFilter::All).await.map_err(|err| {
+            tracing::error!("Error when fetching all notes from the store: {err}");
+            no_match()
+        })?;
+
+        let note_ids = input_notes
+            .iter()
+            .filter_map(InputNoteRecord::id)
+            .chain(output_notes.iter().map(OutputNoteRecord::id))
+            .filter(|id| id.to_hex().starts_with(note_id_prefix))
+            .collect::<BTreeSet<_>>();
+
+        let mut note_ids = note_ids.into_iter();
+        match (note_ids.next(), note_ids.next()) {
+            (Some(note_id), None) => Ok(note_id),
+            (None, _) => Err(no_match()),
+            (Some(_), Some(_)) => {
+                tracing::error!("Multiple notes found for the prefix {note_id_prefix}");
+                Err(IdPrefixFetchError::MultipleMatches(format!("note ID prefix {note_id_prefix}")))
+            },
+        }
+    }
+
+    // NULLIFIER STATUS
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks on-chain whether the provided nullifiers have been consumed, without requiring the
+    /// corresponding notes to be tracked by the client.
+    ///
+    /// Returns a map with an entry for every requested nullifier: `Some(block_num)` with the block
+    /// in which the nullifier was consumed, or `None` if it hasn't been consumed yet.
+    pub async fn check_nullifiers_onchain(
+        &self,
+        nullifiers: &[Nullifier],
+    ) -> Result<BTreeMap<Nullifier, Option<BlockNumber>>, ClientError> {
+        if nullifiers.is_empty() {
+            return Ok(BTreeMap::new());
+        }
+
+        let requested_nullifiers = nullifiers.iter().copied().collect::<BTreeSet<_>>();
+        Ok(self
+            .rpc_api
+            .get_nullifier_commit_heights(requested_nullifiers, BlockNumber::GENESIS)
+            .await?)
+    }
+
     /// Returns an [`InputNoteReader`] that lazily iterates over consumed input notes
     /// for the given consumer account.
     ///
@@ -254,52 +530,3 @@ where
         InputNoteReader::new(self.store.clone(), consumer)
     }
 }
-
-/// Returns the client input note whose ID starts with `note_id_prefix`.
-///
-/// # Errors
-///
-/// - Returns [`IdPrefixFetchError::NoMatch`] if we were unable to find any note where
-///   `note_id_prefix` is a prefix of its ID.
-/// - Returns [`IdPrefixFetchError::MultipleMatches`] if there were more than one note found where
-///   `note_id_prefix` is a prefix of its ID.
-pub async fn get_input_note_with_id_prefix<AUTH>(
-    client: &Client<AUTH>,
-    note_id_prefix: &str,
-) -> Result<InputNoteRecord, IdPrefixFetchError>
-where
-    AUTH: TransactionAuthenticator + Sync,
-{
-    let mut input_note_records = client
-        .get_input_notes(NoteFilter::All)
-        .await
-        .map_err(|err| {
-            tracing::error!("Error when fetching all notes from the store: {err}");
-            IdPrefixFetchError::NoMatch(format!("note ID prefix {note_id_prefix}"))
-        })?
-        .into_iter()
-        .filter(|note_record| {
-            note_record.id().is_some_and(|id| id.to_hex().starts_with(note_id_prefix))
-        })
-        .collect::<Vec<_>>();
-
-    if input_note_records.is_empty() {
-        return Err(IdPrefixFetchError::NoMatch(format!("note ID prefix {note_id_prefix}")));
-    }
-    if input_note_records.len() > 1 {
-        let input_note_record_ids =
-            input_note_records.iter().map(InputNoteRecord::id).collect::<Vec<_>>();
-        tracing::error!(
-            "Multiple notes found for the prefix {}: {:?}",
-            note_id_prefix,
-            input_note_record_ids
-        );
-        return Err(IdPrefixFetchError::MultipleMatches(format!(
-            "note ID prefix {note_id_prefix}"
-        )));
-    }
-
-    Ok(input_note_records
-        .pop()
-        .expect("input_note_records should always have one element"))
-}
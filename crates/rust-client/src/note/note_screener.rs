@@ -4,9 +4,12 @@ use alloc::sync::Arc;
 use alloc::vec::Vec;
 
 use async_trait::async_trait;
+use miden_protocol::Word;
 use miden_protocol::account::{AccountCode, AccountId};
+use miden_protocol::block::BlockNumber;
 use miden_protocol::note::{Note, NoteId};
-use miden_standards::note::NoteConsumptionStatus;
+use miden_standards::note::{NoteConsumptionStatus, PswapNote, StandardNote};
+use miden_tx::utils::serde::Serializable;
 use miden_tx::{
     NoteCheckerError,
     NoteConsumptionChecker,
@@ -16,12 +19,14 @@ use miden_tx::{
 use thiserror::Error;
 
 use crate::ClientError;
+use crate::note::P2ideConditions;
 use crate::rpc::NodeRpcClient;
 use crate::rpc::domain::note::CommittedNote;
 use crate::store::data_store::ClientDataStore;
 use crate::store::{InputNoteRecord, NoteFilter, Store, StoreError};
 use crate::sync::{NoteUpdateAction, OnNoteReceived};
 use crate::transaction::{AdviceMap, InputNote, TransactionArgs, TransactionRequestError};
+use crate::utils::RwLock;
 
 /// Represents the consumability of a note by a specific account.
 ///
@@ -39,6 +44,191 @@ fn is_relevant(consumption_status: &NoteConsumptionStatus) -> bool {
     )
 }
 
+// NOTE CONSUMPTION REQUIREMENTS
+// ================================================================================================
+
+/// What consuming a note requires, as reported by
+/// [`Client::note_consumption_requirements`](crate::Client::note_consumption_requirements).
+#[derive(Clone, Debug)]
+pub struct NoteConsumptionRequirements {
+    requires_note_args: Option<bool>,
+    timelock_height: Option<BlockNumber>,
+    reclaim_height: Option<BlockNumber>,
+    consumability: Vec<NoteConsumability>,
+}
+
+impl NoteConsumptionRequirements {
+    pub(crate) fn new(note: &Note, consumability: Vec<NoteConsumability>) -> Self {
+        let standard_note = StandardNote::from_script(note.script());
+        let requires_note_args = if PswapNote::try_from(note).is_ok() {
+            Some(true)
+        } else {
+            standard_note.is_some().then_some(false)
+        };
+
+        let p2ide_conditions = P2ideConditions::from_recipient(note.recipient());
+
+        Self {
+            requires_note_args,
+            timelock_height: p2ide_conditions
+                .as_ref()
+                .and_then(|conditions| conditions.timelock_height),
+            reclaim_height: p2ide_conditions.and_then(|conditions| conditions.reclaim_height),
+            consumability,
+        }
+    }
+
+    /// Returns whether the note script needs [`NoteArgs`](crate::transaction::NoteArgs) to be
+    /// consumed, such as the fill amounts of a PSWAP note.
+    ///
+    /// Returns `None` for custom scripts, whose inputs (including any secret they check) can't be
+    /// inferred from the compiled script. Standard scripts other than PSWAP take no arguments.
+    pub fn requires_note_args(&self) -> Option<bool> {
+        self.requires_note_args
+    }
+
+    /// Returns the block before which the target account can't consume the note, for P2IDE notes
+    /// with a timelock.
+    pub fn timelock_height(&self) -> Option<BlockNumber> {
+        self.timelock_height
+    }
+
+    /// Returns the block from which the sender can consume the note back, for P2IDE notes with a
+    /// reclaim height.
+    pub fn reclaim_height(&self) -> Option<BlockNumber> {
+        self.reclaim_height
+    }
+
+    /// Returns the tracked accounts that may consume the note, along with the conditions under
+    /// which they can, as checked by executing the note against each account.
+    pub fn consumability(&self) -> &[NoteConsumability] {
+        &self.consumability
+    }
+}
+
+// RELEVANCE CACHE
+// ================================================================================================
+
+/// Identifies notes that the consumption checks treat identically for a given account: the
+/// outcome of executing a note depends on its script, its storage, its assets and its sender, not
+/// on its serial number. The account and reference block are part of the key, so a decision is
+/// never reused for another account or another chain height.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct NoteShape {
+    account_id: AccountId,
+    block_ref: BlockNumber,
+    script_root: Word,
+    storage: Vec<u8>,
+    assets_commitment: Word,
+    sender: AccountId,
+}
+
+impl NoteShape {
+    fn new(account_id: AccountId, block_ref: BlockNumber, note: &Note) -> Self {
+        Self {
+            account_id,
+            block_ref,
+            script_root: Word::from(note.script().root()),
+            storage: note.recipient().storage().to_bytes(),
+            assets_commitment: note.assets().commitment(),
+            sender: note.metadata().sender(),
+        }
+    }
+}
+
+/// Cached outcome of a consumption check. Only relevant statuses are kept in full, as they are
+/// the only ones returned by the screener.
+#[derive(Clone, Copy, Debug)]
+enum CachedRelevance {
+    Irrelevant,
+    Consumable,
+    ConsumableAfter(BlockNumber),
+    ConsumableWithAuthorization,
+}
+
+impl CachedRelevance {
+    /// Returns `None` for statuses that can't be reproduced from the cache, which are then
+    /// always checked again.
+    fn from_status(consumption_status: &NoteConsumptionStatus) -> Option<Self> {
+        match consumption_status {
+            NoteConsumptionStatus::Consumable => Some(Self::Consumable),
+            NoteConsumptionStatus::ConsumableAfter(block_num) => {
+                Some(Self::ConsumableAfter(*block_num))
+            },
+            NoteConsumptionStatus::ConsumableWithAuthorization => {
+                Some(Self::ConsumableWithAuthorization)
+            },
+            status if !is_relevant(status) => Some(Self::Irrelevant),
+            _ => None,
+        }
+    }
+
+    fn to_status(self) -> Option<NoteConsumptionStatus> {
+        match self {
+            Self::Irrelevant => None,
+            Self::Consumable => Some(NoteConsumptionStatus::Consumable),
+            Self::ConsumableAfter(block_num) => {
+                Some(NoteConsumptionStatus::ConsumableAfter(block_num))
+            },
+            Self::ConsumableWithAuthorization => {
+                Some(NoteConsumptionStatus::ConsumableWithAuthorization)
+            },
+        }
+    }
+}
+
+// SCREENER POLICY
+// ================================================================================================
+
+/// Decision returned by a [`ScreenerPolicy`] for a note that isn't tracked by the client.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ScreeningDecision {
+    /// Track the note, regardless of the default relevance checks. Only public notes can be
+    /// tracked this way, since the details of private notes aren't known to the client.
+    Track,
+    /// Ignore the note, regardless of the default relevance checks.
+    Ignore,
+    /// Fall back to the default relevance checks of the [`NoteScreener`].
+    UseDefault,
+}
+
+/// Overrides or extends the relevance decision made by the [`NoteScreener`] for notes received
+/// during sync.
+///
+/// The policy is only consulted for notes that aren't already tracked by the client; tracked notes
+/// are always updated. This allows integrators to track notes that the default checks would
+/// discard (e.g. P2ID notes targeting a watched address without a subscribed tag), or to skip
+/// notes they aren't interested in.
+#[async_trait(?Send)]
+pub trait ScreenerPolicy: Send + Sync {
+    /// Returns the [`ScreeningDecision`] for the committed note. `public_note` contains the note
+    /// details if the note is public.
+    async fn screen(
+        &self,
+        committed_note: &CommittedNote,
+        public_note: Option<&InputNoteRecord>,
+    ) -> Result<ScreeningDecision, ClientError>;
+}
+
+/// The default [`ScreenerPolicy`], which defers every decision to the default relevance checks of
+/// the [`NoteScreener`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultScreenerPolicy;
+
+#[async_trait(?Send)]
+impl ScreenerPolicy for DefaultScreenerPolicy {
+    async fn screen(
+        &self,
+        _committed_note: &CommittedNote,
+        _public_note: Option<&InputNoteRecord>,
+    ) -> Result<ScreeningDecision, ClientError> {
+        Ok(ScreeningDecision::UseDefault)
+    }
+}
+
+// NOTE SCREENER
+// ================================================================================================
+
 /// Provides functionality for testing whether a note is relevant to the client or not.
 ///
 /// Here, relevance is based on whether the note is able to be consumed by an account that is
@@ -53,11 +243,42 @@ pub struct NoteScreener {
     tx_args: Option<TransactionArgs>,
     /// RPC client used for lazy-loading foreign account data during note screening.
     rpc_api: Arc<dyn NodeRpcClient>,
+    /// Policy consulted for notes that aren't tracked by the client.
+    policy: Arc<dyn ScreenerPolicy>,
+    /// Relevance decisions of previously screened notes, shared by the clones of this screener.
+    /// `None` if caching is disabled.
+    relevance_cache: Option<Arc<RwLock<BTreeMap<NoteShape, CachedRelevance>>>>,
 }
 
 impl NoteScreener {
     pub fn new(store: Arc<dyn Store>, rpc_api: Arc<dyn NodeRpcClient>) -> Self {
-        Self { store, tx_args: None, rpc_api }
+        Self {
+            store,
+            tx_args: None,
+            rpc_api,
+            policy: Arc::new(DefaultScreenerPolicy),
+            relevance_cache: Some(Arc::new(RwLock::new(BTreeMap::new()))),
+        }
+    }
+
+    /// Enables or disables caching of relevance decisions. Enabled by default.
+    ///
+    /// When enabled, notes with the same script, storage and sender are only checked once per
+    /// account, which avoids re-executing the consumption checks for recurring note shapes
+    /// (e.g. many P2ID notes for the same account) during a sync. The cache lives as long as the
+    /// screener, so a new screener should be created when the tracked accounts change.
+    #[must_use]
+    pub fn with_relevance_cache(mut self, enabled: bool) -> Self {
+        self.relevance_cache = enabled.then(|| Arc::new(RwLock::new(BTreeMap::new())));
+        self
+    }
+
+    /// Sets the [`ScreenerPolicy`] used to decide whether untracked notes received during sync
+    /// should be tracked. Defaults to [`DefaultScreenerPolicy`].
+    #[must_use]
+    pub fn with_policy(mut self, policy: Arc<dyn ScreenerPolicy>) -> Self {
+        self.policy = policy;
+        self
     }
 
     /// Sets the transaction arguments to use when checking note consumability.
@@ -65,6 +286,10 @@ impl NoteScreener {
     #[must_use]
     pub fn with_transaction_args(mut self, tx_args: TransactionArgs) -> Self {
         self.tx_args = Some(tx_args);
+        // Decisions made with other transaction arguments don't apply anymore.
+        if self.relevance_cache.is_some() {
+            self.relevance_cache = Some(Arc::new(RwLock::new(BTreeMap::new())));
+        }
         self
     }
 
@@ -123,16 +348,33 @@ impl NoteScreener {
             data_store.mast_store().load_account_code(&account_code);
 
             for note in notes {
-                let consumption_status = consumption_checker
-                    .can_consume(
-                        account_id,
-                        block_ref,
-                        InputNote::unauthenticated(note.clone()),
-                        tx_args.clone(),
-                    )
-                    .await?;
-
-                if is_relevant(&consumption_status) {
+                let shape = NoteShape::new(account_id, block_ref, note);
+                let cached = self
+                    .relevance_cache
+                    .as_ref()
+                    .and_then(|cache| cache.read().get(&shape).copied());
+
+                let consumption_status = match cached {
+                    Some(cached) => cached.to_status(),
+                    None => {
+                        let consumption_status = consumption_checker
+                            .can_consume(
+                                account_id,
+                                block_ref,
+                                InputNote::unauthenticated(note.clone()),
+                                tx_args.clone(),
+                            )
+                            .await?;
+                        if let Some(cache) = &self.relevance_cache
+                            && let Some(cached) = CachedRelevance::from_status(&consumption_status)
+                        {
+                            cache.write().insert(shape, cached);
+                        }
+                        Some(consumption_status).filter(is_relevant)
+                    },
+                };
+
+                if let Some(consumption_status) = consumption_status {
                     relevant_notes
                         .entry(note.id())
                         .or_default()
@@ -227,6 +469,18 @@ impl OnNoteReceived for NoteScreener {
             return Ok(NoteUpdateAction::Commit(committed_note));
         }
 
+        match self.policy.screen(&committed_note, public_note.as_ref()).await? {
+            ScreeningDecision::Track => {
+                if let Some(public_note) = public_note {
+                    return Ok(NoteUpdateAction::Insert(public_note));
+                }
+                // Private notes can't be tracked without their details.
+                return Ok(NoteUpdateAction::Discard);
+            },
+            ScreeningDecision::Ignore => return Ok(NoteUpdateAction::Discard),
+            ScreeningDecision::UseDefault => {},
+        }
+
         match public_note {
             Some(public_note) => {
                 // If tracked by the user, keep note regardless of inputs and extra checks
@@ -0,0 +1,41 @@
+//! Decoding of the conditions stored in P2IDE notes.
+
+use miden_protocol::block::BlockNumber;
+use miden_protocol::note::NoteRecipient;
+use miden_standards::note::StandardNote;
+
+/// Index of the reclaim height in the storage of P2IDE notes.
+const P2IDE_RECLAIM_HEIGHT_INDEX: usize = 2;
+/// Index of the timelock height in the storage of P2IDE notes.
+const P2IDE_TIMELOCK_HEIGHT_INDEX: usize = 3;
+
+/// Reclaim and timelock heights of a P2IDE note, read from its storage.
+pub(crate) struct P2ideConditions {
+    /// Block from which the sender can consume the note back.
+    pub reclaim_height: Option<BlockNumber>,
+    /// Block before which the target account can't consume the note.
+    pub timelock_height: Option<BlockNumber>,
+}
+
+impl P2ideConditions {
+    /// Returns the conditions of the note with `recipient`, or `None` if it isn't a P2IDE note.
+    pub(crate) fn from_recipient(recipient: &NoteRecipient) -> Option<Self> {
+        if !matches!(StandardNote::from_script(recipient.script()), Some(StandardNote::P2IDE)) {
+            return None;
+        }
+
+        let height = |index: usize| {
+            let height = recipient.storage().items().get(index)?;
+            // A height of zero means the condition isn't set.
+            u32::try_from(height.as_canonical_u64())
+                .ok()
+                .filter(|height| *height != 0)
+                .map(BlockNumber::from)
+        };
+
+        Some(Self {
+            reclaim_height: height(P2IDE_RECLAIM_HEIGHT_INDEX),
+            timelock_height: height(P2IDE_TIMELOCK_HEIGHT_INDEX),
+        })
+    }
+}
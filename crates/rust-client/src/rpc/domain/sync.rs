@@ -38,6 +38,10 @@ pub struct ChainMmrInfo {
     pub mmr_delta: MmrDelta,
     /// The block header at `block_to`.
     pub block_header: BlockHeader,
+    /// The chain tip at the requested finality level. It is higher than `block_to` when the
+    /// response only covers part of the range, in which case the rest has to be requested starting
+    /// from `block_to`.
+    pub chain_tip: BlockNumber,
 }
 
 impl TryFrom<proto::rpc::SyncChainMmrResponse> for ChainMmrInfo {
@@ -63,6 +67,9 @@ impl TryFrom<proto::rpc::SyncChainMmrResponse> for ChainMmrInfo {
             block_to: block_range.block_to.into(),
             mmr_delta,
             block_header,
+            // The response doesn't carry the chain tip, so the range is assumed to end at it
+            // unless the caller knows better (see `GrpcClient::sync_chain_mmr`).
+            chain_tip: block_range.block_to.into(),
         })
     }
 }
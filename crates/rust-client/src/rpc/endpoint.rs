@@ -3,6 +3,19 @@ use core::fmt;
 
 use miden_protocol::address::NetworkId;
 
+/// Environment variable holding the full URL of the RPC endpoint, e.g.
+/// `https://rpc.testnet.miden.io`. Takes precedence over the per-component variables.
+pub const RPC_URL_ENV_VAR: &str = "MIDEN_RPC_URL";
+/// Environment variable holding the protocol of the RPC endpoint. Defaults to `https`.
+pub const RPC_SCHEME_ENV_VAR: &str = "MIDEN_RPC_SCHEME";
+/// Environment variable holding the host of the RPC endpoint.
+pub const RPC_HOST_ENV_VAR: &str = "MIDEN_RPC_HOST";
+/// Environment variable holding the port of the RPC endpoint. Optional.
+pub const RPC_PORT_ENV_VAR: &str = "MIDEN_RPC_PORT";
+
+/// Key of the store setting holding the network ID of the node the client is connected to.
+pub(crate) const NETWORK_ID_STORE_SETTING: &str = "network_id";
+
 // ENDPOINT
 // ================================================================================================
 
@@ -49,6 +62,48 @@ impl Endpoint {
         Self::new("http".into(), "localhost".into(), Some(Self::MIDEN_NODE_PORT))
     }
 
+    /// Reads the endpoint from the environment.
+    ///
+    /// If `MIDEN_RPC_URL` is set, it is parsed like any endpoint string and the other variables
+    /// are ignored. Otherwise, the endpoint is built from `MIDEN_RPC_HOST`, `MIDEN_RPC_SCHEME`
+    /// (defaulting to `https`) and `MIDEN_RPC_PORT` (optional).
+    ///
+    /// Returns `Ok(None)` if none of the variables is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL or port can't be parsed, or if the scheme or port are set
+    /// without a host.
+    #[cfg(feature = "std")]
+    pub fn from_env() -> Result<Option<Self>, String> {
+        Self::from_vars(|name| std::env::var(name).ok().filter(|value| !value.is_empty()))
+    }
+
+    /// Builds the endpoint from the variables returned by `var`. See [`Endpoint::from_env`].
+    #[cfg(feature = "std")]
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Result<Option<Self>, String> {
+        if let Some(url) = var(RPC_URL_ENV_VAR) {
+            return Self::try_from(url.as_str()).map(Some);
+        }
+
+        let scheme = var(RPC_SCHEME_ENV_VAR);
+        let port = var(RPC_PORT_ENV_VAR)
+            .map(|port| {
+                port.parse::<u16>().map_err(|err| format!("invalid {RPC_PORT_ENV_VAR}: {err}"))
+            })
+            .transpose()?;
+
+        match var(RPC_HOST_ENV_VAR) {
+            Some(host) => {
+                Ok(Some(Self::new(scheme.unwrap_or_else(|| "https".to_string()), host, port)))
+            },
+            None if scheme.is_some() || port.is_some() => Err(format!(
+                "{RPC_HOST_ENV_VAR} must be set when {RPC_SCHEME_ENV_VAR} or {RPC_PORT_ENV_VAR} is"
+            )),
+            None => Ok(None),
+        }
+    }
+
     pub fn protocol(&self) -> &str {
         &self.protocol
     }
@@ -159,6 +214,49 @@ mod test {
 
     use crate::rpc::Endpoint;
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn endpoint_from_vars() {
+        use alloc::collections::BTreeMap;
+        use alloc::string::String;
+
+        use super::{RPC_HOST_ENV_VAR, RPC_PORT_ENV_VAR, RPC_SCHEME_ENV_VAR, RPC_URL_ENV_VAR};
+
+        let from_vars = |vars: &[(&str, &str)]| {
+            let vars: BTreeMap<&str, String> =
+                vars.iter().map(|(name, value)| (*name, (*value).to_string())).collect();
+            Endpoint::from_vars(|name| vars.get(name).cloned())
+        };
+
+        let host_vars = [
+            (RPC_SCHEME_ENV_VAR, "http"),
+            (RPC_HOST_ENV_VAR, "node.internal"),
+            (RPC_PORT_ENV_VAR, "8000"),
+        ];
+        assert_eq!(
+            from_vars(&host_vars).unwrap(),
+            Some(Endpoint::new("http".to_string(), "node.internal".to_string(), Some(8000)))
+        );
+
+        // The scheme defaults to `https`.
+        assert_eq!(
+            from_vars(&[(RPC_HOST_ENV_VAR, "node.internal")]).unwrap(),
+            Some(Endpoint::new("https".to_string(), "node.internal".to_string(), None))
+        );
+
+        // The URL takes precedence over the individual variables.
+        let mut url_vars = host_vars.to_vec();
+        url_vars.push((RPC_URL_ENV_VAR, "https://rpc.testnet.miden.io"));
+        assert_eq!(from_vars(&url_vars).unwrap(), Some(Endpoint::testnet()));
+
+        assert!(
+            from_vars(&[(RPC_HOST_ENV_VAR, "node.internal"), (RPC_PORT_ENV_VAR, "not-a-port")])
+                .is_err()
+        );
+        assert!(from_vars(&[(RPC_PORT_ENV_VAR, "8000")]).is_err());
+        assert_eq!(from_vars(&[]).unwrap(), None);
+    }
+
     #[test]
     fn endpoint_parsing_with_hostname_only() {
         let endpoint = Endpoint::try_from("some.test.domain").unwrap();
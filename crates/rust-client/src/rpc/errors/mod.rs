@@ -50,6 +50,8 @@ pub enum RpcError {
     NoteNotFound(NoteId),
     #[error("invalid Miden node endpoint '{0}'; expected format: https://host:port")]
     InvalidNodeEndpoint(String),
+    #[error("invalid gRPC TLS configuration: {0}")]
+    InvalidTlsConfig(String),
 }
 
 impl RpcError {
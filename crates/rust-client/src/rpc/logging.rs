@@ -0,0 +1,520 @@
+//! Provides [`LoggingRpcClient`], a [`NodeRpcClient`] wrapper that logs every request made to the
+//! node.
+
+use alloc::boxed::Box;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::future::Future;
+use core::time::Duration;
+
+use miden_protocol::Word;
+use miden_protocol::account::{Account, AccountId};
+use miden_protocol::address::NetworkId;
+use miden_protocol::batch::{ProposedBatch, ProvenBatch};
+use miden_protocol::block::{BlockHeader, BlockNumber, FeeParameters, ProvenBlock};
+use miden_protocol::crypto::merkle::mmr::MmrProof;
+use miden_protocol::note::{NoteId, NoteScript, NoteTag, Nullifier};
+use miden_protocol::transaction::{ProvenTransaction, TransactionInputs};
+
+use super::domain::account::{AccountDetails, AccountProof, GetAccountRequest};
+use super::domain::account_vault::AccountVaultInfo;
+use super::domain::note::{FetchedNote, NoteSyncBlock, SyncedNoteDetails};
+use super::domain::nullifier::NullifierUpdate;
+use super::domain::status::NetworkNoteStatusInfo;
+use super::domain::storage_map::StorageMapInfo;
+use super::domain::sync::{ChainMmrInfo, SyncTarget};
+use super::domain::transaction::TransactionRecord;
+use super::{AccountStateAt, NodeRpcClient, RpcEndpoint, RpcError, RpcLimits, RpcStatusInfo};
+use crate::store::InputNoteRecord;
+
+// LOGGING RPC CLIENT
+// ================================================================================================
+
+/// A [`NodeRpcClient`] that delegates every call to an inner client, logging the method name, a
+/// summary of its arguments, its latency and the size of its result at `debug` level.
+///
+/// This is meant for diagnosing protocol and sync issues without changing the inner client. It can
+/// be enabled on a client with
+/// [`ClientBuilder::with_rpc_logging`](crate::builder::ClientBuilder::with_rpc_logging). Latency
+/// is only measured when the `std` feature is enabled.
+pub struct LoggingRpcClient<R: ?Sized = dyn NodeRpcClient> {
+    inner: Arc<R>,
+}
+
+impl<R: NodeRpcClient + ?Sized> LoggingRpcClient<R> {
+    /// Creates a new [`LoggingRpcClient`] that logs the calls made to `inner`.
+    pub fn new(inner: Arc<R>) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the wrapped client.
+    pub fn inner(&self) -> &Arc<R> {
+        &self.inner
+    }
+}
+
+/// Awaits `call`, logging it as a call to `method` made with the given arguments. The result of a
+/// successful call is described with `summarize`.
+async fn log_call<T>(
+    method: &'static str,
+    args: String,
+    call: impl Future<Output = Result<T, RpcError>>,
+    summarize: impl FnOnce(&T) -> String,
+) -> Result<T, RpcError> {
+    let stopwatch = Stopwatch::start();
+    let result = call.await;
+    let latency = stopwatch.elapsed();
+
+    match &result {
+        Ok(value) => tracing::debug!(
+            method,
+            args = %args,
+            latency = ?latency,
+            result = %summarize(value),
+            "RPC call succeeded"
+        ),
+        Err(err) => tracing::debug!(
+            method,
+            args = %args,
+            latency = ?latency,
+            error = %err,
+            "RPC call failed"
+        ),
+    }
+
+    result
+}
+
+/// Measures the time elapsed since it was started, where a clock is available.
+struct Stopwatch {
+    #[cfg(feature = "std")]
+    start: std::time::Instant,
+}
+
+impl Stopwatch {
+    fn start() -> Self {
+        Self {
+            #[cfg(feature = "std")]
+            start: std::time::Instant::now(),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn elapsed(&self) -> Option<Duration> {
+        Some(self.start.elapsed())
+    }
+
+    #[cfg(not(feature = "std"))]
+    #[allow(clippy::unused_self)]
+    fn elapsed(&self) -> Option<Duration> {
+        None
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+impl<R: NodeRpcClient + ?Sized> NodeRpcClient for LoggingRpcClient<R> {
+    async fn set_genesis_commitment(&self, commitment: Word) -> Result<(), RpcError> {
+        self.inner.set_genesis_commitment(commitment).await
+    }
+
+    fn has_genesis_commitment(&self) -> Option<Word> {
+        self.inner.has_genesis_commitment()
+    }
+
+    fn set_default_timeout(&self, timeout: Duration) {
+        self.inner.set_default_timeout(timeout);
+    }
+
+    fn set_operation_timeout(&self, endpoint: RpcEndpoint, timeout: Option<Duration>) {
+        self.inner.set_operation_timeout(endpoint, timeout);
+    }
+
+    async fn get_genesis_commitment(&self) -> Result<Word, RpcError> {
+        log_call(
+            "get_genesis_commitment",
+            String::new(),
+            self.inner.get_genesis_commitment(),
+            Word::to_hex,
+        )
+        .await
+    }
+
+    async fn submit_proven_transaction(
+        &self,
+        proven_transaction: ProvenTransaction,
+        transaction_inputs: TransactionInputs,
+    ) -> Result<BlockNumber, RpcError> {
+        log_call(
+            "submit_proven_transaction",
+            format!("transaction: {}", proven_transaction.id()),
+            self.inner.submit_proven_transaction(proven_transaction, transaction_inputs),
+            |chain_tip| format!("chain tip: {chain_tip}"),
+        )
+        .await
+    }
+
+    async fn submit_proven_batch(
+        &self,
+        proven_batch: ProvenBatch,
+        proposed_batch: ProposedBatch,
+        transaction_inputs: Vec<TransactionInputs>,
+    ) -> Result<BlockNumber, RpcError> {
+        log_call(
+            "submit_proven_batch",
+            format!("transactions: {}", transaction_inputs.len()),
+            self.inner.submit_proven_batch(proven_batch, proposed_batch, transaction_inputs),
+            |chain_tip| format!("chain tip: {chain_tip}"),
+        )
+        .await
+    }
+
+    async fn get_block_header_by_number(
+        &self,
+        block_num: Option<BlockNumber>,
+        include_mmr_proof: bool,
+    ) -> Result<(BlockHeader, Option<MmrProof>), RpcError> {
+        log_call(
+            "get_block_header_by_number",
+            format!("block: {block_num:?}, include_mmr_proof: {include_mmr_proof}"),
+            self.inner.get_block_header_by_number(block_num, include_mmr_proof),
+            |(header, _)| format!("block: {}", header.block_num()),
+        )
+        .await
+    }
+
+    async fn get_block_by_number(
+        &self,
+        block_num: BlockNumber,
+        include_proof: bool,
+    ) -> Result<ProvenBlock, RpcError> {
+        log_call(
+            "get_block_by_number",
+            format!("block: {block_num}, include_proof: {include_proof}"),
+            self.inner.get_block_by_number(block_num, include_proof),
+            |block| format!("block: {}", block.header().block_num()),
+        )
+        .await
+    }
+
+    async fn get_notes_by_id(&self, note_ids: &[NoteId]) -> Result<Vec<FetchedNote>, RpcError> {
+        log_call(
+            "get_notes_by_id",
+            format!("notes: {}", note_ids.len()),
+            self.inner.get_notes_by_id(note_ids),
+            |notes| format!("notes: {}", notes.len()),
+        )
+        .await
+    }
+
+    async fn sync_chain_mmr(
+        &self,
+        current_block_height: BlockNumber,
+        upper_bound: SyncTarget,
+    ) -> Result<ChainMmrInfo, RpcError> {
+        log_call(
+            "sync_chain_mmr",
+            format!("from: {current_block_height}, to: {upper_bound:?}"),
+            self.inner.sync_chain_mmr(current_block_height, upper_bound),
+            |info| {
+                format!(
+                    "blocks: {}..={}, chain tip: {}",
+                    info.block_from, info.block_to, info.chain_tip
+                )
+            },
+        )
+        .await
+    }
+
+    async fn get_account_details(
+        &self,
+        account_id: AccountId,
+    ) -> Result<Option<Account>, RpcError> {
+        log_call(
+            "get_account_details",
+            format!("account: {account_id}"),
+            self.inner.get_account_details(account_id),
+            |account| format!("public state: {}", account.is_some()),
+        )
+        .await
+    }
+
+    async fn get_account_details_at(
+        &self,
+        account_id: AccountId,
+        at: AccountStateAt,
+    ) -> Result<Option<Account>, RpcError> {
+        log_call(
+            "get_account_details_at",
+            format!("account: {account_id}, at: {at:?}"),
+            self.inner.get_account_details_at(account_id, at),
+            |account| format!("public state: {}", account.is_some()),
+        )
+        .await
+    }
+
+    async fn sync_notes(
+        &self,
+        block_from: BlockNumber,
+        block_to: BlockNumber,
+        note_tags: &BTreeSet<NoteTag>,
+    ) -> Result<Vec<NoteSyncBlock>, RpcError> {
+        log_call(
+            "sync_notes",
+            format!("blocks: {block_from}..={block_to}, tags: {}", note_tags.len()),
+            self.inner.sync_notes(block_from, block_to, note_tags),
+            |blocks| format!("blocks: {}", blocks.len()),
+        )
+        .await
+    }
+
+    async fn sync_notes_with_details(
+        &self,
+        block_from: BlockNumber,
+        block_to: BlockNumber,
+        note_tags: &BTreeSet<NoteTag>,
+    ) -> Result<(Vec<NoteSyncBlock>, BTreeMap<NoteId, SyncedNoteDetails>), RpcError> {
+        log_call(
+            "sync_notes_with_details",
+            format!("blocks: {block_from}..={block_to}, tags: {}", note_tags.len()),
+            self.inner.sync_notes_with_details(block_from, block_to, note_tags),
+            |(blocks, details)| {
+                format!("blocks: {}, note details: {}", blocks.len(), details.len())
+            },
+        )
+        .await
+    }
+
+    async fn sync_nullifiers(
+        &self,
+        prefix: &[u16],
+        block_from: BlockNumber,
+        block_to: BlockNumber,
+    ) -> Result<Vec<NullifierUpdate>, RpcError> {
+        log_call(
+            "sync_nullifiers",
+            format!("prefixes: {}, blocks: {block_from}..={block_to}", prefix.len()),
+            self.inner.sync_nullifiers(prefix, block_from, block_to),
+            |nullifiers| format!("nullifiers: {}", nullifiers.len()),
+        )
+        .await
+    }
+
+    async fn get_account(
+        &self,
+        account_id: AccountId,
+        request: GetAccountRequest,
+    ) -> Result<(BlockNumber, AccountProof), RpcError> {
+        log_call(
+            "get_account",
+            format!("account: {account_id}"),
+            self.inner.get_account(account_id, request),
+            |(block_num, proof)| {
+                format!("block: {block_num}, details: {}", proof.account_header().is_some())
+            },
+        )
+        .await
+    }
+
+    async fn resolve_oversize_vault(
+        &self,
+        account_id: AccountId,
+        block_to: BlockNumber,
+        details: &mut AccountDetails,
+    ) -> Result<(), RpcError> {
+        log_call(
+            "resolve_oversize_vault",
+            format!("account: {account_id}, block: {block_to}"),
+            self.inner.resolve_oversize_vault(account_id, block_to, details),
+            |()| String::new(),
+        )
+        .await
+    }
+
+    async fn resolve_oversize_storage_maps(
+        &self,
+        account_id: AccountId,
+        block_to: BlockNumber,
+        details: &mut AccountDetails,
+    ) -> Result<(), RpcError> {
+        log_call(
+            "resolve_oversize_storage_maps",
+            format!("account: {account_id}, block: {block_to}"),
+            self.inner.resolve_oversize_storage_maps(account_id, block_to, details),
+            |()| String::new(),
+        )
+        .await
+    }
+
+    async fn get_nullifier_commit_heights(
+        &self,
+        requested_nullifiers: BTreeSet<Nullifier>,
+        block_from: BlockNumber,
+    ) -> Result<BTreeMap<Nullifier, Option<BlockNumber>>, RpcError> {
+        log_call(
+            "get_nullifier_commit_heights",
+            format!("nullifiers: {}, from: {block_from}", requested_nullifiers.len()),
+            self.inner.get_nullifier_commit_heights(requested_nullifiers, block_from),
+            |heights| format!("committed: {}", heights.values().flatten().count()),
+        )
+        .await
+    }
+
+    async fn get_public_note_records(
+        &self,
+        note_ids: &[NoteId],
+        current_timestamp: Option<u64>,
+    ) -> Result<Vec<InputNoteRecord>, RpcError> {
+        log_call(
+            "get_public_note_records",
+            format!("notes: {}", note_ids.len()),
+            self.inner.get_public_note_records(note_ids, current_timestamp),
+            |notes| format!("notes: {}", notes.len()),
+        )
+        .await
+    }
+
+    async fn get_block_header_with_proof(
+        &self,
+        block_num: BlockNumber,
+    ) -> Result<(BlockHeader, MmrProof), RpcError> {
+        log_call(
+            "get_block_header_with_proof",
+            format!("block: {block_num}"),
+            self.inner.get_block_header_with_proof(block_num),
+            |(header, _)| format!("block: {}", header.block_num()),
+        )
+        .await
+    }
+
+    async fn get_fee_parameters(&self) -> Result<FeeParameters, RpcError> {
+        log_call(
+            "get_fee_parameters",
+            String::new(),
+            self.inner.get_fee_parameters(),
+            |fee_parameters| {
+                format!(
+                    "fee faucet: {}, verification base fee: {}",
+                    fee_parameters.fee_faucet_id(),
+                    fee_parameters.verification_base_fee()
+                )
+            },
+        )
+        .await
+    }
+
+    async fn get_note_by_id(&self, note_id: NoteId) -> Result<FetchedNote, RpcError> {
+        log_call(
+            "get_note_by_id",
+            format!("note: {note_id}"),
+            self.inner.get_note_by_id(note_id),
+            |note| format!("block: {}", note.inclusion_proof().location().block_num()),
+        )
+        .await
+    }
+
+    async fn get_note_script_by_root(&self, root: Word) -> Result<Option<NoteScript>, RpcError> {
+        log_call(
+            "get_note_script_by_root",
+            format!("root: {}", root.to_hex()),
+            self.inner.get_note_script_by_root(root),
+            |script| format!("found: {}", script.is_some()),
+        )
+        .await
+    }
+
+    async fn sync_storage_maps(
+        &self,
+        block_from: BlockNumber,
+        block_to: BlockNumber,
+        account_id: AccountId,
+    ) -> Result<StorageMapInfo, RpcError> {
+        log_call(
+            "sync_storage_maps",
+            format!("account: {account_id}, blocks: {block_from}..={block_to}"),
+            self.inner.sync_storage_maps(block_from, block_to, account_id),
+            |info| format!("updates: {}", info.updates.len()),
+        )
+        .await
+    }
+
+    async fn sync_account_vault(
+        &self,
+        block_from: BlockNumber,
+        block_to: BlockNumber,
+        account_id: AccountId,
+    ) -> Result<AccountVaultInfo, RpcError> {
+        log_call(
+            "sync_account_vault",
+            format!("account: {account_id}, blocks: {block_from}..={block_to}"),
+            self.inner.sync_account_vault(block_from, block_to, account_id),
+            |info| format!("updates: {}", info.updates.len()),
+        )
+        .await
+    }
+
+    async fn sync_transactions(
+        &self,
+        block_from: BlockNumber,
+        block_to: BlockNumber,
+        account_ids: Vec<AccountId>,
+    ) -> Result<Vec<TransactionRecord>, RpcError> {
+        log_call(
+            "sync_transactions",
+            format!("accounts: {}, blocks: {block_from}..={block_to}", account_ids.len()),
+            self.inner.sync_transactions(block_from, block_to, account_ids),
+            |transactions| format!("transactions: {}", transactions.len()),
+        )
+        .await
+    }
+
+    async fn get_network_id(&self) -> Result<NetworkId, RpcError> {
+        log_call(
+            "get_network_id",
+            String::new(),
+            self.inner.get_network_id(),
+            ToString::to_string,
+        )
+        .await
+    }
+
+    async fn get_rpc_limits(&self) -> Result<RpcLimits, RpcError> {
+        log_call("get_rpc_limits", String::new(), self.inner.get_rpc_limits(), |limits| {
+            format!("{limits:?}")
+        })
+        .await
+    }
+
+    fn has_rpc_limits(&self) -> Option<RpcLimits> {
+        self.inner.has_rpc_limits()
+    }
+
+    async fn set_rpc_limits(&self, limits: RpcLimits) {
+        self.inner.set_rpc_limits(limits).await;
+    }
+
+    async fn get_status_unversioned(&self) -> Result<RpcStatusInfo, RpcError> {
+        log_call(
+            "get_status_unversioned",
+            String::new(),
+            self.inner.get_status_unversioned(),
+            |status| format!("version: {}", status.version),
+        )
+        .await
+    }
+
+    async fn get_network_note_status(
+        &self,
+        note_id: NoteId,
+    ) -> Result<NetworkNoteStatusInfo, RpcError> {
+        log_call(
+            "get_network_note_status",
+            format!("note: {note_id}"),
+            self.inner.get_network_note_status(note_id),
+            |status| format!("status: {}", status.status),
+        )
+        .await
+    }
+}
@@ -10,6 +10,9 @@
 //! - Sync state updates (including notes, nullifiers, and account updates).
 //! - Fetch details for specific notes and accounts.
 //!
+//! Any implementation can be wrapped in a [`LoggingRpcClient`] to log the requests made to the
+//! node.
+//!
 //! The client implementation adapts to the target environment automatically:
 //! - Native targets use `tonic` transport with TLS.
 //! - `wasm32` targets use `tonic-web-wasm-client` transport.
@@ -46,6 +49,7 @@ use alloc::collections::{BTreeMap, BTreeSet};
 use alloc::string::String;
 use alloc::vec::Vec;
 use core::fmt;
+use core::time::Duration;
 
 use domain::account::{
     AccountDetails,
@@ -63,7 +67,7 @@ use miden_protocol::Word;
 use miden_protocol::account::{Account, AccountId};
 use miden_protocol::address::NetworkId;
 use miden_protocol::batch::{ProposedBatch, ProvenBatch};
-use miden_protocol::block::{BlockHeader, BlockNumber, ProvenBlock};
+use miden_protocol::block::{BlockHeader, BlockNumber, FeeParameters, ProvenBlock};
 use miden_protocol::crypto::merkle::mmr::MmrProof;
 use miden_protocol::note::{NoteId, NoteMetadata, NoteScript, NoteTag, NoteType, Nullifier};
 use miden_protocol::transaction::{ProvenTransaction, TransactionInputs};
@@ -78,10 +82,20 @@ mod errors;
 pub use errors::*;
 
 mod endpoint;
+
+mod logging;
 pub(crate) use domain::limits::RPC_LIMITS_STORE_SETTING;
 pub use domain::limits::RpcLimits;
 pub use domain::status::{NetworkNoteStatus, NetworkNoteStatusInfo, RpcStatusInfo};
-pub use endpoint::Endpoint;
+pub(crate) use endpoint::NETWORK_ID_STORE_SETTING;
+pub use endpoint::{
+    Endpoint,
+    RPC_HOST_ENV_VAR,
+    RPC_PORT_ENV_VAR,
+    RPC_SCHEME_ENV_VAR,
+    RPC_URL_ENV_VAR,
+};
+pub use logging::LoggingRpcClient;
 
 #[cfg(not(feature = "testing"))]
 mod generated;
@@ -91,7 +105,7 @@ pub mod generated;
 #[cfg(feature = "tonic")]
 mod tonic_client;
 #[cfg(feature = "tonic")]
-pub use tonic_client::GrpcClient;
+pub use tonic_client::{GrpcClient, GrpcTlsConfig};
 
 use crate::rpc::domain::account_vault::AccountVaultInfo;
 use crate::rpc::domain::transaction::TransactionRecord;
@@ -135,6 +149,30 @@ pub trait NodeRpcClient: Send + Sync {
     /// Returns the genesis commitment if it has been set, without fetching from the node.
     fn has_genesis_commitment(&self) -> Option<Word>;
 
+    /// Sets the timeout applied to calls that don't have an operation-specific timeout (see
+    /// [`NodeRpcClient::set_operation_timeout`]).
+    ///
+    /// Implementations that don't support timeouts ignore this setting.
+    fn set_default_timeout(&self, _timeout: Duration) {}
+
+    /// Overrides the timeout applied to calls to the provided endpoint, e.g. to give large state
+    /// syncs more time than quick block header fetches. Passing `None` removes the override, so
+    /// the default timeout applies again.
+    ///
+    /// Implementations that don't support timeouts ignore this setting.
+    fn set_operation_timeout(&self, _endpoint: RpcEndpoint, _timeout: Option<Duration>) {}
+
+    /// Fetches the commitment of the genesis block served by the node.
+    ///
+    /// Unlike regular requests, this doesn't depend on the genesis commitment set in the client,
+    /// so it can be used to check that the node serves the expected chain.
+    async fn get_genesis_commitment(&self) -> Result<Word, RpcError> {
+        self.get_status_unversioned()
+            .await?
+            .genesis_commitment
+            .ok_or_else(|| RpcError::ExpectedDataMissing("genesis_commitment".into()))
+    }
+
     /// Given a Proven Transaction, send it to the node for it to be included in a future block
     /// using the `/SubmitProvenTransaction` RPC endpoint.
     ///
@@ -237,6 +275,20 @@ pub trait NodeRpcClient: Send + Sync {
     async fn get_account_details(
         &self,
         account_id: AccountId,
+    ) -> Result<Option<Account>, RpcError> {
+        self.get_account_details_at(account_id, AccountStateAt::ChainTip).await
+    }
+
+    /// Fetches the full state of a public account as of the requested point in the chain.
+    ///
+    /// Behaves like [`NodeRpcClient::get_account_details`], but `at` selects whether the state is
+    /// taken at the chain tip or at a specific block.
+    ///
+    /// Returns `Ok(None)` for accounts without public state.
+    async fn get_account_details_at(
+        &self,
+        account_id: AccountId,
+        at: AccountStateAt,
     ) -> Result<Option<Account>, RpcError> {
         // Accounts without public state have no full state to fetch; only a commitment is on-chain.
         if !account_id.is_public() {
@@ -250,7 +302,8 @@ pub trait NodeRpcClient: Send + Sync {
                 account_id,
                 GetAccountRequest::new()
                     .with_storage(StorageMapFetch::All)
-                    .with_vault(VaultFetch::Always),
+                    .with_vault(VaultFetch::Always)
+                    .at(at),
             )
             .await?;
 
@@ -522,6 +575,15 @@ pub trait NodeRpcClient: Send + Sync {
         Ok((header, proof.ok_or(RpcError::ExpectedDataMissing(String::from("MmrProof")))?))
     }
 
+    /// Fetches the fee parameters currently in force on the node.
+    ///
+    /// The default implementation of this method reads them from the header of the node's latest
+    /// block, fetched with [`NodeRpcClient::get_block_header_by_number`].
+    async fn get_fee_parameters(&self) -> Result<FeeParameters, RpcError> {
+        let (header, _) = self.get_block_header_by_number(None, false).await?;
+        Ok(header.fee_parameters().clone())
+    }
+
     /// Fetches the note with the specified ID.
     ///
     /// The default implementation of this method uses [`NodeRpcClient::get_notes_by_id`].
@@ -620,7 +682,7 @@ pub trait NodeRpcClient: Send + Sync {
 // ================================================================================================
 //
 /// RPC methods for the Miden protocol.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum RpcEndpoint {
     Status,
     SyncNullifiers,
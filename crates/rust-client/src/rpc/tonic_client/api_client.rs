@@ -1,6 +1,7 @@
 use alloc::string::String;
 use core::fmt::Write;
 use core::ops::{Deref, DerefMut};
+use core::time::Duration;
 
 use api_client_wrapper::{ApiClient, InnerClient};
 use miden_protocol::Word;
@@ -14,13 +15,14 @@ use tonic::service::Interceptor;
 #[cfg(target_arch = "wasm32")]
 pub(crate) mod api_client_wrapper {
     use alloc::string::String;
+    use core::time::Duration;
 
     use miden_protocol::Word;
     use tonic::service::interceptor::InterceptedService;
 
     use super::{MetadataInterceptor, accept_header_interceptor};
-    use crate::rpc::RpcError;
     use crate::rpc::generated::rpc::api_client::ApiClient as ProtoClient;
+    use crate::rpc::{GrpcTlsConfig, RpcError};
 
     pub type WasmClient = tonic_web_wasm_client::Client;
     pub type InnerClient = ProtoClient<InterceptedService<WasmClient, MetadataInterceptor>>;
@@ -28,6 +30,7 @@ pub(crate) mod api_client_wrapper {
     pub struct ApiClient {
         pub(crate) client: InnerClient,
         wasm_client: WasmClient,
+        interceptor: MetadataInterceptor,
         bearer_token: Option<String>,
     }
 
@@ -36,36 +39,49 @@ pub(crate) mod api_client_wrapper {
         ///
         /// When `bearer_token` is `Some`, an `authorization: Bearer <token>` header is
         /// injected into every outbound request alongside the standard `accept` header.
+        ///
+        /// `tls_config` is ignored: TLS is negotiated by the browser.
         // Kept async for API parity with the native client; in WASM this is synchronous.
         #[allow(clippy::unused_async)]
         pub async fn new_client(
             endpoint: String,
-            _timeout_ms: u64,
             genesis_commitment: Option<Word>,
             bearer_token: Option<String>,
+            _tls_config: Option<&GrpcTlsConfig>,
         ) -> Result<ApiClient, RpcError> {
             let wasm_client = WasmClient::new(endpoint);
             let interceptor =
                 accept_header_interceptor(genesis_commitment, bearer_token.as_deref())?;
-            let client = ProtoClient::with_interceptor(wasm_client.clone(), interceptor);
-            Ok(ApiClient { client, wasm_client, bearer_token })
+            let client = ProtoClient::with_interceptor(wasm_client.clone(), interceptor.clone());
+            Ok(ApiClient {
+                client,
+                wasm_client,
+                interceptor,
+                bearer_token,
+            })
         }
 
         /// Connects to the Miden node API without injecting an Accept header.
         ///
         /// `bearer_token`, if set, is still forwarded as `authorization: Bearer <token>`.
+        /// `tls_config` is ignored: TLS is negotiated by the browser.
         // Kept async for API parity with the native client; in WASM this is synchronous.
         #[allow(clippy::unused_async)]
         pub async fn new_client_without_accept_header(
             endpoint: String,
-            _timeout_ms: u64,
             bearer_token: Option<String>,
+            _tls_config: Option<&GrpcTlsConfig>,
         ) -> Result<ApiClient, RpcError> {
             let wasm_client = WasmClient::new(endpoint);
             let interceptor =
                 MetadataInterceptor::default().with_bearer_token(bearer_token.as_deref())?;
-            let client = ProtoClient::with_interceptor(wasm_client.clone(), interceptor);
-            Ok(ApiClient { client, wasm_client, bearer_token })
+            let client = ProtoClient::with_interceptor(wasm_client.clone(), interceptor.clone());
+            Ok(ApiClient {
+                client,
+                wasm_client,
+                interceptor,
+                bearer_token,
+            })
         }
 
         /// Returns a new `ApiClient` with an updated genesis commitment.
@@ -74,12 +90,29 @@ pub(crate) mod api_client_wrapper {
         pub fn set_genesis_commitment(&mut self, genesis_commitment: Word) -> &mut Self {
             // The bearer token was validated at construction time; re-applying the same
             // value here cannot fail.
-            let interceptor =
+            self.interceptor =
                 accept_header_interceptor(Some(genesis_commitment), self.bearer_token.as_deref())
                     .expect("bearer token already validated at construction time");
-            self.client = ProtoClient::with_interceptor(self.wasm_client.clone(), interceptor);
+            self.client =
+                ProtoClient::with_interceptor(self.wasm_client.clone(), self.interceptor.clone());
             self
         }
+
+        /// Returns a client sharing the same underlying channel whose requests carry the
+        /// provided deadline.
+        #[must_use]
+        pub fn with_timeout(&self, timeout: Duration) -> ApiClient {
+            let interceptor = self.interceptor.clone().with_timeout(timeout);
+            ApiClient {
+                client: ProtoClient::with_interceptor(
+                    self.wasm_client.clone(),
+                    interceptor.clone(),
+                ),
+                wasm_client: self.wasm_client.clone(),
+                interceptor,
+                bearer_token: self.bearer_token.clone(),
+            }
+        }
     }
 }
 
@@ -97,27 +130,32 @@ pub(crate) mod api_client_wrapper {
     use tonic::transport::Channel;
 
     use super::{MetadataInterceptor, accept_header_interceptor};
-    use crate::rpc::RpcError;
     use crate::rpc::generated::rpc::api_client::ApiClient as ProtoClient;
+    use crate::rpc::{GrpcTlsConfig, RpcError};
 
     pub type InnerClient = ProtoClient<InterceptedService<Channel, MetadataInterceptor>>;
     #[derive(Clone)]
     pub struct ApiClient {
         pub(crate) client: InnerClient,
         channel: Channel,
+        interceptor: MetadataInterceptor,
         bearer_token: Option<String>,
     }
 
     impl ApiClient {
-        /// Connects to the Miden node API using the provided URL, timeout and genesis commitment.
+        /// Connects to the Miden node API using the provided URL and genesis commitment.
         ///
         /// When `bearer_token` is `Some`, an `authorization: Bearer <token>` header is
         /// injected into every outbound request alongside the standard `accept` header.
+        /// `tls_config` adds custom trust roots and a client identity on top of the native roots.
+        ///
+        /// The channel has no timeout of its own: deadlines are set per request (see
+        /// [`Self::with_timeout`]).
         pub async fn new_client(
             endpoint: String,
-            timeout_ms: u64,
             genesis_commitment: Option<Word>,
             bearer_token: Option<String>,
+            tls_config: Option<&GrpcTlsConfig>,
         ) -> Result<ApiClient, RpcError> {
             // Build the interceptor first so an invalid bearer token fails fast,
             // before we attempt the network connection.
@@ -126,27 +164,32 @@ pub(crate) mod api_client_wrapper {
 
             // Setup connection channel.
             let endpoint = tonic::transport::Endpoint::try_from(endpoint)
-                .map_err(|err| RpcError::ConnectionError(Box::new(err)))?
-                .timeout(Duration::from_millis(timeout_ms));
+                .map_err(|err| RpcError::ConnectionError(Box::new(err)))?;
             let channel = endpoint
-                .tls_config(tonic::transport::ClientTlsConfig::new().with_native_roots())
+                .tls_config(client_tls_config(tls_config))
                 .map_err(|err| RpcError::ConnectionError(Box::new(err)))?
                 .connect()
                 .await
                 .map_err(|err| RpcError::ConnectionError(Box::new(err)))?;
 
             // Return the connected client.
-            let client = ProtoClient::with_interceptor(channel.clone(), interceptor);
-            Ok(ApiClient { client, channel, bearer_token })
+            let client = ProtoClient::with_interceptor(channel.clone(), interceptor.clone());
+            Ok(ApiClient {
+                client,
+                channel,
+                interceptor,
+                bearer_token,
+            })
         }
 
         /// Connects to the Miden node API without injecting an Accept header.
         ///
-        /// `bearer_token`, if set, is still forwarded as `authorization: Bearer <token>`.
+        /// `bearer_token`, if set, is still forwarded as `authorization: Bearer <token>`, and
+        /// `tls_config` is applied as in [`Self::new_client`].
         pub async fn new_client_without_accept_header(
             endpoint: String,
-            timeout_ms: u64,
             bearer_token: Option<String>,
+            tls_config: Option<&GrpcTlsConfig>,
         ) -> Result<ApiClient, RpcError> {
             // Fail fast on an invalid bearer token, before opening the channel.
             let interceptor =
@@ -154,17 +197,21 @@ pub(crate) mod api_client_wrapper {
 
             // Setup connection channel.
             let endpoint = tonic::transport::Endpoint::try_from(endpoint)
-                .map_err(|err| RpcError::ConnectionError(Box::new(err)))?
-                .timeout(Duration::from_millis(timeout_ms));
+                .map_err(|err| RpcError::ConnectionError(Box::new(err)))?;
             let channel = endpoint
-                .tls_config(tonic::transport::ClientTlsConfig::new().with_native_roots())
+                .tls_config(client_tls_config(tls_config))
                 .map_err(|err| RpcError::ConnectionError(Box::new(err)))?
                 .connect()
                 .await
                 .map_err(|err| RpcError::ConnectionError(Box::new(err)))?;
 
-            let client = ProtoClient::with_interceptor(channel.clone(), interceptor);
-            Ok(ApiClient { client, channel, bearer_token })
+            let client = ProtoClient::with_interceptor(channel.clone(), interceptor.clone());
+            Ok(ApiClient {
+                client,
+                channel,
+                interceptor,
+                bearer_token,
+            })
         }
 
         /// Returns a new `ApiClient` with an updated genesis commitment.
@@ -173,12 +220,35 @@ pub(crate) mod api_client_wrapper {
         pub fn set_genesis_commitment(&mut self, genesis_commitment: Word) -> &mut Self {
             // The bearer token was validated at construction time; re-applying the same
             // value here cannot fail.
-            let interceptor =
+            self.interceptor =
                 accept_header_interceptor(Some(genesis_commitment), self.bearer_token.as_deref())
                     .expect("bearer token already validated at construction time");
-            self.client = ProtoClient::with_interceptor(self.channel.clone(), interceptor);
+            self.client =
+                ProtoClient::with_interceptor(self.channel.clone(), self.interceptor.clone());
             self
         }
+
+        /// Returns a client sharing the same underlying channel whose requests carry the
+        /// provided deadline.
+        #[must_use]
+        pub fn with_timeout(&self, timeout: Duration) -> ApiClient {
+            let interceptor = self.interceptor.clone().with_timeout(timeout);
+            ApiClient {
+                client: ProtoClient::with_interceptor(self.channel.clone(), interceptor.clone()),
+                channel: self.channel.clone(),
+                interceptor,
+                bearer_token: self.bearer_token.clone(),
+            }
+        }
+    }
+
+    /// Returns the TLS configuration for the channel: the native roots, extended by `tls_config`
+    /// when one is provided.
+    fn client_tls_config(tls_config: Option<&GrpcTlsConfig>) -> tonic::transport::ClientTlsConfig {
+        tls_config.map_or_else(
+            || tonic::transport::ClientTlsConfig::new().with_native_roots(),
+            GrpcTlsConfig::to_client_tls_config,
+        )
     }
 }
 
@@ -202,6 +272,7 @@ impl DerefMut for ApiClient {
 #[derive(Default, Clone)]
 pub struct MetadataInterceptor {
     metadata: alloc::collections::BTreeMap<&'static str, AsciiMetadataValue>,
+    timeout: Option<Duration>,
 }
 
 impl MetadataInterceptor {
@@ -215,6 +286,14 @@ impl MetadataInterceptor {
         Ok(self)
     }
 
+    /// Sets the deadline of the intercepted requests, sent to the node as the `grpc-timeout`
+    /// header.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /// Adds or overwrites the `authorization: Bearer <token>` header on the interceptor.
     /// A `None` token is a no-op.
     ///
@@ -238,6 +317,9 @@ impl Interceptor for MetadataInterceptor {
         for (key, value) in &self.metadata {
             request.metadata_mut().insert(*key, value.clone());
         }
+        if let Some(timeout) = self.timeout {
+            request.set_timeout(timeout);
+        }
         Ok(request)
     }
 }
@@ -5,7 +5,10 @@ use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use core::error::Error;
 use core::pin::Pin;
+use core::sync::atomic::{AtomicU64, Ordering};
+use core::time::Duration;
 
+use futures::future::{self, Either};
 use miden_protocol::vm::FutureMaybeSend;
 
 type RpcFuture<T> = Pin<Box<dyn FutureMaybeSend<T>>>;
@@ -50,8 +53,10 @@ use crate::rpc::{AccountStateAt, generated as proto};
 
 mod api_client;
 mod retry;
+mod tls;
 
 use api_client::api_client_wrapper::ApiClient;
+pub use tls::GrpcTlsConfig;
 
 /// Tracks the pagination state for block-driven endpoints.
 struct BlockPagination {
@@ -175,6 +180,22 @@ fn ensure_requested_note_ids(
     Ok(())
 }
 
+/// Resolves `future`, failing with a [`tonic::Code::DeadlineExceeded`] status if it takes longer
+/// than `timeout`.
+async fn with_timeout<T>(
+    future: RpcFuture<Result<T, Status>>,
+    timeout: Duration,
+) -> Result<T, Status> {
+    let sleep = core::pin::pin!(retry::async_sleep(timeout));
+    match future::select(future, sleep).await {
+        Either::Left((result, _)) => result,
+        Either::Right(((), _)) => Err(Status::deadline_exceeded(format!(
+            "request timed out after {} ms",
+            timeout.as_millis()
+        ))),
+    }
+}
+
 // GRPC CLIENT
 // ================================================================================================
 
@@ -193,8 +214,10 @@ pub struct GrpcClient {
     client: RwLock<Option<ApiClient>>,
     /// The node endpoint URL to connect to.
     endpoint: String,
-    /// Request timeout in milliseconds.
-    timeout_ms: u64,
+    /// Default request timeout in milliseconds.
+    timeout_ms: AtomicU64,
+    /// Per-endpoint timeouts overriding the default one.
+    operation_timeouts: RwLock<BTreeMap<RpcEndpoint, Duration>>,
     /// The genesis block commitment, used for request validation by the node.
     genesis_commitment: RwLock<Option<Word>>,
     /// Cached RPC limits fetched from the node.
@@ -207,6 +230,9 @@ pub struct GrpcClient {
     /// gRPC call, alongside the standard `accept` header. Used when talking to an
     /// authenticating gateway in front of the node.
     bearer_token: Option<String>,
+    /// Optional TLS settings (custom root CA, client identity) applied on top of the native
+    /// root certificates. Ignored on `wasm32`, where the browser negotiates TLS.
+    tls_config: Option<GrpcTlsConfig>,
 }
 
 impl GrpcClient {
@@ -216,12 +242,14 @@ impl GrpcClient {
         GrpcClient {
             client: RwLock::new(None),
             endpoint: endpoint.to_string(),
-            timeout_ms,
+            timeout_ms: AtomicU64::new(timeout_ms),
+            operation_timeouts: RwLock::new(BTreeMap::new()),
             genesis_commitment: RwLock::new(None),
             limits: RwLock::new(None),
             max_retries: retry::DEFAULT_MAX_RETRIES,
             retry_interval_ms: retry::DEFAULT_RETRY_INTERVAL_MS,
             bearer_token: None,
+            tls_config: None,
         }
     }
 
@@ -271,6 +299,39 @@ impl GrpcClient {
         self
     }
 
+    /// Uses the provided TLS settings when connecting to the node, e.g. to trust a node whose
+    /// certificate is issued by a private CA, or to authenticate with a client certificate.
+    ///
+    /// This only applies to native targets. On `wasm32` the connection is made by the browser,
+    /// which negotiates TLS with its own trust store, so the configuration is ignored.
+    ///
+    /// Calling this method twice overwrites the earlier configuration.
+    #[must_use]
+    pub fn with_tls_config(mut self, tls_config: GrpcTlsConfig) -> Self {
+        self.tls_config = Some(tls_config);
+        self
+    }
+
+    /// Sets the default request timeout in milliseconds, which applies to every call without an
+    /// operation-specific timeout (see [`NodeRpcClient::set_operation_timeout`]).
+    pub fn set_default_timeout(&self, timeout_ms: u64) {
+        self.timeout_ms.store(timeout_ms, Ordering::Relaxed);
+    }
+
+    /// Returns the default request timeout in milliseconds.
+    pub fn default_timeout(&self) -> u64 {
+        self.timeout_ms.load(Ordering::Relaxed)
+    }
+
+    /// Returns the timeout that applies to calls to the provided endpoint.
+    fn timeout_for(&self, endpoint: RpcEndpoint) -> Duration {
+        self.operation_timeouts
+            .read()
+            .get(&endpoint)
+            .copied()
+            .unwrap_or_else(|| Duration::from_millis(self.default_timeout()))
+    }
+
     /// Takes care of establishing the RPC connection if not connected yet. It ensures that the
     /// `rpc_api` field is initialized and returns a write guard to it.
     async fn ensure_connected(&self) -> Result<ApiClient, RpcError> {
@@ -281,15 +342,15 @@ impl GrpcClient {
         Ok(self.client.read().as_ref().expect("rpc_api should be initialized").clone())
     }
 
-    /// Connects to the Miden node, setting the client API with the provided URL, timeout and
-    /// genesis commitment.
+    /// Connects to the Miden node, setting the client API with the provided URL and genesis
+    /// commitment.
     async fn connect(&self) -> Result<(), RpcError> {
         let genesis_commitment = *self.genesis_commitment.read();
         let new_client = ApiClient::new_client(
             self.endpoint.clone(),
-            self.timeout_ms,
             genesis_commitment,
             self.bearer_token.clone(),
+            self.tls_config.as_ref(),
         )
         .await?;
         let mut client = self.client.write();
@@ -318,6 +379,10 @@ impl GrpcClient {
     /// [`tonic::Code::ResourceExhausted`] and [`tonic::Code::Unavailable`] responses, including
     /// honoring cooldown delays when the node provides them.
     ///
+    /// Each attempt is bounded by the timeout configured for the endpoint (see
+    /// [`NodeRpcClient::set_operation_timeout`]), falling back to the client's default timeout.
+    /// The timeout is also sent to the node as the request's deadline.
+    ///
     /// Returns the first successful gRPC response. If the call keeps failing after retries are
     /// exhausted, or if the error is not retryable, this returns the corresponding [`RpcError`]
     /// for the provided [`RpcEndpoint`].
@@ -327,11 +392,12 @@ impl GrpcClient {
         mut call: impl FnMut(ApiClient) -> RpcFuture<Result<tonic::Response<T>, Status>>,
     ) -> Result<tonic::Response<T>, RpcError> {
         let mut retry_state = retry::RetryState::new(self.max_retries, self.retry_interval_ms);
+        let timeout = self.timeout_for(endpoint);
 
         loop {
-            let rpc_api = self.ensure_connected().await?;
+            let rpc_api = self.ensure_connected().await?.with_timeout(timeout);
 
-            match call(rpc_api).await {
+            match with_timeout(call(rpc_api), timeout).await {
                 Ok(response) => return Ok(response),
                 Err(status) if retry_state.should_retry(&status).await => {},
                 Err(status) => return Err(self.rpc_error_from_status(endpoint, status)),
@@ -348,10 +414,11 @@ impl GrpcClient {
     pub async fn get_status_unversioned(&self) -> Result<RpcStatusInfo, RpcError> {
         let mut rpc_api = ApiClient::new_client_without_accept_header(
             self.endpoint.clone(),
-            self.timeout_ms,
             self.bearer_token.clone(),
+            self.tls_config.as_ref(),
         )
-        .await?;
+        .await?
+        .with_timeout(self.timeout_for(RpcEndpoint::Status));
         rpc_api
             .status(())
             .await
@@ -372,6 +439,21 @@ impl NodeRpcClient for GrpcClient {
         *self.genesis_commitment.read()
     }
 
+    fn set_default_timeout(&self, timeout: Duration) {
+        GrpcClient::set_default_timeout(
+            self,
+            u64::try_from(timeout.as_millis()).unwrap_or(u64::MAX),
+        );
+    }
+
+    fn set_operation_timeout(&self, endpoint: RpcEndpoint, timeout: Option<Duration>) {
+        let mut operation_timeouts = self.operation_timeouts.write();
+        match timeout {
+            Some(timeout) => operation_timeouts.insert(endpoint, timeout),
+            None => operation_timeouts.remove(&endpoint),
+        };
+    }
+
     async fn set_genesis_commitment(&self, commitment: Word) -> Result<(), RpcError> {
         // Check if already set before doing anything else
         if self.genesis_commitment.read().is_some() {
@@ -540,7 +622,20 @@ impl NodeRpcClient for GrpcClient {
             })
             .await?;
 
-        response.into_inner().try_into()
+        let mut chain_mmr_info: ChainMmrInfo = response.into_inner().try_into()?;
+
+        // The response doesn't carry the chain tip. A response that adds no blocks leaves the
+        // peaks unchanged and already ends at the tip, so the tip is only requested when new
+        // blocks were served, to tell a partial response from a complete one. The node doesn't
+        // report its proven tip, so responses up to it are taken as complete.
+        if upper_bound == SyncTarget::CommittedChainTip
+            && chain_mmr_info.block_to > current_block_height
+        {
+            let (chain_tip, _) = self.get_block_header_by_number(None, false).await?;
+            chain_mmr_info.chain_tip = chain_mmr_info.chain_tip.max(chain_tip.block_num());
+        }
+
+        Ok(chain_mmr_info)
     }
 
     /// Sends a `GetAccount` request to the Miden node, and extracts the [`AccountProof`]
@@ -1081,6 +1176,7 @@ impl From<&Status> for GrpcError {
 #[cfg(test)]
 mod tests {
     use core::slice;
+    use core::time::Duration;
     use std::boxed::Box;
     use std::collections::BTreeSet;
 
@@ -1091,6 +1187,7 @@ mod tests {
     use super::{
         BlockPagination,
         GrpcClient,
+        GrpcTlsConfig,
         NullifierUpdate,
         PaginationResult,
         ensure_requested_note_ids,
@@ -1098,7 +1195,7 @@ mod tests {
         ensure_requested_tags,
     };
     use crate::alloc::string::ToString;
-    use crate::rpc::{Endpoint, NodeRpcClient, RpcError};
+    use crate::rpc::{Endpoint, NodeRpcClient, RpcEndpoint, RpcError};
 
     fn assert_send_sync<T: Send + Sync>() {}
 
@@ -1108,6 +1205,26 @@ mod tests {
         assert_send_sync::<Box<dyn NodeRpcClient>>();
     }
 
+    #[test]
+    fn operation_timeouts_override_the_default_timeout() {
+        let endpoint = Endpoint::new("https".into(), "node.example".into(), Some(443));
+        let client = GrpcClient::new(&endpoint, 1_000);
+        assert_eq!(client.timeout_for(RpcEndpoint::SyncNotes), Duration::from_secs(1));
+
+        // Operation timeouts aren't capped by the default timeout.
+        client.set_operation_timeout(RpcEndpoint::SyncNotes, Some(Duration::from_secs(30)));
+        assert_eq!(client.timeout_for(RpcEndpoint::SyncNotes), Duration::from_secs(30));
+        assert_eq!(client.timeout_for(RpcEndpoint::GetAccount), Duration::from_secs(1));
+
+        // The default timeout only applies to endpoints without their own timeout.
+        client.set_default_timeout(5_000);
+        assert_eq!(client.timeout_for(RpcEndpoint::SyncNotes), Duration::from_secs(30));
+        assert_eq!(client.timeout_for(RpcEndpoint::GetAccount), Duration::from_secs(5));
+
+        client.set_operation_timeout(RpcEndpoint::SyncNotes, None);
+        assert_eq!(client.timeout_for(RpcEndpoint::SyncNotes), Duration::from_secs(5));
+    }
+
     #[test]
     fn block_pagination_errors_when_block_num_goes_backwards() {
         let mut pagination = BlockPagination::new(10_u32.into(), 20_u32.into());
@@ -1290,6 +1407,59 @@ mod tests {
         assert!(!status.version.is_empty(), "status must include a server version");
     }
 
+    /// Serves the gRPC health service over TLS on a local port, using a certificate issued by the
+    /// test CA in `testdata/ca.pem`, and returns the endpoint of the server.
+    async fn spawn_tls_server() -> Endpoint {
+        use tokio_stream::wrappers::TcpListenerStream;
+        use tonic::transport::{Identity, Server, ServerTlsConfig};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let identity = Identity::from_pem(
+            include_str!("testdata/server.pem"),
+            include_str!("testdata/server.key"),
+        );
+        let (_, health_service) = tonic_health::server::health_reporter();
+        let server = Server::builder()
+            .tls_config(ServerTlsConfig::new().identity(identity))
+            .unwrap()
+            .add_service(health_service)
+            .serve_with_incoming(TcpListenerStream::new(listener));
+        tokio::spawn(server);
+
+        Endpoint::new("https".to_string(), "127.0.0.1".to_string(), Some(port))
+    }
+
+    #[tokio::test]
+    async fn with_tls_config_trusts_custom_ca() {
+        let endpoint = spawn_tls_server().await;
+        let tls_config = GrpcTlsConfig::new()
+            .with_ca_certificate_pem(include_str!("testdata/ca.pem"))
+            .unwrap();
+        let client = GrpcClient::new(&endpoint, 10_000).with_tls_config(tls_config);
+
+        // The handshake succeeds and the request reaches the server, which doesn't implement
+        // the node API.
+        let err = client.get_status_unversioned().await.unwrap_err();
+        assert!(
+            matches!(err, RpcError::RequestError { .. }),
+            "expected RequestError, got {err:?}",
+        );
+    }
+
+    #[tokio::test]
+    async fn tls_handshake_fails_for_untrusted_ca() {
+        let endpoint = spawn_tls_server().await;
+        let client = GrpcClient::new(&endpoint, 10_000);
+
+        let err = client.get_status_unversioned().await.unwrap_err();
+        assert!(
+            matches!(err, RpcError::ConnectionError(_)),
+            "expected ConnectionError, got {err:?}",
+        );
+    }
+
     fn nullifier_with_prefix(prefix: u16) -> Nullifier {
         Nullifier::from_raw(Word::new([
             Felt::ZERO,
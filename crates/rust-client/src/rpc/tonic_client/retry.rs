@@ -84,13 +84,13 @@ fn extract_retry_after(status: &Status) -> Option<Duration> {
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-async fn async_sleep(duration: Duration) {
+pub(super) async fn async_sleep(duration: Duration) {
     tokio::time::sleep(duration).await;
 }
 
 /// On WASM, sleep using browser timers so retry delays are honored.
 #[cfg(target_arch = "wasm32")]
-async fn async_sleep(duration: Duration) {
+pub(super) async fn async_sleep(duration: Duration) {
     gloo_timers::future::sleep(duration).await;
 }
 
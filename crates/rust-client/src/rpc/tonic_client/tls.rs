@@ -0,0 +1,165 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use rustls_pki_types::pem::PemObject;
+use rustls_pki_types::{CertificateDer, PrivateKeyDer};
+
+use crate::rpc::RpcError;
+
+// GRPC TLS CONFIG
+// ================================================================================================
+
+/// TLS settings for connecting to a node whose certificate is issued by a private certificate
+/// authority, or that requires clients to authenticate with a certificate (mTLS).
+///
+/// The platform's native root certificates are always trusted; a custom CA is trusted in addition
+/// to them.
+///
+/// Only native targets use this configuration. On `wasm32`, TLS is negotiated by the browser,
+/// which relies on its own trust store and client certificates, so the configuration is ignored.
+///
+/// # Example
+///
+/// ```no_run
+/// # use miden_client::rpc::{Endpoint, GrpcClient, GrpcTlsConfig, RpcError};
+/// # fn main() -> Result<(), RpcError> {
+/// let ca_pem = std::fs::read("ca.pem").unwrap();
+/// let tls_config = GrpcTlsConfig::new().with_ca_certificate_pem(ca_pem)?;
+///
+/// let endpoint = Endpoint::new("https".into(), "node.internal".into(), Some(443));
+/// let client = GrpcClient::new(&endpoint, 10_000).with_tls_config(tls_config);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GrpcTlsConfig {
+    /// PEM-encoded certificates of additional root CAs to trust.
+    ca_certificate_pem: Option<Vec<u8>>,
+    /// PEM-encoded client certificate chain and private key presented to the node.
+    client_identity_pem: Option<(Vec<u8>, Vec<u8>)>,
+}
+
+impl GrpcTlsConfig {
+    /// Returns a configuration that only trusts the platform's native root certificates.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Trusts the CA certificates in `pem` (one or more `CERTIFICATE` blocks) in addition to the
+    /// native root certificates.
+    ///
+    /// # Errors
+    /// Returns [`RpcError::InvalidTlsConfig`] if `pem` doesn't contain any well-formed
+    /// certificate.
+    pub fn with_ca_certificate_pem(mut self, pem: impl Into<Vec<u8>>) -> Result<Self, RpcError> {
+        let pem = pem.into();
+        validate_certificates_pem(&pem)
+            .map_err(|err| RpcError::InvalidTlsConfig(format!("invalid CA certificate: {err}")))?;
+
+        self.ca_certificate_pem = Some(pem);
+        Ok(self)
+    }
+
+    /// Presents the certificate chain in `certificate_pem`, together with its private key in
+    /// `private_key_pem`, to nodes that require client authentication.
+    ///
+    /// # Errors
+    /// Returns [`RpcError::InvalidTlsConfig`] if either input doesn't contain a well-formed PEM
+    /// certificate or private key.
+    pub fn with_client_identity_pem(
+        mut self,
+        certificate_pem: impl Into<Vec<u8>>,
+        private_key_pem: impl Into<Vec<u8>>,
+    ) -> Result<Self, RpcError> {
+        let certificate_pem = certificate_pem.into();
+        let private_key_pem = private_key_pem.into();
+        validate_certificates_pem(&certificate_pem).map_err(|err| {
+            RpcError::InvalidTlsConfig(format!("invalid client certificate: {err}"))
+        })?;
+        validate_private_key_pem(&private_key_pem).map_err(|err| {
+            RpcError::InvalidTlsConfig(format!("invalid client private key: {err}"))
+        })?;
+
+        self.client_identity_pem = Some((certificate_pem, private_key_pem));
+        Ok(self)
+    }
+
+    /// Builds the `tonic` TLS configuration for this config.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn to_client_tls_config(&self) -> tonic::transport::ClientTlsConfig {
+        use tonic::transport::{Certificate, ClientTlsConfig, Identity};
+
+        let mut tls_config = ClientTlsConfig::new().with_native_roots();
+        if let Some(pem) = &self.ca_certificate_pem {
+            tls_config = tls_config.ca_certificate(Certificate::from_pem(pem));
+        }
+        if let Some((certificate, private_key)) = &self.client_identity_pem {
+            tls_config = tls_config.identity(Identity::from_pem(certificate, private_key));
+        }
+        tls_config
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Checks that `pem` holds at least one certificate and that every certificate in it is
+/// well-formed. Text outside of the PEM blocks, such as the subject lines some tools print before
+/// each certificate, is ignored.
+fn validate_certificates_pem(pem: &[u8]) -> Result<(), String> {
+    let mut certificates = 0;
+    for certificate in CertificateDer::pem_slice_iter(pem) {
+        certificate.map_err(|err| err.to_string())?;
+        certificates += 1;
+    }
+
+    if certificates == 0 {
+        return Err("no PEM certificate found".to_string());
+    }
+    Ok(())
+}
+
+/// Checks that `pem` holds a well-formed private key.
+fn validate_private_key_pem(pem: &[u8]) -> Result<(), String> {
+    PrivateKeyDer::from_pem_slice(pem).map(|_| ()).map_err(|err| err.to_string())
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::GrpcTlsConfig;
+    use crate::rpc::RpcError;
+
+    const CA_PEM: &str = include_str!("testdata/ca.pem");
+    const SERVER_PEM: &str = include_str!("testdata/server.pem");
+    const SERVER_KEY_PEM: &str = include_str!("testdata/server.key");
+
+    #[test]
+    fn valid_pem_is_accepted() {
+        GrpcTlsConfig::new().with_ca_certificate_pem(CA_PEM).unwrap();
+        GrpcTlsConfig::new()
+            .with_client_identity_pem(SERVER_PEM, SERVER_KEY_PEM)
+            .unwrap();
+    }
+
+    #[test]
+    fn invalid_ca_certificate_is_rejected() {
+        let truncated = &CA_PEM[..CA_PEM.len() / 2];
+        for invalid in ["", "not a certificate", truncated, SERVER_KEY_PEM] {
+            let err = GrpcTlsConfig::new().with_ca_certificate_pem(invalid).unwrap_err();
+            assert!(
+                matches!(&err, RpcError::InvalidTlsConfig(msg) if msg.starts_with("invalid CA certificate")),
+                "unexpected error for {invalid:?}: {err}"
+            );
+        }
+    }
+
+    #[test]
+    fn text_between_pem_blocks_is_ignored() {
+        let bundle = format!("subject=CN=Root CA\n{CA_PEM}\nsubject=CN=Server\n{SERVER_PEM}");
+        GrpcTlsConfig::new().with_ca_certificate_pem(bundle).unwrap();
+    }
+}
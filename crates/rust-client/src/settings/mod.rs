@@ -1,13 +1,60 @@
 //! The `settings` module provides methods for managing arbitrary setting values that are persisted
 //! in the client's store.
 
+use alloc::collections::BTreeSet;
+use alloc::format;
 use alloc::string::String;
 use alloc::vec::Vec;
 
+use miden_protocol::Word;
+use miden_protocol::account::{AccountId, StorageSlotName};
+use miden_protocol::asset::{Asset, FungibleAsset};
 use miden_tx::utils::serde::{Deserializable, Serializable};
 
 use super::Client;
+use crate::account::FaucetMetadata;
 use crate::errors::ClientError;
+use crate::store::NoteFilter;
+use crate::utils::base_units_to_tokens;
+
+/// Settings key prefix under which faucet display metadata is persisted.
+pub const FAUCET_METADATA_SETTING_PREFIX: &str = "faucet_metadata:";
+
+/// Returns the settings key under which the [`FaucetMetadata`] for `faucet_id` is persisted.
+pub fn faucet_metadata_setting_key(faucet_id: AccountId) -> String {
+    format!("{FAUCET_METADATA_SETTING_PREFIX}{}", faucet_id.to_hex())
+}
+
+/// Settings key prefix under which the MASM source of account code is persisted.
+pub const ACCOUNT_CODE_SOURCE_SETTING_PREFIX: &str = "account_code_source:";
+
+/// Returns the settings key under which the MASM source for the account code with
+/// `code_commitment` is persisted.
+pub fn account_code_source_setting_key(code_commitment: Word) -> String {
+    format!("{ACCOUNT_CODE_SOURCE_SETTING_PREFIX}{}", code_commitment.to_hex())
+}
+
+/// Settings key prefix under which the vault sync cursor of public accounts is persisted.
+pub const ACCOUNT_VAULT_SYNC_CURSOR_SETTING_PREFIX: &str = "account_vault_sync_cursor:";
+
+/// Returns the settings key under which the block up to which the vault of `account_id` was
+/// synced by [`Client::sync_account_vault_incremental`] is persisted.
+pub fn account_vault_sync_cursor_setting_key(account_id: AccountId) -> String {
+    format!("{ACCOUNT_VAULT_SYNC_CURSOR_SETTING_PREFIX}{}", account_id.to_hex())
+}
+
+/// Settings key prefix under which the sync cursor of storage maps of public accounts is
+/// persisted.
+pub const STORAGE_MAP_SYNC_CURSOR_SETTING_PREFIX: &str = "storage_map_sync_cursor:";
+
+/// Returns the settings key under which the block up to which the storage map `slot_name` of
+/// `account_id` was synced by [`Client::sync_storage_map_incremental`] is persisted.
+pub fn storage_map_sync_cursor_setting_key(
+    account_id: AccountId,
+    slot_name: &StorageSlotName,
+) -> String {
+    format!("{STORAGE_MAP_SYNC_CURSOR_SETTING_PREFIX}{}:{slot_name}", account_id.to_hex())
+}
 
 // CLIENT METHODS
 // ================================================================================================
@@ -17,6 +64,10 @@ use crate::errors::ClientError;
 /// - **Settings accessors:** Methods to get, set, and delete setting values from the store.
 /// - **Default account ID:** Methods to get, set, and delete the default account ID. This is a
 ///   wrapper around a specific setting value.
+/// - **Faucet metadata:** Methods to register and look up the display symbol and decimals of
+///   faucets, persisted as setting values.
+/// - **Account code sources:** Methods to register and look up the MASM source of account code,
+///   persisted as setting values.
 impl<AUTH> Client<AUTH> {
     // SETTINGS ACCESSORS
     // --------------------------------------------------------------------------------------------
@@ -52,4 +103,145 @@ impl<AUTH> Client<AUTH> {
     pub async fn list_setting_keys(&self) -> Result<Vec<String>, ClientError> {
         self.store.list_setting_keys().await.map_err(Into::into)
     }
+
+    /// Returns the setting keys from the store that start with `prefix`.
+    pub async fn list_setting_keys_with_prefix(
+        &self,
+        prefix: String,
+    ) -> Result<Vec<String>, ClientError> {
+        self.store.list_setting_keys_with_prefix(prefix).await.map_err(Into::into)
+    }
+
+    // FAUCET METADATA
+    // --------------------------------------------------------------------------------------------
+
+    /// Persists the display `symbol` and `decimals` of a fungible faucet, so amounts of its
+    /// asset can be formatted with [`Client::format_asset_amount`] and the faucet can be found by
+    /// symbol with [`Client::resolve_faucet`]. Registering a faucet again overwrites its metadata.
+    pub async fn register_faucet_metadata(
+        &mut self,
+        faucet_id: AccountId,
+        symbol: String,
+        decimals: u8,
+    ) -> Result<(), ClientError> {
+        self.set_setting(
+            faucet_metadata_setting_key(faucet_id),
+            FaucetMetadata { symbol, decimals },
+        )
+        .await
+    }
+
+    /// Returns the registered [`FaucetMetadata`] for `faucet_id`, or `None` if it hasn't been
+    /// registered.
+    pub async fn get_faucet_metadata(
+        &self,
+        faucet_id: AccountId,
+    ) -> Result<Option<FaucetMetadata>, ClientError> {
+        self.get_setting(faucet_metadata_setting_key(faucet_id)).await
+    }
+
+    /// Returns the ID of the faucet registered with the given `symbol`, or `None` if no faucet
+    /// uses it. If several faucets were registered with the same symbol, the one with the lowest
+    /// ID is returned.
+    pub async fn resolve_faucet(&self, symbol: &str) -> Result<Option<AccountId>, ClientError> {
+        let settings = self
+            .store
+            .get_settings_with_prefix(FAUCET_METADATA_SETTING_PREFIX.into())
+            .await?;
+
+        let mut resolved = None;
+        for (key, value) in settings {
+            let Some(faucet_id) = key
+                .strip_prefix(FAUCET_METADATA_SETTING_PREFIX)
+                .and_then(|faucet_id| AccountId::from_hex(faucet_id).ok())
+            else {
+                continue;
+            };
+
+            if FaucetMetadata::read_from_bytes(&value)?.symbol == symbol
+                && resolved.is_none_or(|resolved| faucet_id < resolved)
+            {
+                resolved = Some(faucet_id);
+            }
+        }
+
+        Ok(resolved)
+    }
+
+    /// Returns the IDs of the faucets whose assets appear in the vaults of the tracked accounts or
+    /// in the tracked input and output notes.
+    ///
+    /// Their display metadata, if registered, can be looked up with
+    /// [`Client::get_faucet_metadata`].
+    pub async fn known_faucets(&self) -> Result<BTreeSet<AccountId>, ClientError> {
+        let mut faucet_ids = BTreeSet::new();
+
+        for account_id in self.store.get_account_ids().await? {
+            let vault = self.store.get_account_vault(account_id).await?;
+            faucet_ids.extend(vault.assets().map(|asset| asset.faucet_id()));
+        }
+
+        for note in self.store.get_input_notes(NoteFilter::All).await? {
+            faucet_ids.extend(note.assets().iter().map(Asset::faucet_id));
+        }
+
+        for note in self.store.get_output_notes(NoteFilter::All).await? {
+            faucet_ids.extend(note.assets().iter().map(Asset::faucet_id));
+        }
+
+        Ok(faucet_ids)
+    }
+
+    /// Formats the amount of a fungible asset for display, e.g. `1.5 TKN`, using the registered
+    /// metadata of its faucet. If no metadata is registered, the amount is shown in base units
+    /// followed by the faucet ID (`<AMOUNT>::<FAUCET_ID>`).
+    pub async fn format_asset_amount(&self, asset: &FungibleAsset) -> Result<String, ClientError> {
+        let amount = asset.amount().as_u64();
+        Ok(match self.get_faucet_metadata(asset.faucet_id()).await? {
+            Some(metadata) => {
+                format!("{} {}", base_units_to_tokens(amount, metadata.decimals), metadata.symbol)
+            },
+            None => format!("{amount}::{}", asset.faucet_id().to_hex()),
+        })
+    }
+
+    // ACCOUNT CODE SOURCES
+    // --------------------------------------------------------------------------------------------
+
+    /// Persists the MASM `source` the account code with `code_commitment` was compiled from, so
+    /// it can be retrieved with [`Client::get_account_code_source`] after a restart.
+    ///
+    /// Sources of account components compiled with [`Client::code_builder`] are registered
+    /// automatically when an account built from them is added, so this is only needed for code
+    /// compiled elsewhere. Registering a source again overwrites it.
+    pub async fn register_account_code_source(
+        &mut self,
+        code_commitment: Word,
+        source: String,
+    ) -> Result<(), ClientError> {
+        self.set_setting(account_code_source_setting_key(code_commitment), source).await
+    }
+
+    /// Returns the MASM source registered for the code of the account with `account_id`, or
+    /// `None` if the account isn't tracked or no source was registered for its code.
+    ///
+    /// Sources are keyed by code commitment, so accounts sharing the same code share the source.
+    pub async fn get_account_code_source(
+        &self,
+        account_id: AccountId,
+    ) -> Result<Option<String>, ClientError> {
+        let Some(code) = self.store.get_account_code(account_id).await? else {
+            return Ok(None);
+        };
+
+        self.get_account_code_source_by_commitment(code.commitment()).await
+    }
+
+    /// Returns the MASM source registered for the account code with `code_commitment`.
+    pub(crate) async fn get_account_code_source_by_commitment(
+        &self,
+        code_commitment: Word,
+    ) -> Result<Option<String>, ClientError> {
+        self.get_setting(account_code_source_setting_key(code_commitment)).await
+    }
 }
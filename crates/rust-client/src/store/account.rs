@@ -64,6 +64,8 @@ pub struct AccountRecord {
     status: AccountStatus,
     /// How the client tracks this account.
     client_account_type: ClientAccountType,
+    /// Whether the account can't be used as a transaction sender.
+    watch_only: bool,
 }
 
 impl AccountRecord {
@@ -86,9 +88,17 @@ impl AccountRecord {
             account_data,
             status,
             client_account_type,
+            watch_only: false,
         }
     }
 
+    /// Sets whether the account is watch-only. See [`AccountRecord::is_watch_only`].
+    #[must_use]
+    pub fn with_watch_only(mut self, watch_only: bool) -> Self {
+        self.watch_only = watch_only;
+        self
+    }
+
     pub fn is_locked(&self) -> bool {
         self.status.is_locked()
     }
@@ -101,6 +111,12 @@ impl AccountRecord {
         self.client_account_type == ClientAccountType::Watched
     }
 
+    /// Returns whether the account was marked as watch-only. Watch-only accounts are still
+    /// tracked and synced, but can't be used as the sender of a transaction.
+    pub fn is_watch_only(&self) -> bool {
+        self.watch_only
+    }
+
     pub fn nonce(&self) -> Felt {
         self.account_data.nonce()
     }
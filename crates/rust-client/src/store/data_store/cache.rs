@@ -2,7 +2,7 @@ use alloc::collections::BTreeMap;
 use alloc::sync::Arc;
 
 use miden_protocol::Word;
-use miden_protocol::account::{AccountId, StorageMapKey, StorageMapWitness};
+use miden_protocol::account::{Account, AccountId, StorageMapKey, StorageMapWitness};
 use miden_protocol::block::BlockNumber;
 use miden_protocol::note::NoteScript;
 use miden_protocol::transaction::AccountInputs;
@@ -18,8 +18,9 @@ use crate::utils::RwLock;
 ///
 /// This bundles everything that exists only for the duration of an execution session: data
 /// registered up front for the in-flight transaction request (account code, foreign account
-/// inputs, output note scripts) plus data cached lazily while the transaction executes (RPC-fetched
-/// foreign accounts and storage map witnesses, the reference block).
+/// inputs, output note scripts, an overriding native account state) plus data cached lazily while
+/// the transaction executes (RPC-fetched foreign accounts and storage map witnesses, the reference
+/// block).
 pub(super) struct DataStoreCache {
     /// Store used to provide MAST nodes to the transaction executor.
     pub(super) mast_store: Arc<TransactionMastStore>,
@@ -34,6 +35,8 @@ pub(super) struct DataStoreCache {
     storage_map_witnesses: RwLock<BTreeMap<(Word, StorageMapKey), StorageMapWitness>>,
     /// The transaction reference block number.
     ref_block: RwLock<Option<BlockNumber>>,
+    /// State of the native account to execute against instead of the one in the store, if any.
+    native_account: RwLock<Option<Account>>,
 }
 
 impl DataStoreCache {
@@ -44,6 +47,7 @@ impl DataStoreCache {
             note_scripts: RwLock::new(BTreeMap::new()),
             storage_map_witnesses: RwLock::new(BTreeMap::new()),
             ref_block: RwLock::new(None),
+            native_account: RwLock::new(None),
         }
     }
 
@@ -123,4 +127,23 @@ impl DataStoreCache {
     pub(super) fn set_ref_block(&self, block_num: BlockNumber) {
         *self.ref_block.write() = Some(block_num);
     }
+
+    /// Sets the state of the native account that overrides the one in the store.
+    pub(super) fn set_native_account(&self, account: Account) {
+        self.native_account.write().replace(account);
+    }
+
+    /// Runs `f` against the overriding native account state if it belongs to the given account,
+    /// without cloning it.
+    pub(super) fn with_native_account<R>(
+        &self,
+        account_id: AccountId,
+        f: impl FnOnce(&Account) -> R,
+    ) -> Option<R> {
+        self.native_account
+            .read()
+            .as_ref()
+            .filter(|account| account.id() == account_id)
+            .map(f)
+    }
 }
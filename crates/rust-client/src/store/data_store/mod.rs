@@ -72,6 +72,12 @@ impl ClientDataStore {
         self.cache.replace_foreign_account_inputs(foreign_accounts);
     }
 
+    /// Serves the provided state of the native account to the executor instead of the one in the
+    /// store, so that programs can be executed against a hypothetical account state.
+    pub fn register_native_account(&self, account: Account) {
+        self.cache.set_native_account(account);
+    }
+
     /// Registers note scripts so they can be served to the executor upon request.
     ///
     /// Scripts accumulate across calls (they are not cleared) so that a data store reused for
@@ -81,6 +87,38 @@ impl ClientDataStore {
         self.cache.insert_note_scripts(note_scripts);
     }
 
+    /// Loads the partial state of the given account from the store.
+    async fn get_stored_partial_account(
+        &self,
+        account_id: AccountId,
+    ) -> Result<PartialAccount, DataStoreError> {
+        let partial_account_record = self
+            .store
+            .get_minimal_partial_account(account_id)
+            .await?
+            .ok_or(DataStoreError::AccountNotFound(account_id))?;
+
+        // New accounts (nonce == 0) need full storage maps as advice inputs for the
+        // kernel to validate during account creation. For these, fetch the full account
+        // and convert to PartialAccount (which includes full storage for new accounts).
+        // Existing accounts use the minimal partial record directly.
+        if partial_account_record.nonce() == ZERO {
+            let full_record = self
+                .store
+                .get_account(account_id)
+                .await?
+                .ok_or(DataStoreError::AccountNotFound(account_id))?;
+            let account: Account = full_record
+                .try_into()
+                .map_err(|_| DataStoreError::AccountNotFound(account_id))?;
+            Ok(PartialAccount::from(&account))
+        } else {
+            partial_account_record
+                .try_into()
+                .map_err(|_| DataStoreError::AccountNotFound(account_id))
+        }
+    }
+
     /// Attempts to resolve a storage map witness from the local store.
     ///
     /// This covers any account present in the store (local or foreign) as well as any
@@ -216,30 +254,13 @@ impl DataStore for ClientDataStore {
         // Cache the reference block so lazy-loading methods can use it
         self.cache.set_ref_block(ref_block);
 
-        let partial_account_record = self
-            .store
-            .get_minimal_partial_account(account_id)
-            .await?
-            .ok_or(DataStoreError::AccountNotFound(account_id))?;
-
-        // New accounts (nonce == 0) need full storage maps as advice inputs for the
-        // kernel to validate during account creation. For these, fetch the full account
-        // and convert to PartialAccount (which includes full storage for new accounts).
-        // Existing accounts use the minimal partial record directly.
-        let partial_account: PartialAccount = if partial_account_record.nonce() == ZERO {
-            let full_record = self
-                .store
-                .get_account(account_id)
-                .await?
-                .ok_or(DataStoreError::AccountNotFound(account_id))?;
-            let account: Account = full_record
-                .try_into()
-                .map_err(|_| DataStoreError::AccountNotFound(account_id))?;
-            PartialAccount::from(&account)
+        // Overriding native account states are served in full, as they aren't in the store.
+        let partial_account = if let Some(partial_account) =
+            self.cache.with_native_account(account_id, |account| account.into())
+        {
+            partial_account
         } else {
-            partial_account_record
-                .try_into()
-                .map_err(|_| DataStoreError::AccountNotFound(account_id))?
+            self.get_stored_partial_account(account_id).await?
         };
 
         // Get header data
@@ -279,6 +300,12 @@ impl DataStore for ClientDataStore {
         vault_root: Word,
         vault_keys: BTreeSet<AssetVaultKey>,
     ) -> Result<Vec<AssetWitness>, DataStoreError> {
+        if let Some(asset_witnesses) = self.cache.with_native_account(account_id, |account| {
+            open_vault_witnesses(account, vault_root, vault_keys.clone())
+        }) {
+            return asset_witnesses;
+        }
+
         let mut asset_witnesses = vec![];
         for vault_key in vault_keys {
             match self.store.get_account_asset(account_id, vault_key).await {
@@ -325,6 +352,13 @@ impl DataStore for ClientDataStore {
             return Ok(witness);
         }
 
+        // Check the overriding native account state, which isn't in the store.
+        if let Some(witness) = self.cache.with_native_account(account_id, |account| {
+            open_storage_map_witness(account, map_root, map_key)
+        }) {
+            return witness;
+        }
+
         // Try the local store.
         if let Some(witness) =
             self.get_local_storage_map_witness(account_id, map_root, map_key).await?
@@ -477,6 +511,48 @@ fn resolve_witness_from_inputs(
     Ok(WitnessResolution::FetchParams(slot_name, inputs.code().clone()))
 }
 
+/// Opens the witnesses of the given vault keys in the vault of an in-memory account.
+fn open_vault_witnesses(
+    account: &Account,
+    vault_root: Word,
+    vault_keys: BTreeSet<AssetVaultKey>,
+) -> Result<Vec<AssetWitness>, DataStoreError> {
+    if account.vault().root() != vault_root {
+        return Err(DataStoreError::other("Vault root mismatch"));
+    }
+
+    vault_keys
+        .into_iter()
+        .map(|vault_key| {
+            AssetWitness::new(account.vault().open(vault_key).into()).map_err(|err| {
+                DataStoreError::other_with_source("Failed to open vault asset tree", err)
+            })
+        })
+        .collect()
+}
+
+/// Opens the witness of a storage map entry of an in-memory account.
+fn open_storage_map_witness(
+    account: &Account,
+    map_root: Word,
+    map_key: StorageMapKey,
+) -> Result<StorageMapWitness, DataStoreError> {
+    account
+        .storage()
+        .slots()
+        .iter()
+        .find_map(|slot| match slot.content() {
+            StorageSlotContent::Map(map) if map.root() == map_root => Some(map.open(&map_key)),
+            _ => None,
+        })
+        .ok_or_else(|| {
+            DataStoreError::other(format!(
+                "did not find map slot with root {map_root} for account {}",
+                account.id()
+            ))
+        })
+}
+
 /// Builds a [`PartialMmr`] from the given peaks and a list of blocks that should be
 /// authenticated against them.
 ///
@@ -17,6 +17,8 @@ use miden_protocol::errors::{
     StorageMapError,
     TransactionScriptError,
 };
+use miden_protocol::note::NoteId;
+use miden_protocol::transaction::TransactionId;
 use miden_protocol::utils::HexParseError;
 use miden_protocol::utils::serde::DeserializationError;
 use miden_protocol::{Word, WordError};
@@ -74,20 +76,30 @@ pub enum StoreError {
     MmrError(#[from] MmrError),
     #[error("failed to create note inclusion proof")]
     NoteInclusionProofError(#[from] NoteError),
+    #[error("note {0} not found")]
+    NoteNotFound(NoteId),
     #[error("note tag {0} is already being tracked")]
     NoteTagAlreadyTracked(u64),
+    #[error("note tag {0} is not being tracked")]
+    NoteTagNotTracked(u64),
     #[error("note script with root {0} not found")]
     NoteScriptNotFound(String),
     #[error("failed to parse data retrieved from the database: {0}")]
     ParsingError(String),
     #[error("failed to retrieve data from the database: {0}")]
     QueryError(String),
+    #[error("the store was opened in read-only mode")]
+    ReadOnly,
+    #[error("block {0} is not a recorded sync point that can be rolled back to")]
+    RollbackTargetNotFound(BlockNumber),
     #[error("sparse merkle tree proof error")]
     SmtProofError(#[from] SmtProofError),
     #[error("account storage map error")]
     StorageMapError(#[from] StorageMapError),
     #[error("failed to instantiate transaction script")]
     TransactionScriptError(#[from] TransactionScriptError),
+    #[error("transaction {0} not found")]
+    TransactionNotFound(TransactionId),
     #[error("account vault data for root {0} not found")]
     VaultDataNotFound(Word),
     #[error("failed to parse word")]
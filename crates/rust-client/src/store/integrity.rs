@@ -0,0 +1,85 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use miden_protocol::Word;
+use miden_protocol::account::AccountId;
+use miden_protocol::block::BlockNumber;
+use miden_protocol::note::NoteId;
+
+// INTEGRITY REPORT
+// ================================================================================================
+
+/// Result of a [`Store::check_integrity`](super::Store::check_integrity) run.
+///
+/// An empty report means no issues were found.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntegrityReport {
+    issues: Vec<IntegrityIssue>,
+}
+
+impl IntegrityReport {
+    /// Returns a report listing the provided issues. Store implementations can also start from
+    /// [`IntegrityReport::default`] and add issues as they find them.
+    pub fn new(issues: Vec<IntegrityIssue>) -> Self {
+        Self { issues }
+    }
+
+    /// Returns `true` if no issues were found.
+    pub fn is_healthy(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Returns the issues found in the store.
+    pub fn issues(&self) -> &[IntegrityIssue] {
+        &self.issues
+    }
+
+    /// Adds an issue to the report.
+    pub fn push(&mut self, issue: IntegrityIssue) {
+        self.issues.push(issue);
+    }
+
+    /// Adds the issues in `issues` to the report.
+    pub fn extend(&mut self, issues: impl IntoIterator<Item = IntegrityIssue>) {
+        self.issues.extend(issues);
+    }
+}
+
+/// A single inconsistency found while checking the integrity of a store.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IntegrityIssue {
+    /// The underlying database reported a problem with its own structure (e.g. a corrupted page
+    /// or index).
+    Database(String),
+    /// The account's state couldn't be loaded from the store.
+    AccountUnreadable { account_id: AccountId, reason: String },
+    /// The commitment stored for the account doesn't match the one computed from its stored code,
+    /// storage and vault.
+    AccountCommitmentMismatch {
+        account_id: AccountId,
+        stored: Word,
+        computed: Word,
+    },
+    /// The note is committed in a block whose header isn't stored.
+    MissingNoteBlockHeader { note_id: NoteId, block_num: BlockNumber },
+}
+
+impl fmt::Display for IntegrityIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntegrityIssue::Database(message) => write!(f, "database check failed: {message}"),
+            IntegrityIssue::AccountUnreadable { account_id, reason } => {
+                write!(f, "account {account_id} could not be loaded: {reason}")
+            },
+            IntegrityIssue::AccountCommitmentMismatch { account_id, stored, computed } => write!(
+                f,
+                "account {account_id} has stored commitment {stored}, but its state commits to {computed}"
+            ),
+            IntegrityIssue::MissingNoteBlockHeader { note_id, block_num } => write!(
+                f,
+                "note {note_id} is committed in block {block_num}, whose header is not stored"
+            ),
+        }
+    }
+}
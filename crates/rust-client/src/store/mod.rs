@@ -23,6 +23,7 @@
 use alloc::boxed::Box;
 use alloc::collections::{BTreeMap, BTreeSet};
 use alloc::string::{String, ToString};
+use alloc::vec;
 use alloc::vec::Vec;
 use core::fmt::Debug;
 
@@ -38,18 +39,23 @@ use miden_protocol::account::{
     StorageSlotContent,
     StorageSlotName,
 };
-use miden_protocol::address::Address;
+use miden_protocol::address::{Address, NetworkId};
 use miden_protocol::asset::{Asset, AssetVault, AssetVaultKey, AssetWitness};
 use miden_protocol::block::{BlockHeader, BlockNumber};
 use miden_protocol::crypto::merkle::mmr::{Forest, InOrderIndex, MmrPeaks, PartialMmr};
 use miden_protocol::errors::AccountError;
 use miden_protocol::note::{NoteDetailsCommitment, NoteId, NoteScript, NoteTag, Nullifier};
-use miden_protocol::transaction::TransactionId;
+use miden_protocol::transaction::{
+    ProvenTransaction,
+    RawOutputNote,
+    TransactionId,
+    TransactionInputs,
+};
 use miden_protocol::{Felt, Word};
-use miden_tx::utils::serde::{Deserializable, Serializable};
+use miden_tx::utils::serde::{Deserializable, DeserializationError, Serializable};
 
 use crate::note_transport::{NOTE_TRANSPORT_CURSOR_STORE_SETTING, NoteTransportCursor};
-use crate::rpc::{RPC_LIMITS_STORE_SETTING, RpcLimits};
+use crate::rpc::{NETWORK_ID_STORE_SETTING, RPC_LIMITS_STORE_SETTING, RpcLimits};
 use crate::sync::{NoteTagRecord, StateSyncUpdate};
 use crate::transaction::{TransactionRecord, TransactionStatusVariant, TransactionStoreUpdate};
 
@@ -67,6 +73,9 @@ pub use errors::*;
 mod smt_forest;
 pub use smt_forest::AccountSmtForest;
 
+mod integrity;
+pub use integrity::{IntegrityIssue, IntegrityReport};
+
 mod account;
 pub use account::{
     AccountRecord,
@@ -79,10 +88,15 @@ pub use account::{
 pub use crate::sync::PublicAccountUpdate;
 mod note_record;
 pub use note_record::{
+    INPUT_NOTE_RECORD_DTO_VERSION,
     InputNoteRecord,
+    InputNoteRecordDto,
     InputNoteState,
+    NoteAssetDto,
     NoteExportType,
+    NoteMetadataDto,
     NoteRecordError,
+    NoteStateTransition,
     OutputNoteRecord,
     OutputNoteState,
     input_note_states,
@@ -166,6 +180,59 @@ pub trait Store: Send + Sync {
         tx_updates: Vec<TransactionStoreUpdate>,
     ) -> Result<(), StoreError>;
 
+    /// Persists a transaction record that failed to execute locally. The record's status is
+    /// expected to be [`TransactionStatus::Failed`](crate::transaction::TransactionStatus::Failed);
+    /// no account or note state is modified.
+    async fn insert_failed_transaction(
+        &self,
+        transaction_record: TransactionRecord,
+    ) -> Result<(), StoreError>;
+
+    /// Sets the local-only category of the transaction with the provided ID. Passing `None`
+    /// clears the category.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StoreError::TransactionNotFound`] if the transaction isn't tracked.
+    async fn set_transaction_category(
+        &self,
+        transaction_id: TransactionId,
+        category: Option<String>,
+    ) -> Result<(), StoreError>;
+
+    /// Retrieves the local-only category of the transaction with the provided ID, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StoreError::TransactionNotFound`] if the transaction isn't tracked.
+    async fn get_transaction_category(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<Option<String>, StoreError>;
+
+    /// Persists a proven transaction along with the inputs it was executed with, so they can be
+    /// retrieved later with [`Store::get_proven_transaction`] and
+    /// [`Store::get_proven_transaction_inputs`]. Storing a proof for an already stored
+    /// transaction replaces it.
+    async fn insert_proven_transaction(
+        &self,
+        proven_transaction: ProvenTransaction,
+        transaction_inputs: TransactionInputs,
+    ) -> Result<(), StoreError>;
+
+    /// Retrieves the proven transaction with the provided ID, if it was stored.
+    async fn get_proven_transaction(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<Option<ProvenTransaction>, StoreError>;
+
+    /// Retrieves the inputs of the proven transaction with the provided ID, if its proof was
+    /// stored.
+    async fn get_proven_transaction_inputs(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<Option<TransactionInputs>, StoreError>;
+
     // NOTES
     // --------------------------------------------------------------------------------------------
 
@@ -176,12 +243,39 @@ pub trait Store: Send + Sync {
     async fn get_input_notes(&self, filter: NoteFilter)
     -> Result<Vec<InputNoteRecord>, StoreError>;
 
+    /// Retrieves the states the input note with the provided details commitment went through,
+    /// oldest first.
+    ///
+    /// A transition is recorded whenever a note is stored with a state variant different from its
+    /// previous one, whether through an import, a transaction or a state sync. Notes are
+    /// identified by their details commitment so the history of notes without metadata (such as
+    /// expected private notes) can be retrieved too. Implementations may cap how many transitions
+    /// are kept per note, dropping the oldest ones.
+    async fn get_note_state_history(
+        &self,
+        details_commitment: NoteDetailsCommitment,
+    ) -> Result<Vec<NoteStateTransition>, StoreError>;
+
     /// Retrieves the output notes from the store.
     async fn get_output_notes(
         &self,
         filter: NoteFilter,
     ) -> Result<Vec<OutputNoteRecord>, StoreError>;
 
+    /// Retrieves the input notes that were inserted or updated after the change sequence value
+    /// `seq`, ordered by the time of their last change.
+    ///
+    /// Every write to an input note assigns it the next value of a store-wide, monotonically
+    /// increasing sequence. Alongside the changed notes, the method returns the current value of
+    /// that sequence; passing it back on the next call yields only the notes changed in between.
+    /// Use `0` to get every note.
+    ///
+    /// Deleted notes (see [`Store::prune_consumed_notes`]) are not reported.
+    async fn get_notes_modified_since(
+        &self,
+        seq: u64,
+    ) -> Result<(Vec<InputNoteRecord>, u64), StoreError>;
+
     /// Retrieves a single input note at the given offset from the filtered set for the given
     /// consumer account. Optionally restricts to a block range via `block_start` and
     /// `block_end`. Returns `None` when the offset is past the end of the matching notes.
@@ -198,6 +292,21 @@ pub trait Store: Send + Sync {
         offset: u32,
     ) -> Result<Option<InputNoteRecord>, StoreError>;
 
+    /// Retrieves the output note with the provided recipient digest, or `None` if the store
+    /// doesn't track an output note for that recipient.
+    ///
+    /// Several output notes may share a recipient (e.g. notes with different assets sent to the
+    /// same recipient), in which case only one of them is returned. Use
+    /// [`NoteFilter::Recipient`] with [`Store::get_output_notes`] to get all of them.
+    ///
+    /// The default implementation of this method uses [`Store::get_output_notes`].
+    async fn get_output_note_by_recipient(
+        &self,
+        recipient_digest: Word,
+    ) -> Result<Option<OutputNoteRecord>, StoreError> {
+        Ok(self.get_output_notes(NoteFilter::Recipient(recipient_digest)).await?.pop())
+    }
+
     /// Returns the nullifiers of all unspent input notes.
     ///
     /// The default implementation of this method uses [`Store::get_input_notes`].
@@ -210,10 +319,94 @@ pub trait Store: Send + Sync {
             .collect())
     }
 
+    /// Returns the output notes that haven't been observed as consumed yet. These are notes the
+    /// client created that are still waiting to be consumed by their recipient.
+    ///
+    /// The default implementation of this method uses [`Store::get_output_notes`].
+    async fn get_unconsumed_output_notes(&self) -> Result<Vec<OutputNoteRecord>, StoreError> {
+        self.get_output_notes(NoteFilter::Unspent).await
+    }
+
+    /// Returns the input notes consumed by the transaction with the provided ID, along with the
+    /// output notes it created.
+    ///
+    /// Notes are matched against the input note nullifiers and output notes recorded in the
+    /// transaction's details, so notes the store no longer tracks (e.g. pruned ones) are left out.
+    ///
+    /// The default implementation of this method uses [`Store::get_transactions`],
+    /// [`Store::get_input_notes`] and [`Store::get_output_notes`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError::TransactionNotFound`] if the transaction isn't tracked.
+    async fn get_transaction_notes(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<(Vec<InputNoteRecord>, Vec<OutputNoteRecord>), StoreError> {
+        let transaction = self
+            .get_transactions(TransactionFilter::Ids(vec![transaction_id]))
+            .await?
+            .pop()
+            .ok_or(StoreError::TransactionNotFound(transaction_id))?;
+
+        let nullifiers: Vec<Nullifier> = transaction
+            .details
+            .input_note_nullifiers
+            .iter()
+            .copied()
+            .map(Nullifier::from_raw)
+            .collect();
+        let input_notes = if nullifiers.is_empty() {
+            Vec::new()
+        } else {
+            self.get_input_notes(NoteFilter::Nullifiers(nullifiers)).await?
+        };
+
+        let note_ids: Vec<NoteId> =
+            transaction.details.output_notes.iter().map(RawOutputNote::id).collect();
+        let output_notes = if note_ids.is_empty() {
+            Vec::new()
+        } else {
+            self.get_output_notes(NoteFilter::List(note_ids)).await?
+        };
+
+        Ok((input_notes, output_notes))
+    }
+
+    /// Deletes the input notes in a consumed state whose consumption block is lower than
+    /// `before`.
+    ///
+    /// Pinned notes and notes whose nullifiers are referenced by an uncommitted transaction are
+    /// kept. Note scripts are kept as well, since the ones stored through
+    /// [`Store::upsert_note_scripts`] are shared with other notes.
+    ///
+    /// Returns the number of input notes removed.
+    async fn prune_consumed_notes(&self, before: BlockNumber) -> Result<usize, StoreError>;
+
+    /// Pins or unpins the input and output notes with the provided ID. Pinned notes are never
+    /// removed by [`Self::prune_consumed_notes`], and neither are their scripts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError::NoteNotFound`] if no input or output note has the provided ID.
+    async fn set_note_pinned(&self, note_id: NoteId, pinned: bool) -> Result<(), StoreError>;
+
     /// Inserts the provided input notes into the database. If a note with the same ID already
     /// exists, it will be replaced.
     async fn upsert_input_notes(&self, notes: &[InputNoteRecord]) -> Result<(), StoreError>;
 
+    /// Inserts the provided input notes into the database, resolving conflicts with notes that
+    /// are already stored according to `strategy`. Returns the number of notes written.
+    ///
+    /// With [`NoteConflictStrategy::KeepNewerState`], notes whose stored state is further along
+    /// the note lifecycle (see [`InputNoteState::precedes`]) are left untouched, so out-of-order
+    /// updates can't regress a note, e.g. from `Committed` back to `Expected`.
+    async fn bulk_upsert_input_notes(
+        &self,
+        notes: &[InputNoteRecord],
+        strategy: NoteConflictStrategy,
+    ) -> Result<usize, StoreError>;
+
     /// Returns the note script associated with the given root.
     async fn get_note_script(&self, script_root: Word) -> Result<NoteScript, StoreError>;
 
@@ -250,6 +443,20 @@ pub trait Store: Send + Sync {
             .map(|mut block_headers_list| block_headers_list.pop())
     }
 
+    /// Retrieves whether the block with the provided number contains notes relevant to the
+    /// client, without returning its header. Returns `None` if the block is not found.
+    ///
+    /// The default implementation of this method uses [`Store::get_block_header_by_num`].
+    async fn get_block_relevance(
+        &self,
+        block_number: BlockNumber,
+    ) -> Result<Option<BlockRelevance>, StoreError> {
+        Ok(self
+            .get_block_header_by_num(block_number)
+            .await?
+            .map(|(_, relevance)| relevance))
+    }
+
     /// Retrieves a list of [`BlockHeader`] that include relevant notes to the client.
     async fn get_tracked_block_headers(&self) -> Result<Vec<BlockHeader>, StoreError>;
 
@@ -324,14 +531,50 @@ pub trait Store: Send + Sync {
     // ACCOUNT
     // --------------------------------------------------------------------------------------------
 
-    /// Returns the account IDs of all accounts stored in the database.
+    /// Returns the account IDs of all non-archived accounts stored in the database.
     async fn get_account_ids(&self) -> Result<Vec<AccountId>, StoreError>;
 
-    /// Returns a list of [`AccountHeader`] of all accounts stored in the database along with their
-    /// statuses.
+    /// Returns a list of [`AccountHeader`] of all non-archived accounts stored in the database
+    /// along with their statuses.
     ///
     /// Said accounts' state is the state after the last performed sync.
-    async fn get_account_headers(&self) -> Result<Vec<(AccountHeader, AccountStatus)>, StoreError>;
+    async fn get_account_headers(&self) -> Result<Vec<(AccountHeader, AccountStatus)>, StoreError> {
+        self.get_filtered_account_headers(AccountFilter::Active).await
+    }
+
+    /// Returns a list of [`AccountHeader`] of the accounts matching `filter` along with their
+    /// statuses.
+    async fn get_filtered_account_headers(
+        &self,
+        filter: AccountFilter,
+    ) -> Result<Vec<(AccountHeader, AccountStatus)>, StoreError>;
+
+    /// Marks the account as archived.
+    ///
+    /// Archived accounts keep their full state and history, but are excluded from
+    /// [`Self::get_account_ids`] and [`Self::get_account_headers`], and hence from sync.
+    /// Returns [`StoreError::AccountDataNotFound`] if the account isn't tracked.
+    async fn archive_account(&self, account_id: AccountId) -> Result<(), StoreError>;
+
+    /// Clears the archived mark of the account. Returns [`StoreError::AccountDataNotFound`] if the
+    /// account isn't tracked.
+    async fn unarchive_account(&self, account_id: AccountId) -> Result<(), StoreError>;
+
+    /// Sets whether the account is watch-only.
+    ///
+    /// Watch-only accounts are still tracked and synced, but can't be used as the sender of a
+    /// transaction, e.g. because the client doesn't hold their keys. The flag is returned through
+    /// [`AccountRecord::is_watch_only`] and is kept across account state updates. Returns
+    /// [`StoreError::AccountDataNotFound`] if the account isn't tracked.
+    async fn set_account_watch_only(
+        &self,
+        account_id: AccountId,
+        watch_only: bool,
+    ) -> Result<(), StoreError>;
+
+    /// Retrieves the current nonce of the specified account without loading the rest of its
+    /// header. Returns `None` if the account is not found.
+    async fn get_account_nonce(&self, account_id: AccountId) -> Result<Option<Felt>, StoreError>;
 
     /// Retrieves an [`AccountHeader`] object for the specified [`AccountId`] along with its status.
     /// Returns `None` if the account is not found.
@@ -354,6 +597,15 @@ pub trait Store: Send + Sync {
     async fn get_account(&self, account_id: AccountId)
     -> Result<Option<AccountRecord>, StoreError>;
 
+    /// Retrieves the full [`Account`] state whose commitment matches `account_commitment`.
+    ///
+    /// Both the latest and the historical states tracked by the store are considered. Returns
+    /// `None` if no tracked state matches the commitment (e.g. it was pruned).
+    async fn get_account_by_commitment(
+        &self,
+        account_commitment: Word,
+    ) -> Result<Option<Account>, StoreError>;
+
     /// Retrieves the [`AccountCode`] for the specified account.
     /// Returns `None` if the account is not found.
     async fn get_account_code(
@@ -375,6 +627,24 @@ pub trait Store: Send + Sync {
         client_account_type: ClientAccountType,
     ) -> Result<(), StoreError>;
 
+    /// Inserts a native [`Account`] alongside its [`Address`], input notes and note tags in a
+    /// single database transaction.
+    ///
+    /// Either the whole bundle is imported or, if any part of it fails, nothing is written. Notes
+    /// are upserted as in [`Self::upsert_input_notes`] and tags that are already tracked are
+    /// skipped.
+    ///
+    /// # Errors
+    ///
+    /// - If the account is new and does not contain a seed
+    async fn import_account_bundle(
+        &self,
+        account: &Account,
+        address: Address,
+        notes: &[InputNoteRecord],
+        tags: &[NoteTagRecord],
+    ) -> Result<(), StoreError>;
+
     /// Upserts the account code for a foreign account. This value will be used as a cache of known
     /// script roots and added to the `GetForeignAccountCode` request.
     async fn upsert_foreign_account_code(
@@ -395,6 +665,12 @@ pub trait Store: Send + Sync {
         account_id: AccountId,
     ) -> Result<Vec<Address>, StoreError>;
 
+    /// Retrieves all tracked [`Address`] objects along with the ID of the account they belong to.
+    ///
+    /// Addresses are ordered by account ID, and then by their serialized representation, so the
+    /// result is stable across calls.
+    async fn get_all_addresses(&self) -> Result<Vec<(Address, AccountId)>, StoreError>;
+
     /// Updates an existing [`Account`] with a new state.
     ///
     /// # Errors
@@ -431,6 +707,36 @@ pub trait Store: Send + Sync {
     /// Returns all the keys from the `settings` table.
     async fn list_setting_keys(&self) -> Result<Vec<String>, StoreError>;
 
+    /// Returns the keys from the `settings` table that start with `prefix`.
+    ///
+    /// The default implementation filters the result of [`Store::list_setting_keys`]. Stores
+    /// that can scan a key range should override it to avoid fetching every key.
+    async fn list_setting_keys_with_prefix(
+        &self,
+        prefix: String,
+    ) -> Result<Vec<String>, StoreError> {
+        let mut keys = self.list_setting_keys().await?;
+        keys.retain(|key| key.starts_with(&prefix));
+        Ok(keys)
+    }
+
+    /// Returns the keys and values from the `settings` table whose key starts with `prefix`.
+    ///
+    /// The default implementation calls [`Store::get_setting`] for each key returned by
+    /// [`Store::list_setting_keys_with_prefix`].
+    async fn get_settings_with_prefix(
+        &self,
+        prefix: String,
+    ) -> Result<BTreeMap<String, Vec<u8>>, StoreError> {
+        let mut settings = BTreeMap::new();
+        for key in self.list_setting_keys_with_prefix(prefix).await? {
+            if let Some(value) = self.get_setting(key.clone()).await? {
+                settings.insert(key, value);
+            }
+        }
+        Ok(settings)
+    }
+
     /// Applies a batch of [`SettingMutation`]s. Use this when several `settings` entries must stay
     /// mutually consistent (e.g. a record and its secondary index).
     async fn apply_settings_mutations(
@@ -449,6 +755,12 @@ pub trait Store: Send + Sync {
         Ok(self.get_note_tags().await?.into_iter().map(|r| r.tag).collect())
     }
 
+    /// Returns the note tag records that the client is interested in, each along with the number
+    /// of input notes in the store that carry its tag.
+    async fn get_note_tags_with_note_counts(
+        &self,
+    ) -> Result<Vec<(NoteTagRecord, usize)>, StoreError>;
+
     /// Adds a note tag to the list of tags that the client is interested in.
     ///
     /// If the tag was already being tracked, returns false since no new tags were actually added.
@@ -461,6 +773,22 @@ pub trait Store: Send + Sync {
     /// Otherwise returns true.
     async fn remove_note_tag(&self, tag: NoteTagRecord) -> Result<usize, StoreError>;
 
+    /// Replaces the `old` note tag record with the `new` one in a single atomic operation, so
+    /// that there is no point in time where neither tag is tracked.
+    ///
+    /// If `new` is already being tracked, only `old` is removed. If `old` and `new` are the same
+    /// record, the store is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StoreError::NoteTagNotTracked`] if `old` isn't being tracked, in which case
+    /// the store is left unchanged.
+    async fn replace_note_tag(
+        &self,
+        old: NoteTagRecord,
+        new: NoteTagRecord,
+    ) -> Result<(), StoreError>;
+
     /// Returns the block number of the last state sync block.
     async fn get_sync_height(&self) -> Result<BlockNumber, StoreError>;
 
@@ -470,7 +798,9 @@ pub trait Store: Send + Sync {
     /// - Updating the corresponding tracked input/output notes. Consumed notes carry consumption
     ///   metadata — `consumed_block_height`, `consumed_tx_order`, and `consumer_account_id` — in
     ///   their note state. Implementations must persist these fields so that ordered queries (see
-    ///   [`Store::get_input_note_by_offset`]) work correctly.
+    ///   [`Store::get_input_note_by_offset`]) work correctly. Input notes are written as with
+    ///   [`NoteConflictStrategy::KeepNewerState`], so a stale update never moves a stored note
+    ///   backwards in its lifecycle.
     /// - Removing note tags that are no longer relevant.
     /// - Updating transactions in the store, marking as `committed` or `discarded`.
     ///   - In turn, validating private account's state transitions. If a private account's
@@ -480,6 +810,26 @@ pub trait Store: Send + Sync {
     /// - Updating the tracked public accounts.
     async fn apply_state_sync(&self, state_sync_update: StateSyncUpdate) -> Result<(), StoreError>;
 
+    /// Returns up to `limit` of the most recent sync points, newest first.
+    ///
+    /// Each entry holds the block number a state sync reached and the timestamp (seconds since
+    /// the Unix epoch) at which it was applied. Implementations may cap how many entries are kept.
+    async fn get_sync_history(&self, limit: usize) -> Result<Vec<(BlockNumber, u64)>, StoreError>;
+
+    /// Rolls the local chain state back to a previous sync point at `block_num`. This involves:
+    ///
+    /// - Restoring the sync height and MMR peaks recorded for that sync point.
+    /// - Removing the block headers, sync points and MMR authentication nodes after `block_num`.
+    /// - Marking transactions committed after `block_num` as pending again, so that the next sync
+    ///   re-evaluates them.
+    /// - Reverting the public account updates applied by the syncs after `block_num`.
+    /// - Reverting the notes committed or consumed after `block_num` to their earlier state.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError::RollbackTargetNotFound`] if `block_num` is not a recorded sync point.
+    async fn rollback_to_block(&self, block_num: BlockNumber) -> Result<(), StoreError>;
+
     // TRANSPORT
     // --------------------------------------------------------------------------------------------
 
@@ -538,6 +888,37 @@ pub trait Store: Send + Sync {
         self.set_setting(RPC_LIMITS_STORE_SETTING.into(), limits.to_bytes()).await
     }
 
+    // NETWORK ID
+    // --------------------------------------------------------------------------------------------
+
+    /// Gets the persisted network ID of the node the client is connected to. Returns `None` if
+    /// not stored.
+    async fn get_network_id(&self) -> Result<Option<NetworkId>, StoreError> {
+        let Some(bytes) = self.get_setting(NETWORK_ID_STORE_SETTING.into()).await? else {
+            return Ok(None);
+        };
+        let network_id = String::read_from_bytes(&bytes)?;
+        NetworkId::new(&network_id).map(Some).map_err(|err| {
+            StoreError::DataDeserializationError(DeserializationError::InvalidValue(format!(
+                "invalid network ID `{network_id}`: {err}"
+            )))
+        })
+    }
+
+    /// Persists the network ID of the node the client is connected to.
+    async fn set_network_id(&self, network_id: NetworkId) -> Result<(), StoreError> {
+        self.set_setting(NETWORK_ID_STORE_SETTING.into(), network_id.to_string().to_bytes())
+            .await
+    }
+
+    /// Removes the persisted network ID, if any.
+    async fn remove_network_id(&self) -> Result<(), StoreError> {
+        if self.get_setting(NETWORK_ID_STORE_SETTING.into()).await?.is_some() {
+            self.remove_setting(NETWORK_ID_STORE_SETTING.into()).await?;
+        }
+        Ok(())
+    }
+
     // PARTIAL MMR
     // --------------------------------------------------------------------------------------------
 
@@ -668,6 +1049,53 @@ pub trait Store: Send + Sync {
         }
     }
 
+    /// Retrieves multiple items from the same storage map of an account, along with their Merkle
+    /// proofs. Results are returned in the same order as `keys`.
+    ///
+    /// The default implementation of this method calls [`Store::get_account_map_item`] for each
+    /// key. Stores that keep the account's storage maps in memory should override it to open all
+    /// keys against the same map in a single pass.
+    async fn get_account_map_items(
+        &self,
+        account_id: AccountId,
+        slot_name: StorageSlotName,
+        keys: &[StorageMapKey],
+    ) -> Result<Vec<(Word, StorageMapWitness)>, StoreError> {
+        let mut items = Vec::with_capacity(keys.len());
+        for key in keys {
+            items.push(self.get_account_map_item(account_id, slot_name.clone(), *key).await?);
+        }
+        Ok(items)
+    }
+
+    /// Retrieves the keys of a storage map slot without loading the map values.
+    ///
+    /// Keys are returned in ascending order of their hex encoding, so repeated calls over an
+    /// unchanged map return them in the same order.
+    ///
+    /// The default implementation of this method uses [`Store::get_account_storage`].
+    async fn get_storage_map_keys(
+        &self,
+        account_id: AccountId,
+        slot_name: StorageSlotName,
+    ) -> Result<Vec<StorageMapKey>, StoreError> {
+        let storage = self
+            .get_account_storage(account_id, AccountStorageFilter::SlotName(slot_name.clone()))
+            .await?;
+        match storage.get(&slot_name).map(StorageSlot::content) {
+            Some(StorageSlotContent::Map(map)) => {
+                let mut keys: Vec<StorageMapKey> = map.entries().map(|(key, _)| *key).collect();
+                keys.sort_by_cached_key(|key| key.to_hex());
+
+                Ok(keys)
+            },
+            Some(_) => Err(StoreError::AccountError(AccountError::StorageSlotNotMap(slot_name))),
+            None => {
+                Err(StoreError::AccountError(AccountError::StorageSlotNameNotFound { slot_name }))
+            },
+        }
+    }
+
     // PARTIAL ACCOUNTS
     // --------------------------------------------------------------------------------------------
 
@@ -677,6 +1105,19 @@ pub trait Store: Send + Sync {
         &self,
         account_id: AccountId,
     ) -> Result<Option<AccountRecord>, StoreError>;
+
+    // INTEGRITY
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks the consistency of the stored data and returns a report of the issues found.
+    ///
+    /// Implementations should at least check the underlying storage for corruption, that every
+    /// tracked account's stored commitment matches the one computed from its stored state, and
+    /// that every committed input note references a stored block header.
+    ///
+    /// Inconsistencies are reported in the [`IntegrityReport`]; an error is only returned if the
+    /// check itself couldn't be run.
+    async fn check_integrity(&self) -> Result<IntegrityReport, StoreError>;
 }
 
 // PARTIAL BLOCKCHAIN NODE FILTER
@@ -713,6 +1154,12 @@ pub enum TransactionFilter {
     /// A transaction is considered expired if is uncommitted and the transaction's block number
     /// is less than the provided block number.
     ExpiredBefore(BlockNumber),
+    /// Return a list of the transactions that have been assigned the provided category.
+    Category(String),
+    /// Return the transactions that failed to execute locally. These are only recorded when
+    /// [`ClientBuilder::record_failed_transactions`](crate::builder::ClientBuilder::record_failed_transactions)
+    /// is enabled.
+    Failed,
 }
 
 // TRANSACTIONS FILTER HELPERS
@@ -721,7 +1168,7 @@ pub enum TransactionFilter {
 impl TransactionFilter {
     /// Returns a [String] containing the query for this Filter.
     pub fn to_query(&self) -> String {
-        const QUERY: &str = "SELECT tx.id, script.script, tx.details, tx.status \
+        const QUERY: &str = "SELECT tx.id, script.script, tx.details, tx.status, tx.execution_cycles \
             FROM transactions AS tx LEFT JOIN transaction_scripts AS script ON tx.script_root = script.script_root";
         match self {
             TransactionFilter::All => QUERY.to_string(),
@@ -733,18 +1180,36 @@ impl TransactionFilter {
                 // Use SQLite's array parameter binding
                 format!("{QUERY} WHERE tx.id IN rarray(?)")
             },
+            TransactionFilter::Category(_) => format!("{QUERY} WHERE tx.category = ?"),
+            TransactionFilter::Failed => format!(
+                "{QUERY} WHERE tx.status_variant = {}",
+                TransactionStatusVariant::Failed as u8,
+            ),
             TransactionFilter::ExpiredBefore(block_num) => {
                 format!(
-                    "{QUERY} WHERE tx.block_num < {} AND tx.status_variant != {} AND tx.status_variant != {}",
+                    "{QUERY} WHERE tx.block_num < {} AND tx.status_variant = {}",
                     block_num.as_u32(),
-                    TransactionStatusVariant::Discarded as u8,
-                    TransactionStatusVariant::Committed as u8
+                    TransactionStatusVariant::Pending as u8,
                 )
             },
         }
     }
 }
 
+// NOTE CONFLICT STRATEGY
+// ================================================================================================
+
+/// How [`Store::bulk_upsert_input_notes`] resolves conflicts with input notes that are already
+/// stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteConflictStrategy {
+    /// The stored note is always replaced, as in [`Store::upsert_input_notes`].
+    Replace,
+    /// The stored note is only replaced if the new state isn't earlier in the note lifecycle than
+    /// the stored one.
+    KeepNewerState,
+}
+
 // NOTE FILTER
 // ================================================================================================
 
@@ -759,6 +1224,12 @@ pub enum NoteFilter {
     /// Filter by consumed notes ([`InputNoteRecord`] or [`OutputNoteRecord`]). notes that have
     /// been used as inputs in transactions.
     Consumed,
+    /// Return a list of notes consumed in a block after the provided [`BlockNumber`], excluding
+    /// that block.
+    ConsumedAfter(BlockNumber),
+    /// Return a list of notes created in a block after the provided [`BlockNumber`], excluding
+    /// that block. Notes whose inclusion block isn't known, such as expected notes, never match.
+    CreatedAfter(BlockNumber),
     /// Return a list of expected notes ([`InputNoteRecord`] or [`OutputNoteRecord`]). These
     /// represent notes for which the store doesn't have anchor data.
     Expected,
@@ -774,6 +1245,9 @@ pub enum NoteFilter {
     /// Return a list of notes that are currently being processed. This filter doesn't apply to
     /// output notes.
     Processing,
+    /// Return a list containing the notes whose recipient digest matches the provided [`Word`].
+    /// This filter doesn't apply to input notes.
+    Recipient(Word),
     /// Return a list containing the note that matches with the provided [`NoteId`]. The query will
     /// return an error if the note isn't found.
     Unique(NoteId),
@@ -789,7 +1263,7 @@ pub enum NoteFilter {
 // ================================================================================================
 
 /// Expresses metadata about the block header.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum BlockRelevance {
     /// The block header includes notes that the client may consume.
     HasNotes,
@@ -816,6 +1290,21 @@ impl From<bool> for BlockRelevance {
     }
 }
 
+// ACCOUNT FILTER
+// ================================================================================================
+
+/// Filters for narrowing the set of account headers returned by the client's store.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AccountFilter {
+    /// Return the accounts that aren't archived.
+    #[default]
+    Active,
+    /// Return only the archived accounts.
+    Archived,
+    /// Return all accounts, archived or not.
+    All,
+}
+
 // STORAGE FILTER
 // ================================================================================================
 
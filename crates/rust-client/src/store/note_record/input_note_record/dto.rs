@@ -0,0 +1,151 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use miden_protocol::asset::Asset;
+use miden_protocol::note::{NoteMetadata, NoteType};
+use miden_protocol::utils::serde::{Deserializable, Serializable};
+use serde::{Deserialize, Serialize};
+
+use super::{InputNoteRecord, InputNoteState};
+use crate::store::NoteRecordError;
+
+/// Version of the [`InputNoteRecordDto`] format. It's bumped whenever a field is removed or its
+/// meaning changes, so consumers can detect records they don't know how to read.
+pub const INPUT_NOTE_RECORD_DTO_VERSION: u32 = 1;
+
+// INPUT NOTE RECORD DTO
+// ================================================================================================
+
+/// Stable `serde` representation of an [`InputNoteRecord`], meant to be shared by every client
+/// that exposes input notes as JSON so that they all use the same field structure.
+///
+/// Besides the human-readable fields, the DTO carries the hex-encoded serialized record, which is
+/// what [`InputNoteRecord`] is restored from. The readable fields are derived from it and are
+/// ignored when converting back.
+///
+/// Field names are serialized in `camelCase`, and identifiers and commitments are hex-encoded.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InputNoteRecordDto {
+    /// Version of the format, see [`INPUT_NOTE_RECORD_DTO_VERSION`].
+    pub version: u32,
+    /// The note ID. It's only known once the note's metadata is available.
+    pub id: Option<String>,
+    /// The commitment to the note details, which identifies the note even without metadata.
+    pub details_commitment: String,
+    /// Name of the note state (e.g. `expected`, `committed` or `consumedExternal`).
+    pub state: String,
+    /// The note metadata, if known.
+    pub metadata: Option<NoteMetadataDto>,
+    /// The assets carried by the note.
+    pub assets: Vec<NoteAssetDto>,
+    /// Whether the record holds an inclusion proof for the note.
+    pub has_inclusion_proof: bool,
+    /// The timestamp at which the note was created, if known.
+    pub created_at: Option<u64>,
+    /// The hex-encoded serialized record.
+    pub record: String,
+}
+
+/// The metadata fields of a note exposed by [`InputNoteRecordDto`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NoteMetadataDto {
+    /// The hex-encoded ID of the account that created the note.
+    pub sender: String,
+    /// The note type, either `public` or `private`.
+    pub note_type: String,
+    /// The note tag.
+    pub tag: u32,
+}
+
+/// An asset carried by a note, as exposed by [`InputNoteRecordDto`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum NoteAssetDto {
+    /// A fungible asset with the hex-encoded ID of its faucet and its amount.
+    Fungible { faucet_id: String, amount: u64 },
+    /// A non-fungible asset with the hex-encoded ID of its faucet.
+    NonFungible { faucet_id: String },
+}
+
+impl From<&InputNoteRecord> for InputNoteRecordDto {
+    fn from(record: &InputNoteRecord) -> Self {
+        Self {
+            version: INPUT_NOTE_RECORD_DTO_VERSION,
+            id: record.id().map(|id| id.to_hex()),
+            details_commitment: record.details_commitment().to_hex(),
+            state: state_name(record.state()).to_string(),
+            metadata: record.metadata().map(NoteMetadataDto::from),
+            assets: record.assets().iter().map(NoteAssetDto::from).collect(),
+            has_inclusion_proof: record.inclusion_proof().is_some(),
+            created_at: record.created_at(),
+            record: hex::encode(record.to_bytes()),
+        }
+    }
+}
+
+impl TryFrom<&InputNoteRecordDto> for InputNoteRecord {
+    type Error = NoteRecordError;
+
+    fn try_from(dto: &InputNoteRecordDto) -> Result<Self, Self::Error> {
+        if dto.version != INPUT_NOTE_RECORD_DTO_VERSION {
+            return Err(NoteRecordError::ConversionError(format!(
+                "unsupported input note record DTO version {}",
+                dto.version
+            )));
+        }
+
+        let bytes = hex::decode(&dto.record).map_err(|err| {
+            NoteRecordError::ConversionError(format!("invalid input note record encoding: {err}"))
+        })?;
+
+        InputNoteRecord::read_from_bytes(&bytes)
+            .map_err(|err| NoteRecordError::ConversionError(err.to_string()))
+    }
+}
+
+impl From<&NoteMetadata> for NoteMetadataDto {
+    fn from(metadata: &NoteMetadata) -> Self {
+        let note_type = match metadata.note_type() {
+            NoteType::Private => "private",
+            NoteType::Public => "public",
+        };
+
+        Self {
+            sender: metadata.sender().to_hex(),
+            note_type: note_type.to_string(),
+            tag: metadata.tag().as_u32(),
+        }
+    }
+}
+
+impl From<&Asset> for NoteAssetDto {
+    fn from(asset: &Asset) -> Self {
+        match asset {
+            Asset::Fungible(fungible_asset) => NoteAssetDto::Fungible {
+                faucet_id: fungible_asset.faucet_id().to_hex(),
+                amount: u64::from(fungible_asset.amount()),
+            },
+            Asset::NonFungible(non_fungible_asset) => NoteAssetDto::NonFungible {
+                faucet_id: non_fungible_asset.faucet_id().to_hex(),
+            },
+        }
+    }
+}
+
+/// Returns the name of the note state used by [`InputNoteRecordDto`].
+fn state_name(state: &InputNoteState) -> &'static str {
+    match state {
+        InputNoteState::Expected(_) => "expected",
+        InputNoteState::Unverified(_) => "unverified",
+        InputNoteState::Committed(_) => "committed",
+        InputNoteState::Invalid(_) => "invalid",
+        InputNoteState::ProcessingAuthenticated(_) => "processingAuthenticated",
+        InputNoteState::ProcessingUnauthenticated(_) => "processingUnauthenticated",
+        InputNoteState::ConsumedAuthenticatedLocal(_) => "consumedAuthenticatedLocal",
+        InputNoteState::ConsumedUnauthenticatedLocal(_) => "consumedUnauthenticatedLocal",
+        InputNoteState::ConsumedExternal(_) => "consumedExternal",
+    }
+}
@@ -25,6 +25,9 @@ use miden_protocol::utils::serde::{
 
 use super::NoteRecordError;
 
+mod dto;
+pub use dto::{INPUT_NOTE_RECORD_DTO_VERSION, InputNoteRecordDto, NoteAssetDto, NoteMetadataDto};
+
 mod states;
 pub use states::{
     CommittedNoteState,
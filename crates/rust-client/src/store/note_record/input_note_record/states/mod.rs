@@ -138,6 +138,32 @@ impl InputNoteState {
         }
     }
 
+    /// Returns `true` if this state comes earlier in the note lifecycle than `other`, that is, if
+    /// replacing `other` with this state would move the note backwards (e.g. from committed back
+    /// to expected).
+    ///
+    /// The lifecycle goes from expected, to unverified or invalid, to committed or processing and
+    /// finally to consumed. States in the same stage don't precede each other. Committed and
+    /// processing share a stage because a processing note goes back to committed when its
+    /// consuming transaction is discarded.
+    pub fn precedes(&self, other: &InputNoteState) -> bool {
+        self.lifecycle_stage() < other.lifecycle_stage()
+    }
+
+    /// Returns the position of the state's stage in the note lifecycle.
+    fn lifecycle_stage(&self) -> u8 {
+        match self {
+            InputNoteState::Expected(_) => 0,
+            InputNoteState::Unverified(_) | InputNoteState::Invalid(_) => 1,
+            InputNoteState::Committed(_)
+            | InputNoteState::ProcessingAuthenticated(_)
+            | InputNoteState::ProcessingUnauthenticated(_) => 2,
+            InputNoteState::ConsumedAuthenticatedLocal(_)
+            | InputNoteState::ConsumedUnauthenticatedLocal(_)
+            | InputNoteState::ConsumedExternal(_) => 3,
+        }
+    }
+
     /// Sets the consumed transaction order on the inner consumed state. No-op if the note is
     /// not in a consumed state.
     pub(crate) fn set_consumed_tx_order(&mut self, order: Option<u32>) {
@@ -20,13 +20,21 @@
 
 use alloc::string::{String, ToString};
 
+use miden_protocol::block::BlockNumber;
 use miden_protocol::errors::NoteError;
 use thiserror::Error;
 
 mod input_note_record;
 mod output_note_record;
 
-pub use input_note_record::{InputNoteRecord, InputNoteState};
+pub use input_note_record::{
+    INPUT_NOTE_RECORD_DTO_VERSION,
+    InputNoteRecord,
+    InputNoteRecordDto,
+    InputNoteState,
+    NoteAssetDto,
+    NoteMetadataDto,
+};
 pub use output_note_record::{NoteExportType, OutputNoteRecord, OutputNoteState};
 
 /// Contains structures that model all states in which an input note can be.
@@ -46,6 +54,43 @@ pub mod input_note_states {
     };
 }
 
+// NOTE STATE TRANSITION
+// ================================================================================================
+
+/// A state an input note entered, as recorded by the store.
+///
+/// The store records an input note's state whenever its variant changes, so a note's transitions
+/// (see [`Store::get_note_state_history`](crate::store::Store::get_note_state_history)) describe
+/// the path it followed through its lifecycle.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NoteStateTransition {
+    state: InputNoteState,
+    block_num: BlockNumber,
+    timestamp: u64,
+}
+
+impl NoteStateTransition {
+    pub fn new(state: InputNoteState, block_num: BlockNumber, timestamp: u64) -> Self {
+        Self { state, block_num, timestamp }
+    }
+
+    /// Returns the state the note entered.
+    pub fn state(&self) -> &InputNoteState {
+        &self.state
+    }
+
+    /// Returns the client's sync height when the note entered the state.
+    pub fn block_num(&self) -> BlockNumber {
+        self.block_num
+    }
+
+    /// Returns the UTC timestamp (seconds since the Unix epoch) at which the note entered the
+    /// state.
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+}
+
 // NOTE RECORD ERROR
 // ================================================================================================
 
@@ -27,6 +27,7 @@ use miden_tx::utils::serde::{
 };
 
 use super::NoteRecordError;
+use crate::note::P2ideConditions;
 
 // OUTPUT NOTE RECORD
 // ================================================================================================
@@ -125,6 +126,14 @@ impl OutputNoteRecord {
         self.expected_height
     }
 
+    /// Returns the block from which the sender can consume the note back, if it's a P2IDE note
+    /// with a reclaim height.
+    ///
+    /// Returns `None` for other notes, and for notes whose recipient isn't known.
+    pub fn reclaim_height(&self) -> Option<BlockNumber> {
+        P2ideConditions::from_recipient(self.recipient()?)?.reclaim_height
+    }
+
     /// Returns true if the note has been nullified on chain.
     pub fn is_consumed(&self) -> bool {
         matches!(self.state, OutputNoteState::Consumed { .. })
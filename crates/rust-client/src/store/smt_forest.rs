@@ -11,7 +11,7 @@ use miden_protocol::account::{
     StorageSlotContent,
 };
 use miden_protocol::asset::{Asset, AssetVault, AssetVaultKey, AssetWitness};
-use miden_protocol::crypto::merkle::smt::{SMT_DEPTH, Smt, SmtForest};
+use miden_protocol::crypto::merkle::smt::{LeafIndex, SMT_DEPTH, Smt, SmtForest};
 use miden_protocol::crypto::merkle::{EmptySubtreeRoots, MerkleError};
 use miden_protocol::{EMPTY_WORD, Word};
 
@@ -76,6 +76,39 @@ impl AccountSmtForest {
         Ok(StorageMapWitness::new(proof, [key])?)
     }
 
+    /// Retrieves the storage map witnesses for multiple keys of the map with the provided root,
+    /// returned in the order of `keys`.
+    ///
+    /// Keys are grouped by the SMT leaf they hash to, so each leaf is opened once and its witness
+    /// covers all the requested keys in it. Keys sharing a leaf get the same witness.
+    pub fn get_storage_map_item_witnesses(
+        &self,
+        map_root: Word,
+        keys: &[StorageMapKey],
+    ) -> Result<Vec<StorageMapWitness>, StoreError> {
+        let leaf_indices: Vec<LeafIndex<SMT_DEPTH>> =
+            keys.iter().map(|key| Smt::key_to_leaf_index(&Word::from(key.hash()))).collect();
+
+        let mut keys_by_leaf: BTreeMap<LeafIndex<SMT_DEPTH>, Vec<StorageMapKey>> = BTreeMap::new();
+        for (leaf_index, key) in leaf_indices.iter().zip(keys) {
+            let leaf_keys = keys_by_leaf.entry(*leaf_index).or_default();
+            if !leaf_keys.contains(key) {
+                leaf_keys.push(*key);
+            }
+        }
+
+        let mut witnesses_by_leaf = BTreeMap::new();
+        for (leaf_index, leaf_keys) in keys_by_leaf {
+            let proof = self.forest.open(map_root, Word::from(leaf_keys[0].hash()))?;
+            witnesses_by_leaf.insert(leaf_index, StorageMapWitness::new(proof, leaf_keys)?);
+        }
+
+        Ok(leaf_indices
+            .iter()
+            .map(|leaf_index| witnesses_by_leaf[leaf_index].clone())
+            .collect())
+    }
+
     // ROOT LIFECYCLE
     // --------------------------------------------------------------------------------------------
 
@@ -4,7 +4,7 @@ use alloc::vec::Vec;
 use miden_protocol::block::{BlockHeader, BlockNumber};
 use miden_protocol::crypto::hash::rpo::Rpo256;
 use miden_protocol::crypto::merkle::MerklePath;
-use miden_protocol::crypto::merkle::mmr::{Forest, InOrderIndex, PartialMmr};
+use miden_protocol::crypto::merkle::mmr::{Forest, InOrderIndex, MmrPeaks, PartialMmr};
 use miden_protocol::{Felt, Word};
 use tracing::warn;
 
@@ -26,11 +26,87 @@ impl<AUTH> Client<AUTH> {
         self.store.get_block_header_by_num(block_num).await.map_err(Into::into)
     }
 
-    /// Ensures that the genesis block is available. If the genesis commitment is already
-    /// cached in the RPC client, returns early. Otherwise, fetches the genesis block from
-    /// the node, stores it, and sets the commitment in the RPC client.
+    /// Retrieves a block header from the store together with the chain [`MmrPeaks`] at the
+    /// current sync height.
+    ///
+    /// Only the peaks at the sync height are persisted, so those are the ones returned. They
+    /// commit to every block before the sync height, which allows verifying inclusion proofs
+    /// offline: a note's path is checked against the header's note root, and the header's
+    /// commitment against the peaks using the authentication path from
+    /// [`Client::get_current_partial_mmr`] (available for tracked blocks).
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StoreError::BlockHeaderNotFound`] if the header is not stored.
+    pub async fn get_block_with_peaks(
+        &self,
+        block_num: BlockNumber,
+    ) -> Result<(BlockHeader, MmrPeaks), ClientError> {
+        let (block_header, _) = self
+            .store
+            .get_block_header_by_num(block_num)
+            .await?
+            .ok_or(StoreError::BlockHeaderNotFound(block_num))?;
+        let peaks = self.store.get_current_blockchain_peaks().await?;
+
+        Ok((block_header, peaks))
+    }
+
+    /// Fetches the block headers in the `from..=to` range from the node and stores them along
+    /// with their MMR authentication paths, so that [`Self::get_block_with_peaks`] and inclusion
+    /// proof verification work for those blocks without network access.
+    ///
+    /// Blocks whose authentication data is already stored are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ClientError::InvalidBlockRange`] if `from` is greater than `to` or if `to` isn't
+    /// lower than the current sync height (the stored peaks only commit to earlier blocks).
+    pub async fn fetch_block_range(
+        &mut self,
+        from: BlockNumber,
+        to: BlockNumber,
+    ) -> Result<(), ClientError> {
+        let sync_height = self.get_sync_height().await?;
+        if from > to || to >= sync_height {
+            return Err(ClientError::InvalidBlockRange { from, to, sync_height });
+        }
+
+        let mut partial_mmr = self.get_current_partial_mmr().await?;
+        for block_num in from.as_u32()..=to.as_u32() {
+            let block_num = BlockNumber::from(block_num);
+            if partial_mmr.is_tracked(block_num.as_usize()) {
+                continue;
+            }
+            self.get_and_store_authenticated_block(block_num, &mut partial_mmr).await?;
+        }
+        self.cache_partial_mmr(partial_mmr).await
+    }
+
+    /// Ensures that the genesis block is available. If the store already holds a genesis block,
+    /// checks (once per client) that the node serves the same one and sets its commitment in the
+    /// RPC client. Otherwise, fetches the genesis block from the node, stores it, and sets the
+    /// commitment in the RPC client.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ClientError::GenesisMismatch`] if the store was created for a different genesis
+    /// block than the one served by the node.
     pub async fn ensure_genesis_in_place(&mut self) -> Result<(), ClientError> {
-        if self.rpc_api.has_genesis_commitment().is_some() {
+        if self.genesis_verified {
+            return Ok(());
+        }
+
+        if let Some((stored_genesis, _)) =
+            self.store.get_block_header_by_num(BlockNumber::GENESIS).await?
+        {
+            let expected = stored_genesis.commitment();
+            let actual = self.rpc_api.get_genesis_commitment().await?;
+            if actual != expected {
+                return Err(ClientError::GenesisMismatch { expected, actual });
+            }
+            self.rpc_api.set_genesis_commitment(expected).await?;
+            self.genesis_verified = true;
             return Ok(());
         }
 
@@ -39,10 +115,13 @@ impl<AUTH> Client<AUTH> {
             .get_block_header_by_number(Some(BlockNumber::GENESIS), false)
             .await?;
 
+        self.verify_block_headers(core::slice::from_ref(&genesis)).await?;
+
         // Genesis is untracked since there are no client notes associated with it, so we fetch no
         // MMR proof and pass no nodes.
         self.store.insert_block_header(&genesis, &[], false).await?;
         self.rpc_api.set_genesis_commitment(genesis.commitment()).await?;
+        self.genesis_verified = true;
         Ok(())
     }
 
@@ -145,11 +224,46 @@ impl<AUTH> Client<AUTH> {
             fetch_block_header(self.rpc_api.clone(), block_num, current_partial_mmr).await?;
         let tracked_nodes = authenticated_block_nodes(&block_header, path_nodes);
 
+        self.verify_block_headers(core::slice::from_ref(&block_header)).await?;
+
         // Insert header and MMR nodes atomically
         self.store.insert_block_header(&block_header, &tracked_nodes, true).await?;
 
         Ok(block_header)
     }
+
+    /// Runs the configured [`BlockVerifier`](crate::sync::BlockVerifier) over the given block
+    /// headers, which must be sorted by block number.
+    ///
+    /// Each header is verified along with its preceding block's header, taken from the given
+    /// headers or the store.
+    pub(crate) async fn verify_block_headers(
+        &self,
+        block_headers: &[BlockHeader],
+    ) -> Result<(), ClientError> {
+        let Some(verifier) = &self.block_verifier else {
+            return Ok(());
+        };
+
+        let mut last_verified: Option<BlockHeader> = None;
+        for block_header in block_headers {
+            let block_num = block_header.block_num();
+            let prev_header = if block_num == BlockNumber::GENESIS {
+                None
+            } else {
+                let prev_block_num = BlockNumber::from(block_num.as_u32() - 1);
+                match last_verified.take() {
+                    Some(header) if header.block_num() == prev_block_num => Some(header),
+                    _ => self.store.get_block_header_by_num(prev_block_num).await?.map(|(h, _)| h),
+                }
+            };
+
+            verifier.verify(block_header, prev_header.as_ref()).await?;
+            last_verified = Some(block_header.clone());
+        }
+
+        Ok(())
+    }
 }
 
 // UTILS
@@ -0,0 +1,124 @@
+//! Pluggable verification of the block headers received from the node.
+//!
+//! A [`BlockVerifier`] is consulted before the client persists a block header, whether it comes
+//! from a state sync or is fetched on demand, and can reject it to stop a misbehaving node from
+//! corrupting the local chain data.
+
+use alloc::boxed::Box;
+use alloc::format;
+
+use async_trait::async_trait;
+use miden_protocol::block::BlockHeader;
+
+use crate::ClientError;
+
+// BLOCK VERIFIER
+// ================================================================================================
+
+/// Verifies block headers received from the node before they're persisted in the store.
+///
+/// Implementations reject a header by returning an error, usually
+/// [`ClientError::BlockVerificationFailed`]. The operation that received the header is then
+/// aborted and nothing is persisted.
+#[async_trait(?Send)]
+pub trait BlockVerifier: Send + Sync {
+    /// Verifies `block_header`. `previous_header` is the header of the immediately preceding
+    /// block, if the client has it (either in the store or in the same update).
+    async fn verify(
+        &self,
+        block_header: &BlockHeader,
+        previous_header: Option<&BlockHeader>,
+    ) -> Result<(), ClientError>;
+}
+
+/// The default [`BlockVerifier`], which accepts every block header.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoopBlockVerifier;
+
+#[async_trait(?Send)]
+impl BlockVerifier for NoopBlockVerifier {
+    async fn verify(
+        &self,
+        _block_header: &BlockHeader,
+        _previous_header: Option<&BlockHeader>,
+    ) -> Result<(), ClientError> {
+        Ok(())
+    }
+}
+
+/// A [`BlockVerifier`] that checks that each block header commits to the preceding block known
+/// by the client.
+///
+/// Headers whose preceding block isn't known are accepted, as the client only stores the headers
+/// it needs; those are still authenticated against the chain MMR.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PrevCommitmentVerifier;
+
+#[async_trait(?Send)]
+impl BlockVerifier for PrevCommitmentVerifier {
+    async fn verify(
+        &self,
+        block_header: &BlockHeader,
+        previous_header: Option<&BlockHeader>,
+    ) -> Result<(), ClientError> {
+        let Some(previous_header) = previous_header else {
+            return Ok(());
+        };
+
+        if block_header.prev_block_commitment() != previous_header.commitment() {
+            return Err(ClientError::BlockVerificationFailed {
+                block_num: block_header.block_num(),
+                reason: format!(
+                    "previous block commitment {} doesn't match the commitment {} of block {}",
+                    block_header.prev_block_commitment(),
+                    previous_header.commitment(),
+                    previous_header.block_num(),
+                ),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_testing::{MockChain, MockChainBuilder};
+
+    use super::{BlockVerifier, NoopBlockVerifier, PrevCommitmentVerifier};
+    use crate::ClientError;
+
+    #[tokio::test]
+    async fn prev_commitment_verifier_rejects_forked_header() {
+        let mut chain = MockChain::new();
+        chain.prove_next_block().unwrap();
+        chain.prove_next_block().unwrap();
+
+        // A different chain, whose block 1 is a fork of the first chain's block 1.
+        let mut builder = MockChainBuilder::new();
+        builder.add_existing_mock_account(miden_testing::Auth::IncrNonce).unwrap();
+        let mut forked_chain = builder.build().unwrap();
+        forked_chain.prove_next_block().unwrap();
+
+        let block_1 = chain.block_header(1);
+        let block_2 = chain.block_header(2);
+        let forked_block_1 = forked_chain.block_header(1);
+
+        PrevCommitmentVerifier.verify(&block_2, Some(&block_1)).await.unwrap();
+        PrevCommitmentVerifier.verify(&block_2, None).await.unwrap();
+
+        let err = PrevCommitmentVerifier
+            .verify(&block_2, Some(&forked_block_1))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ClientError::BlockVerificationFailed { block_num, .. } if block_num == block_2.block_num()
+        ));
+
+        NoopBlockVerifier.verify(&block_2, Some(&forked_block_1)).await.unwrap();
+    }
+}
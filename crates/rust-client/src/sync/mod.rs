@@ -61,25 +61,48 @@ use alloc::sync::Arc;
 use alloc::vec::Vec;
 use core::cmp::max;
 
-use miden_protocol::account::AccountId;
+use miden_protocol::Felt;
+use miden_protocol::account::{
+    Account,
+    AccountDelta,
+    AccountHeader,
+    AccountId,
+    AccountStorageDelta,
+    AccountVaultDelta,
+    StorageSlot,
+    StorageSlotContent,
+    StorageSlotName,
+};
 use miden_protocol::block::BlockNumber;
+use miden_protocol::errors::AccountError;
 use miden_protocol::note::NoteId;
 use miden_protocol::transaction::TransactionId;
 use miden_tx::auth::TransactionAuthenticator;
 use miden_tx::utils::serde::{Deserializable, DeserializationError, Serializable};
-use tracing::{debug, info};
+use tracing::{Instrument, debug, info, info_span};
 
 use crate::pswap::PswapChainObserver;
-use crate::store::{NoteFilter, TransactionFilter};
+use crate::rpc::AccountStateAt;
+use crate::rpc::domain::account::GetAccountRequest;
+use crate::rpc::domain::storage_map::StorageMapUpdate;
+use crate::settings::{account_vault_sync_cursor_setting_key, storage_map_sync_cursor_setting_key};
+use crate::store::{NoteFilter, StoreError, TransactionFilter};
+use crate::utils::next_correlation_id;
 use crate::{Client, ClientError};
 mod block_header;
 
+mod block_verifier;
+pub use block_verifier::{BlockVerifier, NoopBlockVerifier, PrevCommitmentVerifier};
+
 mod tag;
 pub use tag::{NoteTagRecord, NoteTagSource};
 
 mod note_observer;
 pub use note_observer::NoteObserver;
 
+mod pruning;
+pub use pruning::PruningPolicy;
+
 mod state_sync;
 pub use state_sync::{NoteUpdateAction, OnNoteReceived, StateSync, StateSyncInput};
 
@@ -92,6 +115,7 @@ pub use state_sync_update::{
     StateSyncUpdate,
     TransactionUpdateTracker,
 };
+use state_sync_update::{replay_storage_updates, replay_vault_updates};
 
 /// Client synchronization methods.
 impl<AUTH> Client<AUTH>
@@ -106,6 +130,67 @@ where
         self.store.get_sync_height().await.map_err(Into::into)
     }
 
+    /// Returns up to `limit` of the most recent sync points as `(block number, timestamp)` pairs,
+    /// newest first. Timestamps are seconds since the Unix epoch.
+    pub async fn get_sync_history(
+        &self,
+        limit: usize,
+    ) -> Result<Vec<(BlockNumber, u64)>, ClientError> {
+        self.store.get_sync_history(limit).await.map_err(Into::into)
+    }
+
+    /// Returns how far the client's sync height is behind the node's chain tip, which gives an
+    /// idea of the amount of work the next sync has to do.
+    ///
+    /// The chain tip is fetched from the node; nothing is synced or stored.
+    pub async fn sync_lag(&self) -> Result<SyncLag, ClientError> {
+        let sync_height = self.get_sync_height().await?;
+        let (chain_tip_header, _) = self.rpc_api.get_block_header_by_number(None, false).await?;
+
+        Ok(SyncLag {
+            sync_height,
+            chain_tip: chain_tip_header.block_num(),
+        })
+    }
+
+    /// Rolls the local chain state back to the sync point at `block_num`, for recovering from a
+    /// sync that applied bad data (e.g. served by a misbehaving node).
+    ///
+    /// The sync height, MMR peaks and block headers are restored to the state they had at that
+    /// sync point, and transactions committed after it are marked as pending again. Public
+    /// account updates and note state changes fetched by the syncs after `block_num` are
+    /// reverted, so the next sync fetches them again.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError::RollbackTargetNotFound`](crate::store::StoreError::RollbackTargetNotFound)
+    /// if `block_num` is not one of the sync points returned by [`Client::get_sync_history`].
+    pub async fn rollback_to_block(&mut self, block_num: BlockNumber) -> Result<(), ClientError> {
+        self.store.rollback_to_block(block_num).await?;
+        self.partial_mmr = None;
+        Ok(())
+    }
+
+    /// Rolls the local chain state back to the most recent sync point below the current sync
+    /// height, and returns its block number. Returns `None` if there is no such sync point.
+    ///
+    /// See [`Client::rollback_to_block`] for what is reverted.
+    pub async fn undo_last_sync(&mut self) -> Result<Option<BlockNumber>, ClientError> {
+        let sync_height = self.get_sync_height().await?;
+        let Some(previous_block_num) = self
+            .get_sync_history(usize::MAX)
+            .await?
+            .into_iter()
+            .map(|(block_num, _)| block_num)
+            .find(|block_num| *block_num < sync_height)
+        else {
+            return Ok(None);
+        };
+
+        self.rollback_to_block(previous_block_num).await?;
+        Ok(Some(previous_block_num))
+    }
+
     /// Syncs the client's on-chain state with the current state of the Miden network and returns
     /// a [`SyncSummary`] corresponding to the local state update.
     ///
@@ -116,10 +201,61 @@ where
     /// Builds the default sync input, runs [`StateSync::sync_state`] (see that method for the
     /// detailed pipeline), applies the resulting update to the store, caches the partial MMR, and
     /// prunes irrelevant blocks according to the configured cadence.
+    ///
+    /// If the node only serves part of the range up to its chain tip, each part is applied in turn
+    /// until the client reaches the chain tip, and the returned summary covers all of them.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ClientError::SyncStalled`] if a sync step makes no progress while the node
+    /// reports a chain tip past the client's sync height.
     pub async fn sync_chain(&mut self) -> Result<SyncSummary, ClientError> {
+        self.sync_chain_with_progress(|_, _| {}).await
+    }
+
+    /// Same as [`Client::sync_chain`], but calls `progress` after each sync step is applied with
+    /// the block the client synced to and the node's chain tip, so that long catch-ups can report
+    /// their progress.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ClientError::SyncStalled`] if a sync step makes no progress while the node
+    /// reports a chain tip past the client's sync height.
+    pub async fn sync_chain_with_progress(
+        &mut self,
+        mut progress: impl FnMut(BlockNumber, BlockNumber),
+    ) -> Result<SyncSummary, ClientError> {
         self.ensure_genesis_in_place().await?;
         self.ensure_rpc_limits_in_place().await?;
 
+        let mut sync_summary: Option<SyncSummary> = None;
+        loop {
+            let block_from = self.store.get_sync_height().await?;
+            let (block_num, chain_tip, step_summary) = self.sync_chain_step().await?;
+
+            match &mut sync_summary {
+                Some(summary) => summary.combine_with(step_summary),
+                None => sync_summary = Some(step_summary),
+            }
+            progress(block_num, chain_tip);
+
+            if block_num >= chain_tip {
+                break;
+            }
+            if block_num <= block_from {
+                return Err(ClientError::SyncStalled { block_num, chain_tip });
+            }
+            debug!(%block_num, %chain_tip, "Partial sync applied, continuing to the chain tip");
+        }
+
+        Ok(sync_summary.expect("the sync loop runs at least once"))
+    }
+
+    /// Runs a single sync step of [`Client::sync_chain`] and applies it, returning the block the
+    /// client synced to, the node's chain tip and the step's summary.
+    async fn sync_chain_step(
+        &mut self,
+    ) -> Result<(BlockNumber, BlockNumber, SyncSummary), ClientError> {
         // Each `NoteObserver` owns its own per-sync state; `with_note_observer` just attaches.
         let note_screener = self.note_screener();
         let state_sync =
@@ -131,10 +267,14 @@ where
 
         // Get the sync update from the network
         let state_sync_update = state_sync.sync_state(&mut partial_mmr, input).await?;
+        let (block_num, chain_tip) = (state_sync_update.block_num, state_sync_update.chain_tip);
 
         let sync_summary: SyncSummary = (&state_sync_update).into();
         debug!(sync_summary = ?sync_summary, "Sync summary computed");
 
+        // Reject the update before it reaches the observers or the store if the verifier fails.
+        self.verify_sync_update_headers(&state_sync_update).await?;
+
         // Post-sync observer hooks; run before persisting. Per-observer errors are logged, not
         // propagated.
         state_sync.run_apply_hooks(&state_sync_update).await?;
@@ -151,8 +291,48 @@ where
         self.cache_partial_mmr(partial_mmr).await?;
 
         self.maybe_untrack_and_prune_irrelevant_blocks().await?;
+        self.maybe_apply_pruning_policy().await?;
+
+        Ok((block_num, chain_tip, sync_summary))
+    }
 
-        Ok(sync_summary)
+    /// Fetches the next state sync update from the node and returns the [`SyncSummary`] that
+    /// [`Client::sync_chain`] would report, without applying it.
+    ///
+    /// This still makes the same network requests as a real sync, but no local state is advanced:
+    /// the store, the sync height and the cached partial MMR are left untouched, and sync
+    /// observers are not run. The Note Transport Layer isn't queried, so
+    /// [`SyncSummary::new_private_notes`] is always empty.
+    ///
+    /// The configured [`BlockVerifier`] is run over the update, so a header it rejects makes the
+    /// preview fail as the actual sync would.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError::BlockHeaderNotFound`] if the client hasn't stored the genesis block
+    /// yet, which happens on its first sync.
+    pub async fn preview_sync(&self) -> Result<SyncSummary, ClientError> {
+        if self.rpc_api.has_genesis_commitment().is_none() {
+            let (genesis, _) = self
+                .store
+                .get_block_header_by_num(BlockNumber::GENESIS)
+                .await?
+                .ok_or(StoreError::BlockHeaderNotFound(BlockNumber::GENESIS))?;
+            self.rpc_api.set_genesis_commitment(genesis.commitment()).await?;
+        }
+
+        let note_screener = self.note_screener();
+        let state_sync =
+            StateSync::new(self.rpc_api.clone(), Arc::new(note_screener), self.tx_discard_delta)
+                .with_note_observer(Arc::new(PswapChainObserver::new(self.store.clone())));
+        let input = self.build_sync_input().await?;
+
+        // The sync mutates the MMR it's given, so work on a copy.
+        let mut partial_mmr = self.get_current_partial_mmr().await?;
+        let state_sync_update = state_sync.sync_state(&mut partial_mmr, input).await?;
+        self.verify_sync_update_headers(&state_sync_update).await?;
+
+        Ok((&state_sync_update).into())
     }
 
     /// Fetches private notes from the Note Transport Layer for the tracked note tags.
@@ -187,11 +367,87 @@ where
     ///
     /// Fails fast on the first error. Private notes delivered via NTL are imported before the
     /// chain sync reads its input set, so their nullifiers are checked in the same call.
+    ///
+    /// The sync runs inside a `sync_state` tracing span carrying a `correlation_id` field, with
+    /// `sync_note_transport` and `sync_chain` child spans.
     pub async fn sync_state(&mut self) -> Result<SyncSummary, ClientError> {
-        let new_private_notes = self.sync_note_transport().await?;
-        let mut summary = self.sync_chain().await?;
-        summary.new_private_notes = new_private_notes;
-        Ok(summary)
+        self.sync_state_with_progress(|_, _| {}).await
+    }
+
+    /// Same as [`Client::sync_state`], but calls `progress` with the block the client synced to
+    /// and the node's chain tip after each step of the chain sync (see
+    /// [`Client::sync_chain_with_progress`]).
+    pub async fn sync_state_with_progress(
+        &mut self,
+        progress: impl FnMut(BlockNumber, BlockNumber),
+    ) -> Result<SyncSummary, ClientError> {
+        let span = info_span!("sync_state", correlation_id = next_correlation_id());
+
+        async {
+            let new_private_notes =
+                self.sync_note_transport().instrument(info_span!("sync_note_transport")).await?;
+            let mut summary = self
+                .sync_chain_with_progress(progress)
+                .instrument(info_span!("sync_chain"))
+                .await?;
+            summary.new_private_notes = new_private_notes;
+            Ok::<_, ClientError>(summary)
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// Waits until the input note with the given ID is committed, syncing with the node in
+    /// between checks.
+    ///
+    /// Returns immediately if the note is already in the
+    /// [`InputNoteState::Committed`](crate::store::InputNoteState::Committed) state. Otherwise,
+    /// runs [`Client::sync_state`] repeatedly, backing off exponentially between attempts, until
+    /// the note becomes committed or `timeout` elapses.
+    ///
+    /// Note that this drives real syncs, so the client's local state is updated as a side effect
+    /// (exactly as if [`Client::sync_state`] had been called by hand).
+    ///
+    /// # Errors
+    ///
+    /// - Returns [`ClientError::Timeout`] if the note is not committed before `timeout` elapses.
+    ///   This includes notes that are not tracked by the client.
+    /// - Propagates any error returned by the underlying syncs.
+    #[cfg(feature = "std")]
+    pub async fn wait_for_note_commitment(
+        &mut self,
+        note_id: NoteId,
+        timeout: core::time::Duration,
+    ) -> Result<(), ClientError> {
+        use core::time::Duration;
+
+        use tokio::time::Instant;
+
+        const INITIAL_BACKOFF: Duration = Duration::from_millis(250);
+        const MAX_BACKOFF: Duration = Duration::from_secs(5);
+
+        let deadline = Instant::now() + timeout;
+        let mut backoff = INITIAL_BACKOFF;
+
+        if self.get_input_note(note_id).await?.is_some_and(|note| note.is_committed()) {
+            return Ok(());
+        }
+
+        loop {
+            if Instant::now() >= deadline {
+                return Err(ClientError::Timeout(timeout));
+            }
+
+            self.sync_state().await?;
+
+            if self.get_input_note(note_id).await?.is_some_and(|note| note.is_committed()) {
+                return Ok(());
+            }
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            tokio::time::sleep(backoff.min(remaining)).await;
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        }
     }
 
     /// Builds a default [`StateSyncInput`] from the current client state.
@@ -224,18 +480,38 @@ where
         })
     }
 
-    /// Applies the state sync update to the store and prunes irrelevant blocks according to the
-    /// configured cadence.
+    /// Applies the state sync update to the store and prunes irrelevant blocks and other stale
+    /// data according to the configured cadence and [`PruningPolicy`].
+    ///
+    /// The update's block headers are checked by the configured [`BlockVerifier`] first, and
+    /// nothing is applied if any of them is rejected.
     ///
     /// See [`crate::Store::apply_state_sync()`] for what the update implies.
     pub async fn apply_state_sync(&mut self, update: StateSyncUpdate) -> Result<(), ClientError> {
+        self.verify_sync_update_headers(&update).await?;
+
         self.store.apply_state_sync(update).await?;
 
         self.maybe_untrack_and_prune_irrelevant_blocks().await?;
+        self.maybe_apply_pruning_policy().await?;
 
         Ok(())
     }
 
+    /// Runs the configured [`BlockVerifier`] over the block headers of a state sync update.
+    async fn verify_sync_update_headers(
+        &self,
+        update: &StateSyncUpdate,
+    ) -> Result<(), ClientError> {
+        let block_headers: Vec<_> = update
+            .partial_blockchain_updates
+            .block_headers()
+            .map(|(block_header, _)| block_header.clone())
+            .collect();
+
+        self.verify_block_headers(&block_headers).await
+    }
+
     /// Prunes irrelevant blocks and their MMR authentication nodes according to the configured
     /// cadence.
     async fn maybe_untrack_and_prune_irrelevant_blocks(&mut self) -> Result<(), ClientError> {
@@ -257,6 +533,42 @@ where
         Ok(())
     }
 
+    /// Prunes the data covered by the configured [`PruningPolicy`] once the sync height has
+    /// advanced by the policy's interval since the last run.
+    async fn maybe_apply_pruning_policy(&mut self) -> Result<(), ClientError> {
+        let Some(policy) = self.pruning_policy else {
+            return Ok(());
+        };
+
+        let sync_height = self.store.get_sync_height().await?;
+
+        if let Some(last_run_height) = self.last_pruning_sync_height
+            && sync_height < last_run_height + policy.interval().get()
+        {
+            return Ok(());
+        }
+
+        let mut pruned_notes = 0;
+        if let Some(retention) = policy.consumed_notes_retention() {
+            let before = BlockNumber::from(sync_height.as_u32().saturating_sub(retention));
+            pruned_notes = self.store.prune_consumed_notes(before).await?;
+        }
+
+        let mut untracked_blocks = 0;
+        if policy.prunes_irrelevant_blocks() {
+            untracked_blocks = self.untrack_and_prune_irrelevant_blocks().await?;
+            self.last_irrelevant_block_prune_sync_height = Some(sync_height);
+        }
+
+        self.last_pruning_sync_height = Some(sync_height);
+        info!(
+            sync_height = sync_height.as_u32(),
+            pruned_notes, untracked_blocks, "Applied pruning policy"
+        );
+
+        Ok(())
+    }
+
     /// Prunes irrelevant block data from the store.
     ///
     /// Identifies tracked blocks whose input notes have all been consumed, untracks them from the
@@ -264,7 +576,9 @@ where
     /// to [`Store::untrack_and_prune_irrelevant_blocks`] to atomically remove the stale nodes,
     /// mark the blocks as irrelevant, and delete irrelevant block headers.
     /// Any caller of this function should've cached the `PartialMmr` beforehand.
-    async fn untrack_and_prune_irrelevant_blocks(&mut self) -> Result<(), ClientError> {
+    ///
+    /// Returns the number of blocks that were untracked.
+    async fn untrack_and_prune_irrelevant_blocks(&mut self) -> Result<usize, ClientError> {
         let tracked_blocks = self.store.get_tracked_block_header_numbers().await?;
         let to_untrack: Vec<usize> = if tracked_blocks.is_empty() {
             // Do not early-return: even without blocks to untrack, old irrelevant tip headers may
@@ -311,7 +625,7 @@ where
             self.cache_partial_mmr(partial_mmr).await?;
         }
 
-        Ok(())
+        Ok(blocks_to_untrack.len())
     }
 
     /// Ensures that the RPC limits are set in the RPC client. If not already cached,
@@ -325,6 +639,257 @@ where
         self.store.set_rpc_limits(limits).await?;
         Ok(())
     }
+
+    // INCREMENTAL ACCOUNT SYNC
+    // --------------------------------------------------------------------------------------------
+
+    /// Brings the vault of a tracked public account up to the chain tip by fetching only the vault
+    /// changes committed since it was last synced, and returns the applied [`AccountVaultDelta`].
+    ///
+    /// Unlike [`Client::sync_state`], the vault is never refetched in full, which keeps syncing
+    /// accounts with very large vaults cheap. The changes are requested with
+    /// [`NodeRpcClient::sync_account_vault`](crate::rpc::NodeRpcClient::sync_account_vault)
+    /// starting after the account's vault sync cursor, which is persisted per account and
+    /// defaults to the client's sync height. The account header returned by the node is verified
+    /// against the chain tip's account root, and the rebuilt account must match its commitment.
+    ///
+    /// Returns an empty delta when the account didn't change on chain, or when the local state is
+    /// ahead of the node's (e.g. a local transaction is still pending).
+    ///
+    /// # Errors
+    ///
+    /// - [`ClientError::AccountDataNotFound`] if the account isn't tracked.
+    /// - [`ClientError::AccountIsPrivate`] if the account is private.
+    /// - [`ClientError::AccountVaultSyncIncomplete`] if parts of the account other than its vault
+    ///   changed on chain; [`Client::sync_state`] needs to be used to fetch them.
+    pub async fn sync_account_vault_incremental(
+        &mut self,
+        account_id: AccountId,
+    ) -> Result<AccountVaultDelta, ClientError> {
+        let account = self.try_get_account(account_id).await?;
+        let cursor_key = account_vault_sync_cursor_setting_key(account_id);
+        let Some(target) = self.prepare_incremental_sync(account, &cursor_key).await? else {
+            return Ok(AccountVaultDelta::default());
+        };
+
+        // The lower bound is inclusive at the node, so request from `block_from + 1` to skip the
+        // block whose state we already have.
+        let vault_info = self
+            .rpc_api
+            .sync_account_vault(target.block_from + 1, target.block_to, account_id)
+            .await?;
+        let vault_delta = replay_vault_updates(target.account.vault(), &vault_info.updates)
+            .map_err(StoreError::from)?;
+
+        let applied = self
+            .apply_incremental_sync(
+                target,
+                cursor_key,
+                AccountStorageDelta::new(),
+                vault_delta.clone(),
+            )
+            .await?;
+        if !applied {
+            return Err(ClientError::AccountVaultSyncIncomplete(account_id));
+        }
+
+        Ok(vault_delta)
+    }
+
+    /// Brings a storage map of a tracked public account up to the chain tip by fetching only the
+    /// entries changed since it was last synced, and returns the applied [`AccountStorageDelta`].
+    ///
+    /// This is the storage map counterpart of [`Client::sync_account_vault_incremental`]: the
+    /// changes are requested with
+    /// [`NodeRpcClient::sync_storage_maps`](crate::rpc::NodeRpcClient::sync_storage_maps)
+    /// starting after the cursor persisted for the slot, which defaults to the client's sync
+    /// height, so large maps are never refetched in full.
+    ///
+    /// Returns an empty delta when the account didn't change on chain, or when the local state is
+    /// ahead of the node's (e.g. a local transaction is still pending).
+    ///
+    /// # Errors
+    ///
+    /// - [`ClientError::AccountDataNotFound`] if the account isn't tracked.
+    /// - [`ClientError::AccountIsPrivate`] if the account is private.
+    /// - [`ClientError::AccountError`] if the account has no storage map named `slot_name`.
+    /// - [`ClientError::StorageMapSyncIncomplete`] if parts of the account other than the storage
+    ///   map changed on chain; [`Client::sync_state`] needs to be used to fetch them.
+    pub async fn sync_storage_map_incremental(
+        &mut self,
+        account_id: AccountId,
+        slot_name: StorageSlotName,
+    ) -> Result<AccountStorageDelta, ClientError> {
+        let account = self.try_get_account(account_id).await?;
+        match account.storage().get(&slot_name).map(StorageSlot::content) {
+            Some(StorageSlotContent::Map(_)) => {},
+            Some(_) => return Err(AccountError::StorageSlotNotMap(slot_name).into()),
+            None => return Err(AccountError::StorageSlotNameNotFound { slot_name }.into()),
+        }
+
+        let cursor_key = storage_map_sync_cursor_setting_key(account_id, &slot_name);
+        let Some(target) = self.prepare_incremental_sync(account, &cursor_key).await? else {
+            return Ok(AccountStorageDelta::new());
+        };
+
+        // The node returns the updates of every map slot of the account; only the requested
+        // slot's are replayed.
+        let map_updates: Vec<StorageMapUpdate> = self
+            .rpc_api
+            .sync_storage_maps(target.block_from + 1, target.block_to, account_id)
+            .await?
+            .updates
+            .into_iter()
+            .filter(|update| update.slot_name == slot_name)
+            .collect();
+        let storage_delta = replay_storage_updates(target.account.storage(), &[], &map_updates)
+            .map_err(StoreError::from)?;
+
+        let applied = self
+            .apply_incremental_sync(
+                target,
+                cursor_key,
+                storage_delta.clone(),
+                AccountVaultDelta::default(),
+            )
+            .await?;
+        if !applied {
+            return Err(ClientError::StorageMapSyncIncomplete { account_id, slot_name });
+        }
+
+        Ok(storage_delta)
+    }
+
+    /// Determines the range an incremental sync of the public `account` has to cover: from the
+    /// block stored under `cursor_key` (or the sync height if there's none) to the chain tip.
+    ///
+    /// Returns `None` when there is nothing to apply, either because the account's state at the
+    /// chain tip is the local one (in which case the cursor is moved to the tip) or because the
+    /// local state is ahead of the node's.
+    async fn prepare_incremental_sync(
+        &mut self,
+        account: Account,
+        cursor_key: &str,
+    ) -> Result<Option<IncrementalSyncTarget>, ClientError> {
+        let account_id = account.id();
+        if !account_id.is_public() {
+            return Err(ClientError::AccountIsPrivate(account_id));
+        }
+
+        let block_from = match self.store.get_setting(cursor_key.to_string()).await? {
+            Some(bytes) => BlockNumber::from(u32::read_from_bytes(&bytes)?),
+            None => self.store.get_sync_height().await?,
+        };
+
+        let (chain_tip_header, _) = self.rpc_api.get_block_header_by_number(None, false).await?;
+        let block_to = chain_tip_header.block_num();
+        if block_to <= block_from {
+            return Ok(None);
+        }
+
+        let (proof_block_num, proof) = self
+            .rpc_api
+            .get_account(account_id, GetAccountRequest::new().at(AccountStateAt::Block(block_to)))
+            .await?;
+        let node_header = StateSync::validate_account_proof(
+            proof,
+            proof_block_num,
+            account_id,
+            &chain_tip_header,
+        )?
+        .header;
+
+        if node_header.to_commitment() == account.to_commitment() {
+            self.store
+                .set_setting(cursor_key.to_string(), block_to.as_u32().to_bytes())
+                .await?;
+            return Ok(None);
+        }
+        if node_header.nonce().as_canonical_u64() <= account.nonce().as_canonical_u64() {
+            return Ok(None);
+        }
+
+        Ok(Some(IncrementalSyncTarget {
+            account,
+            node_header,
+            block_from,
+            block_to,
+        }))
+    }
+
+    /// Applies the storage and vault changes of an incremental sync to the target account,
+    /// advancing its nonce to the node's. The new state is only persisted, along with the cursor
+    /// stored under `cursor_key`, if it matches the node's account header.
+    ///
+    /// Returns whether the new state was persisted.
+    async fn apply_incremental_sync(
+        &mut self,
+        target: IncrementalSyncTarget,
+        cursor_key: String,
+        storage_delta: AccountStorageDelta,
+        vault_delta: AccountVaultDelta,
+    ) -> Result<bool, ClientError> {
+        let IncrementalSyncTarget { mut account, node_header, block_to, .. } = target;
+
+        let nonce_delta =
+            Felt::new(node_header.nonce().as_canonical_u64() - account.nonce().as_canonical_u64())
+                .expect("the node's nonce was checked to be higher than the local one");
+        let account_delta =
+            AccountDelta::new(account.id(), storage_delta, vault_delta, nonce_delta)
+                .map_err(StoreError::from)?;
+        account.apply_delta(&account_delta)?;
+
+        if account.to_commitment() != node_header.to_commitment() {
+            return Ok(false);
+        }
+
+        self.store.update_account(&account).await?;
+        self.store.set_setting(cursor_key, block_to.as_u32().to_bytes()).await?;
+
+        Ok(true)
+    }
+}
+
+// INCREMENTAL SYNC TARGET
+// ================================================================================================
+
+/// State an incremental account sync starts from and the node state it has to reach.
+struct IncrementalSyncTarget {
+    /// Locally stored state of the account.
+    account: Account,
+    /// Header of the account at `block_to`, verified against the chain tip.
+    node_header: AccountHeader,
+    /// Block up to which the synced part of the account was last brought.
+    block_from: BlockNumber,
+    /// Chain tip the account is synced to.
+    block_to: BlockNumber,
+}
+
+// SYNC LAG
+// ================================================================================================
+
+/// Distance between the client's sync height and the node's chain tip, as returned by
+/// [`Client::sync_lag`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyncLag {
+    /// Block number up to which the client has been synced.
+    pub sync_height: BlockNumber,
+    /// The node's chain tip at the time of the request.
+    pub chain_tip: BlockNumber,
+}
+
+impl SyncLag {
+    /// Returns the number of blocks the next sync has to cover to reach the chain tip. The sync
+    /// only requests data for the blocks that are relevant to the client, so it's an upper bound
+    /// on the blocks it processes.
+    pub fn blocks_behind(&self) -> u32 {
+        self.chain_tip.as_u32().saturating_sub(self.sync_height.as_u32())
+    }
+
+    /// Returns `true` if the client is synced up to the chain tip.
+    pub fn is_synced(&self) -> bool {
+        self.blocks_behind() == 0
+    }
 }
 
 // SYNC SUMMARY
@@ -0,0 +1,69 @@
+//! Policy-driven pruning of the data the client no longer needs.
+//!
+//! A [`PruningPolicy`] set through
+//! [`ClientBuilder::pruning_policy`](crate::builder::ClientBuilder::pruning_policy) makes the
+//! client prune its store as part of syncing, at a coarser cadence than the sync itself, so that
+//! long-running clients don't need to call the individual prune methods manually.
+
+use core::num::NonZeroU32;
+
+// PRUNING POLICY
+// ================================================================================================
+
+/// Retention settings for the store maintenance the client runs after applying state syncs.
+///
+/// Pruning runs when the client's sync height has advanced by at least
+/// [`PruningPolicy::interval`] blocks since the last run (the first run happens on the first
+/// sync). Every category is disabled by default, so a policy only prunes what it's configured
+/// for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PruningPolicy {
+    interval: NonZeroU32,
+    consumed_notes_retention: Option<u32>,
+    prune_irrelevant_blocks: bool,
+}
+
+impl PruningPolicy {
+    /// Creates a policy that runs every `interval` synced blocks and doesn't prune anything until
+    /// configured to.
+    pub fn new(interval: NonZeroU32) -> Self {
+        Self {
+            interval,
+            consumed_notes_retention: None,
+            prune_irrelevant_blocks: false,
+        }
+    }
+
+    /// Keeps consumed input notes for `blocks` blocks after their consumption. Older consumed
+    /// notes are deleted with
+    /// [`Client::prune_consumed_notes`](crate::Client::prune_consumed_notes).
+    #[must_use]
+    pub fn with_consumed_notes_retention(mut self, blocks: u32) -> Self {
+        self.consumed_notes_retention = Some(blocks);
+        self
+    }
+
+    /// Prunes the block headers that no longer back any unspent note, along with the MMR
+    /// authentication nodes only they needed.
+    #[must_use]
+    pub fn with_irrelevant_block_pruning(mut self) -> Self {
+        self.prune_irrelevant_blocks = true;
+        self
+    }
+
+    /// Returns the number of synced blocks between pruning runs.
+    pub fn interval(&self) -> NonZeroU32 {
+        self.interval
+    }
+
+    /// Returns the number of blocks consumed notes are kept for, or `None` if they're never
+    /// pruned by the policy.
+    pub fn consumed_notes_retention(&self) -> Option<u32> {
+        self.consumed_notes_retention
+    }
+
+    /// Returns whether irrelevant blocks and their MMR authentication nodes are pruned.
+    pub fn prunes_irrelevant_blocks(&self) -> bool {
+        self.prune_irrelevant_blocks
+    }
+}
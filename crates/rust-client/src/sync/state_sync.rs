@@ -61,6 +61,9 @@ struct FetchedSyncData {
     mmr_delta: MmrDelta,
     /// Chain tip block header.
     chain_tip_header: BlockHeader,
+    /// The node's chain tip. It is past `chain_tip_header` when the node only served part of the
+    /// range.
+    node_chain_tip: BlockNumber,
     /// Blocks with matching notes that the client is interested in.
     note_blocks: Vec<NoteSyncBlock>,
     /// Content fetched for the synced notes (public note bodies and private-note attachments),
@@ -261,6 +264,7 @@ impl StateSync {
 
         let mut state_sync_update = StateSyncUpdate {
             block_num,
+            chain_tip: block_num,
             note_updates: NoteUpdateTracker::new(input_notes, output_notes),
             transaction_updates: TransactionUpdateTracker::new(uncommitted_transactions),
             ..Default::default()
@@ -274,6 +278,7 @@ impl StateSync {
         };
 
         state_sync_update.block_num = sync_data.chain_tip_header.block_num();
+        state_sync_update.chain_tip = sync_data.node_chain_tip;
 
         let new_commitments = derive_account_commitments(&sync_data.transactions);
         let superseded_states = self
@@ -328,6 +333,14 @@ impl StateSync {
         // Validate the response covers the range we requested.
         Self::validate_chain_mmr_response(&chain_mmr_info, current_block_num)?;
 
+        // No progress while the node reports more blocks: syncing again would loop forever.
+        if chain_tip == current_block_num && chain_mmr_info.chain_tip > current_block_num {
+            return Err(ClientError::SyncStalled {
+                block_num: current_block_num,
+                chain_tip: chain_mmr_info.chain_tip,
+            });
+        }
+
         // No progress — already at the tip.
         if chain_tip == current_block_num {
             info!(block_num = %current_block_num, "Already at chain tip, nothing to sync.");
@@ -382,6 +395,7 @@ impl StateSync {
         Ok(Some(FetchedSyncData {
             mmr_delta: chain_mmr_info.mmr_delta,
             chain_tip_header: chain_mmr_info.block_header,
+            node_chain_tip: chain_mmr_info.chain_tip,
             note_blocks,
             synced_notes,
             transactions: transaction_records,
@@ -406,6 +420,7 @@ impl StateSync {
         let FetchedSyncData {
             mmr_delta,
             chain_tip_header,
+            node_chain_tip: _,
             note_blocks,
             synced_notes,
             transactions,
@@ -903,7 +918,7 @@ impl StateSync {
     /// Validates that a `get_account` proof is bound to the sync target `chain_tip_header`: it must
     /// be for the requested `account_id`, at the target block, and its witness must open under the
     /// target header's account root. Returns the account details on success.
-    fn validate_account_proof(
+    pub(crate) fn validate_account_proof(
         proof: AccountProof,
         proof_block_num: BlockNumber,
         account_id: AccountId,
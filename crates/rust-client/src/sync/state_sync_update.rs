@@ -35,6 +35,9 @@ use crate::transaction::{DiscardCause, TransactionRecord, TransactionStatus};
 pub struct StateSyncUpdate {
     /// The block number of the last block that was synced.
     pub block_num: BlockNumber,
+    /// The node's chain tip when the update was fetched. It is higher than `block_num` when the
+    /// node only served part of the range.
+    pub chain_tip: BlockNumber,
     /// New blocks, authentication nodes and MMR peaks.
     pub partial_blockchain_updates: PartialBlockchainUpdates,
     /// New and updated notes to be upserted in the store.
@@ -482,7 +485,7 @@ impl PublicAccountDelta {
 // ================================================================================================
 
 /// Computes a storage delta by replaying incremental updates onto the locally-stored state.
-fn replay_storage_updates(
+pub(crate) fn replay_storage_updates(
     local_storage: &AccountStorage,
     value_slot_updates: &[(StorageSlotName, Word)],
     storage_map_updates: &[StorageMapUpdate],
@@ -517,7 +520,7 @@ fn replay_storage_updates(
 }
 
 /// Computes a vault delta by replaying incremental updates onto the locally-stored vault.
-fn replay_vault_updates(
+pub(crate) fn replay_vault_updates(
     local_vault: &AssetVault,
     vault_updates: &[AccountVaultUpdate],
 ) -> Result<AccountVaultDelta, AccountDeltaError> {
@@ -1,9 +1,10 @@
+use alloc::collections::BTreeSet;
 use alloc::string::ToString;
 use alloc::vec::Vec;
 
 use miden_protocol::Word;
 use miden_protocol::account::{Account, AccountId};
-use miden_protocol::note::{NoteDetailsCommitment, NoteTag};
+use miden_protocol::note::{NoteDetailsCommitment, NoteMetadata, NoteTag};
 use miden_tx::utils::serde::{
     ByteReader,
     ByteWriter,
@@ -15,7 +16,7 @@ use tracing::warn;
 
 use crate::Client;
 use crate::errors::ClientError;
-use crate::store::{InputNoteRecord, NoteRecordError};
+use crate::store::{InputNoteRecord, InputNoteState, NoteFilter, NoteRecordError};
 
 /// Tag management methods
 impl<AUTH> Client<AUTH> {
@@ -35,6 +36,17 @@ impl<AUTH> Client<AUTH> {
         self.store.get_note_tags().await.map_err(Into::into)
     }
 
+    /// Returns the same tag records as [`Client::get_note_tags`], each along with the number of
+    /// input notes the client has that carry its tag.
+    ///
+    /// Tags with a count of zero haven't produced any notes yet, which makes them candidates for
+    /// removal.
+    pub async fn note_tags_with_note_counts(
+        &self,
+    ) -> Result<Vec<(NoteTagRecord, usize)>, ClientError> {
+        self.store.get_note_tags_with_note_counts().await.map_err(Into::into)
+    }
+
     /// Adds a note tag for the client to track. This tag's source will be marked as `User`.
     pub async fn add_note_tag(&mut self, tag: NoteTag) -> Result<(), ClientError> {
         let added = self
@@ -60,6 +72,73 @@ impl<AUTH> Client<AUTH> {
 
         Ok(())
     }
+
+    /// Removes the note tags that are no longer needed and returns the number of tags removed.
+    ///
+    /// Only tags derived by the client are pruned. Such a tag is considered unused when:
+    /// - Its source is an account that is no longer tracked by the client.
+    /// - Its source is a note that is no longer tracked or has already been spent.
+    ///
+    /// Tags carried by unspent notes, including expected notes that haven't been received yet, are
+    /// never removed. Tags added by the user are only removed by the user, and subscription tags
+    /// are left to the subscriptions that registered them.
+    pub async fn prune_unused_note_tags(&mut self) -> Result<usize, ClientError> {
+        let tracked_accounts: BTreeSet<AccountId> =
+            self.store.get_account_ids().await?.into_iter().collect();
+        let unspent_notes = self.store.get_input_notes(NoteFilter::Unspent).await?;
+        let unspent_commitments: BTreeSet<NoteDetailsCommitment> =
+            unspent_notes.iter().map(InputNoteRecord::details_commitment).collect();
+        let needed_tags: BTreeSet<NoteTag> = unspent_notes.iter().filter_map(note_tag).collect();
+
+        let mut removed = 0;
+        for record in self.store.get_note_tags().await? {
+            let unused = match record.source {
+                NoteTagSource::Account(account_id) => {
+                    !tracked_accounts.contains(&account_id) && !needed_tags.contains(&record.tag)
+                },
+                NoteTagSource::Note(details_commitment) => {
+                    !unspent_commitments.contains(&details_commitment)
+                },
+                NoteTagSource::User | NoteTagSource::Subscription(_) => false,
+            };
+
+            if unused {
+                removed += self.store.remove_note_tag(record).await?;
+            }
+        }
+
+        Ok(removed)
+    }
+
+    /// Atomically replaces a tag added by the user with a new one, so that notes matching either
+    /// tag are never left untracked in between.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `old` isn't being tracked as a user tag. In that case no tags are
+    /// changed. Replacing a tracked tag with itself leaves it tracked.
+    pub async fn replace_note_tag(
+        &mut self,
+        old: NoteTag,
+        new: NoteTag,
+    ) -> Result<(), ClientError> {
+        self.store
+            .replace_note_tag(
+                NoteTagRecord { tag: old, source: NoteTagSource::User },
+                NoteTagRecord { tag: new, source: NoteTagSource::User },
+            )
+            .await
+            .map_err(Into::into)
+    }
+}
+
+/// Returns the tag of an input note, taken from its metadata or, for expected notes imported
+/// without metadata, from the expected state.
+fn note_tag(note: &InputNoteRecord) -> Option<NoteTag> {
+    note.metadata().map(NoteMetadata::tag).or(match note.state() {
+        InputNoteState::Expected(state) => state.tag,
+        _ => None,
+    })
 }
 
 /// Represents a note tag of which the Store can keep track and retrieve.
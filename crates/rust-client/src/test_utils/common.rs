@@ -225,6 +225,9 @@ pub async fn wait_for_tx(client: &mut TestClient, transaction_id: TransactionId)
             TransactionStatus::Discarded(cause) => {
                 anyhow::bail!("transaction was discarded with cause: {cause:?}");
             },
+            TransactionStatus::Failed { reason } => {
+                anyhow::bail!("transaction failed to execute: {reason}");
+            },
         }
 
         // Log wait time in a file if the env var is set
@@ -1,16 +1,18 @@
 use alloc::boxed::Box;
-use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::collections::{BTreeMap, BTreeSet, VecDeque};
 use alloc::sync::Arc;
 use alloc::vec::Vec;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use core::time::Duration;
 
 use miden_protocol::Word;
 use miden_protocol::account::delta::AccountUpdateDetails;
 use miden_protocol::account::{AccountId, StorageSlot, StorageSlotContent, StorageSlotType};
 use miden_protocol::address::NetworkId;
 use miden_protocol::batch::{ProposedBatch, ProvenBatch};
-use miden_protocol::block::{BlockHeader, BlockNumber, ProvenBlock};
+use miden_protocol::block::{BlockHeader, BlockNumber, FeeParameters, ProvenBlock};
 use miden_protocol::crypto::merkle::mmr::{Forest, Mmr, MmrProof};
-use miden_protocol::note::{NoteAttachments, NoteHeader, NoteId, NoteScript, NoteTag};
+use miden_protocol::note::{NoteAttachments, NoteHeader, NoteId, NoteScript, NoteTag, Nullifier};
 use miden_protocol::transaction::{ProvenTransaction, TransactionInputs};
 use miden_testing::{MockChain, MockChainNote};
 use miden_tx::utils::sync::RwLock;
@@ -34,7 +36,7 @@ use crate::rpc::domain::status::NetworkNoteStatusInfo;
 use crate::rpc::domain::storage_map::{StorageMapInfo, StorageMapUpdate};
 use crate::rpc::domain::sync::{ChainMmrInfo, SyncTarget};
 use crate::rpc::domain::transaction::TransactionRecord;
-use crate::rpc::{AccountStateAt, NodeRpcClient, RpcError, RpcStatusInfo};
+use crate::rpc::{AccountStateAt, GrpcError, NodeRpcClient, RpcEndpoint, RpcError, RpcStatusInfo};
 
 pub type MockClient<AUTH> = Client<AUTH>;
 
@@ -59,6 +61,29 @@ pub struct MockRpcApi {
     /// notes without their attachment content (only metadata), so tests that need
     /// `get_notes_by_id` to return private-note attachments register them here.
     private_note_attachments: Arc<RwLock<BTreeMap<NoteId, NoteAttachments>>>,
+    /// Block headers served in place of the chain's ones, keyed by block number.
+    scripted_block_headers: Arc<RwLock<BTreeMap<BlockNumber, BlockHeader>>>,
+    /// Notes reported by `sync_notes` in addition to the ones committed to the [`MockChain`].
+    scripted_notes: Arc<RwLock<Vec<CommittedNote>>>,
+    /// Nullifiers reported by `sync_nullifiers` in addition to the ones in the [`MockChain`]'s
+    /// nullifier tree.
+    scripted_nullifiers: Arc<RwLock<Vec<NullifierUpdate>>>,
+    /// Chain tips to report, in order, on successive `sync_chain_mmr` calls. Once the queue is
+    /// exhausted, the actual chain tip is reported.
+    scripted_sync_tips: Arc<RwLock<VecDeque<BlockNumber>>>,
+    /// Maximum number of blocks served by a single `sync_chain_mmr` response, if any. Responses
+    /// covering less than the full range still report the actual chain tip.
+    sync_page_size: Arc<RwLock<Option<u32>>>,
+    /// Number of `get_account` requests served so far.
+    get_account_calls: Arc<AtomicUsize>,
+    /// Number of `get_fee_parameters` requests served so far.
+    get_fee_parameters_calls: Arc<AtomicUsize>,
+    /// Artificial latency added to calls to each endpoint.
+    latencies: Arc<RwLock<BTreeMap<RpcEndpoint, Duration>>>,
+    /// Timeout applied to calls without an operation-specific timeout, if any.
+    default_timeout: Arc<RwLock<Option<Duration>>>,
+    /// Per-endpoint timeouts overriding the default one.
+    operation_timeouts: Arc<RwLock<BTreeMap<RpcEndpoint, Duration>>>,
 }
 
 impl Default for MockRpcApi {
@@ -79,9 +104,33 @@ impl MockRpcApi {
             oversize_threshold: 1000,
             erased_notes: Arc::new(RwLock::new(Vec::new())),
             private_note_attachments: Arc::new(RwLock::new(BTreeMap::new())),
+            scripted_block_headers: Arc::new(RwLock::new(BTreeMap::new())),
+            scripted_notes: Arc::new(RwLock::new(Vec::new())),
+            scripted_nullifiers: Arc::new(RwLock::new(Vec::new())),
+            scripted_sync_tips: Arc::new(RwLock::new(VecDeque::new())),
+            sync_page_size: Arc::new(RwLock::new(None)),
+            get_account_calls: Arc::new(AtomicUsize::new(0)),
+            get_fee_parameters_calls: Arc::new(AtomicUsize::new(0)),
+            latencies: Arc::new(RwLock::new(BTreeMap::new())),
+            default_timeout: Arc::new(RwLock::new(None)),
+            operation_timeouts: Arc::new(RwLock::new(BTreeMap::new())),
         }
     }
 
+    /// Returns a [`MockRpcClientBuilder`] for the provided [`MockChain`].
+    pub fn builder(mock_chain: MockChain) -> MockRpcClientBuilder {
+        MockRpcClientBuilder::new(mock_chain)
+    }
+
+    /// Queues a chain tip to be reported by the next `sync_chain_mmr` call that isn't already
+    /// scripted. The reported tip is capped to the actual chain tip.
+    ///
+    /// This allows tests to simulate a node that lags behind the mock chain, e.g. to reproduce
+    /// notes committed between two consecutive syncs.
+    pub fn queue_sync_tip(&self, block_num: BlockNumber) {
+        self.scripted_sync_tips.write().push_back(block_num);
+    }
+
     /// Registers the attachment content for a private note so that subsequent `get_notes_by_id`
     /// responses include it, mirroring a node that stores private-note attachments on-chain.
     pub fn register_private_note_attachments(&self, note_id: NoteId, attachments: NoteAttachments) {
@@ -97,11 +146,60 @@ impl MockRpcApi {
         self
     }
 
+    /// Returns the number of `get_account` requests served so far.
+    pub fn get_account_call_count(&self) -> usize {
+        self.get_account_calls.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of `get_fee_parameters` requests served so far.
+    pub fn get_fee_parameters_call_count(&self) -> usize {
+        self.get_fee_parameters_calls.load(Ordering::Relaxed)
+    }
+
+    /// Adds an artificial latency to subsequent calls to the provided endpoint. Calls whose
+    /// configured timeout is shorter than the latency fail with a
+    /// [`GrpcError::DeadlineExceeded`] error, like a real node that takes too long to respond.
+    ///
+    /// Only `sync_notes`, `sync_chain_mmr`, `get_block_header_by_number`, `get_notes_by_id` and
+    /// `get_account` are delayed.
+    pub fn set_latency(&self, endpoint: RpcEndpoint, latency: Duration) {
+        self.latencies.write().insert(endpoint, latency);
+    }
+
     /// Registers a note header to be reported as erased in subsequent sync transaction responses.
     pub fn mark_note_as_erased(&self, header: NoteHeader) {
         self.erased_notes.write().push(header);
     }
 
+    /// Waits for the latency configured for `endpoint`, failing if it exceeds the timeout that
+    /// applies to the endpoint.
+    async fn simulate_latency(&self, endpoint: RpcEndpoint) -> Result<(), RpcError> {
+        let Some(latency) = self.latencies.read().get(&endpoint).copied() else {
+            return Ok(());
+        };
+        let timeout = self
+            .operation_timeouts
+            .read()
+            .get(&endpoint)
+            .copied()
+            .or(*self.default_timeout.read());
+
+        if let Some(timeout) = timeout.filter(|timeout| *timeout < latency) {
+            #[cfg(feature = "std")]
+            tokio::time::sleep(timeout).await;
+            return Err(RpcError::RequestError {
+                endpoint,
+                error_kind: GrpcError::DeadlineExceeded,
+                endpoint_error: None,
+                source: None,
+            });
+        }
+
+        #[cfg(feature = "std")]
+        tokio::time::sleep(latency).await;
+        Ok(())
+    }
+
     /// Returns the current MMR of the blockchain.
     pub fn get_mmr(&self) -> Mmr {
         self.mock_chain.read().blockchain().as_mmr().clone()
@@ -130,8 +228,11 @@ impl MockRpcApi {
         }
     }
 
-    /// Retrieves a block by its block number.
+    /// Retrieves a block by its block number, preferring a scripted header over the chain's one.
     fn get_block_by_num(&self, block_num: BlockNumber) -> BlockHeader {
+        if let Some(header) = self.scripted_block_headers.read().get(&block_num) {
+            return header.clone();
+        }
         self.mock_chain.read().block_header(block_num.as_usize())
     }
 
@@ -316,6 +417,23 @@ impl NodeRpcClient for MockRpcApi {
         None
     }
 
+    fn set_default_timeout(&self, timeout: Duration) {
+        self.default_timeout.write().replace(timeout);
+    }
+
+    fn set_operation_timeout(&self, endpoint: RpcEndpoint, timeout: Option<Duration>) {
+        let mut operation_timeouts = self.operation_timeouts.write();
+        match timeout {
+            Some(timeout) => operation_timeouts.insert(endpoint, timeout),
+            None => operation_timeouts.remove(&endpoint),
+        };
+    }
+
+    async fn get_genesis_commitment(&self) -> Result<Word, RpcError> {
+        let mock_chain = self.mock_chain.read();
+        Ok(mock_chain.block_header(BlockNumber::GENESIS.as_usize()).commitment())
+    }
+
     async fn set_genesis_commitment(&self, _commitment: Word) -> Result<(), RpcError> {
         // The mock client doesn't use accept headers, so we don't need to do anything here.
         Ok(())
@@ -329,6 +447,8 @@ impl NodeRpcClient for MockRpcApi {
         block_to: BlockNumber,
         note_tags: &BTreeSet<NoteTag>,
     ) -> Result<Vec<NoteSyncBlock>, RpcError> {
+        self.simulate_latency(RpcEndpoint::SyncNotes).await?;
+
         let mut blocks_with_notes: BTreeMap<BlockNumber, BTreeMap<NoteId, CommittedNote>> =
             BTreeMap::new();
         for note in self.mock_chain.read().committed_notes().values() {
@@ -342,6 +462,16 @@ impl NodeRpcClient for MockRpcApi {
                 blocks_with_notes.entry(note_block).or_default().insert(note.id(), committed);
             }
         }
+        for note in self.scripted_notes.read().iter() {
+            let note_block = note.inclusion_proof().location().block_num();
+            if note_tags.contains(&note.tag()) && note_block >= block_from && note_block <= block_to
+            {
+                blocks_with_notes
+                    .entry(note_block)
+                    .or_default()
+                    .insert(*note.note_id(), note.clone());
+            }
+        }
 
         Ok(blocks_with_notes
             .into_iter()
@@ -358,11 +488,22 @@ impl NodeRpcClient for MockRpcApi {
         current_block_height: BlockNumber,
         upper_bound: SyncTarget,
     ) -> Result<ChainMmrInfo, RpcError> {
+        self.simulate_latency(RpcEndpoint::SyncChainMmr).await?;
+
         let chain_tip = self.get_chain_tip_block_num();
         // The mock chain doesn't distinguish committed vs proven tips.
         let target_block = match upper_bound {
             SyncTarget::CommittedChainTip | SyncTarget::ProvenChainTip => chain_tip,
         };
+        let target_block = match self.scripted_sync_tips.write().pop_front() {
+            Some(scripted_tip) => scripted_tip.min(target_block).max(current_block_height),
+            None => target_block,
+        };
+        let reported_chain_tip = target_block;
+        let target_block = match *self.sync_page_size.read() {
+            Some(page_size) => target_block.min(current_block_height + page_size),
+            None => target_block,
+        };
 
         let from_forest = if current_block_height == target_block {
             target_block.as_usize()
@@ -385,6 +526,7 @@ impl NodeRpcClient for MockRpcApi {
             block_to: target_block,
             mmr_delta,
             block_header,
+            chain_tip: reported_chain_tip,
         })
     }
 
@@ -395,11 +537,10 @@ impl NodeRpcClient for MockRpcApi {
         block_num: Option<BlockNumber>,
         include_mmr_proof: bool,
     ) -> Result<(BlockHeader, Option<MmrProof>), RpcError> {
-        let block = if let Some(block_num) = block_num {
-            self.mock_chain.read().block_header(block_num.as_usize())
-        } else {
-            self.mock_chain.read().latest_block_header()
-        };
+        self.simulate_latency(RpcEndpoint::GetBlockHeaderByNumber).await?;
+
+        let block =
+            self.get_block_by_num(block_num.unwrap_or_else(|| self.get_chain_tip_block_num()));
 
         let mmr_proof = if include_mmr_proof {
             Some(self.get_mmr().open(block_num.unwrap().as_usize()).unwrap())
@@ -410,8 +551,16 @@ impl NodeRpcClient for MockRpcApi {
         Ok((block, mmr_proof))
     }
 
+    /// Returns the fee parameters of the latest block of the mock chain.
+    async fn get_fee_parameters(&self) -> Result<FeeParameters, RpcError> {
+        self.get_fee_parameters_calls.fetch_add(1, Ordering::Relaxed);
+        Ok(self.mock_chain.read().latest_block_header().fee_parameters().clone())
+    }
+
     /// Returns the node's tracked notes that match the provided note IDs.
     async fn get_notes_by_id(&self, note_ids: &[NoteId]) -> Result<Vec<FetchedNote>, RpcError> {
+        self.simulate_latency(RpcEndpoint::GetNotesById).await?;
+
         // assume all public notes for now
         let notes = self.mock_chain.read().committed_notes().clone();
 
@@ -489,6 +638,9 @@ impl NodeRpcClient for MockRpcApi {
         account_id: AccountId,
         request: GetAccountRequest,
     ) -> Result<(BlockNumber, AccountProof), RpcError> {
+        self.get_account_calls.fetch_add(1, Ordering::Relaxed);
+        self.simulate_latency(RpcEndpoint::GetAccount).await?;
+
         let mock_chain = self.mock_chain.read();
 
         let block_number = match request.at {
@@ -591,6 +743,17 @@ impl NodeRpcClient for MockRpcApi {
                     None
                 }
             })
+            .chain(
+                self.scripted_nullifiers
+                    .read()
+                    .iter()
+                    .filter(|update| {
+                        prefixes.contains(&update.nullifier.prefix())
+                            && update.block_num >= block_from
+                            && update.block_num <= block_to
+                    })
+                    .cloned(),
+            )
             .collect::<Vec<_>>();
 
         Ok(nullifiers)
@@ -722,6 +885,138 @@ impl NodeRpcClient for MockRpcApi {
     }
 }
 
+// MOCK RPC CLIENT BUILDER
+// ================================================================================================
+
+/// Builder for [`MockRpcApi`] instances with scripted responses.
+///
+/// The chain state is provided by a [`MockChain`], while the builder controls how the mock node
+/// exposes it: which chain tip each sync observes, which notes are reported as erased, and which
+/// attachments are returned for private notes. Block headers, notes and nullifiers can also be
+/// scripted on top of the chain's ones, to reproduce node responses that are hard to obtain by
+/// executing transactions against the [`MockChain`].
+///
+/// # Example
+///
+/// ```rust,ignore
+/// // The first sync only sees up to block 2, the following ones see the actual chain tip.
+/// let rpc_api = MockRpcClientBuilder::new(mock_chain).with_sync_tip(2.into()).build();
+/// ```
+pub struct MockRpcClientBuilder {
+    mock_chain: MockChain,
+    oversize_threshold: Option<usize>,
+    erased_notes: Vec<NoteHeader>,
+    private_note_attachments: BTreeMap<NoteId, NoteAttachments>,
+    block_headers: BTreeMap<BlockNumber, BlockHeader>,
+    notes: Vec<CommittedNote>,
+    nullifiers: Vec<NullifierUpdate>,
+    sync_tips: VecDeque<BlockNumber>,
+    sync_page_size: Option<u32>,
+}
+
+impl MockRpcClientBuilder {
+    /// Creates a new builder backed by the provided [`MockChain`].
+    pub fn new(mock_chain: MockChain) -> Self {
+        Self {
+            mock_chain,
+            oversize_threshold: None,
+            erased_notes: Vec::new(),
+            private_note_attachments: BTreeMap::new(),
+            block_headers: BTreeMap::new(),
+            notes: Vec::new(),
+            nullifiers: Vec::new(),
+            sync_tips: VecDeque::new(),
+            sync_page_size: None,
+        }
+    }
+
+    /// Sets the oversize threshold used by `get_account`. See
+    /// [`MockRpcApi::with_oversize_threshold`].
+    #[must_use]
+    pub fn with_oversize_threshold(mut self, threshold: usize) -> Self {
+        self.oversize_threshold = Some(threshold);
+        self
+    }
+
+    /// Reports the note header as erased in sync transaction responses.
+    #[must_use]
+    pub fn with_erased_note(mut self, header: NoteHeader) -> Self {
+        self.erased_notes.push(header);
+        self
+    }
+
+    /// Returns the provided attachments for the private note in `get_notes_by_id` responses.
+    #[must_use]
+    pub fn with_private_note_attachments(
+        mut self,
+        note_id: NoteId,
+        attachments: NoteAttachments,
+    ) -> Self {
+        self.private_note_attachments.insert(note_id, attachments);
+        self
+    }
+
+    /// Serves `header` in place of the chain's header for the same block number, both in
+    /// `get_block_header_by_number` responses and in the sync responses that include it.
+    #[must_use]
+    pub fn with_block(mut self, header: BlockHeader) -> Self {
+        self.block_headers.insert(header.block_num(), header);
+        self
+    }
+
+    /// Reports the note in `sync_notes` responses covering the block of its inclusion proof, in
+    /// addition to the notes committed to the chain.
+    #[must_use]
+    pub fn with_note(mut self, note: CommittedNote) -> Self {
+        self.notes.push(note);
+        self
+    }
+
+    /// Reports the nullifier as created at `block_num` in `sync_nullifiers` responses, in
+    /// addition to the nullifiers of the chain.
+    #[must_use]
+    pub fn with_nullifier(mut self, nullifier: Nullifier, block_num: BlockNumber) -> Self {
+        self.nullifiers.push(NullifierUpdate { nullifier, block_num });
+        self
+    }
+
+    /// Appends a chain tip to the sequence reported by successive syncs. The first call to this
+    /// method scripts the first sync, the second call the second sync, and so on. Syncs after the
+    /// scripted sequence observe the actual chain tip.
+    #[must_use]
+    pub fn with_sync_tip(mut self, block_num: BlockNumber) -> Self {
+        self.sync_tips.push_back(block_num);
+        self
+    }
+
+    /// Caps the number of blocks served by each `sync_chain_mmr` response, simulating a node that
+    /// serves long syncs in several pages. A page size of `0` simulates a node that makes no
+    /// progress.
+    #[must_use]
+    pub fn with_sync_page_size(mut self, page_size: u32) -> Self {
+        self.sync_page_size = Some(page_size);
+        self
+    }
+
+    /// Builds the [`MockRpcApi`].
+    pub fn build(self) -> MockRpcApi {
+        let mut rpc_api = MockRpcApi::new(self.mock_chain);
+        if let Some(threshold) = self.oversize_threshold {
+            rpc_api = rpc_api.with_oversize_threshold(threshold);
+        }
+
+        *rpc_api.erased_notes.write() = self.erased_notes;
+        *rpc_api.private_note_attachments.write() = self.private_note_attachments;
+        *rpc_api.scripted_block_headers.write() = self.block_headers;
+        *rpc_api.scripted_notes.write() = self.notes;
+        *rpc_api.scripted_nullifiers.write() = self.nullifiers;
+        *rpc_api.scripted_sync_tips.write() = self.sync_tips;
+        *rpc_api.sync_page_size.write() = self.sync_page_size;
+
+        rpc_api
+    }
+}
+
 // CONVERSIONS
 // ================================================================================================
 
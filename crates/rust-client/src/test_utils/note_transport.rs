@@ -1,5 +1,5 @@
 use alloc::boxed::Box;
-use alloc::collections::BTreeMap;
+use alloc::collections::{BTreeMap, VecDeque};
 use alloc::string::ToString;
 use alloc::sync::Arc;
 use alloc::vec::Vec;
@@ -126,14 +126,48 @@ impl Default for MockNoteTransportNode {
 /// Mock Note Transport API
 ///
 /// Simulates communications with the note transport node.
+///
+/// Fetches can be scripted to reproduce delivery timing issues: a fetch can return a scripted
+/// response instead of querying the node, and notes can be delivered to the node right before a
+/// given fetch. Use [`MockNoteTransportApiBuilder`] to set these up before handing the mock to a
+/// client. Clones share the same node and scripted state.
 #[derive(Clone, Default)]
 pub struct MockNoteTransportApi {
     pub mock_node: Arc<RwLock<MockNoteTransportNode>>,
+    /// Number of `fetch_notes` calls served so far.
+    fetch_count: Arc<AtomicUsize>,
+    /// Responses returned by the next fetches, in order, instead of querying the node.
+    scripted_fetches: Arc<RwLock<VecDeque<Vec<NoteInfo>>>>,
+    /// Notes added to the node right before the fetch with the given (1-based) number.
+    scheduled_deliveries: Arc<RwLock<Vec<(usize, NoteInfo)>>>,
 }
 
 impl MockNoteTransportApi {
     pub fn new(mock_node: Arc<RwLock<MockNoteTransportNode>>) -> Self {
-        Self { mock_node }
+        Self { mock_node, ..Default::default() }
+    }
+
+    /// Returns a [`MockNoteTransportApiBuilder`] for the provided node.
+    pub fn builder(mock_node: Arc<RwLock<MockNoteTransportNode>>) -> MockNoteTransportApiBuilder {
+        MockNoteTransportApiBuilder::new(mock_node)
+    }
+
+    /// Appends a response to the sequence returned by the next fetches. See
+    /// [`MockNoteTransportApiBuilder::with_fetch_response`].
+    pub fn queue_fetch_response(&self, notes: Vec<NoteInfo>) {
+        self.scripted_fetches.write().push_back(notes);
+    }
+
+    /// Delivers the note to the node right before the `fetch`-th call to `fetch_notes`. See
+    /// [`MockNoteTransportApiBuilder::deliver_on_fetch`].
+    pub fn deliver_on_fetch(&self, fetch: usize, header: NoteHeader, details_bytes: Vec<u8>) {
+        let info = NoteInfo { header, details_bytes, block_hint: None };
+        self.scheduled_deliveries.write().push((fetch, info));
+    }
+
+    /// Returns the number of `fetch_notes` calls served so far.
+    pub fn fetch_count(&self) -> usize {
+        self.fetch_count.load(Ordering::SeqCst)
     }
 }
 
@@ -156,10 +190,97 @@ impl MockNoteTransportApi {
         tags: &[NoteTag],
         cursor: NoteTransportCursor,
     ) -> (Vec<NoteInfo>, NoteTransportCursor) {
+        let fetch = self.fetch_count.fetch_add(1, Ordering::SeqCst) + 1;
+
+        // Deliver the notes scheduled for this fetch before serving it.
+        let mut due = Vec::new();
+        self.scheduled_deliveries.write().retain(|(scheduled_fetch, info)| {
+            if *scheduled_fetch == fetch {
+                due.push(info.clone());
+                false
+            } else {
+                true
+            }
+        });
+        for info in due {
+            self.mock_node
+                .write()
+                .add_note_after(info.header, info.details_bytes, info.block_hint);
+        }
+
+        // A scripted response doesn't advance the cursor, so the node's notes are still served
+        // once the script runs out.
+        if let Some(notes) = self.scripted_fetches.write().pop_front() {
+            return (notes, cursor);
+        }
+
         self.mock_node.read().get_notes(tags, cursor)
     }
 }
 
+// MOCK NOTE TRANSPORT API BUILDER
+// ================================================================================================
+
+/// Builder for [`MockNoteTransportApi`] instances with scripted fetches.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// // The first fetch returns nothing, the note is delivered right before the second one.
+/// let transport = MockNoteTransportApiBuilder::new(mock_node)
+///     .with_fetch_response(vec![])
+///     .deliver_on_fetch(2, *note.header(), details_bytes)
+///     .build();
+/// ```
+pub struct MockNoteTransportApiBuilder {
+    mock_node: Arc<RwLock<MockNoteTransportNode>>,
+    fetch_responses: VecDeque<Vec<NoteInfo>>,
+    deliveries: Vec<(usize, NoteInfo)>,
+}
+
+impl MockNoteTransportApiBuilder {
+    /// Creates a new builder backed by the provided node.
+    pub fn new(mock_node: Arc<RwLock<MockNoteTransportNode>>) -> Self {
+        Self {
+            mock_node,
+            fetch_responses: VecDeque::new(),
+            deliveries: Vec::new(),
+        }
+    }
+
+    /// Appends a response to the sequence returned by successive fetches, regardless of the
+    /// requested tags. The first call to this method scripts the first fetch, the second call the
+    /// second fetch, and so on. Fetches after the scripted sequence query the node.
+    #[must_use]
+    pub fn with_fetch_response(mut self, notes: Vec<NoteInfo>) -> Self {
+        self.fetch_responses.push_back(notes);
+        self
+    }
+
+    /// Delivers the note to the node right before the `fetch`-th (1-based) call to
+    /// `fetch_notes`, modeling a sender whose relay reaches the node late.
+    #[must_use]
+    pub fn deliver_on_fetch(
+        mut self,
+        fetch: usize,
+        header: NoteHeader,
+        details_bytes: Vec<u8>,
+    ) -> Self {
+        self.deliveries
+            .push((fetch, NoteInfo { header, details_bytes, block_hint: None }));
+        self
+    }
+
+    /// Builds the [`MockNoteTransportApi`].
+    pub fn build(self) -> MockNoteTransportApi {
+        let transport = MockNoteTransportApi::new(self.mock_node);
+        *transport.scripted_fetches.write() = self.fetch_responses;
+        *transport.scheduled_deliveries.write() = self.deliveries;
+
+        transport
+    }
+}
+
 pub struct DummyNoteStream {}
 impl Stream for DummyNoteStream {
     type Item = Result<Vec<NoteInfo>, NoteTransportError>;
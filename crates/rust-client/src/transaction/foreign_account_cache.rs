@@ -0,0 +1,128 @@
+//! In-memory cache for the foreign account data fetched from the node when executing transactions
+//! that use foreign procedure invocation (FPI).
+
+use alloc::collections::VecDeque;
+
+use miden_protocol::account::AccountId;
+use miden_protocol::block::BlockNumber;
+use miden_protocol::block::account_tree::AccountWitness;
+use miden_protocol::transaction::AccountInputs;
+
+use crate::rpc::domain::account::AccountStorageRequirements;
+
+/// Default number of foreign accounts kept in a [`ForeignAccountCache`].
+pub(crate) const DEFAULT_FOREIGN_ACCOUNT_CACHE_CAPACITY: usize = 64;
+
+/// Data fetched for a single foreign account at a given block.
+#[derive(Clone)]
+enum CachedForeignAccount {
+    /// Witness of a private foreign account. The account data itself is provided by the caller.
+    Private(AccountWitness),
+    /// Inputs of a public foreign account, along with the storage requirements they were fetched
+    /// with.
+    Public {
+        storage_requirements: AccountStorageRequirements,
+        inputs: AccountInputs,
+    },
+}
+
+/// Bounded LRU cache of foreign account data keyed by `(AccountId, BlockNumber)`.
+///
+/// Account proofs are only valid for the block they were requested at, so entries for blocks
+/// older than the client's sync height are dropped through [`ForeignAccountCache::retain_from`].
+pub(crate) struct ForeignAccountCache {
+    capacity: usize,
+    /// Entries ordered from least to most recently used.
+    entries: VecDeque<((AccountId, BlockNumber), CachedForeignAccount)>,
+}
+
+impl ForeignAccountCache {
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity, entries: VecDeque::new() }
+    }
+
+    /// Drops every entry fetched at a block older than `block_num`.
+    pub fn retain_from(&mut self, block_num: BlockNumber) {
+        self.entries.retain(|((_, cached_block), _)| *cached_block >= block_num);
+    }
+
+    /// Returns the cached witness of a private foreign account, if any.
+    pub fn get_private(
+        &mut self,
+        account_id: AccountId,
+        block_num: BlockNumber,
+    ) -> Option<AccountWitness> {
+        match self.get(account_id, block_num)? {
+            CachedForeignAccount::Private(witness) => Some(witness),
+            CachedForeignAccount::Public { .. } => None,
+        }
+    }
+
+    /// Returns the cached inputs of a public foreign account, if they were fetched with the same
+    /// storage requirements.
+    pub fn get_public(
+        &mut self,
+        account_id: AccountId,
+        block_num: BlockNumber,
+        storage_requirements: &AccountStorageRequirements,
+    ) -> Option<AccountInputs> {
+        match self.get(account_id, block_num)? {
+            CachedForeignAccount::Public { storage_requirements: cached, inputs }
+                if &cached == storage_requirements =>
+            {
+                Some(inputs)
+            },
+            _ => None,
+        }
+    }
+
+    pub fn insert_private(
+        &mut self,
+        account_id: AccountId,
+        block_num: BlockNumber,
+        witness: AccountWitness,
+    ) {
+        self.insert((account_id, block_num), CachedForeignAccount::Private(witness));
+    }
+
+    pub fn insert_public(
+        &mut self,
+        account_id: AccountId,
+        block_num: BlockNumber,
+        storage_requirements: AccountStorageRequirements,
+        inputs: AccountInputs,
+    ) {
+        self.insert(
+            (account_id, block_num),
+            CachedForeignAccount::Public { storage_requirements, inputs },
+        );
+    }
+
+    /// Returns a copy of the entry for the key and marks it as the most recently used.
+    fn get(
+        &mut self,
+        account_id: AccountId,
+        block_num: BlockNumber,
+    ) -> Option<CachedForeignAccount> {
+        let position = self.entries.iter().position(|(key, _)| *key == (account_id, block_num))?;
+        let entry = self.entries.remove(position)?;
+        let value = entry.1.clone();
+        self.entries.push_back(entry);
+
+        Some(value)
+    }
+
+    /// Inserts (or replaces) the entry for the key, evicting the least recently used entry if the
+    /// cache is full.
+    fn insert(&mut self, key: (AccountId, BlockNumber), value: CachedForeignAccount) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        self.entries.retain(|(cached_key, _)| *cached_key != key);
+        if self.entries.len() >= self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back((key, value));
+    }
+}
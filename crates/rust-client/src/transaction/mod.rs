@@ -64,12 +64,15 @@
 
 use alloc::boxed::Box;
 use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::{String, ToString};
 use alloc::sync::Arc;
 use alloc::vec::Vec;
 
+use futures::future;
 use miden_protocol::account::{Account, AccountCode, AccountId};
 use miden_protocol::asset::{Asset, NonFungibleAsset};
 use miden_protocol::block::BlockNumber;
+use miden_protocol::crypto::hash::rpo::Rpo256;
 use miden_protocol::errors::AssetError;
 use miden_protocol::note::{
     Note,
@@ -82,10 +85,12 @@ use miden_protocol::note::{
 };
 use miden_protocol::transaction::AccountInputs;
 use miden_protocol::vm::MIN_STACK_DEPTH;
-use miden_protocol::{Felt, Word};
+use miden_protocol::{Felt, MAX_INPUT_NOTES_PER_TX, Word};
 use miden_standards::account::interface::AccountInterfaceExt;
+use miden_standards::note::NoteConsumptionStatus;
+use miden_tx::utils::serde::Serializable;
 use miden_tx::{DataStore, NoteConsumptionChecker, TransactionExecutor};
-use tracing::info;
+use tracing::{Instrument, info, info_span, warn};
 
 use super::Client;
 use crate::ClientError;
@@ -112,18 +117,21 @@ use crate::store::{
 };
 use crate::sync::NoteTagRecord;
 use crate::transaction::batch::InMemoryBatchDataStore;
+use crate::utils::next_correlation_id;
 
 pub mod batch;
 pub use batch::{BatchBuilder, BatchBuilderError};
 
 #[cfg(feature = "dap")]
 mod dap_executor;
+pub(crate) mod foreign_account_cache;
 mod prover;
 pub use prover::TransactionProver;
 
 mod record;
 pub use record::{
     DiscardCause,
+    MAX_FAILURE_REASON_LEN,
     TransactionDetails,
     TransactionRecord,
     TransactionStatus,
@@ -198,6 +206,71 @@ where
         self.store.get_transactions(filter).await.map_err(Into::into)
     }
 
+    /// Retrieves the transactions executed by the provided account that have not yet been
+    /// committed to the blockchain as per the last sync.
+    ///
+    /// This can be used to warn about in-flight transactions before submitting a new one against
+    /// the same account.
+    pub async fn pending_transactions(
+        &self,
+        account_id: AccountId,
+    ) -> Result<Vec<TransactionRecord>, ClientError> {
+        let transactions = self.get_transactions(TransactionFilter::Uncommitted).await?;
+
+        Ok(transactions
+            .into_iter()
+            .filter(|record| record.details.account_id == account_id)
+            .collect())
+    }
+
+    /// Sets the category of a tracked transaction (e.g. "salary" or "refund"). Passing `None`
+    /// clears the category.
+    ///
+    /// Categories are local-only bookkeeping data and are never sent to the network. Transactions
+    /// can be filtered by category with [`TransactionFilter::Category`].
+    pub async fn set_transaction_category(
+        &mut self,
+        transaction_id: TransactionId,
+        category: Option<String>,
+    ) -> Result<(), ClientError> {
+        self.store
+            .set_transaction_category(transaction_id, category)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Returns the category of a tracked transaction, if one was set.
+    pub async fn get_transaction_category(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<Option<String>, ClientError> {
+        self.store.get_transaction_category(transaction_id).await.map_err(Into::into)
+    }
+
+    /// Returns the proven transaction with the provided ID, if it was retained.
+    ///
+    /// Proven transactions are only stored when the client was built with
+    /// [`ClientBuilder::retain_proofs`](crate::builder::ClientBuilder::retain_proofs) enabled.
+    pub async fn get_transaction_proof(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<Option<ProvenTransaction>, ClientError> {
+        self.store.get_proven_transaction(transaction_id).await.map_err(Into::into)
+    }
+
+    /// Returns the input notes consumed by the transaction with the provided ID, along with the
+    /// output notes it created.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StoreError::TransactionNotFound`] error if the transaction isn't tracked.
+    pub async fn get_transaction_notes(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<(Vec<InputNoteRecord>, Vec<OutputNoteRecord>), ClientError> {
+        self.store.get_transaction_notes(transaction_id).await.map_err(Into::into)
+    }
+
     // TRANSACTION BATCH
     // --------------------------------------------------------------------------------------------
 
@@ -233,17 +306,188 @@ where
             .await
     }
 
+    /// Consumes every note that `account_id` can consume at the current sync height, submitting
+    /// as many consume transactions as needed to stay within the protocol's limit of
+    /// [`MAX_INPUT_NOTES_PER_TX`] input notes per transaction.
+    ///
+    /// If `faucet_filter` is provided, only notes carrying an asset issued by that faucet are
+    /// consumed. Returns the IDs of the submitted transactions, which is empty if there were no
+    /// notes to consume.
+    ///
+    /// # Errors
+    ///
+    /// Transactions are submitted in order and the method stops at the first failure. If some
+    /// transactions were already submitted, their notes remain consumed and the method returns
+    /// [`ClientError::NoteConsumptionIncomplete`] carrying their IDs along with the failure.
+    pub async fn consume_all_consumable_notes(
+        &mut self,
+        account_id: AccountId,
+        faucet_filter: Option<AccountId>,
+    ) -> Result<Vec<TransactionId>, ClientError> {
+        self.consume_notes_in_batches(account_id, faucet_filter, MAX_INPUT_NOTES_PER_TX)
+            .await
+    }
+
+    /// Implements [`Client::consume_all_consumable_notes`], putting at most `notes_per_tx` notes
+    /// in each transaction.
+    pub(crate) async fn consume_notes_in_batches(
+        &mut self,
+        account_id: AccountId,
+        faucet_filter: Option<AccountId>,
+        notes_per_tx: usize,
+    ) -> Result<Vec<TransactionId>, ClientError> {
+        let notes = self
+            .get_notes_consumable_now(account_id, faucet_filter)
+            .await?
+            .into_iter()
+            .map(Note::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut transaction_ids = Vec::new();
+        for batch in notes.chunks(notes_per_tx) {
+            let result = match TransactionRequestBuilder::new().build_consume_notes(batch.to_vec())
+            {
+                Ok(transaction_request) => {
+                    self.submit_new_transaction(account_id, transaction_request).await
+                },
+                Err(err) => Err(err.into()),
+            };
+
+            match result {
+                Ok(transaction_id) => transaction_ids.push(transaction_id),
+                Err(err) if transaction_ids.is_empty() => return Err(err),
+                Err(err) => {
+                    return Err(ClientError::NoteConsumptionIncomplete {
+                        account_id,
+                        submitted_transactions: transaction_ids,
+                        source: Box::new(err),
+                    });
+                },
+            }
+        }
+
+        Ok(transaction_ids)
+    }
+
+    /// Consumes up to `max_notes` notes carrying fungible assets of `faucet_id` into `account_id`
+    /// in a single transaction, picking the notes with the smallest amounts first.
+    ///
+    /// This compacts the many small notes an account may accumulate, which are otherwise
+    /// inefficient to consume one by one. Only notes consumable at the current sync height are
+    /// considered, and `max_notes` is capped at [`MAX_INPUT_NOTES_PER_TX`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ClientError::NoConsumableNoteForAccount`] if no note gets selected, either
+    /// because the account has no consumable note of the faucet or because `max_notes` is zero.
+    pub async fn coalesce_dust(
+        &mut self,
+        account_id: AccountId,
+        faucet_id: AccountId,
+        max_notes: usize,
+    ) -> Result<TransactionId, ClientError> {
+        let mut notes = self
+            .get_notes_consumable_now(account_id, Some(faucet_id))
+            .await?
+            .into_iter()
+            .map(|note_record| {
+                let amount = note_record
+                    .assets()
+                    .iter()
+                    .filter_map(|asset| match asset {
+                        Asset::Fungible(fungible) if fungible.faucet_id() == faucet_id => {
+                            Some(fungible.amount())
+                        },
+                        _ => None,
+                    })
+                    .fold(0u64, |acc, amount| acc.saturating_add(u64::from(amount)));
+                (amount, note_record)
+            })
+            .collect::<Vec<_>>();
+        notes.sort_by_key(|(amount, _)| *amount);
+
+        let notes = notes
+            .into_iter()
+            .take(max_notes.min(MAX_INPUT_NOTES_PER_TX))
+            .map(|(_, note_record)| Note::try_from(note_record))
+            .collect::<Result<Vec<_>, _>>()?;
+        if notes.is_empty() {
+            return Err(ClientError::NoConsumableNoteForAccount(account_id));
+        }
+
+        let transaction_request = TransactionRequestBuilder::new().build_consume_notes(notes)?;
+        self.submit_new_transaction(account_id, transaction_request).await
+    }
+
+    /// Returns the committed notes that `account_id` can consume at the current sync height.
+    ///
+    /// If `faucet_filter` is provided, only notes carrying an asset issued by that faucet are
+    /// returned.
+    async fn get_notes_consumable_now(
+        &self,
+        account_id: AccountId,
+        faucet_filter: Option<AccountId>,
+    ) -> Result<Vec<InputNoteRecord>, ClientError> {
+        let sync_height = self.get_sync_height().await?;
+
+        let mut notes = Vec::new();
+        for (note_record, consumability) in self.get_consumable_notes(Some(account_id)).await? {
+            let consumable_now = consumability.iter().any(|(_, status)| match status {
+                NoteConsumptionStatus::Consumable
+                | NoteConsumptionStatus::ConsumableWithAuthorization => true,
+                NoteConsumptionStatus::ConsumableAfter(block_num) => *block_num <= sync_height,
+                _ => false,
+            });
+            if !consumable_now {
+                continue;
+            }
+
+            if let Some(faucet_id) = faucet_filter
+                && !note_record.assets().iter().any(|asset| asset.faucet_id() == faucet_id)
+            {
+                continue;
+            }
+
+            notes.push(note_record);
+        }
+
+        Ok(notes)
+    }
+
     /// Executes a transaction specified by the request against the specified account,
     /// proves it with the provided prover, submits it to the network, and updates the local
     /// database.
     ///
     /// This is useful for falling back to a different prover (e.g., local) when the default
     /// prover (e.g., remote) fails with a [`ClientError::TransactionProvingError`].
+    ///
+    /// The whole operation runs inside a `submit_new_transaction` tracing span carrying a
+    /// `correlation_id` field, with `execute`, `prove`, `submit` and `apply` child spans for each
+    /// step.
     pub async fn submit_new_transaction_with_prover(
         &mut self,
         account_id: AccountId,
         transaction_request: TransactionRequest,
         tx_prover: Arc<dyn TransactionProver>,
+    ) -> Result<TransactionId, ClientError> {
+        let span = info_span!(
+            "submit_new_transaction",
+            correlation_id = next_correlation_id(),
+            %account_id,
+        );
+
+        self.submit_new_transaction_steps(account_id, transaction_request, tx_prover)
+            .instrument(span)
+            .await
+    }
+
+    /// Runs the execute -> prove -> submit -> apply pipeline of
+    /// [`Self::submit_new_transaction_with_prover`].
+    async fn submit_new_transaction_steps(
+        &mut self,
+        account_id: AccountId,
+        transaction_request: TransactionRequest,
+        tx_prover: Arc<dyn TransactionProver>,
     ) -> Result<TransactionId, ClientError> {
         // Register any missing NTX scripts before the main transaction.
         // The registration path contains its own full execute -> prove -> submit pipeline.
@@ -256,12 +500,160 @@ where
             .await?;
         }
 
-        let tx_result = self.execute_transaction(account_id, transaction_request).await?;
+        let tx_result = self
+            .execute_transaction(account_id, transaction_request)
+            .instrument(info_span!("execute"))
+            .await?;
         let tx_id = tx_result.executed_transaction().id();
 
-        let proven_transaction = self.prove_transaction_with(&tx_result, tx_prover).await?;
-        let submission_height =
-            self.submit_proven_transaction(proven_transaction, &tx_result).await?;
+        let proven_transaction = self
+            .prove_transaction_with(&tx_result, tx_prover)
+            .instrument(info_span!("prove", %tx_id))
+            .await?;
+
+        self.submit_and_apply_transaction(proven_transaction, &tx_result).await
+    }
+
+    /// Executes the provided requests and submits the resulting transactions, proving the
+    /// transactions of different accounts concurrently with the client's prover.
+    ///
+    /// Requests are processed in rounds that hold the first pending request of each account:
+    /// the round's transactions are executed one after the other, proven concurrently, and then
+    /// submitted and applied to the store in request order. Transactions of the same account thus
+    /// keep their relative order and each one is executed against the state left by the previous
+    /// one. Requests are expected to be independent across accounts; a request consuming a note
+    /// created by another request in the same call may end up in the same round and fail.
+    ///
+    /// Proving only runs in parallel if the prover does, as a remote prover does. The local
+    /// prover still proves the round's transactions one after the other.
+    ///
+    /// Returns one result per request, in the same order as `requests`. A failed request does not
+    /// abort the others, but the later requests of the same account are skipped with
+    /// [`ClientError::PrecedingTransactionFailed`].
+    pub async fn batch_execute(
+        &mut self,
+        requests: Vec<(AccountId, TransactionRequest)>,
+    ) -> Result<Vec<Result<TransactionId, ClientError>>, ClientError> {
+        let span = info_span!(
+            "batch_execute",
+            correlation_id = next_correlation_id(),
+            requests = requests.len(),
+        );
+
+        self.batch_execute_rounds(requests).instrument(span).await
+    }
+
+    /// Runs the rounds of [`Self::batch_execute`].
+    async fn batch_execute_rounds(
+        &mut self,
+        requests: Vec<(AccountId, TransactionRequest)>,
+    ) -> Result<Vec<Result<TransactionId, ClientError>>, ClientError> {
+        let tx_prover = self.tx_prover.clone();
+        let mut results: Vec<Option<Result<TransactionId, ClientError>>> =
+            requests.iter().map(|_| None).collect();
+        let mut pending: Vec<(usize, AccountId, TransactionRequest)> = requests
+            .into_iter()
+            .enumerate()
+            .map(|(index, (account_id, request))| (index, account_id, request))
+            .collect();
+        let mut failed_accounts = BTreeSet::new();
+
+        while !pending.is_empty() {
+            let mut round = Vec::new();
+            let mut round_accounts = BTreeSet::new();
+            let mut deferred = Vec::new();
+            for (index, account_id, request) in pending {
+                if failed_accounts.contains(&account_id) {
+                    results[index] = Some(Err(ClientError::PrecedingTransactionFailed(account_id)));
+                } else if round_accounts.insert(account_id) {
+                    round.push((index, account_id, request));
+                } else {
+                    deferred.push((index, account_id, request));
+                }
+            }
+            pending = deferred;
+
+            // Execution reads and locks the account state from the store, so it stays sequential.
+            let mut executed = Vec::with_capacity(round.len());
+            for (index, account_id, request) in round {
+                match self.execute_batch_request(account_id, request, tx_prover.clone()).await {
+                    Ok(tx_result) => executed.push((index, account_id, tx_result)),
+                    Err(err) => {
+                        failed_accounts.insert(account_id);
+                        results[index] = Some(Err(err));
+                    },
+                }
+            }
+
+            let proofs = future::join_all(executed.iter().map(|(_, _, tx_result)| {
+                self.prove_transaction_with(tx_result, tx_prover.clone())
+            }))
+            .await;
+
+            for ((index, account_id, tx_result), proof) in executed.into_iter().zip(proofs) {
+                let result = match proof {
+                    Ok(proven_transaction) => {
+                        self.submit_and_apply_transaction(proven_transaction, &tx_result).await
+                    },
+                    Err(err) => Err(err),
+                };
+                if result.is_err() {
+                    failed_accounts.insert(account_id);
+                }
+                results[index] = Some(result);
+            }
+        }
+
+        Ok(results
+            .into_iter()
+            .map(|result| result.expect("every request is either processed or skipped"))
+            .collect())
+    }
+
+    /// Registers the NTX scripts expected by `transaction_request` and executes it, as the first
+    /// step of [`Self::batch_execute`].
+    async fn execute_batch_request(
+        &mut self,
+        account_id: AccountId,
+        transaction_request: TransactionRequest,
+        tx_prover: Arc<dyn TransactionProver>,
+    ) -> Result<TransactionResult, ClientError> {
+        if !transaction_request.expected_ntx_scripts().is_empty() {
+            Box::pin(self.ensure_ntx_scripts_registered(
+                account_id,
+                transaction_request.expected_ntx_scripts(),
+                tx_prover,
+            ))
+            .await?;
+        }
+
+        self.execute_transaction(account_id, transaction_request).await
+    }
+
+    /// Submits a proven transaction and applies it to the local store, returning its ID.
+    async fn submit_and_apply_transaction(
+        &mut self,
+        proven_transaction: ProvenTransaction,
+        tx_result: &TransactionResult,
+    ) -> Result<TransactionId, ClientError> {
+        let tx_id = tx_result.executed_transaction().id();
+        let submission_height = self
+            .submit_proven_transaction(proven_transaction, tx_result)
+            .instrument(info_span!("submit", %tx_id))
+            .await?;
+        self.apply_submitted_transaction(tx_result, submission_height).await?;
+
+        Ok(tx_id)
+    }
+
+    /// Applies a transaction accepted by the node to the local store and notifies the
+    /// transaction observers.
+    async fn apply_submitted_transaction(
+        &self,
+        tx_result: &TransactionResult,
+        submission_height: BlockNumber,
+    ) -> Result<(), ClientError> {
+        let tx_id = tx_result.executed_transaction().id();
 
         // The transaction has been accepted by the node; the local store update
         // is a separate step that can fail independently. On failure, return a
@@ -271,10 +663,16 @@ where
         //
         // The update is boxed so it does not inflate the enclosing future
         // across await points (triggers clippy::large_futures).
-        let tx_update =
-            Box::new(self.get_transaction_store_update(&tx_result, submission_height).await?);
-
-        if let Err(apply_err) = self.apply_transaction_update((*tx_update).clone()).await {
+        let apply_span = info_span!("apply", %tx_id);
+        let tx_update = Box::new(
+            self.get_transaction_store_update(tx_result, submission_height)
+                .instrument(apply_span.clone())
+                .await?,
+        );
+
+        if let Err(apply_err) =
+            self.apply_transaction_update((*tx_update).clone()).instrument(apply_span).await
+        {
             info!(
                 "apply_transaction_update failed for submitted tx {tx_id}; returning \
                  ApplyTransactionAfterSubmitFailed with the pending update attached: {apply_err}"
@@ -292,11 +690,11 @@ where
             crate::errors::log_observer_failure(
                 observer.name(),
                 "TransactionObserver::apply",
-                observer.apply(&tx_result).await,
+                observer.apply(tx_result).await,
             );
         }
 
-        Ok(tx_id)
+        Ok(())
     }
 
     /// Creates and executes a transaction specified by the request against the specified account,
@@ -308,10 +706,47 @@ where
     ///   notes are not a subset of executor's output notes.
     /// - Returns a [`ClientError::TransactionExecutorError`] if the execution fails.
     /// - Returns a [`ClientError::TransactionRequestError`] if the request is invalid.
+    ///
+    /// If the client was built with
+    /// [`ClientBuilder::record_failed_transactions`](crate::builder::ClientBuilder::record_failed_transactions)
+    /// enabled, a failed execution is recorded in the store with a
+    /// [`TransactionStatus::Failed`] status before the error is returned.
     pub async fn execute_transaction(
         &mut self,
         account_id: AccountId,
         transaction_request: TransactionRequest,
+    ) -> Result<TransactionResult, ClientError> {
+        if !self.record_failed_transactions {
+            return self.execute_transaction_unrecorded(account_id, transaction_request).await;
+        }
+
+        let input_note_nullifiers: Vec<Word> = transaction_request
+            .input_notes()
+            .iter()
+            .map(|note| note.nullifier().as_word())
+            .collect();
+        let script = match transaction_request.script_template() {
+            Some(TransactionScriptTemplate::CustomScript(script)) => Some(script.clone()),
+            _ => None,
+        };
+
+        let result = self.execute_transaction_unrecorded(account_id, transaction_request).await;
+        if let Err(err) = &result
+            && let Err(record_err) = self
+                .record_failed_transaction(account_id, input_note_nullifiers, script, err)
+                .await
+        {
+            warn!(%account_id, error = %record_err, "Failed to record failed transaction");
+        }
+
+        result
+    }
+
+    /// Executes a transaction without recording it in the store if the execution fails.
+    async fn execute_transaction_unrecorded(
+        &mut self,
+        account_id: AccountId,
+        transaction_request: TransactionRequest,
     ) -> Result<TransactionResult, ClientError> {
         let account: Account = self.get_native_account_record(account_id).await?.try_into()?;
 
@@ -347,6 +782,80 @@ where
         TransactionResult::new(executed_transaction, prep.future_notes)
     }
 
+    /// Simulates a transaction against the specified account without persisting or submitting
+    /// anything.
+    ///
+    /// The transaction is executed locally and validated like in [`Self::execute_transaction`],
+    /// but no proof is generated and nothing is sent to the network. Nothing is written to the
+    /// store either, even if the execution fails and the client was built with
+    /// [`ClientBuilder::record_failed_transactions`](crate::builder::ClientBuilder::record_failed_transactions)
+    /// enabled. The returned [`TransactionResult`] can be used to preview the effects
+    /// of the transaction, such as its output notes
+    /// ([`TransactionResult::created_notes`]), the resulting account state
+    /// ([`TransactionResult::final_account_commitment`]) and the advice data produced during
+    /// execution ([`TransactionResult::advice_witness`]).
+    ///
+    /// To inspect the output stack of a script, use [`Self::execute_program`] instead.
+    pub async fn simulate_transaction(
+        &mut self,
+        account_id: AccountId,
+        transaction_request: TransactionRequest,
+    ) -> Result<TransactionResult, ClientError> {
+        self.execute_transaction_unrecorded(account_id, transaction_request).await
+    }
+
+    /// Persists a [`TransactionRecord`] with a [`TransactionStatus::Failed`] status for a
+    /// transaction against `account_id` whose execution failed with `error`.
+    ///
+    /// Since no transaction was produced, the record's ID is derived from the account's current
+    /// commitment, the nullifiers of the request's input notes and the current timestamp, and both
+    /// the initial and final account states are set to the current account commitment.
+    async fn record_failed_transaction(
+        &self,
+        account_id: AccountId,
+        input_note_nullifiers: Vec<Word>,
+        script: Option<TransactionScript>,
+        error: &ClientError,
+    ) -> Result<(), ClientError> {
+        let Some((account_header, _)) = self.store.get_account_header(account_id).await? else {
+            // Nothing to record against an account that isn't tracked.
+            return Ok(());
+        };
+
+        let account_commitment = account_header.to_commitment();
+        let sync_height = self.store.get_sync_height().await?;
+        let creation_timestamp = self.store.get_current_timestamp().unwrap_or_default();
+
+        let mut id_bytes = account_commitment.to_bytes();
+        id_bytes.extend(input_note_nullifiers.to_bytes());
+        id_bytes.extend(creation_timestamp.to_le_bytes());
+        let id = TransactionId::from_raw(Rpo256::hash(&id_bytes));
+
+        let details = TransactionDetails {
+            account_id,
+            init_account_state: account_commitment,
+            final_account_state: account_commitment,
+            input_note_nullifiers,
+            output_notes: RawOutputNotes::new(Vec::new())
+                .expect("an empty set of output notes is always valid"),
+            block_num: sync_height,
+            submission_height: sync_height,
+            expiration_block_num: sync_height,
+            creation_timestamp,
+            execution_cycles: None,
+        };
+
+        let record = TransactionRecord::new(
+            id,
+            details,
+            script,
+            TransactionStatus::failed(&error.to_string()),
+        );
+
+        self.store.insert_failed_transaction(record).await?;
+        Ok(())
+    }
+
     /// Performs the data-store-independent setup shared by `execute_transaction` and
     /// `execute_transaction_for_batch`: validates the request against the supplied
     /// `account`, loads/filters input notes, builds the transaction script and args,
@@ -451,21 +960,87 @@ where
 
     /// Submits a previously proven transaction to the RPC endpoint and returns the node’s chain tip
     /// upon mempool admission.
+    ///
+    /// If the client retains proofs, the proven transaction is persisted before it is submitted,
+    /// so it can be re-broadcast even if the node rejects it.
     pub async fn submit_proven_transaction(
         &mut self,
         proven_transaction: ProvenTransaction,
         transaction_inputs: impl Into<TransactionInputs>,
     ) -> Result<BlockNumber, ClientError> {
+        let transaction_inputs = transaction_inputs.into();
+        if self.retain_proofs {
+            self.store
+                .insert_proven_transaction(proven_transaction.clone(), transaction_inputs.clone())
+                .await?;
+        }
+
         info!("Submitting transaction to the network...");
         let block_num = self
             .rpc_api
-            .submit_proven_transaction(proven_transaction, transaction_inputs.into())
+            .submit_proven_transaction(proven_transaction, transaction_inputs)
             .await?;
         info!("Transaction submitted.");
 
         Ok(block_num)
     }
 
+    /// Submits the retained proof of a pending transaction to the network again, and returns the
+    /// node's chain tip upon mempool admission.
+    ///
+    /// This recovers transactions that the node accepted but dropped from its mempool without
+    /// committing them, without executing and proving them again. The proof commits to the
+    /// transaction's reference block, so the transaction is resubmitted as is; if it expired in
+    /// the meantime it needs to be executed again.
+    ///
+    /// # Errors
+    ///
+    /// - Returns a [`StoreError::TransactionNotFound`] error if the transaction isn't tracked.
+    /// - Returns a [`ClientError::TransactionNotResubmittable`] error if the transaction isn't
+    ///   pending, has expired, or its proof wasn't retained (see
+    ///   [`ClientBuilder::retain_proofs`](crate::builder::ClientBuilder::retain_proofs)).
+    pub async fn resubmit_transaction(
+        &mut self,
+        transaction_id: TransactionId,
+    ) -> Result<BlockNumber, ClientError> {
+        let not_resubmittable =
+            |reason: String| ClientError::TransactionNotResubmittable { transaction_id, reason };
+
+        let transaction = self
+            .store
+            .get_transactions(TransactionFilter::Ids(vec![transaction_id]))
+            .await?
+            .pop()
+            .ok_or(StoreError::TransactionNotFound(transaction_id))?;
+        if !matches!(transaction.status, TransactionStatus::Pending) {
+            return Err(not_resubmittable(format!("the transaction is {}", transaction.status)));
+        }
+
+        let (Some(proven_transaction), Some(transaction_inputs)) = (
+            self.store.get_proven_transaction(transaction_id).await?,
+            self.store.get_proven_transaction_inputs(transaction_id).await?,
+        ) else {
+            return Err(not_resubmittable("its proof wasn't retained".to_string()));
+        };
+
+        let sync_height = self.store.get_sync_height().await?;
+        if proven_transaction.expiration_block_num() <= sync_height {
+            return Err(not_resubmittable(format!(
+                "it expired at block {}",
+                proven_transaction.expiration_block_num()
+            )));
+        }
+
+        info!("Resubmitting transaction {transaction_id} to the network...");
+        let block_num = self
+            .rpc_api
+            .submit_proven_transaction(proven_transaction, transaction_inputs)
+            .await?;
+        info!("Transaction resubmitted.");
+
+        Ok(block_num)
+    }
+
     /// Builds a [`TransactionStoreUpdate`] for the provided transaction result at the specified
     /// submission height.
     pub async fn get_transaction_store_update(
@@ -561,7 +1136,33 @@ where
         foreign_accounts: BTreeMap<AccountId, ForeignAccount>,
     ) -> Result<[Felt; MIN_STACK_DEPTH], ClientError> {
         let (data_store, block_ref) =
-            self.prepare_program_execution(account_id, foreign_accounts).await?;
+            self.prepare_program_execution(account_id, None, foreign_accounts).await?;
+
+        Ok(self
+            .build_executor(&data_store)?
+            .execute_tx_view_script(account_id, block_ref, tx_script, advice_inputs)
+            .await?)
+    }
+
+    /// Executes the provided transaction script against the given state of an account instead of
+    /// the one in the store, and returns the resulting stack.
+    ///
+    /// This allows iterating on account logic against hypothetical states (e.g. one loaded from an
+    /// [`AccountFile`](crate::account::AccountFile)). The account doesn't need to be
+    /// tracked by the client, and nothing is persisted: the store is left untouched.
+    ///
+    /// The transaction will use the current sync height as the block reference.
+    pub async fn execute_program_with_account_state(
+        &mut self,
+        account: Account,
+        tx_script: TransactionScript,
+        advice_inputs: AdviceInputs,
+        foreign_accounts: BTreeMap<AccountId, ForeignAccount>,
+    ) -> Result<[Felt; MIN_STACK_DEPTH], ClientError> {
+        let account_id = account.id();
+        let (data_store, block_ref) = self
+            .prepare_program_execution(account_id, Some(account), foreign_accounts)
+            .await?;
 
         Ok(self
             .build_executor(&data_store)?
@@ -580,7 +1181,7 @@ where
         foreign_accounts: BTreeMap<AccountId, ForeignAccount>,
     ) -> Result<[Felt; MIN_STACK_DEPTH], ClientError> {
         let (data_store, block_ref) =
-            self.prepare_program_execution(account_id, foreign_accounts).await?;
+            self.prepare_program_execution(account_id, None, foreign_accounts).await?;
 
         Ok(self
             .build_dap_executor(&data_store)?
@@ -588,6 +1189,43 @@ where
             .await?)
     }
 
+    /// Returns the [`AccountInterface`] of the specified account, describing the components and
+    /// procedures the account exposes.
+    ///
+    /// This is useful when building custom transaction scripts, to check which procedures can be
+    /// called against the account.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let interface = client.account_interface(account_id).await?;
+    ///
+    /// let is_wallet = interface.components().contains(&AccountComponentInterface::BasicWallet);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the account isn't tracked by the client.
+    pub async fn account_interface(
+        &self,
+        account_id: AccountId,
+    ) -> Result<AccountInterface, ClientError> {
+        self.get_account_interface(account_id).await
+    }
+
+    /// Returns the components installed on the specified account, as listed by its
+    /// [`AccountInterface`] (e.g. the basic wallet, a fungible faucet, the authentication
+    /// component, or custom components).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the account isn't tracked by the client.
+    pub async fn account_components(
+        &self,
+        account_id: AccountId,
+    ) -> Result<Vec<AccountComponentInterface>, ClientError> {
+        Ok(self.get_account_interface(account_id).await?.components().to_vec())
+    }
+
     // HELPERS
     // --------------------------------------------------------------------------------------------
 
@@ -732,7 +1370,8 @@ where
     ///
     /// For any [`ForeignAccount::Public`] in `foreign_accounts`, these pieces of data are retrieved
     /// from the network. For any [`ForeignAccount::Private`] account, inner data is used and only
-    /// a proof of the account's existence on the network is fetched.
+    /// a proof of the account's existence on the network is fetched. Data already fetched at the
+    /// current sync height is reused from the foreign account cache, if enabled.
     async fn retrieve_foreign_account_inputs(
         &self,
         foreign_accounts: BTreeMap<AccountId, ForeignAccount>,
@@ -742,30 +1381,66 @@ where
         }
 
         let block_num = self.store.get_sync_height().await?;
+        if let Some(cache) = &self.foreign_account_cache {
+            cache.write().retain_from(block_num);
+        }
+
         let mut return_foreign_account_inputs = Vec::with_capacity(foreign_accounts.len());
 
         for foreign_account in foreign_accounts.into_values() {
             let foreign_account_inputs = match foreign_account {
                 ForeignAccount::Public(account_id, storage_requirements) => {
-                    fetch_public_account_inputs(
-                        &self.store,
-                        &self.rpc_api,
-                        account_id,
-                        storage_requirements,
-                        AccountStateAt::Block(block_num),
-                    )
-                    .await?
-                },
-                ForeignAccount::Private(partial_account) => {
-                    let account_id = partial_account.id();
-                    let (_, account_proof) = self
-                        .rpc_api
-                        .get_account(
+                    let cached = self.foreign_account_cache.as_ref().and_then(|cache| {
+                        cache.write().get_public(account_id, block_num, &storage_requirements)
+                    });
+
+                    if let Some(inputs) = cached {
+                        inputs
+                    } else {
+                        let inputs = fetch_public_account_inputs(
+                            &self.store,
+                            &self.rpc_api,
                             account_id,
-                            GetAccountRequest::new().at(AccountStateAt::Block(block_num)),
+                            storage_requirements.clone(),
+                            AccountStateAt::Block(block_num),
                         )
                         .await?;
-                    let (witness, _) = account_proof.into_parts();
+
+                        if let Some(cache) = &self.foreign_account_cache {
+                            cache.write().insert_public(
+                                account_id,
+                                block_num,
+                                storage_requirements,
+                                inputs.clone(),
+                            );
+                        }
+                        inputs
+                    }
+                },
+                ForeignAccount::Private(partial_account) => {
+                    let account_id = partial_account.id();
+                    let cached = self
+                        .foreign_account_cache
+                        .as_ref()
+                        .and_then(|cache| cache.write().get_private(account_id, block_num));
+
+                    let witness = if let Some(witness) = cached {
+                        witness
+                    } else {
+                        let (_, account_proof) = self
+                            .rpc_api
+                            .get_account(
+                                account_id,
+                                GetAccountRequest::new().at(AccountStateAt::Block(block_num)),
+                            )
+                            .await?;
+                        let (witness, _) = account_proof.into_parts();
+
+                        if let Some(cache) = &self.foreign_account_cache {
+                            cache.write().insert_private(account_id, block_num, witness.clone());
+                        }
+                        witness
+                    };
                     AccountInputs::new(partial_account, witness)
                 },
             };
@@ -778,10 +1453,13 @@ where
 
     /// Prepares the data store and block reference for program execution.
     ///
-    /// This is shared setup for both `execute_program` and `execute_program_with_dap`.
+    /// This is shared setup for `execute_program`, `execute_program_with_account_state` and
+    /// `execute_program_with_dap`. If `account_state` is provided, it's served to the executor
+    /// instead of the state of the account in the store.
     async fn prepare_program_execution(
         &mut self,
         account_id: AccountId,
+        account_state: Option<Account>,
         foreign_accounts: BTreeMap<AccountId, ForeignAccount>,
     ) -> Result<(ClientDataStore, BlockNumber), ClientError> {
         let (fpi_block_number, foreign_account_inputs) =
@@ -793,16 +1471,19 @@ where
             self.get_sync_height().await?
         };
 
-        let account_record = self
-            .store
-            .get_account(account_id)
-            .await?
-            .ok_or(ClientError::AccountDataNotFound(account_id))?;
-
-        let account: Account = account_record.try_into()?;
-
         let data_store = ClientDataStore::new(self.store.clone(), self.rpc_api.clone());
 
+        let account = if let Some(account) = account_state {
+            data_store.register_native_account(account.clone());
+            account
+        } else {
+            self.store
+                .get_account(account_id)
+                .await?
+                .ok_or(ClientError::AccountDataNotFound(account_id))?
+                .try_into()?
+        };
+
         // Ensure code is loaded on MAST store
         data_store.mast_store().load_account_code(account.code());
 
@@ -831,7 +1512,7 @@ where
     }
 
     /// Loads an [`AccountRecord`] for an account that must be usable as a transaction's native
-    /// account. Errors out if the account is not tracked or if it is watched.
+    /// account. Errors out if the account is not tracked, or if it is watched or watch-only.
     async fn get_native_account_record(
         &self,
         account_id: AccountId,
@@ -844,6 +1525,9 @@ where
         if account_record.is_watched() {
             return Err(ClientError::AccountIsWatched(account_id));
         }
+        if account_record.is_watch_only() {
+            return Err(ClientError::WatchOnlyAccount(account_id));
+        }
         Ok(account_record)
     }
 
@@ -1,4 +1,4 @@
-use alloc::string::ToString;
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use core::fmt;
 
@@ -60,7 +60,9 @@ impl TransactionRecord {
             // TODO: We need a better strategy here. If a transaction was discarded within this
             // same chain of updates, it would be better to pass the state to committed and then
             // remove the account invalid states and make them valid again
-            TransactionStatus::Discarded(_) | TransactionStatus::Committed { .. } => false,
+            TransactionStatus::Discarded(_)
+            | TransactionStatus::Committed { .. }
+            | TransactionStatus::Failed { .. } => false,
         }
     }
 
@@ -72,7 +74,9 @@ impl TransactionRecord {
                 self.status = TransactionStatus::Discarded(cause);
                 true
             },
-            TransactionStatus::Discarded(_) | TransactionStatus::Committed { .. } => false,
+            TransactionStatus::Discarded(_)
+            | TransactionStatus::Committed { .. }
+            | TransactionStatus::Failed { .. } => false,
         }
     }
 }
@@ -98,6 +102,11 @@ pub struct TransactionDetails {
     pub expiration_block_num: BlockNumber,
     /// Timestamp indicating when the transaction was created by the client.
     pub creation_timestamp: u64,
+    /// Number of VM cycles the transaction took to execute, or `None` if it wasn't recorded (as
+    /// for transactions tracked by older client versions).
+    ///
+    /// Stores persist this value on its own, so it isn't part of the serialized details.
+    pub execution_cycles: Option<u32>,
 }
 
 impl Serializable for TransactionDetails {
@@ -136,6 +145,7 @@ impl Deserializable for TransactionDetails {
             submission_height,
             expiration_block_num,
             creation_timestamp,
+            execution_cycles: None,
         })
     }
 }
@@ -215,12 +225,25 @@ pub enum TransactionStatus {
     },
     /// Transaction has been discarded and isn't included in the node.
     Discarded(DiscardCause),
+    /// Transaction failed to execute locally and was never submitted to the network.
+    ///
+    /// Only recorded when the client is built with
+    /// [`ClientBuilder::record_failed_transactions`](crate::builder::ClientBuilder::record_failed_transactions).
+    Failed {
+        /// Error message describing why the execution failed, truncated to
+        /// [`MAX_FAILURE_REASON_LEN`] characters.
+        reason: String,
+    },
 }
 
+/// Maximum number of characters kept from the error message of a failed transaction.
+pub const MAX_FAILURE_REASON_LEN: usize = 256;
+
 pub enum TransactionStatusVariant {
     Pending = 0,
     Committed = 1,
     Discarded = 2,
+    Failed = 3,
 }
 
 impl TransactionStatus {
@@ -229,6 +252,15 @@ impl TransactionStatus {
             TransactionStatus::Pending => TransactionStatusVariant::Pending,
             TransactionStatus::Committed { .. } => TransactionStatusVariant::Committed,
             TransactionStatus::Discarded(_) => TransactionStatusVariant::Discarded,
+            TransactionStatus::Failed { .. } => TransactionStatusVariant::Failed,
+        }
+    }
+
+    /// Builds a [`TransactionStatus::Failed`] status from an error message, truncating it to
+    /// [`MAX_FAILURE_REASON_LEN`] characters.
+    pub fn failed(reason: &str) -> Self {
+        TransactionStatus::Failed {
+            reason: reason.chars().take(MAX_FAILURE_REASON_LEN).collect(),
         }
     }
 }
@@ -241,6 +273,7 @@ impl fmt::Display for TransactionStatus {
                 write!(f, "Committed (Block: {block_number})")
             },
             TransactionStatus::Discarded(cause) => write!(f, "Discarded ({cause})"),
+            TransactionStatus::Failed { reason } => write!(f, "Failed ({reason})"),
         }
     }
 }
@@ -258,6 +291,10 @@ impl Serializable for TransactionStatus {
                 target.write_u8(self.variant() as u8);
                 cause.write_into(target);
             },
+            TransactionStatus::Failed { reason } => {
+                target.write_u8(self.variant() as u8);
+                reason.write_into(target);
+            },
         }
     }
 }
@@ -277,6 +314,10 @@ impl Deserializable for TransactionStatus {
                 let cause = DiscardCause::read_from(source)?;
                 Ok(TransactionStatus::Discarded(cause))
             },
+            variant if variant == TransactionStatusVariant::Failed as u8 => {
+                let reason = String::read_from(source)?;
+                Ok(TransactionStatus::Failed { reason })
+            },
             _ => Err(DeserializationError::InvalidValue("Invalid transaction status".to_string())),
         }
     }
@@ -28,13 +28,15 @@ use miden_protocol::note::{
 };
 use miden_protocol::transaction::TransactionScript;
 use miden_protocol::vm::AdviceMap;
-use miden_protocol::{Felt, Word};
+use miden_protocol::{Felt, WORD_SIZE, Word};
 use miden_standards::note::{
+    NetworkAccountTarget,
     P2idNote,
     P2ideNote,
     P2ideNoteStorage,
     PswapNote,
     PswapNoteStorage,
+    StandardNote,
     SwapNote,
 };
 
@@ -84,8 +86,9 @@ pub struct TransactionRequestBuilder {
     /// added to the executor and prover.
     foreign_accounts: BTreeMap<AccountId, ForeignAccount>,
     /// The number of blocks in relation to the transaction's reference block after which the
-    /// transaction will expire. If `None`, the transaction will not expire.
-    expiration_delta: Option<u16>,
+    /// transaction will expire. If `None`, the transaction will not expire. Validated against
+    /// [`TransactionRequestBuilder::MAX_EXPIRATION_DELTA`] when building the request.
+    expiration_delta: Option<u32>,
     /// Indicates whether to **silently** ignore invalid input notes when executing the
     /// transaction. This will allow the transaction to be executed even if some input notes
     /// are invalid.
@@ -101,9 +104,16 @@ pub struct TransactionRequestBuilder {
     ///
     /// See [`TransactionRequestBuilder::expected_ntx_scripts`] for details.
     expected_ntx_scripts: Vec<NoteScript>,
+    /// Tags overriding the ones of the own output notes, keyed by the note's index in
+    /// `own_output_notes`.
+    output_note_tags: BTreeMap<usize, NoteTag>,
 }
 
 impl TransactionRequestBuilder {
+    /// Maximum number of blocks after the reference block that a transaction can remain valid
+    /// for, as enforced by the transaction kernel.
+    pub const MAX_EXPIRATION_DELTA: u32 = u16::MAX as u32;
+
     // CONSTRUCTORS
     // --------------------------------------------------------------------------------------------
 
@@ -124,6 +134,7 @@ impl TransactionRequestBuilder {
             script_arg: None,
             auth_arg: None,
             expected_ntx_scripts: vec![],
+            output_note_tags: BTreeMap::new(),
         }
     }
 
@@ -246,9 +257,31 @@ impl TransactionRequestBuilder {
     /// Setting transaction expiration delta defines an upper bound for transaction expiration,
     /// but other code executed during the transaction may impose an even smaller transaction
     /// expiration delta.
+    ///
+    /// A delta of zero is rejected by [`Self::build`], as the transaction kernel doesn't accept
+    /// it.
     #[must_use]
     pub fn expiration_delta(mut self, expiration_delta: u16) -> Self {
-        self.expiration_delta = Some(expiration_delta);
+        self.expiration_delta = Some(expiration_delta.into());
+        self
+    }
+
+    /// Makes the transaction expire `delta_blocks` blocks after its reference block, which is
+    /// the client's sync height at execution time. The node rejects the transaction once the
+    /// chain goes past that block, and the client marks it as
+    /// [`DiscardCause::Expired`](crate::transaction::DiscardCause::Expired) on the next sync.
+    ///
+    /// This is independent from the client's
+    /// [`tx_discard_delta`](crate::builder::ClientBuilder::tx_discard_delta) (formerly
+    /// `tx_graceful_blocks`), which discards pending transactions locally a number of blocks
+    /// after their submission. A transaction is discarded by whichever of the two happens first,
+    /// so an expiration shorter than the discard delta is the one that applies.
+    ///
+    /// `delta_blocks` must be between 1 and [`Self::MAX_EXPIRATION_DELTA`]; this is checked by
+    /// [`Self::build`].
+    #[must_use]
+    pub fn with_expiration(mut self, delta_blocks: u32) -> Self {
+        self.expiration_delta = Some(delta_blocks);
         self
     }
 
@@ -296,6 +329,26 @@ impl TransactionRequestBuilder {
         self
     }
 
+    /// Overrides the [`NoteTag`] of the own output note at `note_index`, in the order notes are
+    /// added through [`TransactionRequestBuilder::own_output_notes`] (standardized requests such
+    /// as [`TransactionRequestBuilder::build_pay_to_id`] append their notes after the ones already
+    /// added).
+    ///
+    /// By default, output notes keep the tag they were created with: P2ID and P2IDE notes target
+    /// the receiving account ([`NoteTag::with_account_target`]), other standard notes (e.g. SWAP)
+    /// keep the tag chosen by their constructor, and notes built without a tag use
+    /// [`NoteTag::default`]. A custom tag is needed when the recipient is subscribed to a
+    /// different tag, as otherwise it won't discover the note during sync.
+    ///
+    /// The tag is validated when the request is built: the index must refer to an own output
+    /// note, and public notes targeting a network account must keep that account's tag so the
+    /// network transaction builder picks them up.
+    #[must_use]
+    pub fn with_output_note_tag(mut self, note_index: usize, tag: NoteTag) -> Self {
+        self.output_note_tags.insert(note_index, tag);
+        self
+    }
+
     // STANDARDIZED REQUESTS
     // --------------------------------------------------------------------------------------------
 
@@ -567,8 +620,11 @@ impl TransactionRequestBuilder {
     /// # Errors
     /// - If both a custom script and own output notes are set.
     /// - If an expiration delta is set when a custom script is set.
+    /// - If the expiration delta is zero or exceeds [`Self::MAX_EXPIRATION_DELTA`].
     /// - If an invalid note variant is encountered in the own output notes.
-    pub fn build(self) -> Result<TransactionRequest, TransactionRequestError> {
+    /// - If a custom output note tag refers to a missing note, or doesn't match the network account
+    ///   targeted by a public note.
+    pub fn build(mut self) -> Result<TransactionRequest, TransactionRequestError> {
         let mut seen_input_notes = BTreeSet::new();
         for (note_id, _) in &self.input_notes_args {
             if !seen_input_notes.insert(note_id) {
@@ -576,6 +632,24 @@ impl TransactionRequestBuilder {
             }
         }
 
+        let expiration_delta = self
+            .expiration_delta
+            .map(|delta| {
+                u16::try_from(delta)
+                    .ok()
+                    .filter(|delta| *delta > 0)
+                    .ok_or(TransactionRequestError::InvalidExpirationDelta(delta))
+            })
+            .transpose()?;
+
+        for (&index, &tag) in &self.output_note_tags {
+            let note = self
+                .own_output_notes
+                .get_mut(index)
+                .ok_or(TransactionRequestError::OutputNoteIndexOutOfBounds(index))?;
+            *note = with_tag(note, index, tag)?;
+        }
+
         let script_template = match (self.custom_script, self.own_output_notes.is_empty()) {
             (Some(_), false) => {
                 return Err(TransactionRequestError::ScriptTemplateError(
@@ -609,7 +683,7 @@ impl TransactionRequestBuilder {
             advice_map: self.advice_map,
             merkle_store: self.merkle_store,
             foreign_accounts: self.foreign_accounts,
-            expiration_delta: self.expiration_delta,
+            expiration_delta,
             ignore_invalid_input_notes: self.ignore_invalid_input_notes,
             script_arg: self.script_arg,
             auth_arg: self.auth_arg,
@@ -618,6 +692,30 @@ impl TransactionRequestBuilder {
     }
 }
 
+/// Returns a copy of `note` carrying `tag`, checking that public notes targeting a network account
+/// keep the tag the network transaction builder listens to.
+fn with_tag(note: &Note, index: usize, tag: NoteTag) -> Result<Note, TransactionRequestError> {
+    if note.metadata().note_type() == NoteType::Public
+        && let Ok(target) = NetworkAccountTarget::try_from(note.attachments())
+    {
+        let expected = NoteTag::with_account_target(target.target_id());
+        if tag != expected {
+            return Err(TransactionRequestError::OutputNoteTagMismatch {
+                index,
+                expected,
+                actual: tag,
+            });
+        }
+    }
+
+    Ok(Note::with_attachments(
+        note.assets().clone(),
+        note.metadata().partial_metadata().with_tag(tag),
+        note.recipient().clone(),
+        note.attachments().clone(),
+    ))
+}
+
 // PAYMENT NOTE DESCRIPTION
 // ================================================================================================
 
@@ -770,6 +868,68 @@ impl SwapTransactionData {
         }
     }
 
+    /// Decodes the swap terms of a SWAP note created by `sender_account_id` from its `details`.
+    ///
+    /// The offered asset is the note's only asset, and the requested asset is read from the SWAP
+    /// storage layout: the asset's vault key and value are stored in the third and fourth storage
+    /// words, after the payback note parameters and attachment.
+    ///
+    /// Returns `Ok(None)` if the note wasn't built from the SWAP script.
+    ///
+    /// # Errors
+    /// Returns [`TransactionRequestError::InvalidSwapNote`] if the note was built from the SWAP
+    /// script but its assets or storage don't follow the SWAP layout.
+    pub fn from_swap_note(
+        sender_account_id: AccountId,
+        details: &NoteDetails,
+    ) -> Result<Option<SwapTransactionData>, TransactionRequestError> {
+        /// Number of storage items of a SWAP note.
+        const SWAP_NUM_STORAGE_ITEMS: usize = 5 * WORD_SIZE;
+        /// Index of the first storage item of the requested asset's vault key.
+        const SWAP_REQUESTED_ASSET_KEY_INDEX: usize = 2 * WORD_SIZE;
+        /// Index of the first storage item of the requested asset's value.
+        const SWAP_REQUESTED_ASSET_VALUE_INDEX: usize = 3 * WORD_SIZE;
+
+        if !matches!(StandardNote::from_script(details.script()), Some(StandardNote::SWAP)) {
+            return Ok(None);
+        }
+
+        let offered_asset = match details.assets().iter().collect::<Vec<_>>().as_slice() {
+            [asset] => **asset,
+            assets => {
+                return Err(TransactionRequestError::InvalidSwapNote(format!(
+                    "expected a single offered asset, found {}",
+                    assets.len()
+                )));
+            },
+        };
+
+        let storage = details.storage().items();
+        if storage.len() != SWAP_NUM_STORAGE_ITEMS {
+            return Err(TransactionRequestError::InvalidSwapNote(format!(
+                "expected {SWAP_NUM_STORAGE_ITEMS} storage items, found {}",
+                storage.len()
+            )));
+        }
+
+        let word_at = |index: usize| {
+            Word::from([storage[index], storage[index + 1], storage[index + 2], storage[index + 3]])
+        };
+        let requested_asset = Asset::from_key_value_words(
+            word_at(SWAP_REQUESTED_ASSET_KEY_INDEX),
+            word_at(SWAP_REQUESTED_ASSET_VALUE_INDEX),
+        )
+        .map_err(|err| {
+            TransactionRequestError::InvalidSwapNote(format!("invalid requested asset: {err}"))
+        })?;
+
+        Ok(Some(SwapTransactionData::new(
+            sender_account_id,
+            offered_asset,
+            requested_asset,
+        )))
+    }
+
     /// Returns the executor [`AccountId`].
     pub fn account_id(&self) -> AccountId {
         self.sender_account_id
@@ -503,6 +503,16 @@ pub enum TransactionRequestError {
     InputNoteNotAuthenticated(NoteId),
     #[error("note {0} has already been consumed")]
     InputNoteAlreadyConsumed(NoteId),
+    #[error("output note index {0} is out of bounds: the request has no own output note at it")]
+    OutputNoteIndexOutOfBounds(usize),
+    #[error(
+        "output note {index} targets a network account and must use tag {expected}, but tag {actual} was set"
+    )]
+    OutputNoteTagMismatch {
+        index: usize,
+        expected: NoteTag,
+        actual: NoteTag,
+    },
     #[error(
         "output note declares sender {actual} but the transaction is executed by account {expected}"
     )]
@@ -511,6 +521,11 @@ pub enum TransactionRequestError {
     InvalidTransactionScript(#[from] TransactionScriptError),
     #[error("merkle proof error")]
     MerkleError(#[from] MerkleError),
+    #[error(
+        "invalid expiration delta {0}: must be between 1 and {max} blocks",
+        max = TransactionRequestBuilder::MAX_EXPIRATION_DELTA
+    )]
+    InvalidExpirationDelta(u32),
     #[error("empty transaction: the request has no input notes and no account state changes")]
     NoInputNotesNorAccountChange,
     #[error("note not found: {0}")]
@@ -529,6 +544,8 @@ pub enum TransactionRequestError {
         "non-fungible asset issued by faucet {0} is not available in the account vault or incoming notes"
     )]
     MissingNonFungibleAsset(AccountId),
+    #[error("note doesn't follow the SWAP note layout: {0}")]
+    InvalidSwapNote(String),
     #[error("PSWAP note can only be cancelled by its creator: expected {expected}, got {actual}")]
     PswapCancelCreatorMismatch { expected: AccountId, actual: AccountId },
     #[error("error building script")]
@@ -567,19 +584,40 @@ mod tests {
     };
     use miden_protocol::asset::FungibleAsset;
     use miden_protocol::crypto::rand::{FeltRng, RandomCoin};
-    use miden_protocol::note::{NoteAttachments, NoteTag, NoteType};
+    use miden_protocol::note::{
+        NoteAssets,
+        NoteAttachment,
+        NoteAttachments,
+        NoteDetails,
+        NoteRecipient,
+        NoteStorage,
+        NoteTag,
+        NoteType,
+    };
     use miden_protocol::testing::account_id::{
         ACCOUNT_ID_PRIVATE_FUNGIBLE_FAUCET,
+        ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET,
         ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
         ACCOUNT_ID_SENDER,
     };
     use miden_protocol::{EMPTY_WORD, Felt, Word};
     use miden_standards::account::auth::AuthSingleSig;
-    use miden_standards::note::P2idNote;
+    use miden_standards::note::{
+        NetworkAccountTarget,
+        NoteExecutionHint,
+        P2idNote,
+        StandardNote,
+        SwapNote,
+    };
     use miden_standards::testing::account_component::MockAccountComponent;
     use miden_tx::utils::serde::{Deserializable, Serializable};
 
-    use super::{TransactionRequest, TransactionRequestBuilder};
+    use super::{
+        SwapTransactionData,
+        TransactionRequest,
+        TransactionRequestBuilder,
+        TransactionRequestError,
+    };
     use crate::rpc::domain::account::AccountStorageRequirements;
     use crate::transaction::ForeignAccount;
 
@@ -671,4 +709,157 @@ mod tests {
         let deserialized_tx_request = TransactionRequest::read_from_bytes(&buffer).unwrap();
         assert_eq!(tx_request, deserialized_tx_request);
     }
+
+    #[test]
+    fn expiration_delta_is_validated() {
+        let tx_request = TransactionRequestBuilder::new().with_expiration(10).build().unwrap();
+        assert_eq!(tx_request.expiration_delta, Some(10));
+
+        let max = TransactionRequestBuilder::MAX_EXPIRATION_DELTA;
+        let tx_request = TransactionRequestBuilder::new().with_expiration(max).build().unwrap();
+        assert_eq!(tx_request.expiration_delta, Some(u16::MAX));
+
+        for invalid in [0, max + 1] {
+            let err =
+                TransactionRequestBuilder::new().with_expiration(invalid).build().unwrap_err();
+            assert!(
+                matches!(err, TransactionRequestError::InvalidExpirationDelta(delta) if delta == invalid)
+            );
+        }
+    }
+
+    #[test]
+    fn output_note_tags_can_be_overridden() {
+        let sender_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let target_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_PRIVATE_FUNGIBLE_FAUCET).unwrap();
+        let mut rng = RandomCoin::new(Word::default());
+        let asset = FungibleAsset::new(faucet_id, 100).unwrap();
+
+        let notes: Vec<_> = (0..2)
+            .map(|_| {
+                P2idNote::create(
+                    sender_id,
+                    target_id,
+                    vec![asset.into()],
+                    NoteType::Public,
+                    NoteAttachments::empty(),
+                    &mut rng,
+                )
+                .unwrap()
+            })
+            .collect();
+
+        let custom_tag = NoteTag::new(0xabcd_0000);
+        let tx_request = TransactionRequestBuilder::new()
+            .own_output_notes(notes.clone())
+            .with_output_note_tag(1, custom_tag)
+            .build()
+            .unwrap();
+
+        let output_notes = tx_request.expected_output_own_notes();
+        assert_eq!(output_notes[0].metadata().tag(), notes[0].metadata().tag());
+        assert_eq!(output_notes[1].metadata().tag(), custom_tag);
+        assert_eq!(output_notes[1].id(), notes[1].id());
+
+        let err = TransactionRequestBuilder::new()
+            .own_output_notes(notes)
+            .with_output_note_tag(2, custom_tag)
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, TransactionRequestError::OutputNoteIndexOutOfBounds(2)));
+    }
+
+    #[test]
+    fn network_note_tags_must_match_the_target() {
+        let sender_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let network_account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_PRIVATE_FUNGIBLE_FAUCET).unwrap();
+        let mut rng = RandomCoin::new(Word::default());
+
+        let target =
+            NetworkAccountTarget::new(network_account_id, NoteExecutionHint::Always).unwrap();
+        let attachments = NoteAttachments::new(vec![NoteAttachment::from(target)]).unwrap();
+        let note = P2idNote::create(
+            sender_id,
+            network_account_id,
+            vec![FungibleAsset::new(faucet_id, 100).unwrap().into()],
+            NoteType::Public,
+            attachments,
+            &mut rng,
+        )
+        .unwrap();
+
+        let expected = NoteTag::with_account_target(network_account_id);
+        let err = TransactionRequestBuilder::new()
+            .own_output_notes(vec![note.clone()])
+            .with_output_note_tag(0, NoteTag::new(0xabcd_0000))
+            .build()
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            TransactionRequestError::OutputNoteTagMismatch { index: 0, expected: e, .. } if e == expected
+        ));
+
+        TransactionRequestBuilder::new()
+            .own_output_notes(vec![note])
+            .with_output_note_tag(0, expected)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn swap_terms_are_decoded_from_the_swap_storage_layout() {
+        let sender_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let offered_faucet_id = AccountId::try_from(ACCOUNT_ID_PRIVATE_FUNGIBLE_FAUCET).unwrap();
+        let requested_faucet_id = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET).unwrap();
+        let offered_asset = FungibleAsset::new(offered_faucet_id, 100).unwrap().into();
+        let requested_asset = FungibleAsset::new(requested_faucet_id, 25).unwrap().into();
+        let mut rng = RandomCoin::new(Word::default());
+
+        let (swap_note, _) = SwapNote::create(
+            sender_id,
+            offered_asset,
+            requested_asset,
+            NoteType::Public,
+            NoteAttachments::empty(),
+            NoteType::Private,
+            &mut rng,
+        )
+        .unwrap();
+        let swap_data = SwapTransactionData::from_swap_note(sender_id, &swap_note.into())
+            .unwrap()
+            .unwrap();
+        assert_eq!(swap_data.account_id(), sender_id);
+        assert_eq!(swap_data.offered_asset(), offered_asset);
+        assert_eq!(swap_data.requested_asset(), requested_asset);
+
+        // Notes built from other scripts aren't SWAP notes.
+        let p2id_note = P2idNote::create(
+            sender_id,
+            sender_id,
+            vec![offered_asset],
+            NoteType::Private,
+            NoteAttachments::empty(),
+            &mut rng,
+        )
+        .unwrap();
+        assert!(
+            SwapTransactionData::from_swap_note(sender_id, &p2id_note.into())
+                .unwrap()
+                .is_none()
+        );
+
+        // A SWAP note whose storage doesn't follow the layout is rejected.
+        let recipient = NoteRecipient::new(
+            rng.draw_word(),
+            StandardNote::SWAP.script(),
+            NoteStorage::new(vec![]).unwrap(),
+        );
+        let details = NoteDetails::new(NoteAssets::new(vec![offered_asset]).unwrap(), recipient);
+        let err = SwapTransactionData::from_swap_note(sender_id, &details).unwrap_err();
+        assert!(matches!(err, TransactionRequestError::InvalidSwapNote(_)));
+    }
 }
@@ -1,5 +1,6 @@
 use alloc::vec::Vec;
 
+use miden_protocol::Word;
 use miden_protocol::account::AccountDelta;
 use miden_protocol::block::BlockNumber;
 use miden_protocol::note::{NoteDetails, NoteTag};
@@ -21,6 +22,7 @@ use miden_tx::utils::serde::{
 };
 
 use crate::ClientError;
+use crate::vm::AdviceInputs;
 
 // TRANSACTION RESULT
 // ================================================================================================
@@ -90,6 +92,16 @@ impl TransactionResult {
     pub fn consumed_notes(&self) -> &InputNotes<InputNote> {
         self.transaction.tx_inputs().input_notes()
     }
+
+    /// Returns the commitment of the executing account's state after the transaction.
+    pub fn final_account_commitment(&self) -> Word {
+        self.transaction.final_account().to_commitment()
+    }
+
+    /// Returns the advice inputs captured during the transaction execution.
+    pub fn advice_witness(&self) -> &AdviceInputs {
+        self.transaction.advice_witness()
+    }
 }
 
 impl From<&TransactionResult> for TransactionInputs {
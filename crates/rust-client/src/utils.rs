@@ -4,6 +4,7 @@
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use core::num::ParseIntError;
+use core::sync::atomic::{AtomicU64, Ordering};
 
 use miden_standards::account::faucets::FungibleFaucet;
 pub use miden_tx::utils::serde::{
@@ -100,12 +101,37 @@ pub fn tokens_to_base_units(decimal_str: &str, n_decimals: u8) -> Result<u64, To
     combined.parse::<u64>().map_err(TokenParseError::ParseU64)
 }
 
+// CORRELATION IDS
+// ================================================================================================
+
+static NEXT_CORRELATION_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Returns a new correlation id to tag the `tracing` span of a multi-step client operation.
+///
+/// Ids are unique within the running process, which is enough to tell apart the logs of
+/// concurrent or consecutive operations.
+pub(crate) fn next_correlation_id() -> u64 {
+    NEXT_CORRELATION_ID.fetch_add(1, Ordering::Relaxed)
+}
+
 // TESTS
 // ================================================================================================
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::{TokenParseError, base_units_to_tokens, tokens_to_base_units};
+    use crate::utils::{
+        TokenParseError,
+        base_units_to_tokens,
+        next_correlation_id,
+        tokens_to_base_units,
+    };
+
+    #[test]
+    fn correlation_ids_are_unique() {
+        let first = next_correlation_id();
+        let second = next_correlation_id();
+        assert!(second > first);
+    }
 
     #[test]
     fn convert_tokens_to_base_units() {
@@ -19,19 +19,24 @@ use miden_client::account::{
     PartialStorageMap,
     StorageMap,
     StorageMapKey,
+    StorageSlot,
     StorageSlotName,
     StorageSlotType,
 };
 use miden_client::asset::{Asset, AssetVault, AssetWitness, FungibleAsset};
 use miden_client::store::{
+    AccountFilter,
     AccountRecord,
     AccountRecordData,
     AccountSmtForest,
     AccountStatus,
     AccountStorageFilter,
     ClientAccountType,
+    InputNoteRecord,
+    IntegrityIssue,
     StoreError,
 };
+use miden_client::sync::NoteTagRecord;
 use miden_client::utils::{Deserializable, Serializable};
 use miden_client::{AccountError, Felt, Word};
 use miden_protocol::account::{AccountStorageHeader, StorageMapWitness, StorageSlotHeader};
@@ -43,13 +48,16 @@ use rusqlite::{Connection, OptionalExtension, Transaction, named_params, params}
 use crate::account::helpers::{
     query_account_addresses,
     query_account_code,
+    query_all_addresses,
     query_historical_account_headers,
     query_latest_account_headers,
     query_storage_slots,
     query_storage_values,
     query_vault_assets,
 };
+use crate::note::upsert_input_notes_tx;
 use crate::sql_error::SqlResultExt;
+use crate::sync::add_note_tag_tx;
 use crate::transaction::with_forest_snapshot;
 use crate::{SqliteStore, column_value_as_u64, insert_sql, subst, u64_to_value};
 
@@ -58,7 +66,7 @@ impl SqliteStore {
     // --------------------------------------------------------------------------------------------
 
     pub(crate) fn get_account_ids(conn: &mut Connection) -> Result<Vec<AccountId>, StoreError> {
-        const QUERY: &str = "SELECT id FROM latest_account_headers";
+        const QUERY: &str = "SELECT id FROM latest_account_headers WHERE archived = FALSE";
 
         conn.prepare_cached(QUERY)
             .into_store_error()?
@@ -71,12 +79,18 @@ impl SqliteStore {
             .collect::<Result<Vec<AccountId>, StoreError>>()
     }
 
-    pub(crate) fn get_account_headers(
+    pub(crate) fn get_filtered_account_headers(
         conn: &mut Connection,
+        filter: AccountFilter,
     ) -> Result<Vec<(AccountHeader, AccountStatus)>, StoreError> {
-        Ok(query_latest_account_headers(conn, "1=1 ORDER BY id", params![])?
+        let where_clause = match filter {
+            AccountFilter::Active => "archived = FALSE ORDER BY id",
+            AccountFilter::Archived => "archived = TRUE ORDER BY id",
+            AccountFilter::All => "1=1 ORDER BY id",
+        };
+        Ok(query_latest_account_headers(conn, where_clause, params![])?
             .into_iter()
-            .map(|(header, status, _)| (header, status))
+            .map(|(header, status, ..)| (header, status))
             .collect())
     }
 
@@ -86,7 +100,19 @@ impl SqliteStore {
     ) -> Result<Option<(AccountHeader, AccountStatus)>, StoreError> {
         Ok(query_latest_account_headers(conn, "id = ?", params![account_id.to_hex()])?
             .pop()
-            .map(|(header, status, _)| (header, status)))
+            .map(|(header, status, ..)| (header, status)))
+    }
+
+    pub(crate) fn get_account_nonce(
+        conn: &Connection,
+        account_id: AccountId,
+    ) -> Result<Option<Felt>, StoreError> {
+        const QUERY: &str = "SELECT nonce FROM latest_account_headers WHERE id = ?";
+        Ok(conn
+            .query_row(QUERY, params![account_id.to_hex()], |row| column_value_as_u64(row, 0))
+            .optional()
+            .into_store_error()?
+            .map(|nonce| Felt::new(nonce).expect("stored nonce must be a valid Felt")))
     }
 
     pub(crate) fn get_account_header_by_commitment(
@@ -105,10 +131,10 @@ impl SqliteStore {
 
     /// Retrieves a complete account record with full vault and storage data.
     pub(crate) fn get_account(
-        conn: &mut Connection,
+        conn: &Connection,
         account_id: AccountId,
     ) -> Result<Option<AccountRecord>, StoreError> {
-        let Some((header, status, client_account_type)) =
+        let Some((header, status, client_account_type, watch_only)) =
             query_latest_account_headers(conn, "id = ?", params![account_id.to_hex()])?.pop()
         else {
             return Ok(None);
@@ -137,7 +163,223 @@ impl SqliteStore {
         );
 
         let account_data = AccountRecordData::Full(account);
-        Ok(Some(AccountRecord::new(account_data, status, client_account_type)))
+        Ok(Some(
+            AccountRecord::new(account_data, status, client_account_type)
+                .with_watch_only(watch_only),
+        ))
+    }
+
+    /// Retrieves the full account state whose commitment matches `account_commitment`.
+    ///
+    /// Historical states are rebuilt in memory, so the lookup only reads from the database and
+    /// works on read-only stores.
+    pub(crate) fn get_account_by_commitment(
+        conn: &mut Connection,
+        account_commitment: Word,
+    ) -> Result<Option<Account>, StoreError> {
+        // Read the latest and historical tables from a single snapshot.
+        let tx = conn.transaction().into_store_error()?;
+
+        let account = if let Some((header, ..)) = query_latest_account_headers(
+            &tx,
+            "account_commitment = ?",
+            params![account_commitment.to_string()],
+        )?
+        .pop()
+        {
+            let Some(record) = Self::get_account(&tx, header.id())? else {
+                return Ok(None);
+            };
+            Account::try_from(record)
+                .map_err(|err| StoreError::DatabaseError(format!("error reading account: {err}")))?
+        } else {
+            let Some((header, status)) = query_historical_account_headers(
+                &tx,
+                "account_commitment = ?",
+                params![account_commitment.to_string()],
+            )?
+            .pop() else {
+                return Ok(None);
+            };
+            let Some(account) = Self::rebuild_historical_account(&tx, &header, &status)? else {
+                return Ok(None);
+            };
+            account
+        };
+
+        if account.to_commitment() != account_commitment {
+            return Err(StoreError::AccountCommitmentMismatch(account.id()));
+        }
+
+        Ok(Some(account))
+    }
+
+    /// Rebuilds the historical account state described by `header`, starting from the latest
+    /// state and reverting, newest first, the changes recorded for every later nonce.
+    ///
+    /// Returns `None` if the code of the historical state isn't stored.
+    fn rebuild_historical_account(
+        conn: &Connection,
+        header: &AccountHeader,
+        status: &AccountStatus,
+    ) -> Result<Option<Account>, StoreError> {
+        let account_id_hex = header.id().to_hex();
+        let nonce = u64_to_value(header.nonce().as_canonical_u64());
+
+        let storage = Self::rebuild_historical_storage(conn, &account_id_hex, &nonce)?;
+        let vault = Self::rebuild_historical_vault(conn, &account_id_hex, &nonce)?;
+        let Some(code) = query_account_code(conn, header.code_commitment())? else {
+            return Ok(None);
+        };
+
+        Ok(Some(Account::new_unchecked(
+            header.id(),
+            vault,
+            storage,
+            code,
+            header.nonce(),
+            status.seed().copied(),
+        )))
+    }
+
+    /// Rebuilds the storage of an account as it was at `nonce`. See
+    /// [`SqliteStore::rebuild_historical_account`].
+    fn rebuild_historical_storage(
+        conn: &Connection,
+        account_id_hex: &str,
+        nonce: &Value,
+    ) -> Result<AccountStorage, StoreError> {
+        let mut slots: BTreeMap<String, (String, u8)> = conn
+            .prepare(
+                "SELECT slot_name, slot_value, slot_type FROM latest_account_storage \
+                 WHERE account_id = ?",
+            )
+            .into_store_error()?
+            .query_map(params![account_id_hex], |row| Ok((row.get(0)?, (row.get(1)?, row.get(2)?))))
+            .into_store_error()?
+            .collect::<Result<_, _>>()
+            .into_store_error()?;
+        let reverted_slots = conn
+            .prepare(
+                "SELECT slot_name, old_slot_value, slot_type FROM historical_account_storage \
+                 WHERE account_id = ? AND replaced_at_nonce > ? ORDER BY replaced_at_nonce DESC",
+            )
+            .into_store_error()?
+            .query_map(params![account_id_hex, nonce], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, Option<String>>(1)?, row.get(2)?))
+            })
+            .into_store_error()?
+            .collect::<Result<Vec<_>, _>>()
+            .into_store_error()?;
+        for (slot_name, old_value, slot_type) in reverted_slots {
+            match old_value {
+                Some(old_value) => slots.insert(slot_name, (old_value, slot_type)),
+                None => slots.remove(&slot_name),
+            };
+        }
+
+        let mut map_entries: BTreeMap<(String, String), String> = conn
+            .prepare(
+                "SELECT slot_name, key, value FROM latest_storage_map_entries \
+                 WHERE account_id = ?",
+            )
+            .into_store_error()?
+            .query_map(params![account_id_hex], |row| Ok(((row.get(0)?, row.get(1)?), row.get(2)?)))
+            .into_store_error()?
+            .collect::<Result<_, _>>()
+            .into_store_error()?;
+        let reverted_map_entries = conn
+            .prepare(
+                "SELECT slot_name, key, old_value FROM historical_storage_map_entries \
+                 WHERE account_id = ? AND replaced_at_nonce > ? ORDER BY replaced_at_nonce DESC",
+            )
+            .into_store_error()?
+            .query_map(params![account_id_hex, nonce], |row| {
+                Ok((
+                    (row.get::<_, String>(0)?, row.get::<_, String>(1)?),
+                    row.get::<_, Option<String>>(2)?,
+                ))
+            })
+            .into_store_error()?
+            .collect::<Result<Vec<_>, _>>()
+            .into_store_error()?;
+        for (entry_key, old_value) in reverted_map_entries {
+            match old_value {
+                Some(old_value) => map_entries.insert(entry_key, old_value),
+                None => map_entries.remove(&entry_key),
+            };
+        }
+
+        let mut maps: BTreeMap<String, StorageMap> = BTreeMap::new();
+        for ((slot_name, key), value) in map_entries {
+            maps.entry(slot_name)
+                .or_insert_with(StorageMap::new)
+                .insert(StorageMapKey::new(Word::try_from(key)?), Word::try_from(value)?)?;
+        }
+
+        // Slots are ordered by name, as in `query_storage_slots`.
+        let mut storage_slots = BTreeMap::new();
+        for (slot_name, (value, slot_type)) in slots {
+            let name = StorageSlotName::new(slot_name.clone())
+                .map_err(|err| StoreError::ParsingError(err.to_string()))?;
+            let slot_type = StorageSlotType::try_from(slot_type)
+                .map_err(|err| StoreError::ParsingError(err.to_string()))?;
+            let slot = match slot_type {
+                StorageSlotType::Value => {
+                    StorageSlot::with_value(name.clone(), Word::try_from(value)?)
+                },
+                StorageSlotType::Map => StorageSlot::with_map(
+                    name.clone(),
+                    maps.remove(&slot_name).unwrap_or_else(StorageMap::new),
+                ),
+            };
+            storage_slots.insert(name, slot);
+        }
+
+        Ok(AccountStorage::new(storage_slots.into_values().collect())?)
+    }
+
+    /// Rebuilds the vault of an account as it was at `nonce`. See
+    /// [`SqliteStore::rebuild_historical_account`].
+    fn rebuild_historical_vault(
+        conn: &Connection,
+        account_id_hex: &str,
+        nonce: &Value,
+    ) -> Result<AssetVault, StoreError> {
+        let mut assets: BTreeMap<String, String> = conn
+            .prepare("SELECT vault_key, asset FROM latest_account_assets WHERE account_id = ?")
+            .into_store_error()?
+            .query_map(params![account_id_hex], |row| Ok((row.get(0)?, row.get(1)?)))
+            .into_store_error()?
+            .collect::<Result<_, _>>()
+            .into_store_error()?;
+        let reverted_assets = conn
+            .prepare(
+                "SELECT vault_key, old_asset FROM historical_account_assets \
+                 WHERE account_id = ? AND replaced_at_nonce > ? ORDER BY replaced_at_nonce DESC",
+            )
+            .into_store_error()?
+            .query_map(params![account_id_hex, nonce], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, Option<String>>(1)?))
+            })
+            .into_store_error()?
+            .collect::<Result<Vec<_>, _>>()
+            .into_store_error()?;
+        for (vault_key, old_asset) in reverted_assets {
+            match old_asset {
+                Some(old_asset) => assets.insert(vault_key, old_asset),
+                None => assets.remove(&vault_key),
+            };
+        }
+
+        let assets = assets
+            .into_iter()
+            .map(|(vault_key, asset)| {
+                Ok(Asset::from_key_value_words(Word::try_from(vault_key)?, Word::try_from(asset)?)?)
+            })
+            .collect::<Result<Vec<Asset>, StoreError>>()?;
+
+        Ok(AssetVault::new(&assets)?)
     }
 
     /// Retrieves a minimal partial account record with storage and vault witnesses.
@@ -145,7 +387,7 @@ impl SqliteStore {
         conn: &mut Connection,
         account_id: AccountId,
     ) -> Result<Option<AccountRecord>, StoreError> {
-        let Some((header, status, client_account_type)) =
+        let Some((header, status, client_account_type, watch_only)) =
             query_latest_account_headers(conn, "id = ?", params![account_id.to_hex()])?.pop()
         else {
             return Ok(None);
@@ -188,7 +430,10 @@ impl SqliteStore {
             status.seed().copied(),
         )?;
         let account_record_data = AccountRecordData::Partial(partial_account);
-        Ok(Some(AccountRecord::new(account_record_data, status, client_account_type)))
+        Ok(Some(
+            AccountRecord::new(account_record_data, status, client_account_type)
+                .with_watch_only(watch_only),
+        ))
     }
 
     pub fn get_foreign_account_code(
@@ -297,6 +542,79 @@ impl SqliteStore {
         Ok((item, witness))
     }
 
+    /// Retrieves multiple items from the same storage map of an account. The map root is looked
+    /// up once and all witnesses are produced from the [`AccountSmtForest`] under a single lock.
+    pub(crate) fn get_account_map_items(
+        conn: &mut Connection,
+        smt_forest: &Arc<RwLock<AccountSmtForest>>,
+        account_id: AccountId,
+        slot_name: StorageSlotName,
+        keys: &[StorageMapKey],
+    ) -> Result<Vec<(Word, StorageMapWitness)>, StoreError> {
+        // Acquire forest lock before getting header in order to avoid concurrent writes to it.
+        let smt_forest = smt_forest
+            .read()
+            .map_err(|_| StoreError::DatabaseError("smt_forest read lock poisoned".to_string()))?;
+        let header = Self::get_account_header(conn, account_id)?
+            .ok_or(StoreError::AccountDataNotFound(account_id))?
+            .0;
+
+        let mut storage_values = query_storage_values(conn, account_id)?;
+        let (slot_type, map_root) = storage_values
+            .remove(&slot_name)
+            .ok_or(StoreError::AccountStorageRootNotFound(header.storage_commitment()))?;
+        if slot_type != StorageSlotType::Map {
+            return Err(StoreError::AccountError(AccountError::StorageSlotNotMap(slot_name)));
+        }
+
+        let witnesses = smt_forest.get_storage_map_item_witnesses(map_root, keys)?;
+        Ok(keys
+            .iter()
+            .zip(witnesses)
+            .map(|(key, witness)| (witness.get(*key).unwrap_or(miden_client::EMPTY_WORD), witness))
+            .collect())
+    }
+
+    /// Retrieves the keys of a storage map slot, ordered by their hex encoding, without loading
+    /// the map values.
+    pub(crate) fn get_storage_map_keys(
+        conn: &mut Connection,
+        account_id: AccountId,
+        slot_name: &StorageSlotName,
+    ) -> Result<Vec<StorageMapKey>, StoreError> {
+        const SLOT_TYPE_QUERY: &str = "SELECT slot_type FROM latest_account_storage \
+             WHERE account_id = ?1 AND slot_name = ?2";
+        const KEYS_QUERY: &str = "SELECT key FROM latest_storage_map_entries \
+             WHERE account_id = ?1 AND slot_name = ?2 ORDER BY key";
+
+        let account_id_hex = account_id.to_hex();
+        let slot_name_str = slot_name.to_string();
+
+        let slot_type: Option<u8> = conn
+            .query_row(SLOT_TYPE_QUERY, params![account_id_hex, slot_name_str], |row| row.get(0))
+            .optional()
+            .into_store_error()?;
+        let slot_type = slot_type.ok_or_else(|| {
+            StoreError::AccountError(AccountError::StorageSlotNameNotFound {
+                slot_name: slot_name.clone(),
+            })
+        })?;
+        let slot_type = StorageSlotType::try_from(slot_type)
+            .map_err(|err| StoreError::ParsingError(err.to_string()))?;
+        if slot_type != StorageSlotType::Map {
+            return Err(StoreError::AccountError(AccountError::StorageSlotNotMap(
+                slot_name.clone(),
+            )));
+        }
+
+        conn.prepare(KEYS_QUERY)
+            .into_store_error()?
+            .query_map(params![account_id_hex, slot_name_str], |row| row.get::<_, String>(0))
+            .into_store_error()?
+            .map(|result| Ok(StorageMapKey::new(Word::try_from(result.into_store_error()?)?)))
+            .collect()
+    }
+
     pub(crate) fn get_account_addresses(
         conn: &mut Connection,
         account_id: AccountId,
@@ -304,6 +622,12 @@ impl SqliteStore {
         query_account_addresses(conn, account_id)
     }
 
+    pub(crate) fn get_all_addresses(
+        conn: &mut Connection,
+    ) -> Result<Vec<(Address, AccountId)>, StoreError> {
+        query_all_addresses(conn)
+    }
+
     /// Retrieves the account code for a specific account by ID.
     pub(crate) fn get_account_code_by_id(
         conn: &mut Connection,
@@ -320,6 +644,60 @@ impl SqliteStore {
         query_account_code(conn, header.code_commitment())
     }
 
+    /// Recomputes the commitment of every tracked account from its stored code, storage and
+    /// vault, and reports the accounts whose stored commitment doesn't match.
+    pub(crate) fn check_account_commitments(
+        conn: &mut Connection,
+    ) -> Result<Vec<IntegrityIssue>, StoreError> {
+        const QUERY: &str = "SELECT id, account_commitment FROM latest_account_headers ORDER BY id";
+
+        let stored_commitments = conn
+            .prepare(QUERY)
+            .into_store_error()?
+            .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))
+            .into_store_error()?
+            .map(|result| {
+                let (id, commitment) = result.into_store_error()?;
+                let id = AccountId::from_hex(&id)
+                    .map_err(|err| StoreError::ParsingError(err.to_string()))?;
+                Ok((id, Word::try_from(&commitment)?))
+            })
+            .collect::<Result<Vec<(AccountId, Word)>, StoreError>>()?;
+
+        let mut issues = Vec::new();
+        for (account_id, stored) in stored_commitments {
+            let computed = match Self::get_account(conn, account_id) {
+                Ok(Some(record)) => Account::try_from(record)
+                    .expect("stored accounts are loaded in full")
+                    .to_commitment(),
+                Ok(None) => {
+                    issues.push(IntegrityIssue::AccountUnreadable {
+                        account_id,
+                        reason: "account code is missing".to_string(),
+                    });
+                    continue;
+                },
+                Err(err) => {
+                    issues.push(IntegrityIssue::AccountUnreadable {
+                        account_id,
+                        reason: err.to_string(),
+                    });
+                    continue;
+                },
+            };
+
+            if computed != stored {
+                issues.push(IntegrityIssue::AccountCommitmentMismatch {
+                    account_id,
+                    stored,
+                    computed,
+                });
+            }
+        }
+
+        Ok(issues)
+    }
+
     // MUTATOR/WRITER METHODS
     // --------------------------------------------------------------------------------------------
 
@@ -331,20 +709,31 @@ impl SqliteStore {
         client_account_type: ClientAccountType,
     ) -> Result<(), StoreError> {
         with_forest_snapshot(conn, smt_forest, |tx, smt_forest| {
-            Self::insert_account_code(tx, account.code())?;
-
-            let account_id = account.id();
-            Self::insert_storage_slots(tx, account_id, account.storage().slots().iter())?;
-            Self::insert_assets(tx, account_id, account.vault().assets())?;
-            let watched = matches!(client_account_type, ClientAccountType::Watched);
-            Self::insert_new_account_header(tx, &account.into(), account.seed(), watched)?;
-            Self::insert_address(tx, initial_address, account.id())?;
-
-            smt_forest.insert_and_register_account_state(
-                account.id(),
-                account.vault(),
-                account.storage(),
-            )?;
+            Self::insert_account_tx(tx, smt_forest, account, initial_address, client_account_type)
+        })
+    }
+
+    pub(crate) fn import_account_bundle(
+        conn: &mut Connection,
+        smt_forest: &Arc<RwLock<AccountSmtForest>>,
+        account: &Account,
+        address: &Address,
+        notes: &[InputNoteRecord],
+        tags: &[NoteTagRecord],
+    ) -> Result<(), StoreError> {
+        let mut tracked_tags = Self::get_note_tags(conn)?;
+
+        with_forest_snapshot(conn, smt_forest, |tx, smt_forest| {
+            Self::insert_account_tx(tx, smt_forest, account, address, ClientAccountType::Native)?;
+            upsert_input_notes_tx(tx, notes)?;
+
+            for tag in tags {
+                if !tracked_tags.contains(tag) {
+                    add_note_tag_tx(tx, tag)?;
+                    tracked_tags.push(*tag);
+                }
+            }
+
             Ok(())
         })
     }
@@ -382,6 +771,39 @@ impl SqliteStore {
         })
     }
 
+    /// Sets the `watch_only` flag of the account's latest header.
+    pub(crate) fn set_account_watch_only(
+        conn: &mut Connection,
+        account_id: AccountId,
+        watch_only: bool,
+    ) -> Result<(), StoreError> {
+        const QUERY: &str = "UPDATE latest_account_headers SET watch_only = ? WHERE id = ?";
+        let updated = conn
+            .execute(QUERY, params![watch_only, account_id.to_hex()])
+            .into_store_error()?;
+        if updated == 0 {
+            return Err(StoreError::AccountDataNotFound(account_id));
+        }
+
+        Ok(())
+    }
+
+    /// Sets the `archived` flag of the account's latest header.
+    pub(crate) fn set_account_archived(
+        conn: &mut Connection,
+        account_id: AccountId,
+        archived: bool,
+    ) -> Result<(), StoreError> {
+        const QUERY: &str = "UPDATE latest_account_headers SET archived = ? WHERE id = ?";
+        let updated =
+            conn.execute(QUERY, params![archived, account_id.to_hex()]).into_store_error()?;
+        if updated == 0 {
+            return Err(StoreError::AccountDataNotFound(account_id));
+        }
+
+        Ok(())
+    }
+
     pub fn upsert_foreign_account_code(
         conn: &mut Connection,
         account_id: AccountId,
@@ -572,6 +994,33 @@ impl SqliteStore {
         Ok(())
     }
 
+    /// Undoes the states of an account written after the state with the given nonce, which
+    /// becomes the latest state again.
+    pub(crate) fn undo_account_states_after_nonce(
+        tx: &Transaction<'_>,
+        account_id_hex: &str,
+        nonce: u64,
+    ) -> Result<(), StoreError> {
+        const QUERY: &str = "SELECT replaced_at_nonce FROM historical_account_headers \
+             WHERE id = ? AND replaced_at_nonce > ? ORDER BY replaced_at_nonce DESC";
+
+        let nonces = tx
+            .prepare(QUERY)
+            .into_store_error()?
+            .query_map(params![account_id_hex, u64_to_value(nonce)], |row| {
+                column_value_as_u64(row, 0)
+            })
+            .into_store_error()?
+            .collect::<Result<Vec<u64>, _>>()
+            .into_store_error()?;
+
+        if nonces.is_empty() {
+            return Ok(());
+        }
+
+        Self::undo_account_nonces(tx, account_id_hex, &nonces)
+    }
+
     /// Undoes all nonces for a single account: restores old values, restores old header,
     /// and cleans up consumed historical entries.
     fn undo_account_nonces(
@@ -603,17 +1052,19 @@ impl SqliteStore {
             > 0;
 
         if old_header_exists {
-            // `watched` is not carried in historical_account_headers, so this restore resets
-            // it to the column default (FALSE). This is safe because undo only fires for discarded
-            // local transactions, and watched accounts have none.
+            // `watched`, `archived` and `watch_only` are not carried in
+            // historical_account_headers, so they're carried over from the row being replaced.
             tx.execute(
                 "INSERT OR REPLACE INTO latest_account_headers \
                  (id, account_commitment, code_commitment, storage_commitment, \
-                  vault_root, nonce, account_seed, locked) \
+                  vault_root, nonce, account_seed, locked, watched, archived, watch_only) \
                  SELECT id, account_commitment, code_commitment, storage_commitment, \
-                        vault_root, nonce, account_seed, locked \
+                        vault_root, nonce, account_seed, locked, \
+                        COALESCE((SELECT watched FROM latest_account_headers WHERE id = ?1), FALSE), \
+                        COALESCE((SELECT archived FROM latest_account_headers WHERE id = ?1), FALSE), \
+                        COALESCE((SELECT watch_only FROM latest_account_headers WHERE id = ?1), FALSE) \
                  FROM historical_account_headers \
-                 WHERE id = ? AND replaced_at_nonce = ?",
+                 WHERE id = ?1 AND replaced_at_nonce = ?2",
                 params![account_id_hex, &min_nonce_val],
             )
             .into_store_error()?;
@@ -917,16 +1368,51 @@ impl SqliteStore {
     // HELPERS
     // --------------------------------------------------------------------------------------------
 
+    /// Writes the account's code, storage, vault, header and initial address within `tx`, and
+    /// registers its state in the provided SMT forest.
+    fn insert_account_tx(
+        tx: &Transaction<'_>,
+        smt_forest: &mut AccountSmtForest,
+        account: &Account,
+        initial_address: &Address,
+        client_account_type: ClientAccountType,
+    ) -> Result<(), StoreError> {
+        Self::insert_account_code(tx, account.code())?;
+
+        let account_id = account.id();
+        Self::insert_storage_slots(tx, account_id, account.storage().slots().iter())?;
+        Self::insert_assets(tx, account_id, account.vault().assets())?;
+        let watched = matches!(client_account_type, ClientAccountType::Watched);
+        Self::insert_new_account_header(
+            tx,
+            &account.into(),
+            account.seed(),
+            watched,
+            false,
+            false,
+        )?;
+        Self::insert_address(tx, initial_address, account.id())?;
+
+        smt_forest.insert_and_register_account_state(
+            account.id(),
+            account.vault(),
+            account.storage(),
+        )?;
+        Ok(())
+    }
+
     /// Writes a new row into `latest_account_headers`.
     ///
     /// Does not archive any previous state, use [`Self::replace_account_header`] when a row
     /// for this account already exists. If a row does exist it will be overwritten with the
-    /// provided `watched` value and no historical row added.
+    /// provided `watched`, `archived` and `watch_only` values and no historical row added.
     fn insert_new_account_header(
         tx: &Transaction<'_>,
         new_header: &AccountHeader,
         account_seed: Option<Word>,
         watched: bool,
+        archived: bool,
+        watch_only: bool,
     ) -> Result<(), StoreError> {
         let id = new_header.id().to_hex();
         let code_commitment = new_header.code_commitment().to_string();
@@ -946,7 +1432,9 @@ impl SqliteStore {
                 account_seed,
                 account_commitment,
                 locked,
-                watched
+                watched,
+                archived,
+                watch_only
             } | REPLACE
         );
 
@@ -962,6 +1450,8 @@ impl SqliteStore {
                 commitment,
                 false,
                 watched,
+                archived,
+                watch_only,
             ],
         )
         .into_store_error()?;
@@ -971,9 +1461,10 @@ impl SqliteStore {
 
     /// Replaces an account's latest header, archiving the previous one to historical.
     ///
-    /// Preserves the `watched` flag from the existing latest row (mode is a per-account
-    /// property, not per-state). The new latest row is written with `account_seed = NULL`
-    /// and `locked = false`; the previous seed and lock state move into the historical row.
+    /// Preserves the `watched`, `archived` and `watch_only` flags from the existing latest row
+    /// (they are per-account properties, not per-state). The new latest row is written with
+    /// `account_seed = NULL` and `locked = false`; the previous seed and lock state move into
+    /// the historical row.
     fn replace_account_header(
         tx: &Transaction<'_>,
         new_header: &AccountHeader,
@@ -999,16 +1490,23 @@ impl SqliteStore {
 
         // `AccountHeader` doesn't carry the seed or per-account flags, so read them from the row
         // we're about to overwrite: `account_seed`/`locked` get archived into the historical row,
-        // `watched` is carried into the new latest row.
-        let (old_seed, old_locked, old_watched): (Option<Vec<u8>>, bool, bool) = tx
+        // `watched`/`archived`/`watch_only` are carried into the new latest row.
+        let (old_seed, old_locked, old_watched, old_archived, old_watch_only): (
+            Option<Vec<u8>>,
+            bool,
+            bool,
+            bool,
+            bool,
+        ) = tx
             .query_row(
-                "SELECT account_seed, locked, watched FROM latest_account_headers WHERE id = ?",
+                "SELECT account_seed, locked, watched, archived, watch_only \
+                 FROM latest_account_headers WHERE id = ?",
                 params![&id_hex],
-                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?)),
             )
             .optional()
             .into_store_error()?
-            .unwrap_or((None, false, false));
+            .unwrap_or((None, false, false, false, false));
 
         // Archive the old header to historical.
         let old_id = old_header.id().to_hex();
@@ -1050,7 +1548,14 @@ impl SqliteStore {
         .into_store_error()?;
 
         // Write the new latest row.
-        Self::insert_new_account_header(tx, new_header, None, old_watched)
+        Self::insert_new_account_header(
+            tx,
+            new_header,
+            None,
+            old_watched,
+            old_archived,
+            old_watch_only,
+        )
     }
 
     /// Prunes historical account states for a single account up to the given nonce.
@@ -65,16 +65,16 @@ pub(crate) fn parse_accounts(
     ))
 }
 
-/// Fetches rows from `latest_account_headers`. Each row includes the [`ClientAccountType`],
-/// which `historical_account_headers` doesn't carry — that's why this query lives separately
-/// from [`query_historical_account_headers`].
+/// Fetches rows from `latest_account_headers`. Each row includes the [`ClientAccountType`] and
+/// the `watch_only` flag, which `historical_account_headers` doesn't carry — that's why this
+/// query lives separately from [`query_historical_account_headers`].
 pub(crate) fn query_latest_account_headers(
     conn: &Connection,
     where_clause: &str,
     params: impl Params,
-) -> Result<Vec<(AccountHeader, AccountStatus, ClientAccountType)>, StoreError> {
+) -> Result<Vec<(AccountHeader, AccountStatus, ClientAccountType, bool)>, StoreError> {
     let query = format!(
-        "SELECT id, nonce, vault_root, storage_commitment, code_commitment, account_seed, locked, watched \
+        "SELECT id, nonce, vault_root, storage_commitment, code_commitment, account_seed, locked, watched, watch_only \
          FROM latest_account_headers WHERE {where_clause}"
     );
     conn.prepare(&query)
@@ -88,6 +88,7 @@ pub(crate) fn query_latest_account_headers(
             let account_seed: Option<Vec<u8>> = row.get(5)?;
             let locked: bool = row.get(6)?;
             let watched: bool = row.get(7)?;
+            let watch_only: bool = row.get(8)?;
 
             Ok((
                 SerializedHeaderData {
@@ -100,18 +101,19 @@ pub(crate) fn query_latest_account_headers(
                     locked,
                 },
                 watched,
+                watch_only,
             ))
         })
         .into_store_error()?
         .map(|result| {
-            let (parts, watched) = result.into_store_error()?;
+            let (parts, watched, watch_only) = result.into_store_error()?;
             let (header, status) = parse_accounts(parts)?;
             let client_type = if watched {
                 ClientAccountType::Watched
             } else {
                 ClientAccountType::Native
             };
-            Ok((header, status, client_type))
+            Ok((header, status, client_type, watch_only))
         })
         .collect::<Result<Vec<_>, StoreError>>()
 }
@@ -195,6 +197,30 @@ pub(crate) fn query_account_addresses(
         .collect::<Result<Vec<Address>, StoreError>>()
 }
 
+pub(crate) fn query_all_addresses(
+    conn: &Connection,
+) -> Result<Vec<(Address, AccountId)>, StoreError> {
+    const ADDRESS_QUERY: &str =
+        "SELECT address, account_id FROM addresses ORDER BY account_id, address";
+
+    conn.prepare_cached(ADDRESS_QUERY)
+        .into_store_error()?
+        .query_map([], |row| {
+            let address: Vec<u8> = row.get(0)?;
+            let account_id: String = row.get(1)?;
+            Ok((address, account_id))
+        })
+        .into_store_error()?
+        .map(|result| {
+            let (serialized_address, account_id) = result.into_store_error()?;
+            let address = Address::read_from_bytes(&serialized_address)?;
+            let account_id = AccountId::from_hex(&account_id)
+                .expect("Conversion from stored AccountID should not panic");
+            Ok((address, account_id))
+        })
+        .collect::<Result<Vec<_>, StoreError>>()
+}
+
 pub(crate) fn query_vault_assets(
     conn: &Connection,
     account_id: AccountId,
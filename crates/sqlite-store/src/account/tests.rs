@@ -29,9 +29,21 @@ use miden_client::asset::{
     NonFungibleAssetDetails,
 };
 use miden_client::auth::{AuthSchemeId, AuthSingleSig, PublicKeyCommitment};
-use miden_client::store::{ClientAccountType, Store, StoreError};
-use miden_client::testing::common::ACCOUNT_ID_REGULAR;
-use miden_client::{EMPTY_WORD, Felt, ONE, ZERO};
+use miden_client::block::BlockNumber;
+use miden_client::note::{NoteAssets, NoteAttachments, NoteDetails, NoteRecipient, NoteStorage};
+use miden_client::store::input_note_states::ExpectedNoteState;
+use miden_client::store::{
+    AccountFilter,
+    ClientAccountType,
+    InputNoteRecord,
+    IntegrityIssue,
+    NoteFilter,
+    Store,
+    StoreError,
+};
+use miden_client::sync::NoteTagRecord;
+use miden_client::testing::common::{ACCOUNT_ID_REGULAR, create_test_store_path};
+use miden_client::{EMPTY_WORD, Felt, ONE, Word, ZERO};
 use miden_protocol::account::AccountComponentMetadata;
 use miden_protocol::asset::AssetCallbackFlag;
 use miden_protocol::testing::account_id::{
@@ -39,6 +51,7 @@ use miden_protocol::testing::account_id::{
     ACCOUNT_ID_PUBLIC_NON_FUNGIBLE_FAUCET,
 };
 use miden_protocol::testing::constants::NON_FUNGIBLE_ASSET_DATA;
+use miden_standards::note::StandardNote;
 use rusqlite::params;
 
 use crate::SqliteStore;
@@ -397,6 +410,122 @@ async fn apply_account_delta_removals() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn get_account_by_commitment_rebuilds_historical_state_on_read_only_store()
+-> anyhow::Result<()> {
+    let store_path = create_test_store_path();
+    let store = SqliteStore::new(store_path.clone()).await?;
+
+    let value_slot_name =
+        StorageSlotName::new("miden::testing::sqlite_store::value").expect("valid slot name");
+    let map_slot_name =
+        StorageSlotName::new("miden::testing::sqlite_store::map").expect("valid slot name");
+
+    let mut dummy_map = StorageMap::new();
+    dummy_map
+        .insert(StorageMapKey::new([ONE, ZERO, ZERO, ZERO].into()), [ONE, ONE, ONE, ONE].into())?;
+
+    let dummy_component = AccountComponent::new(
+        BasicWallet::code().as_library().clone(),
+        vec![
+            StorageSlot::with_value(value_slot_name.clone(), [ZERO, ZERO, ZERO, ONE].into()),
+            StorageSlot::with_map(map_slot_name.clone(), dummy_map),
+        ],
+        AccountComponentMetadata::new("miden::testing::dummy_component"),
+    )?;
+
+    let assets: Vec<Asset> = vec![
+        FungibleAsset::new(AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET)?, 100)?.into(),
+    ];
+    let account = AccountBuilder::new([0; 32])
+        .account_type(AccountType::Private)
+        .with_auth_component(AuthSingleSig::new(
+            PublicKeyCommitment::from(EMPTY_WORD),
+            AuthSchemeId::Falcon512Poseidon2,
+        ))
+        .with_component(dummy_component)
+        .with_assets(assets.clone())
+        .build_existing()?;
+    let default_address = Address::new(account.id());
+    store
+        .insert_account(&account, default_address, ClientAccountType::Native)
+        .await?;
+
+    // Change a value, remove a map entry and add another, and remove the only asset.
+    let mut storage_delta = AccountStorageDelta::new();
+    storage_delta.set_item(value_slot_name.clone(), [ONE, ONE, ZERO, ZERO].into())?;
+    storage_delta.set_map_item(
+        map_slot_name.clone(),
+        StorageMapKey::new([ONE, ZERO, ZERO, ZERO].into()),
+        EMPTY_WORD,
+    )?;
+    storage_delta.set_map_item(
+        map_slot_name,
+        StorageMapKey::new([ZERO, ONE, ZERO, ZERO].into()),
+        [ONE, ZERO, ONE, ZERO].into(),
+    )?;
+    let vault_delta = AccountVaultDelta::from_iters([], assets);
+    let delta = AccountDelta::new(account.id(), storage_delta, vault_delta, ONE)?;
+
+    let mut account_after_delta = account.clone();
+    account_after_delta.apply_delta(&delta)?;
+    let final_state: AccountHeader = (&account_after_delta).into();
+
+    let smt_forest = store.smt_forest.clone();
+    let account_record = account.clone();
+    store
+        .interact_with_connection(move |conn| {
+            let fungible_assets = SqliteStore::get_account_fungible_assets_for_delta(
+                conn,
+                account_record.id(),
+                &delta,
+            )?;
+            let old_map_roots =
+                SqliteStore::get_storage_map_roots_for_delta(conn, account_record.id(), &delta)?;
+            let tx = conn.transaction().into_store_error()?;
+            let mut smt_forest = smt_forest.write().expect("smt_forest write lock not poisoned");
+
+            SqliteStore::apply_account_delta(
+                &tx,
+                &mut smt_forest,
+                &account_record.into(),
+                &final_state,
+                fungible_assets,
+                &old_map_roots,
+                &delta,
+            )?;
+
+            tx.commit().into_store_error()?;
+            Ok(())
+        })
+        .await?;
+    drop(store);
+
+    let store = SqliteStore::open_read_only(store_path).await?;
+
+    let historical = store
+        .get_account_by_commitment(account.to_commitment())
+        .await?
+        .context("failed to find historical account state")?;
+    assert_eq!(historical, account);
+
+    let latest = store
+        .get_account_by_commitment(account_after_delta.to_commitment())
+        .await?
+        .context("failed to find latest account state")?;
+    assert_eq!(latest, account_after_delta);
+
+    // The lookup doesn't modify the stored state.
+    let current: Account = store
+        .get_account(account.id())
+        .await?
+        .context("failed to find account")?
+        .try_into()?;
+    assert_eq!(current, account_after_delta);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn get_account_storage_item_success() -> anyhow::Result<()> {
     let store = create_test_store().await;
@@ -433,6 +562,31 @@ async fn get_account_storage_item_success() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn get_account_nonce_matches_account_header() -> anyhow::Result<()> {
+    let store = create_test_store().await;
+
+    let account = AccountBuilder::new([0; 32])
+        .account_type(AccountType::Private)
+        .with_auth_component(AuthSingleSig::new(
+            PublicKeyCommitment::from(EMPTY_WORD),
+            AuthSchemeId::Falcon512Poseidon2,
+        ))
+        .with_component(BasicWallet)
+        .build_existing()?;
+    assert!(store.get_account_nonce(account.id()).await?.is_none());
+
+    store
+        .insert_account(&account, Address::new(account.id()), ClientAccountType::Native)
+        .await?;
+
+    let (header, _) = store.get_account_header(account.id()).await?.context("header not found")?;
+    assert_eq!(store.get_account_nonce(account.id()).await?, Some(header.nonce()));
+    assert_eq!(header.nonce(), account.nonce());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn get_account_storage_item_not_found() -> anyhow::Result<()> {
     let store = create_test_store().await;
@@ -512,6 +666,109 @@ async fn get_account_map_item_success() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn get_account_map_items_matches_individual_witnesses() -> anyhow::Result<()> {
+    let store = create_test_store().await;
+
+    let map_slot_name =
+        StorageSlotName::new("miden::testing::sqlite_store::map").expect("valid slot name");
+
+    let mut storage_map = StorageMap::new();
+    let mut keys = Vec::new();
+    for i in 1..=4u32 {
+        let key = StorageMapKey::new([Felt::from(i), ZERO, ZERO, ZERO].into());
+        storage_map.insert(key, [ONE, ONE, ONE, Felt::from(i)].into())?;
+        keys.push(key);
+    }
+    // Include a key that is not present in the map
+    keys.push(StorageMapKey::new([Felt::from(100u32), ZERO, ZERO, ZERO].into()));
+
+    let dummy_component = AccountComponent::new(
+        BasicWallet::code().as_library().clone(),
+        vec![StorageSlot::with_map(map_slot_name.clone(), storage_map)],
+        AccountComponentMetadata::new("miden::testing::dummy_component"),
+    )?;
+
+    let account = AccountBuilder::new([0; 32])
+        .account_type(AccountType::Private)
+        .with_auth_component(AuthSingleSig::new(
+            PublicKeyCommitment::from(EMPTY_WORD),
+            AuthSchemeId::Falcon512Poseidon2,
+        ))
+        .with_component(dummy_component)
+        .build_existing()?;
+
+    let default_address = Address::new(account.id());
+    store
+        .insert_account(&account, default_address, ClientAccountType::Native)
+        .await?;
+
+    let batch = store.get_account_map_items(account.id(), map_slot_name.clone(), &keys).await?;
+    assert_eq!(batch.len(), keys.len());
+
+    for (key, (value, witness)) in keys.iter().zip(batch) {
+        let (expected_value, expected_witness) =
+            store.get_account_map_item(account.id(), map_slot_name.clone(), *key).await?;
+        assert_eq!(value, expected_value);
+        assert_eq!(witness, expected_witness);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_storage_map_keys_returns_all_keys() -> anyhow::Result<()> {
+    let store = create_test_store().await;
+
+    let map_slot_name =
+        StorageSlotName::new("miden::testing::sqlite_store::map").expect("valid slot name");
+    let value_slot_name =
+        StorageSlotName::new("miden::testing::sqlite_store::value").expect("valid slot name");
+
+    let mut storage_map = StorageMap::new();
+    let mut expected_keys = Vec::new();
+    for i in 1..=5u32 {
+        let key = StorageMapKey::new([Felt::from(i), ZERO, ZERO, ZERO].into());
+        storage_map.insert(key, [ONE, ONE, ONE, Felt::from(i)].into())?;
+        expected_keys.push(key);
+    }
+
+    let dummy_component = AccountComponent::new(
+        BasicWallet::code().as_library().clone(),
+        vec![
+            StorageSlot::with_map(map_slot_name.clone(), storage_map),
+            StorageSlot::with_empty_value(value_slot_name.clone()),
+        ],
+        AccountComponentMetadata::new("miden::testing::dummy_component"),
+    )?;
+
+    let account = AccountBuilder::new([0; 32])
+        .account_type(AccountType::Private)
+        .with_auth_component(AuthSingleSig::new(
+            PublicKeyCommitment::from(EMPTY_WORD),
+            AuthSchemeId::Falcon512Poseidon2,
+        ))
+        .with_component(dummy_component)
+        .build_existing()?;
+
+    let default_address = Address::new(account.id());
+    store
+        .insert_account(&account, default_address, ClientAccountType::Native)
+        .await?;
+
+    let keys = store.get_storage_map_keys(account.id(), map_slot_name).await?;
+    expected_keys.sort_by_cached_key(|key| key.to_hex());
+    assert_eq!(keys, expected_keys);
+
+    // Value slots and missing slots are rejected.
+    assert!(store.get_storage_map_keys(account.id(), value_slot_name).await.is_err());
+    let missing_name =
+        StorageSlotName::new("miden::testing::sqlite_store::missing").expect("valid slot name");
+    assert!(store.get_storage_map_keys(account.id(), missing_name).await.is_err());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn get_account_map_item_value_slot_error() -> anyhow::Result<()> {
     let store = create_test_store().await;
@@ -755,6 +1012,187 @@ async fn account_reader_addresses_access() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn archived_accounts_are_filtered_out_by_default() -> anyhow::Result<()> {
+    let store = create_test_store().await;
+
+    let mut accounts = Vec::new();
+    for seed in [[0; 32], [1; 32]] {
+        let account = AccountBuilder::new(seed)
+            .account_type(AccountType::Private)
+            .with_auth_component(AuthSingleSig::new(
+                PublicKeyCommitment::from(EMPTY_WORD),
+                AuthSchemeId::Falcon512Poseidon2,
+            ))
+            .with_component(BasicWallet)
+            .build_existing()?;
+        store
+            .insert_account(&account, Address::new(account.id()), ClientAccountType::Native)
+            .await?;
+        accounts.push(account);
+    }
+    let (archived, active) = (&accounts[0], &accounts[1]);
+    store.archive_account(archived.id()).await?;
+
+    let header_ids = |headers: Vec<(AccountHeader, _)>| {
+        headers.into_iter().map(|(header, _)| header.id()).collect::<Vec<_>>()
+    };
+    assert_eq!(store.get_account_ids().await?, vec![active.id()]);
+    assert_eq!(header_ids(store.get_account_headers().await?), vec![active.id()]);
+    assert_eq!(
+        header_ids(store.get_filtered_account_headers(AccountFilter::Archived).await?),
+        vec![archived.id()]
+    );
+    assert_eq!(store.get_filtered_account_headers(AccountFilter::All).await?.len(), 2);
+
+    // Archived accounts can still be loaded by ID, and stay archived across state updates.
+    let mut updated = archived.clone();
+    let delta = AccountDelta::new(
+        archived.id(),
+        AccountStorageDelta::new(),
+        AccountVaultDelta::from_iters([], []),
+        ONE,
+    )?;
+    updated.apply_delta(&delta)?;
+    store.update_account(&updated).await?;
+    assert!(store.get_account(archived.id()).await?.is_some());
+    assert_eq!(store.get_account_ids().await?, vec![active.id()]);
+
+    store.unarchive_account(archived.id()).await?;
+    assert_eq!(store.get_account_ids().await?.len(), 2);
+    assert!(store.get_filtered_account_headers(AccountFilter::Archived).await?.is_empty());
+
+    let untracked = AccountId::try_from(ACCOUNT_ID_REGULAR)?;
+    assert!(matches!(
+        store.archive_account(untracked).await,
+        Err(StoreError::AccountDataNotFound(id)) if id == untracked
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn watch_only_flag_survives_state_updates() -> anyhow::Result<()> {
+    let store = create_test_store().await;
+
+    let account = AccountBuilder::new([0; 32])
+        .account_type(AccountType::Private)
+        .with_auth_component(AuthSingleSig::new(
+            PublicKeyCommitment::from(EMPTY_WORD),
+            AuthSchemeId::Falcon512Poseidon2,
+        ))
+        .with_component(BasicWallet)
+        .build_existing()?;
+    store
+        .insert_account(&account, Address::new(account.id()), ClientAccountType::Native)
+        .await?;
+    assert!(!store.get_account(account.id()).await?.unwrap().is_watch_only());
+
+    store.set_account_watch_only(account.id(), true).await?;
+    assert!(store.get_account(account.id()).await?.unwrap().is_watch_only());
+
+    let mut updated = account.clone();
+    let delta = AccountDelta::new(
+        account.id(),
+        AccountStorageDelta::new(),
+        AccountVaultDelta::from_iters([], []),
+        ONE,
+    )?;
+    updated.apply_delta(&delta)?;
+    store.update_account(&updated).await?;
+    assert!(store.get_account(account.id()).await?.unwrap().is_watch_only());
+
+    store.set_account_watch_only(account.id(), false).await?;
+    assert!(!store.get_account(account.id()).await?.unwrap().is_watch_only());
+
+    let untracked = AccountId::try_from(ACCOUNT_ID_REGULAR)?;
+    assert!(matches!(
+        store.set_account_watch_only(untracked, true).await,
+        Err(StoreError::AccountDataNotFound(id)) if id == untracked
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_all_addresses_returns_addresses_of_every_account() -> anyhow::Result<()> {
+    let store = create_test_store().await;
+
+    let mut expected = Vec::new();
+    for seed in [[0; 32], [1; 32]] {
+        let account = AccountBuilder::new(seed)
+            .account_type(AccountType::Private)
+            .with_auth_component(AuthSingleSig::new(
+                PublicKeyCommitment::from(EMPTY_WORD),
+                AuthSchemeId::Falcon512Poseidon2,
+            ))
+            .with_component(BasicWallet)
+            .build_existing()?;
+
+        let default_address = Address::new(account.id());
+        store
+            .insert_account(&account, default_address.clone(), ClientAccountType::Native)
+            .await?;
+        expected.push((default_address, account.id()));
+    }
+    expected.sort_by_key(|(_, account_id)| account_id.to_hex());
+
+    let addresses = store.get_all_addresses().await?;
+    assert_eq!(addresses, expected);
+
+    // The order is stable across calls.
+    assert_eq!(store.get_all_addresses().await?, addresses);
+
+    Ok(())
+}
+
+// INTEGRITY TESTS
+// ================================================================================================
+
+#[tokio::test]
+async fn check_integrity_reports_account_commitment_mismatch() -> anyhow::Result<()> {
+    let store = create_test_store().await;
+
+    let account = AccountBuilder::new([0; 32])
+        .account_type(AccountType::Private)
+        .with_auth_component(AuthSingleSig::new(
+            PublicKeyCommitment::from(EMPTY_WORD),
+            AuthSchemeId::Falcon512Poseidon2,
+        ))
+        .with_component(BasicWallet)
+        .build_with_schema_commitment()?;
+    store
+        .insert_account(&account, Address::new(account.id()), ClientAccountType::Native)
+        .await?;
+
+    assert!(store.check_integrity().await?.is_healthy());
+
+    // Simulate a partial write that left the header out of sync with the account's state.
+    let account_id = account.id();
+    let bogus_commitment = Word::from([1u32, 2, 3, 4]);
+    store
+        .interact_with_connection(move |conn| {
+            conn.execute(
+                "UPDATE latest_account_headers SET account_commitment = ? WHERE id = ?",
+                params![bogus_commitment.to_string(), account_id.to_hex()],
+            )
+            .into_store_error()
+        })
+        .await?;
+
+    let report = store.check_integrity().await?;
+    assert_eq!(
+        report.issues(),
+        [IntegrityIssue::AccountCommitmentMismatch {
+            account_id,
+            stored: bogus_commitment,
+            computed: account.to_commitment(),
+        }]
+    );
+
+    Ok(())
+}
+
 // ACCOUNT HISTORY PRUNE TESTS
 // ================================================================================================
 
@@ -2211,3 +2649,122 @@ async fn watched_status_survives_state_replacement() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn import_account_bundle_rolls_back_on_failure() -> anyhow::Result<()> {
+    let store = create_test_store().await;
+
+    let account = AccountBuilder::new([0; 32])
+        .account_type(AccountType::Private)
+        .with_auth_component(AuthSingleSig::new(
+            PublicKeyCommitment::from(EMPTY_WORD),
+            AuthSchemeId::Falcon512Poseidon2,
+        ))
+        .with_component(AccountComponent::new(
+            BasicWallet::code().as_library().clone(),
+            vec![],
+            AccountComponentMetadata::new("miden::testing::bundle"),
+        )?)
+        .build_existing()?;
+    let account_id = account.id();
+    let address = Address::new(account_id);
+
+    let recipient = NoteRecipient::new(
+        [ONE, ZERO, ZERO, ZERO].into(),
+        StandardNote::P2ID.script(),
+        NoteStorage::new(vec![])?,
+    );
+    let note = InputNoteRecord::new(
+        NoteDetails::new(NoteAssets::new(vec![])?, recipient),
+        NoteAttachments::empty(),
+        Some(0),
+        ExpectedNoteState {
+            metadata: None,
+            after_block_num: BlockNumber::from(0u32),
+            tag: None,
+        }
+        .into(),
+    );
+    let tag = NoteTagRecord::with_account_source(address.to_note_tag(), account_id);
+
+    // Make the last write of the bundle fail.
+    store
+        .interact_with_writable_connection(|conn| {
+            conn.execute_batch(
+                "CREATE TRIGGER fail_tag_insert BEFORE INSERT ON tags \
+                 BEGIN SELECT RAISE(ABORT, 'injected failure'); END;",
+            )
+            .into_store_error()
+        })
+        .await?;
+
+    let result = store
+        .import_account_bundle(&account, address.clone(), &[note.clone()], &[tag])
+        .await;
+    assert!(result.is_err());
+
+    assert!(store.get_account_header(account_id).await?.is_none());
+    assert!(store.get_input_notes(NoteFilter::All).await?.is_empty());
+    assert!(store.get_note_tags().await?.is_empty());
+
+    store
+        .interact_with_writable_connection(|conn| {
+            conn.execute_batch("DROP TRIGGER fail_tag_insert;").into_store_error()
+        })
+        .await?;
+
+    store.import_account_bundle(&account, address, &[note], &[tag]).await?;
+
+    assert!(store.get_account_header(account_id).await?.is_some());
+    assert_eq!(store.get_input_notes(NoteFilter::All).await?.len(), 1);
+    assert_eq!(store.get_note_tags().await?, vec![tag]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn in_memory_store_is_shared_across_pooled_connections() -> anyhow::Result<()> {
+    let store = SqliteStore::in_memory().await?;
+
+    let account = AccountBuilder::new([3; 32])
+        .account_type(AccountType::Private)
+        .with_auth_component(AuthSingleSig::new(
+            PublicKeyCommitment::from(EMPTY_WORD),
+            AuthSchemeId::Falcon512Poseidon2,
+        ))
+        .with_component(BasicWallet)
+        .build_existing()?;
+    store
+        .insert_account(&account, Address::new(account.id()), ClientAccountType::Native)
+        .await?;
+
+    // The account was written through the pool's first connection. Hold it so that the query
+    // below runs on another one.
+    let _first_conn = store.pool.get().await?;
+    let second_conn = store.pool.get().await?;
+    let account_id = account.id().to_hex();
+    let stored_accounts: i64 = second_conn
+        .interact(move |conn| {
+            conn.query_row(
+                "SELECT COUNT(*) FROM latest_account_headers WHERE id = ?",
+                params![account_id],
+                |row| row.get(0),
+            )
+        })
+        .await
+        .map_err(|err| anyhow::anyhow!("{err}"))??;
+    assert_eq!(stored_accounts, 1);
+
+    let stored_account: Account = store
+        .get_account(account.id())
+        .await?
+        .context("failed to find inserted account")?
+        .try_into()?;
+    assert_eq!(stored_account.to_commitment(), account.to_commitment());
+
+    // Every in-memory store has a database of its own.
+    let other_store = SqliteStore::in_memory().await?;
+    assert!(other_store.get_account_ids().await?.is_empty());
+
+    Ok(())
+}
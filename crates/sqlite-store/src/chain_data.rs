@@ -62,6 +62,18 @@ impl SqliteStore {
             .collect()
     }
 
+    pub(crate) fn get_block_relevance(
+        conn: &mut Connection,
+        block_number: BlockNumber,
+    ) -> Result<Option<BlockRelevance>, StoreError> {
+        const QUERY: &str = "SELECT has_client_notes FROM block_headers WHERE block_num = ?";
+
+        conn.query_row(QUERY, params![block_number.as_u32()], |row| row.get::<_, bool>(0))
+            .optional()
+            .into_store_error()
+            .map(|has_client_notes| has_client_notes.map(BlockRelevance::from))
+    }
+
     pub(crate) fn get_tracked_block_headers(
         conn: &mut Connection,
     ) -> Result<Vec<BlockHeader>, StoreError> {
@@ -191,7 +203,9 @@ impl SqliteStore {
             serialize_block_header(block_header, has_client_notes);
         const QUERY: &str =
             insert_sql!(block_headers { block_num, header, has_client_notes } | IGNORE);
-        tx.execute(QUERY, params![block_num, header, has_client_notes])
+        tx.prepare_cached(QUERY)
+            .into_store_error()?
+            .execute(params![block_num, header, has_client_notes])
             .into_store_error()?;
 
         set_block_header_has_client_notes(tx, u64::from(block_num), has_client_notes)?;
@@ -204,7 +218,7 @@ impl SqliteStore {
     /// 1. Deletes MMR authentication nodes at the given `node_indices`.
     /// 2. Sets `has_client_notes = false` for `blocks_to_untrack`.
     /// 3. Deletes block headers with `has_client_notes = false` that are not the genesis or
-    ///    sync-height block.
+    ///    sync-height block, nor a recorded sync point (which can be rolled back to).
     pub fn prune_irrelevant_blocks(
         conn: &mut Connection,
         blocks_to_untrack: &[BlockNumber],
@@ -253,7 +267,8 @@ impl SqliteStore {
                 "DELETE FROM block_headers \
                  WHERE has_client_notes = 0 \
                  AND block_num > ?1 \
-                 AND block_num < ?2",
+                 AND block_num < ?2 \
+                 AND block_num NOT IN (SELECT block_num FROM sync_history)",
                 rusqlite::params![genesis, sync_height],
             )
             .into_store_error()?;
@@ -275,7 +290,10 @@ fn insert_partial_blockchain_node(
     let SerializedPartialBlockchainNodeData { id, node } =
         serialize_partial_blockchain_node(id, node);
     const QUERY: &str = insert_sql!(partial_blockchain_nodes { id, node } | IGNORE);
-    tx.execute(QUERY, params![id, node]).into_store_error()?;
+    tx.prepare_cached(QUERY)
+        .into_store_error()?
+        .execute(params![id, node])
+        .into_store_error()?;
     Ok(())
 }
 
@@ -387,7 +405,10 @@ pub(crate) fn set_block_header_has_client_notes(
         UPDATE block_headers
         SET has_client_notes=?
         WHERE block_num=? AND has_client_notes=FALSE;";
-    tx.execute(QUERY, params![has_client_notes, block_num]).into_store_error()?;
+    tx.prepare_cached(QUERY)
+        .into_store_error()?
+        .execute(params![has_client_notes, block_num])
+        .into_store_error()?;
     Ok(())
 }
 
@@ -400,7 +421,7 @@ mod test {
     use miden_client::block::BlockHeader;
     use miden_client::crypto::{Forest, InOrderIndex, MmrPeaks};
     use miden_client::note::BlockNumber;
-    use miden_client::store::{PartialBlockchainFilter, Store};
+    use miden_client::store::{BlockRelevance, PartialBlockchainFilter, Store};
     use miden_client::utils::Serializable;
     use miden_protocol::crypto::merkle::mmr::Mmr;
     use miden_protocol::transaction::TransactionKernel;
@@ -464,6 +485,27 @@ mod test {
         );
     }
 
+    #[tokio::test]
+    async fn get_block_relevance_of_single_blocks() {
+        let store = create_test_store().await;
+        let tx_kernel = TransactionKernel.to_commitment();
+
+        let relevant = BlockHeader::mock(1, None, None, &[], tx_kernel);
+        let irrelevant = BlockHeader::mock(2, None, None, &[], tx_kernel);
+        Store::insert_block_header(&store, &relevant, &[], true).await.unwrap();
+        Store::insert_block_header(&store, &irrelevant, &[], false).await.unwrap();
+
+        assert_eq!(
+            Store::get_block_relevance(&store, 1.into()).await.unwrap(),
+            Some(BlockRelevance::HasNotes)
+        );
+        assert_eq!(
+            Store::get_block_relevance(&store, 2.into()).await.unwrap(),
+            Some(BlockRelevance::Irrelevant)
+        );
+        assert_eq!(Store::get_block_relevance(&store, 3.into()).await.unwrap(), None);
+    }
+
     /// Tests that `insert_block_header` persists the tracked header and its MMR
     /// authentication nodes in the same call, so both are retrievable afterwards.
     #[tokio::test]
@@ -2,8 +2,8 @@ use std::path::PathBuf;
 
 use deadpool::Runtime;
 use deadpool::managed::{Manager, Metrics, RecycleResult};
-use rusqlite::Connection;
 use rusqlite::vtab::array;
+use rusqlite::{Connection, OpenFlags};
 
 use super::errors::SqliteStoreError;
 
@@ -23,21 +23,57 @@ const RUNTIME: Runtime = Runtime::Tokio1;
 /// `SQLite` connection pool manager
 pub struct SqlitePoolManager {
     database_path: PathBuf,
+    read_only: bool,
+    in_memory: bool,
 }
 
 /// `SQLite` connection pool manager
 impl SqlitePoolManager {
     pub fn new(database_path: PathBuf) -> Self {
-        Self { database_path }
+        Self {
+            database_path,
+            read_only: false,
+            in_memory: false,
+        }
+    }
+
+    /// Returns a pool manager whose connections are opened with `SQLITE_OPEN_READ_ONLY`.
+    pub fn new_read_only(database_path: PathBuf) -> Self {
+        Self {
+            database_path,
+            read_only: true,
+            in_memory: false,
+        }
+    }
+
+    /// Returns a pool manager for the in-memory database named by the `database_uri` URI. No
+    /// files back the database, so their permissions are left alone.
+    pub fn new_in_memory(database_uri: PathBuf) -> Self {
+        Self {
+            database_path: database_uri,
+            read_only: false,
+            in_memory: true,
+        }
     }
 
     fn new_connection(&self) -> rusqlite::Result<Connection> {
+        if self.read_only {
+            let conn = Connection::open_with_flags(
+                &self.database_path,
+                OpenFlags::SQLITE_OPEN_READ_ONLY
+                    | OpenFlags::SQLITE_OPEN_URI
+                    | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+            )?;
+            array::load_module(&conn)?;
+            return Ok(conn);
+        }
+
         let conn = Connection::open(&self.database_path)?;
 
         // Restrict database file permissions to owner-only on Unix.
         // Also covers WAL and SHM journal files that SQLite may create.
         #[cfg(unix)]
-        {
+        if !self.in_memory {
             use std::os::unix::fs::PermissionsExt;
             let perms = std::fs::Permissions::from_mode(0o600);
             for suffix in &["", "-wal", "-shm"] {
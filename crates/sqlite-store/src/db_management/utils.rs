@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::string::String;
 use std::sync::LazyLock;
 use std::vec::Vec;
@@ -178,6 +179,32 @@ pub fn list_setting_keys(conn: &Connection) -> Result<Vec<String>, StoreError> {
         .into_store_error()
 }
 
+pub fn list_setting_keys_with_prefix(
+    conn: &Connection,
+    prefix: &str,
+) -> Result<Vec<String>, StoreError> {
+    let mut stmt = conn
+        .prepare("SELECT name FROM settings WHERE substr(name, 1, length($1)) = $1")
+        .into_store_error()?;
+    stmt.query_map(params![prefix], |row| row.get::<_, String>(0))
+        .into_store_error()?
+        .collect::<Result<Vec<String>, _>>()
+        .into_store_error()
+}
+
+pub fn get_settings_with_prefix(
+    conn: &Connection,
+    prefix: &str,
+) -> Result<BTreeMap<String, Vec<u8>>, StoreError> {
+    let mut stmt = conn
+        .prepare("SELECT name, value FROM settings WHERE substr(name, 1, length($1)) = $1")
+        .into_store_error()?;
+    stmt.query_map(params![prefix], |row| Ok((row.get::<_, String>(0)?, row.get::<_, Vec<u8>>(1)?)))
+        .into_store_error()?
+        .collect::<Result<BTreeMap<String, Vec<u8>>, _>>()
+        .into_store_error()
+}
+
 /// Checks if a table exists in the database.
 pub fn table_exists(transaction: &Transaction, table_name: &str) -> rusqlite::Result<bool> {
     Ok(transaction
@@ -189,3 +216,17 @@ pub fn table_exists(transaction: &Transaction, table_name: &str) -> rusqlite::Re
         .optional()?
         .is_some())
 }
+
+/// Runs `SQLite`'s `integrity_check` pragma and returns the problems it reports, if any.
+pub fn run_integrity_check(conn: &Connection) -> Result<Vec<String>, StoreError> {
+    let messages = conn
+        .prepare("PRAGMA integrity_check")
+        .into_store_error()?
+        .query_map([], |row| row.get::<_, String>(0))
+        .into_store_error()?
+        .collect::<Result<Vec<String>, _>>()
+        .into_store_error()?;
+
+    // A healthy database reports a single "ok" row.
+    Ok(messages.into_iter().filter(|message| message != "ok").collect())
+}
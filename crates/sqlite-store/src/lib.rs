@@ -8,15 +8,19 @@ use std::boxed::Box;
 use std::collections::{BTreeMap, BTreeSet};
 use std::path::PathBuf;
 use std::string::{String, ToString};
-use std::sync::{Arc, RwLock};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
 use std::vec::Vec;
 
 use db_management::pool_manager::{Pool, SqlitePoolManager};
 use db_management::utils::{
     apply_migrations,
     get_setting,
+    get_settings_with_prefix,
     list_setting_keys,
+    list_setting_keys_with_prefix,
     remove_setting,
+    run_integrity_check,
     set_setting,
 };
 use miden_client::Word;
@@ -33,8 +37,16 @@ use miden_client::account::{
 use miden_client::asset::{Asset, AssetVault, AssetWitness};
 use miden_client::block::BlockHeader;
 use miden_client::crypto::{InOrderIndex, MmrPeaks};
-use miden_client::note::{BlockNumber, NoteScript, NoteTag, Nullifier};
+use miden_client::note::{
+    BlockNumber,
+    NoteDetailsCommitment,
+    NoteId,
+    NoteScript,
+    NoteTag,
+    Nullifier,
+};
 use miden_client::store::{
+    AccountFilter,
     AccountRecord,
     AccountSmtForest,
     AccountStatus,
@@ -42,7 +54,11 @@ use miden_client::store::{
     BlockRelevance,
     ClientAccountType,
     InputNoteRecord,
+    IntegrityIssue,
+    IntegrityReport,
+    NoteConflictStrategy,
     NoteFilter,
+    NoteStateTransition,
     OutputNoteRecord,
     PartialBlockchainFilter,
     SettingMutation,
@@ -51,7 +67,13 @@ use miden_client::store::{
     TransactionFilter,
 };
 use miden_client::sync::{NoteTagRecord, StateSyncUpdate};
-use miden_client::transaction::{TransactionRecord, TransactionStoreUpdate};
+use miden_client::transaction::{
+    ProvenTransaction,
+    TransactionId,
+    TransactionInputs,
+    TransactionRecord,
+    TransactionStoreUpdate,
+};
 use miden_protocol::Felt;
 use miden_protocol::account::StorageMapWitness;
 use miden_protocol::asset::AssetVaultKey;
@@ -73,6 +95,9 @@ pub use builder::ClientBuilderSqliteExt;
 // SQLITE STORE
 // ================================================================================================
 
+/// Default maximum number of rows kept in the sync history table.
+pub const DEFAULT_MAX_SYNC_HISTORY_ROWS: usize = 1000;
+
 /// Represents a pool of connections with an `SQLite` database. The pool is used to interact
 /// concurrently with the underlying database in a safe and efficient manner.
 ///
@@ -81,6 +106,11 @@ pub struct SqliteStore {
     pub(crate) pool: Pool,
     database_filepath: String,
     smt_forest: Arc<RwLock<AccountSmtForest>>,
+    read_only: bool,
+    max_sync_history_rows: usize,
+    /// Connection kept open for the lifetime of an in-memory store, so the shared in-memory
+    /// database outlives any pooled connection that gets dropped.
+    _in_memory_connection: Option<Mutex<Connection>>,
 }
 
 impl SqliteStore {
@@ -95,26 +125,109 @@ impl SqliteStore {
             .build()
             .map_err(|e| StoreError::DatabaseError(e.to_string()))?;
 
-        let conn = pool.get().await.map_err(|e| StoreError::DatabaseError(e.to_string()))?;
+        Self::open_writable(pool, database_filepath_str, None).await
+    }
+
+    /// Returns a new instance of [Store] backed by an in-memory database, which leaves no files
+    /// behind and is discarded once the store is dropped.
+    ///
+    /// The database is opened in shared-cache mode, so that every connection of the pool sees the
+    /// same data, and the store holds one connection open for its whole lifetime, as an in-memory
+    /// database is discarded once its last connection closes. Each call creates a database with a
+    /// name of its own, so separate in-memory stores don't share data.
+    pub async fn in_memory() -> Result<Self, StoreError> {
+        static NEXT_DATABASE_ID: AtomicUsize = AtomicUsize::new(0);
+
+        let database_uri = format!(
+            "file:miden-client-store-{}-{}?mode=memory&cache=shared",
+            std::process::id(),
+            NEXT_DATABASE_ID.fetch_add(1, Ordering::Relaxed)
+        );
+        let in_memory_connection = Connection::open(&database_uri)
+            .map_err(|e| StoreError::DatabaseError(e.to_string()))?;
+
+        let sqlite_pool_manager = SqlitePoolManager::new_in_memory(PathBuf::from(&database_uri));
+        let pool = Pool::builder(sqlite_pool_manager)
+            .build()
+            .map_err(|e| StoreError::DatabaseError(e.to_string()))?;
+
+        Self::open_writable(pool, database_uri, Some(in_memory_connection)).await
+    }
 
-        conn.interact(apply_migrations)
+    /// Applies the migrations to the database behind `pool` and returns a writable store that uses
+    /// it.
+    async fn open_writable(
+        pool: Pool,
+        database_filepath: String,
+        in_memory_connection: Option<Connection>,
+    ) -> Result<Self, StoreError> {
+        pool.get()
+            .await
+            .map_err(|e| StoreError::DatabaseError(e.to_string()))?
+            .interact(apply_migrations)
             .await
             .map_err(|e| StoreError::DatabaseError(e.to_string()))?
             .map_err(|e| StoreError::DatabaseError(e.to_string()))?;
 
+        let store = SqliteStore {
+            pool,
+            database_filepath,
+            smt_forest: Arc::new(RwLock::new(AccountSmtForest::new())),
+            read_only: false,
+            max_sync_history_rows: DEFAULT_MAX_SYNC_HISTORY_ROWS,
+            _in_memory_connection: in_memory_connection.map(Mutex::new),
+        };
+        store.initialize_smt_forest().await?;
+
+        Ok(store)
+    }
+
+    /// Opens the database at the specified path in read-only mode.
+    ///
+    /// Connections are opened with `SQLITE_OPEN_READ_ONLY` and migrations are not applied, so the
+    /// database file is never modified. Every write method of the [`Store`] implementation returns
+    /// [`StoreError::ReadOnly`].
+    pub async fn open_read_only(database_filepath: PathBuf) -> Result<Self, StoreError> {
+        let database_filepath_str = database_filepath.to_string_lossy().into_owned();
+        let sqlite_pool_manager = SqlitePoolManager::new_read_only(database_filepath);
+        let pool = Pool::builder(sqlite_pool_manager)
+            .build()
+            .map_err(|e| StoreError::DatabaseError(e.to_string()))?;
+
         let store = SqliteStore {
             pool,
             database_filepath: database_filepath_str,
             smt_forest: Arc::new(RwLock::new(AccountSmtForest::new())),
+            read_only: true,
+            max_sync_history_rows: DEFAULT_MAX_SYNC_HISTORY_ROWS,
+            _in_memory_connection: None,
         };
+        store.initialize_smt_forest().await?;
+
+        Ok(store)
+    }
+
+    /// Sets the maximum number of sync points kept in the sync history. Older entries are pruned
+    /// whenever a new sync is applied. Defaults to [`DEFAULT_MAX_SYNC_HISTORY_ROWS`].
+    #[must_use]
+    pub fn with_max_sync_history_rows(mut self, max_rows: usize) -> Self {
+        self.max_sync_history_rows = max_rows;
+        self
+    }
+
+    /// Returns `true` if the store was opened with [`SqliteStore::open_read_only`].
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
 
-        // Initialize SMT forest
-        for id in store.get_account_ids().await? {
-            let vault = store.get_account_vault(id).await?;
-            let storage = store.get_account_storage(id, AccountStorageFilter::All).await?;
-            let header = store.get_account_header(id).await?;
+    /// Loads the state of every stored account into the SMT forest.
+    async fn initialize_smt_forest(&self) -> Result<(), StoreError> {
+        for id in self.get_account_ids().await? {
+            let vault = self.get_account_vault(id).await?;
+            let storage = self.get_account_storage(id, AccountStorageFilter::All).await?;
+            let header = self.get_account_header(id).await?;
 
-            let mut smt_forest = store.smt_forest.write().expect("smt write lock not poisoned");
+            let mut smt_forest = self.smt_forest.write().expect("smt write lock not poisoned");
             if header.is_some() {
                 smt_forest.insert_and_register_account_state(id, &vault, &storage)?;
             } else {
@@ -122,7 +235,7 @@ impl SqliteStore {
             }
         }
 
-        Ok(store)
+        Ok(())
     }
 
     /// Interacts with the database by executing the provided function on a connection from the
@@ -144,6 +257,20 @@ impl SqliteStore {
             .await
             .map_err(|err| StoreError::DatabaseError(err.to_string()))?
     }
+
+    /// Same as [`SqliteStore::interact_with_connection`], but for functions that write to the
+    /// database. Returns [`StoreError::ReadOnly`] if the store was opened in read-only mode.
+    async fn interact_with_writable_connection<F, R>(&self, f: F) -> Result<R, StoreError>
+    where
+        F: FnOnce(&mut Connection) -> Result<R, StoreError> + Send + 'static,
+        R: Send + 'static,
+    {
+        if self.read_only {
+            return Err(StoreError::ReadOnly);
+        }
+
+        self.interact_with_connection(f).await
+    }
 }
 
 // SQLite implementation of the Store trait
@@ -168,28 +295,68 @@ impl Store for SqliteStore {
         self.interact_with_connection(SqliteStore::get_unique_note_tags).await
     }
 
+    async fn get_note_tags_with_note_counts(
+        &self,
+    ) -> Result<Vec<(NoteTagRecord, usize)>, StoreError> {
+        self.interact_with_connection(SqliteStore::get_note_tags_with_note_counts).await
+    }
+
     async fn add_note_tag(&self, tag: NoteTagRecord) -> Result<bool, StoreError> {
-        self.interact_with_connection(move |conn| SqliteStore::add_note_tag(conn, tag))
+        self.interact_with_writable_connection(move |conn| SqliteStore::add_note_tag(conn, tag))
             .await
     }
 
     async fn remove_note_tag(&self, tag: NoteTagRecord) -> Result<usize, StoreError> {
-        self.interact_with_connection(move |conn| SqliteStore::remove_note_tag(conn, tag))
+        self.interact_with_writable_connection(move |conn| SqliteStore::remove_note_tag(conn, tag))
             .await
     }
 
+    async fn replace_note_tag(
+        &self,
+        old: NoteTagRecord,
+        new: NoteTagRecord,
+    ) -> Result<(), StoreError> {
+        self.interact_with_writable_connection(move |conn| {
+            SqliteStore::replace_note_tag(conn, old, new)
+        })
+        .await
+    }
+
     async fn get_sync_height(&self) -> Result<BlockNumber, StoreError> {
         self.interact_with_connection(SqliteStore::get_sync_height).await
     }
 
     async fn apply_state_sync(&self, state_sync_update: StateSyncUpdate) -> Result<(), StoreError> {
         let smt_forest = self.smt_forest.clone();
-        self.interact_with_connection(move |conn| {
-            SqliteStore::apply_state_sync(conn, &smt_forest, state_sync_update)
+        let max_sync_history_rows = self.max_sync_history_rows;
+        self.interact_with_writable_connection(move |conn| {
+            SqliteStore::apply_state_sync(
+                conn,
+                &smt_forest,
+                state_sync_update,
+                max_sync_history_rows,
+            )
         })
         .await
     }
 
+    async fn get_sync_history(&self, limit: usize) -> Result<Vec<(BlockNumber, u64)>, StoreError> {
+        self.interact_with_connection(move |conn| SqliteStore::get_sync_history(conn, limit))
+            .await
+    }
+
+    async fn rollback_to_block(&self, block_num: BlockNumber) -> Result<(), StoreError> {
+        self.interact_with_writable_connection(move |conn| {
+            SqliteStore::rollback_to_block(conn, block_num)
+        })
+        .await?;
+
+        // The rollback may have reverted account states, so the forest is rebuilt from the
+        // reverted accounts, as it would be when reopening the store.
+        *self.smt_forest.write().expect("smt write lock not poisoned") = AccountSmtForest::new();
+        self.initialize_smt_forest().await
+    }
+
     async fn get_transactions(
         &self,
         transaction_filter: TransactionFilter,
@@ -202,7 +369,7 @@ impl Store for SqliteStore {
 
     async fn apply_transaction(&self, tx_update: TransactionStoreUpdate) -> Result<(), StoreError> {
         let smt_forest = self.smt_forest.clone();
-        self.interact_with_connection(move |conn| {
+        self.interact_with_writable_connection(move |conn| {
             SqliteStore::apply_transaction(conn, &smt_forest, &tx_update)
         })
         .await
@@ -213,12 +380,74 @@ impl Store for SqliteStore {
         tx_updates: Vec<TransactionStoreUpdate>,
     ) -> Result<(), StoreError> {
         let smt_forest = self.smt_forest.clone();
-        self.interact_with_connection(move |conn| {
+        self.interact_with_writable_connection(move |conn| {
             SqliteStore::apply_transaction_batch(conn, &smt_forest, &tx_updates)
         })
         .await
     }
 
+    async fn insert_failed_transaction(
+        &self,
+        transaction_record: TransactionRecord,
+    ) -> Result<(), StoreError> {
+        self.interact_with_writable_connection(move |conn| {
+            SqliteStore::insert_failed_transaction(conn, &transaction_record)
+        })
+        .await
+    }
+
+    async fn set_transaction_category(
+        &self,
+        transaction_id: TransactionId,
+        category: Option<String>,
+    ) -> Result<(), StoreError> {
+        self.interact_with_writable_connection(move |conn| {
+            SqliteStore::set_transaction_category(conn, transaction_id, category.as_deref())
+        })
+        .await
+    }
+
+    async fn get_transaction_category(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<Option<String>, StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::get_transaction_category(conn, transaction_id)
+        })
+        .await
+    }
+
+    async fn insert_proven_transaction(
+        &self,
+        proven_transaction: ProvenTransaction,
+        transaction_inputs: TransactionInputs,
+    ) -> Result<(), StoreError> {
+        self.interact_with_writable_connection(move |conn| {
+            SqliteStore::insert_proven_transaction(conn, &proven_transaction, &transaction_inputs)
+        })
+        .await
+    }
+
+    async fn get_proven_transaction(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<Option<ProvenTransaction>, StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::get_proven_transaction(conn, transaction_id)
+        })
+        .await
+    }
+
+    async fn get_proven_transaction_inputs(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<Option<TransactionInputs>, StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::get_proven_transaction_inputs(conn, transaction_id)
+        })
+        .await
+    }
+
     async fn get_input_notes(
         &self,
         filter: NoteFilter,
@@ -227,6 +456,24 @@ impl Store for SqliteStore {
             .await
     }
 
+    async fn get_notes_modified_since(
+        &self,
+        seq: u64,
+    ) -> Result<(Vec<InputNoteRecord>, u64), StoreError> {
+        self.interact_with_connection(move |conn| SqliteStore::get_notes_modified_since(conn, seq))
+            .await
+    }
+
+    async fn get_note_state_history(
+        &self,
+        details_commitment: NoteDetailsCommitment,
+    ) -> Result<Vec<NoteStateTransition>, StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::get_note_state_history(conn, details_commitment)
+        })
+        .await
+    }
+
     async fn get_output_notes(
         &self,
         note_filter: NoteFilter,
@@ -256,10 +503,38 @@ impl Store for SqliteStore {
         .await
     }
 
+    async fn prune_consumed_notes(&self, before: BlockNumber) -> Result<usize, StoreError> {
+        self.interact_with_writable_connection(move |conn| {
+            SqliteStore::prune_consumed_notes(conn, before)
+        })
+        .await
+    }
+
+    async fn set_note_pinned(&self, note_id: NoteId, pinned: bool) -> Result<(), StoreError> {
+        self.interact_with_writable_connection(move |conn| {
+            SqliteStore::set_note_pinned(conn, note_id, pinned)
+        })
+        .await
+    }
+
     async fn upsert_input_notes(&self, notes: &[InputNoteRecord]) -> Result<(), StoreError> {
         let notes = notes.to_vec();
-        self.interact_with_connection(move |conn| SqliteStore::upsert_input_notes(conn, &notes))
-            .await
+        self.interact_with_writable_connection(move |conn| {
+            SqliteStore::upsert_input_notes(conn, &notes)
+        })
+        .await
+    }
+
+    async fn bulk_upsert_input_notes(
+        &self,
+        notes: &[InputNoteRecord],
+        strategy: NoteConflictStrategy,
+    ) -> Result<usize, StoreError> {
+        let notes = notes.to_vec();
+        self.interact_with_writable_connection(move |conn| {
+            SqliteStore::bulk_upsert_input_notes(conn, &notes, strategy)
+        })
+        .await
     }
 
     async fn get_note_script(&self, script_root: Word) -> Result<NoteScript, StoreError> {
@@ -269,7 +544,7 @@ impl Store for SqliteStore {
 
     async fn upsert_note_scripts(&self, note_scripts: &[NoteScript]) -> Result<(), StoreError> {
         let note_scripts = note_scripts.to_vec();
-        self.interact_with_connection(move |conn| {
+        self.interact_with_writable_connection(move |conn| {
             SqliteStore::upsert_note_scripts(conn, &note_scripts)
         })
         .await
@@ -283,7 +558,7 @@ impl Store for SqliteStore {
     ) -> Result<(), StoreError> {
         let block_header = block_header.clone();
         let nodes = nodes.to_vec();
-        self.interact_with_connection(move |conn| {
+        self.interact_with_writable_connection(move |conn| {
             SqliteStore::insert_block_header(conn, &block_header, &nodes, has_client_notes)
         })
         .await
@@ -296,7 +571,7 @@ impl Store for SqliteStore {
     ) -> Result<(), StoreError> {
         let blocks_to_untrack = blocks_to_untrack.to_vec();
         let node_indices_to_remove = node_indices_to_remove.to_vec();
-        self.interact_with_connection(move |conn| {
+        self.interact_with_writable_connection(move |conn| {
             SqliteStore::prune_irrelevant_blocks(conn, &blocks_to_untrack, &node_indices_to_remove)
         })
         .await
@@ -307,7 +582,7 @@ impl Store for SqliteStore {
         account_id: AccountId,
         up_to_nonce: Felt,
     ) -> Result<usize, StoreError> {
-        self.interact_with_connection(move |conn| {
+        self.interact_with_writable_connection(move |conn| {
             SqliteStore::prune_account_history(conn, account_id, up_to_nonce)
         })
         .await
@@ -325,6 +600,16 @@ impl Store for SqliteStore {
             .await?)
     }
 
+    async fn get_block_relevance(
+        &self,
+        block_number: BlockNumber,
+    ) -> Result<Option<BlockRelevance>, StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::get_block_relevance(conn, block_number)
+        })
+        .await
+    }
+
     async fn get_tracked_block_headers(&self) -> Result<Vec<BlockHeader>, StoreError> {
         self.interact_with_connection(SqliteStore::get_tracked_block_headers).await
     }
@@ -357,7 +642,7 @@ impl Store for SqliteStore {
         let cloned_account = account.clone();
         let smt_forest = self.smt_forest.clone();
 
-        self.interact_with_connection(move |conn| {
+        self.interact_with_writable_connection(move |conn| {
             SqliteStore::insert_account(
                 conn,
                 &smt_forest,
@@ -369,11 +654,36 @@ impl Store for SqliteStore {
         .await
     }
 
+    async fn import_account_bundle(
+        &self,
+        account: &Account,
+        address: Address,
+        notes: &[InputNoteRecord],
+        tags: &[NoteTagRecord],
+    ) -> Result<(), StoreError> {
+        let cloned_account = account.clone();
+        let notes = notes.to_vec();
+        let tags = tags.to_vec();
+        let smt_forest = self.smt_forest.clone();
+
+        self.interact_with_writable_connection(move |conn| {
+            SqliteStore::import_account_bundle(
+                conn,
+                &smt_forest,
+                &cloned_account,
+                &address,
+                &notes,
+                &tags,
+            )
+        })
+        .await
+    }
+
     async fn update_account(&self, account: &Account) -> Result<(), StoreError> {
         let cloned_account = account.clone();
         let smt_forest = self.smt_forest.clone();
 
-        self.interact_with_connection(move |conn| {
+        self.interact_with_writable_connection(move |conn| {
             SqliteStore::update_account(conn, &smt_forest, &cloned_account)
         })
         .await
@@ -383,8 +693,44 @@ impl Store for SqliteStore {
         self.interact_with_connection(SqliteStore::get_account_ids).await
     }
 
-    async fn get_account_headers(&self) -> Result<Vec<(AccountHeader, AccountStatus)>, StoreError> {
-        self.interact_with_connection(SqliteStore::get_account_headers).await
+    async fn get_filtered_account_headers(
+        &self,
+        filter: AccountFilter,
+    ) -> Result<Vec<(AccountHeader, AccountStatus)>, StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::get_filtered_account_headers(conn, filter)
+        })
+        .await
+    }
+
+    async fn archive_account(&self, account_id: AccountId) -> Result<(), StoreError> {
+        self.interact_with_writable_connection(move |conn| {
+            SqliteStore::set_account_archived(conn, account_id, true)
+        })
+        .await
+    }
+
+    async fn unarchive_account(&self, account_id: AccountId) -> Result<(), StoreError> {
+        self.interact_with_writable_connection(move |conn| {
+            SqliteStore::set_account_archived(conn, account_id, false)
+        })
+        .await
+    }
+
+    async fn set_account_watch_only(
+        &self,
+        account_id: AccountId,
+        watch_only: bool,
+    ) -> Result<(), StoreError> {
+        self.interact_with_writable_connection(move |conn| {
+            SqliteStore::set_account_watch_only(conn, account_id, watch_only)
+        })
+        .await
+    }
+
+    async fn get_account_nonce(&self, account_id: AccountId) -> Result<Option<Felt>, StoreError> {
+        self.interact_with_connection(move |conn| SqliteStore::get_account_nonce(conn, account_id))
+            .await
     }
 
     async fn get_account_header(
@@ -413,6 +759,16 @@ impl Store for SqliteStore {
             .await
     }
 
+    async fn get_account_by_commitment(
+        &self,
+        account_commitment: Word,
+    ) -> Result<Option<Account>, StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::get_account_by_commitment(conn, account_commitment)
+        })
+        .await
+    }
+
     async fn get_account_code(
         &self,
         account_id: AccountId,
@@ -428,7 +784,7 @@ impl Store for SqliteStore {
         account_id: AccountId,
         code: AccountCode,
     ) -> Result<(), StoreError> {
-        self.interact_with_connection(move |conn| {
+        self.interact_with_writable_connection(move |conn| {
             SqliteStore::upsert_foreign_account_code(conn, account_id, &code)
         })
         .await
@@ -445,7 +801,7 @@ impl Store for SqliteStore {
     }
 
     async fn set_setting(&self, key: String, value: Vec<u8>) -> Result<(), StoreError> {
-        self.interact_with_connection(move |conn| {
+        self.interact_with_writable_connection(move |conn| {
             set_setting(conn, &key, &value).into_store_error()
         })
         .await
@@ -456,18 +812,35 @@ impl Store for SqliteStore {
     }
 
     async fn remove_setting(&self, key: String) -> Result<(), StoreError> {
-        self.interact_with_connection(move |conn| remove_setting(conn, &key)).await
+        self.interact_with_writable_connection(move |conn| remove_setting(conn, &key))
+            .await
     }
 
     async fn list_setting_keys(&self) -> Result<Vec<String>, StoreError> {
         self.interact_with_connection(move |conn| list_setting_keys(conn)).await
     }
 
+    async fn list_setting_keys_with_prefix(
+        &self,
+        prefix: String,
+    ) -> Result<Vec<String>, StoreError> {
+        self.interact_with_connection(move |conn| list_setting_keys_with_prefix(conn, &prefix))
+            .await
+    }
+
+    async fn get_settings_with_prefix(
+        &self,
+        prefix: String,
+    ) -> Result<BTreeMap<String, Vec<u8>>, StoreError> {
+        self.interact_with_connection(move |conn| get_settings_with_prefix(conn, &prefix))
+            .await
+    }
+
     async fn apply_settings_mutations(
         &self,
         mutations: Vec<SettingMutation>,
     ) -> Result<(), StoreError> {
-        self.interact_with_connection(move |conn| {
+        self.interact_with_writable_connection(move |conn| {
             let tx = conn.transaction().into_store_error()?;
             for mutation in &mutations {
                 match mutation {
@@ -488,6 +861,10 @@ impl Store for SqliteStore {
             .await
     }
 
+    async fn get_unconsumed_output_notes(&self) -> Result<Vec<OutputNoteRecord>, StoreError> {
+        self.interact_with_connection(SqliteStore::get_unconsumed_output_notes).await
+    }
+
     async fn get_account_vault(&self, account_id: AccountId) -> Result<AssetVault, StoreError> {
         self.interact_with_connection(move |conn| SqliteStore::get_account_vault(conn, account_id))
             .await
@@ -530,6 +907,32 @@ impl Store for SqliteStore {
         .await
     }
 
+    async fn get_account_map_items(
+        &self,
+        account_id: AccountId,
+        slot_name: StorageSlotName,
+        keys: &[StorageMapKey],
+    ) -> Result<Vec<(Word, StorageMapWitness)>, StoreError> {
+        let smt_forest = self.smt_forest.clone();
+        let keys = keys.to_vec();
+
+        self.interact_with_connection(move |conn| {
+            SqliteStore::get_account_map_items(conn, &smt_forest, account_id, slot_name, &keys)
+        })
+        .await
+    }
+
+    async fn get_storage_map_keys(
+        &self,
+        account_id: AccountId,
+        slot_name: StorageSlotName,
+    ) -> Result<Vec<StorageMapKey>, StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::get_storage_map_keys(conn, account_id, &slot_name)
+        })
+        .await
+    }
+
     async fn get_addresses_by_account_id(
         &self,
         account_id: AccountId,
@@ -540,12 +943,16 @@ impl Store for SqliteStore {
         .await
     }
 
+    async fn get_all_addresses(&self) -> Result<Vec<(Address, AccountId)>, StoreError> {
+        self.interact_with_connection(SqliteStore::get_all_addresses).await
+    }
+
     async fn insert_address(
         &self,
         address: Address,
         account_id: AccountId,
     ) -> Result<(), StoreError> {
-        self.interact_with_connection(move |conn| {
+        self.interact_with_writable_connection(move |conn| {
             let tx = conn.transaction().into_store_error()?;
             SqliteStore::insert_address(&tx, &address, account_id)?;
             tx.commit().into_store_error()
@@ -554,8 +961,10 @@ impl Store for SqliteStore {
     }
 
     async fn remove_address(&self, address: Address) -> Result<(), StoreError> {
-        self.interact_with_connection(move |conn| SqliteStore::remove_address(conn, &address))
-            .await
+        self.interact_with_writable_connection(move |conn| {
+            SqliteStore::remove_address(conn, &address)
+        })
+        .await
     }
 
     async fn get_minimal_partial_account(
@@ -567,6 +976,24 @@ impl Store for SqliteStore {
         })
         .await
     }
+
+    async fn check_integrity(&self) -> Result<IntegrityReport, StoreError> {
+        self.interact_with_connection(|conn| {
+            let mut report = IntegrityReport::default();
+            report.extend(run_integrity_check(conn)?.into_iter().map(IntegrityIssue::Database));
+
+            // The remaining checks read through the damaged structures, so their results can't be
+            // trusted if the database itself is corrupted.
+            if !report.is_healthy() {
+                return Ok(report);
+            }
+
+            report.extend(SqliteStore::check_account_commitments(conn)?);
+            report.extend(SqliteStore::check_committed_note_blocks(conn)?);
+            Ok(report)
+        })
+        .await
+    }
 }
 
 // UTILS
@@ -612,11 +1039,14 @@ pub fn u64_to_value(v: u64) -> Value {
 #[cfg(test)]
 pub mod tests {
     use std::boxed::Box;
+    use std::vec::Vec;
 
-    use miden_client::store::Store;
+    use miden_client::store::{Store, StoreError};
     use miden_client::testing::common::create_test_store_path;
 
     use super::SqliteStore;
+    use crate::sql_error::SqlResultExt;
+    use crate::sync::insert_sync_history_tx;
 
     fn assert_send_sync<T: Send + Sync>() {}
 
@@ -640,6 +1070,75 @@ pub mod tests {
         tokio::task::spawn(async move { dyn_trait_send_fut(client).await });
     }
 
+    #[tokio::test]
+    async fn read_only_store_rejects_writes() {
+        let store_path = create_test_store_path();
+        let store = SqliteStore::new(store_path.clone()).await.unwrap();
+        store.set_setting("key".into(), vec![1, 2, 3]).await.unwrap();
+        drop(store);
+
+        let store = SqliteStore::open_read_only(store_path).await.unwrap();
+        assert!(store.is_read_only());
+
+        // Reads succeed.
+        assert_eq!(store.get_setting("key".into()).await.unwrap(), Some(vec![1, 2, 3]));
+        assert!(store.get_account_ids().await.unwrap().is_empty());
+
+        // Writes are rejected.
+        let result = store.set_setting("key".into(), vec![4]).await;
+        assert!(matches!(result, Err(StoreError::ReadOnly)));
+        assert_eq!(store.get_setting("key".into()).await.unwrap(), Some(vec![1, 2, 3]));
+    }
+
+    #[tokio::test]
+    async fn settings_can_be_listed_by_prefix() {
+        let store = create_test_store().await;
+        store.set_setting("faucet:a".into(), vec![1]).await.unwrap();
+        store.set_setting("faucet:b".into(), vec![2]).await.unwrap();
+        store.set_setting("faucets".into(), vec![3]).await.unwrap();
+        store.set_setting("wallet:a".into(), vec![4]).await.unwrap();
+        // Prefixes are matched case-sensitively.
+        store.set_setting("Faucet:c".into(), vec![5]).await.unwrap();
+
+        let mut keys = store.list_setting_keys_with_prefix("faucet:".into()).await.unwrap();
+        keys.sort();
+        assert_eq!(keys, vec!["faucet:a".to_string(), "faucet:b".to_string()]);
+
+        let settings = store.get_settings_with_prefix("faucet:".into()).await.unwrap();
+        assert_eq!(settings.len(), 2);
+        assert_eq!(settings.get("faucet:a"), Some(&vec![1]));
+        assert_eq!(settings.get("faucet:b"), Some(&vec![2]));
+
+        assert!(store.get_settings_with_prefix("missing:".into()).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn sync_history_is_capped() {
+        let store = create_test_store().await.with_max_sync_history_rows(3);
+        let max_rows = store.max_sync_history_rows;
+
+        store
+            .interact_with_writable_connection(move |conn| {
+                let tx = conn.transaction().into_store_error()?;
+                for block_num in 1..=5u32 {
+                    insert_sync_history_tx(&tx, block_num.into(), &[], max_rows)?;
+                }
+                tx.commit().into_store_error()
+            })
+            .await
+            .unwrap();
+
+        let history = store.get_sync_history(10).await.unwrap();
+        let block_nums: Vec<u32> =
+            history.iter().map(|(block_num, _)| block_num.as_u32()).collect();
+        assert_eq!(block_nums, vec![5, 4, 3]);
+
+        // The limit only returns the most recent entries.
+        let history = store.get_sync_history(1).await.unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].0.as_u32(), 5);
+    }
+
     pub(crate) async fn create_test_store() -> SqliteStore {
         SqliteStore::new(create_test_store_path()).await.unwrap()
     }
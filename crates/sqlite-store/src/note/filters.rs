@@ -11,7 +11,22 @@ type NoteQueryParams = Vec<Rc<Vec<Value>>>;
 
 /// Returns the output notes query for a specific `NoteFilter`
 pub(super) fn note_filter_to_query_output_notes(filter: &NoteFilter) -> (String, NoteQueryParams) {
-    let base = "SELECT
+    let (condition, params) = note_filter_output_notes_condition(filter);
+    let query = format!("{OUTPUT_NOTES_BASE_QUERY} WHERE {condition}");
+
+    (query, params)
+}
+
+/// Returns a query that fetches the output notes created or consumed after the block bound as
+/// the first parameter.
+pub(super) fn output_notes_after_block_query() -> String {
+    format!(
+        "{OUTPUT_NOTES_BASE_QUERY} \
+         WHERE note.created_block_height > ?1 OR note.consumed_block_height > ?1"
+    )
+}
+
+const OUTPUT_NOTES_BASE_QUERY: &str = "SELECT
                     note.recipient_digest,
                     note.assets,
                     note.metadata,
@@ -20,12 +35,6 @@ pub(super) fn note_filter_to_query_output_notes(filter: &NoteFilter) -> (String,
                     note.attachments
                     from output_notes AS note";
 
-    let (condition, params) = note_filter_output_notes_condition(filter);
-    let query = format!("{base} WHERE {condition}");
-
-    (query, params)
-}
-
 /// Returns the WHERE clause  for a specific `NoteFilter`.
 pub(super) fn note_filter_output_notes_condition(filter: &NoteFilter) -> (String, NoteQueryParams) {
     let mut params = Vec::new();
@@ -41,6 +50,12 @@ pub(super) fn note_filter_output_notes_condition(filter: &NoteFilter) -> (String
         NoteFilter::Consumed => {
             format!("state_discriminant = {}", OutputNoteState::STATE_CONSUMED)
         },
+        NoteFilter::ConsumedAfter(block_num) => {
+            format!("note.consumed_block_height > {}", block_num.as_u32())
+        },
+        NoteFilter::CreatedAfter(block_num) => {
+            format!("note.created_block_height > {}", block_num.as_u32())
+        },
         NoteFilter::Expected => {
             format!(
                 "state_discriminant in ({}, {})",
@@ -81,6 +96,10 @@ pub(super) fn note_filter_output_notes_condition(filter: &NoteFilter) -> (String
             params.push(Rc::new(nullifiers_list));
             "note.nullifier IN rarray(?)".to_string()
         },
+        NoteFilter::Recipient(recipient_digest) => {
+            params.push(Rc::new(vec![Value::Text(recipient_digest.to_hex())]));
+            "note.recipient_digest IN rarray(?)".to_string()
+        },
         NoteFilter::Unspent => {
             format!(
                 "state_discriminant in ({}, {}, {}, {})",
@@ -126,6 +145,21 @@ pub(super) fn note_filter_to_query_input_notes(filter: &NoteFilter) -> (String,
     (query, params)
 }
 
+/// Returns a query that fetches the input notes whose `updated_at` sequence value is greater than
+/// the bound parameter, ordered by that value.
+pub(super) fn input_notes_modified_since_query() -> String {
+    format!("{INPUT_NOTES_BASE_QUERY} WHERE note.updated_at > ? ORDER BY note.updated_at ASC")
+}
+
+/// Returns a query that fetches the input notes created or consumed after the block bound as the
+/// first parameter.
+pub(super) fn input_notes_after_block_query() -> String {
+    format!(
+        "{INPUT_NOTES_BASE_QUERY} \
+         WHERE note.created_block_height > ?1 OR note.consumed_block_height > ?1"
+    )
+}
+
 /// Returns a query that fetches a single input note at the given offset from the filtered set,
 /// restricted to a consumer account and optionally to a block range.
 pub(super) fn note_filter_to_query_input_note_by_offset(
@@ -174,6 +208,12 @@ pub(super) fn note_filter_input_notes_condition(filter: &NoteFilter) -> (String,
                 InputNoteState::STATE_CONSUMED_EXTERNAL
             )
         },
+        NoteFilter::ConsumedAfter(block_num) => {
+            format!("(note.consumed_block_height > {})", block_num.as_u32())
+        },
+        NoteFilter::CreatedAfter(block_num) => {
+            format!("(note.created_block_height > {})", block_num.as_u32())
+        },
         NoteFilter::Expected => {
             format!("(state_discriminant = {})", InputNoteState::STATE_EXPECTED)
         },
@@ -216,6 +256,7 @@ pub(super) fn note_filter_input_notes_condition(filter: &NoteFilter) -> (String,
             params.push(Rc::new(nullifiers_list));
             "(note.nullifier IN rarray(?))".to_string()
         },
+        NoteFilter::Recipient(_) => "(1 = 0)".to_string(),
         NoteFilter::Unverified => {
             format!("(state_discriminant = {})", InputNoteState::STATE_UNVERIFIED)
         },
@@ -1,6 +1,6 @@
 #![allow(clippy::items_after_statements)]
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::rc::Rc;
 use std::string::{String, ToString};
 use std::vec::Vec;
@@ -12,6 +12,9 @@ use miden_client::note::{
     NoteAssets,
     NoteAttachments,
     NoteDetails,
+    NoteDetailsCommitment,
+    NoteId,
+    NoteInclusionProof,
     NoteMetadata,
     NoteRecipient,
     NoteScript,
@@ -19,36 +22,59 @@ use miden_client::note::{
     NoteUpdateType,
     Nullifier,
 };
+use miden_client::store::input_note_states::ExpectedNoteState;
 use miden_client::store::{
     InputNoteRecord,
     InputNoteState,
+    IntegrityIssue,
+    NoteConflictStrategy,
     NoteFilter,
+    NoteStateTransition,
     OutputNoteRecord,
     OutputNoteState,
     StoreError,
+    TransactionFilter,
 };
+use miden_client::sync::{NoteTagRecord, NoteTagSource};
 use miden_client::utils::{Deserializable, Serializable};
 use miden_protocol::note::NoteStorage;
 use rusqlite::types::Value;
-use rusqlite::{Connection, Transaction, params, params_from_iter};
+use rusqlite::{
+    Connection,
+    OptionalExtension,
+    Transaction,
+    TransactionBehavior,
+    params,
+    params_from_iter,
+};
 
 use super::SqliteStore;
 use crate::chain_data::set_block_header_has_client_notes;
 use crate::note::filters::{note_filter_to_query_input_notes, note_filter_to_query_output_notes};
 use crate::sql_error::SqlResultExt;
-use crate::{insert_sql, subst};
+use crate::sync::{add_note_tag_tx, remove_note_tag_tx};
 
 mod filters;
 
 // BATCH SIZE CONSTANTS
 // ================================================================================================
 
-// SQLite limits statements to 999 parameters. Each batch size is chosen to stay under that
-// limit: input notes: 13 columns × 50 = 650, output notes: 8 × 80 = 640, scripts: 2 × 200 = 400.
+// Input notes, output notes and note scripts are upserted with multi-row INSERT statements, in
+// chunks of the sizes below. SQLite limits statements to 999 parameters, so each chunk binds
+// `columns × rows` parameters under that limit: input notes 16 × 50 = 800, output notes
+// 12 × 80 = 960, scripts 2 × 200 = 400.
 const INPUT_NOTE_BATCH_SIZE: usize = 50;
 const OUTPUT_NOTE_BATCH_SIZE: usize = 80;
 const SCRIPT_BATCH_SIZE: usize = 200;
 
+/// SQL expression for the current value of the input notes change sequence, which input note
+/// writes store in `updated_at`.
+const INPUT_NOTES_SEQUENCE_VALUE: &str = "(SELECT value FROM input_notes_sequence)";
+
+/// Maximum number of state transitions kept per input note in `input_note_state_log`. Recording a
+/// new transition deletes the oldest ones beyond this limit.
+const MAX_NOTE_STATE_TRANSITIONS: usize = 32;
+
 #[cfg(test)]
 mod tests;
 
@@ -66,9 +92,11 @@ struct SerializedInputNoteData {
     pub script_root: String,
     pub script: Vec<u8>,
     pub nullifier: Option<String>,
+    pub tag: Option<Vec<u8>>,
     pub state_discriminant: u8,
     pub state: Vec<u8>,
     pub created_at: u64,
+    pub created_block_height: Option<u32>,
     pub consumed_block_height: Option<u32>,
     pub consumed_tx_order: Option<u32>,
     pub consumer_account_id: Option<String>,
@@ -86,6 +114,8 @@ struct SerializedOutputNoteData {
     pub state_discriminant: u8,
     pub state: Vec<u8>,
     pub attachments: Vec<u8>,
+    pub created_block_height: Option<u32>,
+    pub consumed_block_height: Option<u32>,
 }
 
 /// Represents the parts retrieved from the database to build an `InputNoteRecord`.
@@ -114,6 +144,7 @@ struct SerializedInputNoteStateUpdate {
     pub details_commitment: String,
     pub state_discriminant: u8,
     pub state: Vec<u8>,
+    pub created_block_height: Option<u32>,
     pub consumed_block_height: Option<u32>,
     pub consumed_tx_order: Option<u32>,
     pub consumer_account_id: Option<String>,
@@ -124,6 +155,8 @@ struct SerializedOutputNoteStateUpdate {
     pub details_commitment: String,
     pub state_discriminant: u8,
     pub state: Vec<u8>,
+    pub created_block_height: Option<u32>,
+    pub consumed_block_height: Option<u32>,
 }
 
 /// Represents the pars retrieved form the database to build a `NoteScript`
@@ -151,6 +184,60 @@ impl SqliteStore {
         Ok(notes)
     }
 
+    /// Retrieves the input notes inserted or updated after the change sequence value `seq`,
+    /// together with the current value of the sequence.
+    pub(crate) fn get_notes_modified_since(
+        conn: &mut Connection,
+        seq: u64,
+    ) -> Result<(Vec<InputNoteRecord>, u64), StoreError> {
+        // Read the notes and the sequence from a single snapshot so that the returned
+        // high-water mark matches the returned notes. Nothing is written, so the transaction is
+        // deferred and dropped at the end without committing.
+        let tx = conn
+            .transaction_with_behavior(TransactionBehavior::Deferred)
+            .into_store_error()?;
+
+        let notes = tx
+            .prepare(&filters::input_notes_modified_since_query())
+            .into_store_error()?
+            .query_map(params![seq], parse_input_note_columns)
+            .into_store_error()?
+            .map(|result| Ok(result.into_store_error()?).and_then(parse_input_note))
+            .collect::<Result<Vec<InputNoteRecord>, _>>()?;
+
+        let current_seq: u64 = tx
+            .query_row("SELECT value FROM input_notes_sequence", [], |row| row.get(0))
+            .into_store_error()?;
+
+        Ok((notes, current_seq.max(seq)))
+    }
+
+    /// Retrieves the logged state transitions of the input note with the provided details
+    /// commitment, oldest first.
+    pub(crate) fn get_note_state_history(
+        conn: &mut Connection,
+        details_commitment: NoteDetailsCommitment,
+    ) -> Result<Vec<NoteStateTransition>, StoreError> {
+        const QUERY: &str = "SELECT state, block_num, timestamp FROM input_note_state_log \
+             WHERE details_commitment = ? ORDER BY id";
+
+        conn.prepare(QUERY)
+            .into_store_error()?
+            .query_map(params![details_commitment.to_hex()], |row| {
+                let state: Vec<u8> = row.get(0)?;
+                let block_num: u32 = row.get(1)?;
+                let timestamp: u64 = row.get(2)?;
+                Ok((state, block_num, timestamp))
+            })
+            .into_store_error()?
+            .map(|result| -> Result<NoteStateTransition, StoreError> {
+                let (state, block_num, timestamp) = result.into_store_error()?;
+                let state = InputNoteState::read_from_bytes(&state)?;
+                Ok(NoteStateTransition::new(state, block_num.into(), timestamp))
+            })
+            .collect()
+    }
+
     /// Retrieves the output notes from the database.
     pub(crate) fn get_output_notes(
         conn: &mut Connection,
@@ -203,21 +290,31 @@ impl SqliteStore {
         notes: &[InputNoteRecord],
     ) -> Result<(), StoreError> {
         let tx = conn.transaction().into_store_error()?;
+        upsert_input_notes_tx(&tx, notes)?;
+        tx.commit().into_store_error()
+    }
 
+    pub(crate) fn bulk_upsert_input_notes(
+        conn: &mut Connection,
+        notes: &[InputNoteRecord],
+        strategy: NoteConflictStrategy,
+    ) -> Result<usize, StoreError> {
+        let tx = conn.transaction().into_store_error()?;
+
+        let mut notes_to_write = Vec::with_capacity(notes.len());
         for note in notes {
-            upsert_input_note_tx(&tx, note)?;
-
-            // Whenever we insert a note, we also update block relevance
-            if let Some(inclusion_proof) = note.inclusion_proof() {
-                set_block_header_has_client_notes(
-                    &tx,
-                    inclusion_proof.location().block_num().as_u64(),
-                    true,
-                )?;
+            if strategy == NoteConflictStrategy::KeepNewerState
+                && is_stored_state_newer_tx(&tx, note)?
+            {
+                continue;
             }
+            notes_to_write.push(note.clone());
         }
 
-        tx.commit().into_store_error()
+        upsert_input_notes_tx(&tx, &notes_to_write)?;
+
+        tx.commit().into_store_error()?;
+        Ok(notes_to_write.len())
     }
 
     pub(crate) fn get_unspent_input_note_nullifiers(
@@ -242,15 +339,115 @@ impl SqliteStore {
             .collect::<Result<Vec<Nullifier>, _>>()
     }
 
+    /// Retrieves the output notes whose nullifier hasn't been observed on chain yet.
+    pub(crate) fn get_unconsumed_output_notes(
+        conn: &mut Connection,
+    ) -> Result<Vec<OutputNoteRecord>, StoreError> {
+        Self::get_output_notes(conn, &NoteFilter::Unspent)
+    }
+
+    /// Deletes consumed input notes whose consumption block is lower than `before`, skipping
+    /// pinned notes and notes referenced by uncommitted transactions. Returns the number of deleted
+    /// input notes.
+    ///
+    /// Note scripts are kept, as they are shared with the scripts cached for transaction
+    /// execution.
+    pub(crate) fn prune_consumed_notes(
+        conn: &mut Connection,
+        before: BlockNumber,
+    ) -> Result<usize, StoreError> {
+        let tx = conn.transaction().into_store_error()?;
+
+        let pending_nullifiers: BTreeSet<String> =
+            Self::get_transactions(&tx, &TransactionFilter::Uncommitted)?
+                .iter()
+                .flat_map(|tx| tx.details.input_note_nullifiers.iter().map(Word::to_hex))
+                .collect();
+
+        const QUERY: &str = "SELECT details_commitment, nullifier FROM input_notes \
+             WHERE state_discriminant IN rarray(?) AND consumed_block_height < ? \
+             AND details_commitment NOT IN (SELECT details_commitment FROM pinned_notes)";
+        let consumed_states = Rc::new(vec![
+            Value::from(InputNoteState::STATE_CONSUMED_AUTHENTICATED_LOCAL.to_string()),
+            Value::from(InputNoteState::STATE_CONSUMED_UNAUTHENTICATED_LOCAL.to_string()),
+            Value::from(InputNoteState::STATE_CONSUMED_EXTERNAL.to_string()),
+        ]);
+        let candidates: Vec<(String, Option<String>)> = tx
+            .prepare(QUERY)
+            .into_store_error()?
+            .query_map(params![consumed_states, before.as_u32()], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .into_store_error()?
+            .collect::<Result<Vec<_>, _>>()
+            .into_store_error()?;
+
+        let mut removed = 0;
+        for (details_commitment, nullifier) in candidates {
+            if nullifier.is_some_and(|nullifier| pending_nullifiers.contains(&nullifier)) {
+                continue;
+            }
+
+            removed += tx
+                .execute(
+                    "DELETE FROM input_notes WHERE details_commitment = ?",
+                    params![details_commitment],
+                )
+                .into_store_error()?;
+            tx.execute(
+                "DELETE FROM input_note_state_log WHERE details_commitment = ?",
+                params![details_commitment],
+            )
+            .into_store_error()?;
+        }
+
+        tx.commit().into_store_error()?;
+        Ok(removed)
+    }
+
+    /// Pins or unpins the input and output notes with the provided ID.
+    pub(crate) fn set_note_pinned(
+        conn: &mut Connection,
+        note_id: NoteId,
+        pinned: bool,
+    ) -> Result<(), StoreError> {
+        let tx = conn.transaction().into_store_error()?;
+
+        const QUERY: &str = "SELECT details_commitment FROM input_notes WHERE note_id = ?1 \
+             UNION SELECT details_commitment FROM output_notes WHERE note_id = ?1";
+        let details_commitment: String = tx
+            .query_row(QUERY, params![note_id.as_word().to_string()], |row| row.get(0))
+            .optional()
+            .into_store_error()?
+            .ok_or(StoreError::NoteNotFound(note_id))?;
+
+        if pinned {
+            tx.execute(
+                "INSERT OR IGNORE INTO pinned_notes (details_commitment) VALUES (?)",
+                params![details_commitment],
+            )
+        } else {
+            tx.execute(
+                "DELETE FROM pinned_notes WHERE details_commitment = ?",
+                params![details_commitment],
+            )
+        }
+        .into_store_error()?;
+
+        tx.commit().into_store_error()
+    }
+
     pub(crate) fn upsert_note_scripts(
         conn: &mut Connection,
         note_scripts: &[NoteScript],
     ) -> Result<(), StoreError> {
         let tx = conn.transaction().into_store_error()?;
 
-        for note_script in note_scripts {
-            upsert_note_script_tx(&tx, note_script)?;
-        }
+        let scripts: BTreeMap<String, Vec<u8>> = note_scripts
+            .iter()
+            .map(|note_script| (note_script.root().to_hex(), note_script.to_bytes()))
+            .collect();
+        batch_upsert_scripts(&tx, &scripts)?;
 
         tx.commit().into_store_error()
     }
@@ -275,81 +472,139 @@ impl SqliteStore {
 
         Ok(note_script)
     }
+
+    /// Reports the committed input notes whose inclusion block header isn't stored.
+    pub(crate) fn check_committed_note_blocks(
+        conn: &mut Connection,
+    ) -> Result<Vec<IntegrityIssue>, StoreError> {
+        let note_blocks: Vec<(NoteId, BlockNumber)> =
+            Self::get_input_notes(conn, &NoteFilter::Committed)?
+                .iter()
+                .filter_map(|note| {
+                    Some((note.id()?, note.inclusion_proof()?.location().block_num()))
+                })
+                .collect();
+
+        let block_nums = note_blocks.iter().map(|(_, block_num)| *block_num).collect();
+        let stored_blocks: BTreeSet<BlockNumber> = Self::get_block_headers(conn, &block_nums)?
+            .into_iter()
+            .map(|(header, _)| header.block_num())
+            .collect();
+
+        Ok(note_blocks
+            .into_iter()
+            .filter(|(_, block_num)| !stored_blocks.contains(block_num))
+            .map(|(note_id, block_num)| IntegrityIssue::MissingNoteBlockHeader {
+                note_id,
+                block_num,
+            })
+            .collect())
+    }
 }
 
 // HELPERS
 // ================================================================================================
 
+/// Upserts the provided input notes, flagging the block headers of the included ones as relevant
+/// to the client.
+pub(super) fn upsert_input_notes_tx(
+    tx: &Transaction<'_>,
+    notes: &[InputNoteRecord],
+) -> Result<(), StoreError> {
+    let mut scripts: BTreeMap<String, Vec<u8>> = BTreeMap::new();
+    let serialized_notes: Vec<SerializedInputNoteData> = notes
+        .iter()
+        .map(|note| {
+            let serialized = serialize_input_note(note);
+            scripts.insert(serialized.script_root.clone(), serialized.script.clone());
+            serialized
+        })
+        .collect();
+
+    batch_upsert_scripts(tx, &scripts)?;
+    batch_insert_input_notes(tx, &serialized_notes)?;
+
+    for note in notes {
+        log_input_note_state_tx(tx, note)?;
+
+        // Whenever we insert a note, we also update block relevance
+        if let Some(inclusion_proof) = note.inclusion_proof() {
+            set_block_header_has_client_notes(
+                tx,
+                inclusion_proof.location().block_num().as_u64(),
+                true,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns `true` if the stored version of the provided note is further along the note lifecycle
+/// than the note itself. Returns `false` if the note isn't stored.
+fn is_stored_state_newer_tx(
+    tx: &Transaction<'_>,
+    note: &InputNoteRecord,
+) -> Result<bool, StoreError> {
+    const QUERY: &str = "SELECT state FROM input_notes WHERE details_commitment = ?";
+    let stored_state: Option<Vec<u8>> = tx
+        .query_row(QUERY, params![note.details_commitment().to_hex()], |row| row.get(0))
+        .optional()
+        .into_store_error()?;
+
+    match stored_state {
+        Some(stored_state) => {
+            Ok(note.state().precedes(&InputNoteState::read_from_bytes(&stored_state)?))
+        },
+        None => Ok(false),
+    }
+}
+
 /// Inserts the provided input note into the database, if the note already exists, it will be
 /// replaced.
 pub(super) fn upsert_input_note_tx(
     tx: &Transaction<'_>,
     note: &InputNoteRecord,
 ) -> Result<(), StoreError> {
-    let SerializedInputNoteData {
-        details_commitment,
-        id,
-        assets,
-        attachments,
-        serial_number,
-        inputs,
-        script_root,
-        script,
-        nullifier,
-        state_discriminant,
-        state,
-        created_at,
-        consumed_block_height,
-        consumed_tx_order,
-        consumer_account_id,
-    } = serialize_input_note(note);
+    let serialized = serialize_input_note(note);
+    let scripts = BTreeMap::from([(serialized.script_root.clone(), serialized.script.clone())]);
 
-    const SCRIPT_QUERY: &str =
-        insert_sql!(notes_scripts { script_root, serialized_note_script } | REPLACE);
-    tx.prepare_cached(SCRIPT_QUERY)
-        .into_store_error()?
-        .execute(params![script_root, script])
-        .into_store_error()?;
+    batch_upsert_scripts(tx, &scripts)?;
+    batch_insert_input_notes(tx, std::slice::from_ref(&serialized))
+}
 
-    const NOTE_QUERY: &str = insert_sql!(
-        input_notes {
-            details_commitment,
-            note_id,
-            assets,
-            attachments,
-            serial_number,
-            inputs,
-            script_root,
-            nullifier,
-            state_discriminant,
-            state,
-            created_at,
-            consumed_block_height,
-            consumed_tx_order,
-            consumer_account_id,
-        } | REPLACE
-    );
-
-    tx.prepare_cached(NOTE_QUERY)
+/// Records the state of the provided input note in `input_note_state_log`, stamped with the
+/// current sync height and time, unless it's the same variant as the note's last recorded state.
+/// Only the latest [`MAX_NOTE_STATE_TRANSITIONS`] transitions of the note are kept.
+fn log_input_note_state_tx(tx: &Transaction<'_>, note: &InputNoteRecord) -> Result<(), StoreError> {
+    const INSERT_QUERY: &str = "INSERT INTO input_note_state_log \
+         (details_commitment, state_discriminant, state, block_num, timestamp) \
+         SELECT ?1, ?2, ?3, (SELECT block_num FROM blockchain_checkpoint), ?4 \
+         WHERE ?2 IS NOT (SELECT state_discriminant FROM input_note_state_log \
+             WHERE details_commitment = ?1 ORDER BY id DESC LIMIT 1)";
+    const PRUNE_QUERY: &str = "DELETE FROM input_note_state_log WHERE details_commitment = ?1 \
+         AND id NOT IN (SELECT id FROM input_note_state_log WHERE details_commitment = ?1 \
+             ORDER BY id DESC LIMIT ?2)";
+
+    let details_commitment = note.details_commitment().to_hex();
+    let inserted = tx
+        .prepare_cached(INSERT_QUERY)
         .into_store_error()?
         .execute(params![
             details_commitment,
-            id,
-            assets,
-            attachments,
-            serial_number,
-            inputs,
-            script_root,
-            nullifier,
-            state_discriminant,
-            state,
-            created_at,
-            consumed_block_height,
-            consumed_tx_order,
-            consumer_account_id,
+            note.state().discriminant(),
+            note.state().to_bytes(),
+            super::current_timestamp_u64(),
         ])
         .into_store_error()?;
 
+    if inserted > 0 {
+        tx.prepare_cached(PRUNE_QUERY)
+            .into_store_error()?
+            .execute(params![details_commitment, MAX_NOTE_STATE_TRANSITIONS])
+            .into_store_error()?;
+    }
+
     Ok(())
 }
 
@@ -415,6 +670,15 @@ fn serialize_input_note(note: &InputNoteRecord) -> SerializedInputNoteData {
     let nullifier = note.metadata().map(|metadata| {
         miden_client::note::Nullifier::from_details_and_metadata(note.details(), metadata).to_hex()
     });
+    // The tag is stored serialized like in the `tags` table, so notes can be joined against it.
+    let tag = note
+        .metadata()
+        .map(NoteMetadata::tag)
+        .or(match note.state() {
+            InputNoteState::Expected(state) => state.tag,
+            _ => None,
+        })
+        .map(|tag| tag.to_bytes());
     let created_at = note.created_at().unwrap_or(0);
 
     let details = note.details();
@@ -431,6 +695,7 @@ fn serialize_input_note(note: &InputNoteRecord) -> SerializedInputNoteData {
     let state_discriminant = note.state().discriminant();
     let state = note.state().to_bytes();
 
+    let created_block_height = note.inclusion_proof().map(inclusion_block_height);
     let consumed_block_height = note.state().consumed_block_height().map(|h| h.as_u32());
     let consumed_tx_order = note.state().consumed_tx_order();
     let consumer_account_id = note.consumer_account().map(AccountId::to_hex);
@@ -445,9 +710,11 @@ fn serialize_input_note(note: &InputNoteRecord) -> SerializedInputNoteData {
         script_root,
         script,
         nullifier,
+        tag,
         state_discriminant,
         state,
         created_at,
+        created_block_height,
         consumed_block_height,
         consumed_tx_order,
         consumer_account_id,
@@ -506,6 +773,7 @@ fn parse_output_note(
 
 /// Serialize the provided input note state into a lightweight update.
 fn serialize_input_note_state(note: &InputNoteRecord) -> SerializedInputNoteStateUpdate {
+    let created_block_height = note.inclusion_proof().map(inclusion_block_height);
     let consumed_block_height = note.state().consumed_block_height().map(|h| h.as_u32());
     let consumed_tx_order = note.state().consumed_tx_order();
     let consumer_account_id = note.consumer_account().map(AccountId::to_hex);
@@ -514,6 +782,7 @@ fn serialize_input_note_state(note: &InputNoteRecord) -> SerializedInputNoteStat
         details_commitment: note.details_commitment().to_hex(),
         state_discriminant: note.state().discriminant(),
         state: note.state().to_bytes(),
+        created_block_height,
         consumed_block_height,
         consumed_tx_order,
         consumer_account_id,
@@ -526,6 +795,8 @@ fn serialize_output_note_state(note: &OutputNoteRecord) -> SerializedOutputNoteS
         details_commitment: note.details_commitment().to_hex(),
         state_discriminant: note.state().discriminant(),
         state: note.state().to_bytes(),
+        created_block_height: note.inclusion_proof().map(inclusion_block_height),
+        consumed_block_height: output_note_consumed_block_height(note),
     }
 }
 
@@ -555,19 +826,179 @@ fn serialize_output_note(note: &OutputNoteRecord) -> SerializedOutputNoteData {
         state_discriminant,
         state,
         attachments,
+        created_block_height: note.inclusion_proof().map(inclusion_block_height),
+        consumed_block_height: output_note_consumed_block_height(note),
+    }
+}
+
+/// Returns the height of the block a note was created in, given its inclusion proof.
+fn inclusion_block_height(inclusion_proof: &NoteInclusionProof) -> u32 {
+    inclusion_proof.location().block_num().as_u32()
+}
+
+/// Returns the height of the block an output note was consumed in, if it was consumed.
+fn output_note_consumed_block_height(note: &OutputNoteRecord) -> Option<u32> {
+    match note.state() {
+        OutputNoteState::Consumed { block_height, .. } => Some(block_height.as_u32()),
+        _ => None,
+    }
+}
+
+/// Reverts the notes whose state depends on a block after `block_num`, as part of a rollback to
+/// that block.
+///
+/// Input notes go back to their latest logged state that doesn't depend on a later block, or to
+/// [`InputNoteState::Expected`] if there's none left in the log. Output notes go back to their
+/// expected state, as their inclusion proof isn't kept once they're consumed.
+pub(crate) fn rollback_notes_tx(
+    tx: &Transaction<'_>,
+    block_num: BlockNumber,
+) -> Result<(), StoreError> {
+    const OUTPUT_NOTE_QUERY: &str = "UPDATE output_notes SET state_discriminant = ?, state = ?, \
+         created_block_height = NULL, consumed_block_height = NULL WHERE details_commitment = ?";
+
+    let block_num_value = i64::from(block_num.as_u32());
+
+    let input_notes = tx
+        .prepare(&filters::input_notes_after_block_query())
+        .into_store_error()?
+        .query_map(params![block_num_value], parse_input_note_columns)
+        .into_store_error()?
+        .map(|result| Ok(result.into_store_error()?).and_then(parse_input_note))
+        .collect::<Result<Vec<InputNoteRecord>, _>>()?;
+
+    for note in &input_notes {
+        rollback_input_note_tx(tx, note, block_num)?;
+    }
+
+    let output_notes = tx
+        .prepare(&filters::output_notes_after_block_query())
+        .into_store_error()?
+        .query_map(params![block_num_value], parse_output_note_columns)
+        .into_store_error()?
+        .map(|result| Ok(result.into_store_error()?).and_then(parse_output_note))
+        .collect::<Result<Vec<OutputNoteRecord>, _>>()?;
+
+    for note in &output_notes {
+        let state = match note.recipient() {
+            Some(recipient) => OutputNoteState::ExpectedFull { recipient: recipient.clone() },
+            None => OutputNoteState::ExpectedPartial,
+        };
+        tx.execute(
+            OUTPUT_NOTE_QUERY,
+            params![state.discriminant(), state.to_bytes(), note.details_commitment().to_hex()],
+        )
+        .into_store_error()?;
+    }
+
+    Ok(())
+}
+
+/// Restores the latest logged state of `note` that doesn't depend on a block after `block_num`,
+/// dropping the later entries from `input_note_state_log`.
+fn rollback_input_note_tx(
+    tx: &Transaction<'_>,
+    note: &InputNoteRecord,
+    block_num: BlockNumber,
+) -> Result<(), StoreError> {
+    const LOG_QUERY: &str =
+        "SELECT id, state FROM input_note_state_log WHERE details_commitment = ? ORDER BY id DESC";
+    const PRUNE_LOG_QUERY: &str =
+        "DELETE FROM input_note_state_log WHERE details_commitment = ? AND id > ?";
+
+    let details_commitment = note.details_commitment().to_hex();
+    let logged_states = tx
+        .prepare(LOG_QUERY)
+        .into_store_error()?
+        .query_map(params![details_commitment], |row| {
+            Ok((row.get::<_, i64>(0)?, row.get::<_, Vec<u8>>(1)?))
+        })
+        .into_store_error()?
+        .collect::<Result<Vec<_>, _>>()
+        .into_store_error()?;
+
+    let mut restored = None;
+    for (log_id, state) in logged_states {
+        let candidate = InputNoteRecord::new(
+            note.details().clone(),
+            note.attachments().clone(),
+            note.created_at(),
+            InputNoteState::read_from_bytes(&state)?,
+        );
+        let created_after = candidate
+            .inclusion_proof()
+            .is_some_and(|proof| proof.location().block_num() > block_num);
+        let consumed_after = candidate
+            .state()
+            .consumed_block_height()
+            .is_some_and(|height| height > block_num);
+
+        if !created_after && !consumed_after {
+            restored = Some((log_id, candidate));
+            break;
+        }
+    }
+
+    let (last_kept_log_id, restored) = restored.unwrap_or_else(|| {
+        let metadata = note.metadata().cloned();
+        let state = ExpectedNoteState {
+            tag: metadata.as_ref().map(NoteMetadata::tag),
+            metadata,
+            after_block_num: block_num,
+        };
+        let restored = InputNoteRecord::new(
+            note.details().clone(),
+            note.attachments().clone(),
+            note.created_at(),
+            state.into(),
+        );
+        (0, restored)
+    });
+
+    tx.execute(PRUNE_LOG_QUERY, params![details_commitment, last_kept_log_id])
+        .into_store_error()?;
+    upsert_input_note_tx(tx, &restored)?;
+    log_input_note_state_tx(tx, &restored)?;
+
+    // Expected notes are found through their tag, which stopped being tracked once the note
+    // got committed.
+    if let InputNoteState::Expected(ExpectedNoteState { tag: Some(tag), .. }) = restored.state() {
+        let tag_record = NoteTagRecord {
+            tag: *tag,
+            source: NoteTagSource::Note(restored.details_commitment()),
+        };
+        remove_note_tag_tx(tx, tag_record)?;
+        add_note_tag_tx(tx, &tag_record)?;
     }
+
+    Ok(())
 }
 
+/// Applies the tracked input and output note updates, resolving conflicts with the input notes
+/// that are already stored according to `strategy`.
 pub(crate) fn apply_note_updates_tx(
     tx: &Transaction,
     note_updates: &NoteUpdateTracker,
+    strategy: NoteConflictStrategy,
 ) -> Result<(), StoreError> {
+    // Drop input note updates that would move a stored note backwards in its lifecycle.
+    let mut updated_input_notes = Vec::new();
+    for input_note in note_updates.updated_input_notes() {
+        if matches!(input_note.update_type(), NoteUpdateType::None)
+            || (strategy == NoteConflictStrategy::KeepNewerState
+                && is_stored_state_newer_tx(tx, input_note.inner())?)
+        {
+            continue;
+        }
+        updated_input_notes.push(input_note);
+    }
+
     // Split input notes into inserts and updates, collecting scripts from new notes.
     let mut input_inserts = Vec::new();
     let mut input_updates = Vec::new();
     let mut scripts: BTreeMap<String, Vec<u8>> = BTreeMap::new();
 
-    for input_note in note_updates.updated_input_notes() {
+    for input_note in &updated_input_notes {
         match input_note.update_type() {
             // `InsertCommitted` is a previously-expected note that just gained its metadata, so it
             // needs a full-row insert (to write `note_id`/`nullifier`), same as `Insert`.
@@ -587,6 +1018,10 @@ pub(crate) fn apply_note_updates_tx(
     batch_insert_input_notes(tx, &input_inserts)?;
     batch_update_input_note_states(tx, &input_updates)?;
 
+    for input_note in &updated_input_notes {
+        log_input_note_state_tx(tx, input_note.inner())?;
+    }
+
     // Split output notes into inserts and updates.
     let mut output_inserts = Vec::new();
     let mut output_updates = Vec::new();
@@ -611,6 +1046,18 @@ pub(crate) fn apply_note_updates_tx(
     Ok(())
 }
 
+/// Advances the input notes change sequence. The input note writes that follow in the same
+/// transaction stamp `updated_at` with the new value through [`INPUT_NOTES_SEQUENCE_VALUE`], so
+/// [`SqliteStore::get_notes_modified_since`] picks them up.
+fn advance_input_notes_sequence_tx(tx: &Transaction) -> Result<(), StoreError> {
+    tx.prepare_cached("UPDATE input_notes_sequence SET value = value + 1")
+        .into_store_error()?
+        .execute([])
+        .into_store_error()?;
+
+    Ok(())
+}
+
 /// Batch-insert note scripts using multi-row INSERT OR REPLACE.
 /// Multi-row inserts reduce per-statement overhead and show faster insertion times than
 /// individual inserts.
@@ -649,17 +1096,22 @@ fn batch_insert_input_notes(
         return Ok(());
     }
 
+    advance_input_notes_sequence_tx(tx)?;
+
     for chunk in notes.chunks(INPUT_NOTE_BATCH_SIZE) {
-        let placeholders =
-            vec!["(?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"; chunk.len()].join(", ");
+        let row = format!(
+            "(?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, {INPUT_NOTES_SEQUENCE_VALUE})"
+        );
+        let placeholders = vec![row.as_str(); chunk.len()].join(", ");
         let query = format!(
             "INSERT OR REPLACE INTO `input_notes` \
              (`details_commitment`, `note_id`, `assets`, `attachments`, `serial_number`, \
-              `inputs`, `script_root`, `nullifier`, `state_discriminant`, `state`, `created_at`, \
-              `consumed_block_height`, `consumed_tx_order`, `consumer_account_id`) \
+              `inputs`, `script_root`, `nullifier`, `tag`, `state_discriminant`, `state`, \
+              `created_at`, `created_block_height`, `consumed_block_height`, `consumed_tx_order`, \
+              `consumer_account_id`, `updated_at`) \
              VALUES {placeholders}"
         );
-        let mut param_values: Vec<Value> = Vec::with_capacity(chunk.len() * 14);
+        let mut param_values: Vec<Value> = Vec::with_capacity(chunk.len() * 16);
         for note in chunk {
             param_values.push(Value::Text(note.details_commitment.clone()));
             match &note.id {
@@ -675,10 +1127,18 @@ fn batch_insert_input_notes(
                 Some(n) => param_values.push(Value::Text(n.clone())),
                 None => param_values.push(Value::Null),
             }
+            match &note.tag {
+                Some(tag) => param_values.push(Value::Blob(tag.clone())),
+                None => param_values.push(Value::Null),
+            }
             param_values.push(Value::Integer(i64::from(note.state_discriminant)));
             param_values.push(Value::Blob(note.state.clone()));
             #[allow(clippy::cast_possible_wrap)]
             param_values.push(Value::Integer(note.created_at as i64));
+            match note.created_block_height {
+                Some(h) => param_values.push(Value::Integer(i64::from(h))),
+                None => param_values.push(Value::Null),
+            }
             match note.consumed_block_height {
                 Some(h) => param_values.push(Value::Integer(i64::from(h))),
                 None => param_values.push(Value::Null),
@@ -707,18 +1167,23 @@ fn batch_update_input_note_states(
         return Ok(());
     }
 
+    advance_input_notes_sequence_tx(tx)?;
+
     let mut stmt = tx
-        .prepare_cached(
+        .prepare_cached(&format!(
             "UPDATE `input_notes` SET state_discriminant = ?, state = ?, \
-             consumed_block_height = ?, consumed_tx_order = ?, consumer_account_id = ? \
-             WHERE details_commitment = ?",
-        )
+             created_block_height = COALESCE(?, created_block_height), \
+             consumed_block_height = ?, consumed_tx_order = ?, consumer_account_id = ?, \
+             updated_at = {INPUT_NOTES_SEQUENCE_VALUE} \
+             WHERE details_commitment = ?"
+        ))
         .into_store_error()?;
 
     for update in updates {
         stmt.execute(params![
             update.state_discriminant,
             update.state,
+            update.created_block_height,
             update.consumed_block_height,
             update.consumed_tx_order,
             update.consumer_account_id,
@@ -740,14 +1205,15 @@ fn batch_insert_output_notes(
     }
 
     for chunk in notes.chunks(OUTPUT_NOTE_BATCH_SIZE) {
-        let placeholders = vec!["(?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"; chunk.len()].join(", ");
+        let placeholders = vec!["(?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"; chunk.len()].join(", ");
         let query = format!(
             "INSERT OR REPLACE INTO `output_notes` \
              (`details_commitment`, `note_id`, `assets`, `recipient_digest`, `metadata`, \
-              `nullifier`, `expected_height`, `state_discriminant`, `state`, `attachments`) \
+              `nullifier`, `expected_height`, `state_discriminant`, `state`, `attachments`, \
+              `created_block_height`, `consumed_block_height`) \
              VALUES {placeholders}"
         );
-        let mut param_values: Vec<Value> = Vec::with_capacity(chunk.len() * 10);
+        let mut param_values: Vec<Value> = Vec::with_capacity(chunk.len() * 12);
         for note in chunk {
             param_values.push(Value::Text(note.details_commitment.clone()));
             param_values.push(Value::Text(note.id.clone()));
@@ -762,6 +1228,14 @@ fn batch_insert_output_notes(
             param_values.push(Value::Integer(i64::from(note.state_discriminant)));
             param_values.push(Value::Blob(note.state.clone()));
             param_values.push(Value::Blob(note.attachments.clone()));
+            match note.created_block_height {
+                Some(h) => param_values.push(Value::Integer(i64::from(h))),
+                None => param_values.push(Value::Null),
+            }
+            match note.consumed_block_height {
+                Some(h) => param_values.push(Value::Integer(i64::from(h))),
+                None => param_values.push(Value::Null),
+            }
         }
         tx.execute(&query, params_from_iter(param_values)).into_store_error()?;
     }
@@ -780,30 +1254,22 @@ fn batch_update_output_note_states(
 
     let mut stmt = tx
         .prepare_cached(
-            "UPDATE `output_notes` SET state_discriminant = ?, state = ? WHERE details_commitment = ?",
+            "UPDATE `output_notes` SET state_discriminant = ?, state = ?, \
+             created_block_height = COALESCE(?, created_block_height), consumed_block_height = ? \
+             WHERE details_commitment = ?",
         )
         .into_store_error()?;
 
     for update in updates {
-        stmt.execute(params![update.state_discriminant, update.state, update.details_commitment])
-            .into_store_error()?;
-    }
-
-    Ok(())
-}
-
-/// Inserts the provided note script into the database, if the script already exists, it will be
-/// replaced.
-pub(super) fn upsert_note_script_tx(
-    tx: &Transaction<'_>,
-    note_script: &NoteScript,
-) -> Result<(), StoreError> {
-    const QUERY: &str =
-        insert_sql!(notes_scripts { script_root, serialized_note_script } | REPLACE);
-    tx.prepare_cached(QUERY)
-        .into_store_error()?
-        .execute(params![note_script.root().to_hex(), note_script.to_bytes()])
+        stmt.execute(params![
+            update.state_discriminant,
+            update.state,
+            update.created_block_height,
+            update.consumed_block_height,
+            update.details_commitment,
+        ])
         .into_store_error()?;
+    }
 
     Ok(())
 }
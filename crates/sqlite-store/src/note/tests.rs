@@ -9,20 +9,37 @@ use miden_client::note::{
     NoteStorage,
     NoteTag,
     NoteType,
+    NoteUpdateTracker,
     PartialNoteMetadata,
 };
 use miden_client::store::input_note_states::{
+    CommittedNoteState,
     ConsumedExternalNoteState,
     ConsumedUnauthenticatedLocalNoteState,
     ExpectedNoteState,
     NoteSubmissionData,
 };
-use miden_client::store::{InputNoteRecord, NoteFilter, Store};
+use miden_client::store::{
+    InputNoteRecord,
+    InputNoteState,
+    NoteConflictStrategy,
+    NoteFilter,
+    Store,
+    StoreError,
+};
+use miden_client::sync::StateSyncUpdate;
+use miden_client::transaction::{
+    RawOutputNotes,
+    TransactionDetails,
+    TransactionRecord,
+    TransactionStatus,
+};
 use miden_client::{Felt, ZERO};
 use miden_protocol::Word;
 use miden_protocol::account::AccountId;
 use miden_protocol::block::BlockNumber;
-use miden_protocol::note::NoteDetails;
+use miden_protocol::crypto::merkle::SparseMerklePath;
+use miden_protocol::note::{NoteDetails, NoteInclusionProof};
 use miden_protocol::testing::account_id::{
     ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET,
     ACCOUNT_ID_REGULAR_PRIVATE_ACCOUNT_UPDATABLE_CODE,
@@ -30,7 +47,9 @@ use miden_protocol::testing::account_id::{
 use miden_protocol::transaction::TransactionId;
 use miden_standards::note::StandardNote;
 
+use crate::sql_error::SqlResultExt;
 use crate::tests::create_test_store;
+use crate::transaction::upsert_transaction_record;
 
 // HELPERS
 // ================================================================================================
@@ -81,6 +100,26 @@ fn create_expected_input_note(index: u32) -> InputNoteRecord {
     InputNoteRecord::new(details, NoteAttachments::empty(), Some(0), state.into())
 }
 
+/// Helper to create a committed version of the provided expected input note.
+fn create_committed_input_note(expected: &InputNoteRecord) -> InputNoteRecord {
+    let sender = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET).unwrap();
+    let partial_metadata = PartialNoteMetadata::new(sender, NoteType::Public);
+    let path = SparseMerklePath::from_parts(0, Vec::new()).unwrap();
+
+    let state = CommittedNoteState {
+        metadata: NoteMetadata::new(partial_metadata, &NoteAttachments::empty()),
+        inclusion_proof: NoteInclusionProof::new(BlockNumber::from(5u32), 0, path).unwrap(),
+        block_note_root: Word::default(),
+    };
+
+    InputNoteRecord::new(
+        expected.details().clone(),
+        NoteAttachments::empty(),
+        Some(0),
+        state.into(),
+    )
+}
+
 /// Helper to create a consumed-unauthenticated-local input note with a specific consumer.
 fn create_consumed_input_note_with_consumer(
     consumer: AccountId,
@@ -411,3 +450,374 @@ async fn consumed_input_notes_null_tx_order_sort_last_within_block() {
     assert_eq!(notes[0].id(), note_with_order.id());
     assert_eq!(notes[1].id(), note_without_order.id());
 }
+
+// INTEGRITY TESTS
+// ================================================================================================
+
+#[tokio::test]
+async fn check_integrity_reports_committed_note_without_block_header() {
+    use miden_client::store::IntegrityIssue;
+    use miden_client::store::input_note_states::CommittedNoteState;
+    use miden_protocol::crypto::merkle::SparseMerklePath;
+    use miden_protocol::note::NoteInclusionProof;
+
+    let store = create_test_store().await;
+
+    let serial_number: Word = [Felt::new_unchecked(7000), ZERO, ZERO, ZERO].into();
+    let recipient = NoteRecipient::new(
+        serial_number,
+        StandardNote::SWAP.script(),
+        NoteStorage::new(vec![]).unwrap(),
+    );
+    let details = NoteDetails::new(NoteAssets::new(vec![]).unwrap(), recipient);
+
+    let sender = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET).unwrap();
+    let partial_metadata = PartialNoteMetadata::new(sender, NoteType::Public);
+    let metadata = NoteMetadata::new(partial_metadata, &NoteAttachments::empty());
+
+    let block_num = BlockNumber::from(5u32);
+    let path = SparseMerklePath::from_parts(0, Vec::new()).unwrap();
+    let state = CommittedNoteState {
+        metadata,
+        inclusion_proof: NoteInclusionProof::new(block_num, 0, path).unwrap(),
+        block_note_root: Word::default(),
+    };
+    let note = InputNoteRecord::new(details, NoteAttachments::empty(), Some(0), state.into());
+    let note_id = note.id().unwrap();
+
+    store.upsert_input_notes(&[note]).await.unwrap();
+
+    let report = store.check_integrity().await.unwrap();
+    assert_eq!(report.issues(), [IntegrityIssue::MissingNoteBlockHeader { note_id, block_num }]);
+}
+
+// BLOCK RANGE FILTER TESTS
+// ================================================================================================
+
+#[tokio::test]
+async fn consumed_after_filter_excludes_notes_consumed_at_the_block() {
+    let store = create_test_store().await;
+
+    let notes: Vec<_> = (4..=6u32)
+        .map(|block_height| create_consumed_external_input_note(block_height, block_height, None))
+        .collect();
+    let after_boundary = notes[2].details_commitment();
+    store.upsert_input_notes(&notes).await.unwrap();
+
+    let result = store
+        .get_input_notes(NoteFilter::ConsumedAfter(BlockNumber::from(5u32)))
+        .await
+        .unwrap();
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].details_commitment(), after_boundary);
+
+    let result = store
+        .get_input_notes(NoteFilter::ConsumedAfter(BlockNumber::from(6u32)))
+        .await
+        .unwrap();
+    assert!(result.is_empty());
+}
+
+#[tokio::test]
+async fn created_after_filter_excludes_notes_created_at_the_block() {
+    use miden_client::store::input_note_states::CommittedNoteState;
+    use miden_protocol::crypto::merkle::SparseMerklePath;
+    use miden_protocol::note::NoteInclusionProof;
+
+    let store = create_test_store().await;
+    let sender = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET).unwrap();
+
+    let mut notes: Vec<_> = (4..=6u32)
+        .map(|block_height| {
+            let serial_number: Word =
+                [Felt::new_unchecked(u64::from(block_height) + 8000), ZERO, ZERO, ZERO].into();
+            let recipient = NoteRecipient::new(
+                serial_number,
+                StandardNote::SWAP.script(),
+                NoteStorage::new(vec![]).unwrap(),
+            );
+            let details = NoteDetails::new(NoteAssets::new(vec![]).unwrap(), recipient);
+
+            let partial_metadata = PartialNoteMetadata::new(sender, NoteType::Public);
+            let metadata = NoteMetadata::new(partial_metadata, &NoteAttachments::empty());
+            let path = SparseMerklePath::from_parts(0, Vec::new()).unwrap();
+            let state = CommittedNoteState {
+                metadata,
+                inclusion_proof: NoteInclusionProof::new(BlockNumber::from(block_height), 0, path)
+                    .unwrap(),
+                block_note_root: Word::default(),
+            };
+
+            InputNoteRecord::new(details, NoteAttachments::empty(), Some(0), state.into())
+        })
+        .collect();
+    let after_boundary = notes[2].details_commitment();
+    // Expected notes have no inclusion block, so they never match.
+    notes.push(create_expected_input_note(0));
+    store.upsert_input_notes(&notes).await.unwrap();
+
+    let result = store
+        .get_input_notes(NoteFilter::CreatedAfter(BlockNumber::from(5u32)))
+        .await
+        .unwrap();
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].details_commitment(), after_boundary);
+
+    let result = store
+        .get_input_notes(NoteFilter::CreatedAfter(BlockNumber::from(0u32)))
+        .await
+        .unwrap();
+    assert_eq!(result.len(), 3);
+}
+
+// PRUNING TESTS
+// ================================================================================================
+
+#[tokio::test]
+async fn prune_consumed_notes_keeps_recent_and_active_notes() {
+    let store = create_test_store().await;
+    let consumer = AccountId::try_from(ACCOUNT_ID_REGULAR_PRIVATE_ACCOUNT_UPDATABLE_CODE).unwrap();
+
+    let old_local = create_consumed_input_note_with_consumer(consumer, 0, 1, 0);
+    let old_external = create_consumed_external_input_note(1, 2, None);
+    let recent = create_consumed_input_note_with_consumer(consumer, 2, 5, 0);
+    let expected = create_expected_input_note(3);
+    let kept_ids = [recent.details_commitment(), expected.details_commitment()];
+
+    store
+        .upsert_input_notes(&[old_local, old_external, recent, expected])
+        .await
+        .unwrap();
+
+    let removed = store.prune_consumed_notes(BlockNumber::from(5u32)).await.unwrap();
+    assert_eq!(removed, 2);
+
+    let remaining = store.get_input_notes(NoteFilter::All).await.unwrap();
+    assert_eq!(remaining.len(), 2);
+    assert!(remaining.iter().all(|note| kept_ids.contains(&note.details_commitment())));
+
+    // Note scripts are shared with the data store cache, so they are never pruned.
+    let script = StandardNote::SWAP.script();
+    assert!(store.get_note_script(script.root()).await.is_ok());
+
+    // Nothing left to prune below the threshold.
+    assert_eq!(store.prune_consumed_notes(BlockNumber::from(5u32)).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn prune_consumed_notes_skips_pinned_notes() {
+    let store = create_test_store().await;
+    let consumer = AccountId::try_from(ACCOUNT_ID_REGULAR_PRIVATE_ACCOUNT_UPDATABLE_CODE).unwrap();
+
+    let pinned = create_consumed_input_note_with_consumer(consumer, 0, 1, 0);
+    let unpinned = create_consumed_input_note_with_consumer(consumer, 1, 1, 1);
+    let pinned_id = pinned.id().unwrap();
+    store.upsert_input_notes(&[pinned, unpinned]).await.unwrap();
+    store.set_note_pinned(pinned_id, true).await.unwrap();
+
+    // The pinned note survives a prune that removes the other one.
+    assert_eq!(store.prune_consumed_notes(BlockNumber::from(5u32)).await.unwrap(), 1);
+    let remaining = store.get_input_notes(NoteFilter::All).await.unwrap();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].id(), Some(pinned_id));
+    assert!(store.get_note_script(StandardNote::SWAP.script().root()).await.is_ok());
+
+    // Once unpinned, it's pruned like any other consumed note.
+    store.set_note_pinned(pinned_id, false).await.unwrap();
+    assert_eq!(store.prune_consumed_notes(BlockNumber::from(5u32)).await.unwrap(), 1);
+    assert!(store.get_input_notes(NoteFilter::All).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn prune_consumed_notes_skips_notes_of_uncommitted_transactions() {
+    let store = create_test_store().await;
+    let consumer = AccountId::try_from(ACCOUNT_ID_REGULAR_PRIVATE_ACCOUNT_UPDATABLE_CODE).unwrap();
+
+    let pending = create_consumed_input_note_with_consumer(consumer, 0, 1, 0);
+    let settled = create_consumed_input_note_with_consumer(consumer, 1, 1, 1);
+    let pending_id = pending.id().unwrap();
+    let pending_nullifier = pending.nullifier().unwrap().as_word();
+    store.upsert_input_notes(&[pending, settled]).await.unwrap();
+
+    let record = TransactionRecord::new(
+        TransactionId::from_raw(Word::default()),
+        TransactionDetails {
+            account_id: consumer,
+            init_account_state: Word::default(),
+            final_account_state: Word::default(),
+            input_note_nullifiers: vec![pending_nullifier],
+            output_notes: RawOutputNotes::new(Vec::new()).unwrap(),
+            block_num: BlockNumber::from(1u32),
+            submission_height: BlockNumber::from(1u32),
+            expiration_block_num: BlockNumber::from(10u32),
+            creation_timestamp: 0,
+            execution_cycles: None,
+        },
+        None,
+        TransactionStatus::Pending,
+    );
+    store
+        .interact_with_connection(move |conn| {
+            let tx = conn.transaction().into_store_error()?;
+            upsert_transaction_record(&tx, &record)?;
+            tx.commit().into_store_error()
+        })
+        .await
+        .unwrap();
+
+    // The note consumed by the pending transaction is kept until the transaction settles.
+    assert_eq!(store.prune_consumed_notes(BlockNumber::from(5u32)).await.unwrap(), 1);
+    let remaining = store.get_input_notes(NoteFilter::All).await.unwrap();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].id(), Some(pending_id));
+}
+
+#[tokio::test]
+async fn set_note_pinned_fails_for_unknown_notes() {
+    let store = create_test_store().await;
+    let consumer = AccountId::try_from(ACCOUNT_ID_REGULAR_PRIVATE_ACCOUNT_UPDATABLE_CODE).unwrap();
+    let note_id = create_consumed_input_note_with_consumer(consumer, 0, 1, 0).id().unwrap();
+
+    let err = store.set_note_pinned(note_id, true).await.unwrap_err();
+    assert!(matches!(err, StoreError::NoteNotFound(id) if id == note_id));
+}
+
+// CHANGE FEED TESTS
+// ================================================================================================
+
+#[tokio::test]
+async fn get_notes_modified_since_returns_only_notes_touched_after_seq() {
+    let store = create_test_store().await;
+
+    let (notes, initial_seq) = store.get_notes_modified_since(0).await.unwrap();
+    assert!(notes.is_empty());
+    assert_eq!(initial_seq, 0);
+
+    let first = create_expected_input_note(0);
+    let second = create_expected_input_note(1);
+    store.upsert_input_notes(&[first.clone(), second]).await.unwrap();
+
+    let (notes, seq) = store.get_notes_modified_since(initial_seq).await.unwrap();
+    assert_eq!(notes.len(), 2);
+    assert!(seq > initial_seq);
+
+    // Touch one of the existing notes and add a new one.
+    let third = create_expected_input_note(2);
+    store.upsert_input_notes(&[third.clone()]).await.unwrap();
+    store.upsert_input_notes(&[first.clone()]).await.unwrap();
+
+    let (notes, new_seq) = store.get_notes_modified_since(seq).await.unwrap();
+    let changed: Vec<_> = notes.iter().map(InputNoteRecord::details_commitment).collect();
+    assert_eq!(changed, vec![third.details_commitment(), first.details_commitment()]);
+    assert!(new_seq > seq);
+
+    // Nothing changed since the last high-water mark.
+    let (notes, unchanged_seq) = store.get_notes_modified_since(new_seq).await.unwrap();
+    assert!(notes.is_empty());
+    assert_eq!(unchanged_seq, new_seq);
+}
+
+// STATE HISTORY TESTS
+// ================================================================================================
+
+#[tokio::test]
+async fn note_state_history_records_capped_state_transitions() {
+    let store = create_test_store().await;
+
+    let expected = create_expected_input_note(0);
+    let details_commitment = expected.details_commitment();
+    let consumed = InputNoteRecord::new(
+        expected.details().clone(),
+        NoteAttachments::empty(),
+        Some(0),
+        ConsumedExternalNoteState {
+            nullifier_block_height: BlockNumber::from(1u32),
+            consumer_account: None,
+            consumed_tx_order: None,
+        }
+        .into(),
+    );
+
+    // Storing a note again with the same state variant doesn't record a transition.
+    store.upsert_input_notes(&[expected.clone()]).await.unwrap();
+    store.upsert_input_notes(&[expected.clone()]).await.unwrap();
+    store.upsert_input_notes(&[consumed.clone()]).await.unwrap();
+
+    let history = store.get_note_state_history(details_commitment).await.unwrap();
+    let states: Vec<_> = history.iter().map(|transition| transition.state().clone()).collect();
+    assert_eq!(states, vec![expected.state().clone(), consumed.state().clone()]);
+    assert!(
+        history
+            .iter()
+            .all(|transition| transition.block_num() == BlockNumber::from(0u32))
+    );
+
+    // Only the most recent transitions are kept.
+    for _ in 0..super::MAX_NOTE_STATE_TRANSITIONS {
+        store.upsert_input_notes(&[expected.clone()]).await.unwrap();
+        store.upsert_input_notes(&[consumed.clone()]).await.unwrap();
+    }
+
+    let history = store.get_note_state_history(details_commitment).await.unwrap();
+    assert_eq!(history.len(), super::MAX_NOTE_STATE_TRANSITIONS);
+    assert_eq!(history.last().unwrap().state(), consumed.state());
+
+    // Pruning the note deletes its history.
+    assert_eq!(store.prune_consumed_notes(BlockNumber::from(2u32)).await.unwrap(), 1);
+    assert!(store.get_note_state_history(details_commitment).await.unwrap().is_empty());
+}
+
+// CONFLICT STRATEGY TESTS
+// ================================================================================================
+
+#[tokio::test]
+async fn bulk_upsert_keeping_newer_state_does_not_regress_committed_notes() {
+    let store = create_test_store().await;
+
+    let expected = create_expected_input_note(0);
+    let committed = create_committed_input_note(&expected);
+    let filter = NoteFilter::DetailsCommitments(vec![expected.details_commitment()]);
+
+    store
+        .bulk_upsert_input_notes(&[committed.clone()], NoteConflictStrategy::KeepNewerState)
+        .await
+        .unwrap();
+
+    // A stale expected update is skipped.
+    let written = store
+        .bulk_upsert_input_notes(&[expected.clone()], NoteConflictStrategy::KeepNewerState)
+        .await
+        .unwrap();
+    assert_eq!(written, 0);
+    let stored = store.get_input_notes(filter.clone()).await.unwrap();
+    assert!(matches!(stored[0].state(), InputNoteState::Committed(_)));
+
+    // Replacing overwrites the note regardless of its state.
+    let written = store
+        .bulk_upsert_input_notes(&[expected], NoteConflictStrategy::Replace)
+        .await
+        .unwrap();
+    assert_eq!(written, 1);
+    let stored = store.get_input_notes(filter).await.unwrap();
+    assert!(matches!(stored[0].state(), InputNoteState::Expected(_)));
+}
+
+#[tokio::test]
+async fn apply_state_sync_does_not_regress_committed_notes() {
+    let store = create_test_store().await;
+
+    let expected = create_expected_input_note(0);
+    let committed = create_committed_input_note(&expected);
+    store.upsert_input_notes(&[committed]).await.unwrap();
+
+    // A sync update carrying a stale expected state for the note is ignored.
+    let state_sync_update = StateSyncUpdate {
+        note_updates: NoteUpdateTracker::for_transaction_updates([], [expected.clone()], []),
+        ..Default::default()
+    };
+    store.apply_state_sync(state_sync_update).await.unwrap();
+
+    let filter = NoteFilter::DetailsCommitments(vec![expected.details_commitment()]);
+    let stored = store.get_input_notes(filter).await.unwrap();
+    assert!(matches!(stored[0].state(), InputNoteState::Committed(_)));
+}
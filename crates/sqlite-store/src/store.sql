@@ -37,6 +37,8 @@ CREATE TABLE latest_account_headers (
     account_seed BLOB NULL,                  -- seed used to generate the ID; NULL for non-new accounts
     locked BOOLEAN NOT NULL,                 -- whether the account is locked
     watched BOOLEAN NOT NULL DEFAULT FALSE, -- Whether the account is tracked in watch mode
+    archived BOOLEAN NOT NULL DEFAULT FALSE, -- Whether the account was archived by the user
+    watch_only BOOLEAN NOT NULL DEFAULT FALSE, -- Whether the account can't be used as a transaction sender
     PRIMARY KEY (id),
     FOREIGN KEY (code_commitment) REFERENCES account_code(commitment)
 );
@@ -139,10 +141,21 @@ CREATE TABLE transactions (
     block_num UNSIGNED BIG INT,                      -- Block number for the block against which the transaction was executed.
     status_variant INT NOT NULL,                     -- Status variant identifier
     status BLOB NOT NULL,                            -- Serialized transaction status
+    category TEXT NULL,                              -- Local-only user-defined category
+    execution_cycles INTEGER NULL,                   -- VM cycles taken to execute the transaction, NULL if not recorded
     FOREIGN KEY (script_root) REFERENCES transaction_scripts(script_root),
     PRIMARY KEY (id)
 ) WITHOUT ROWID;
 CREATE INDEX idx_transactions_uncommitted ON transactions(status_variant);
+CREATE INDEX idx_transactions_category ON transactions(category);
+
+-- Proven transactions, only stored when the client is configured to retain proofs.
+CREATE TABLE proven_transactions (
+    id TEXT NOT NULL,                                -- Transaction ID
+    proven_transaction BLOB NOT NULL,                -- Serialized proven transaction
+    transaction_inputs BLOB NOT NULL,                -- Serialized inputs the transaction was executed with, needed to resubmit it
+    PRIMARY KEY (id)
+) WITHOUT ROWID;
 
 
 CREATE TABLE transaction_scripts (
@@ -163,20 +176,58 @@ CREATE TABLE input_notes (
     inputs BLOB NOT NULL,                                   -- the serialized list of note inputs
     script_root TEXT NOT NULL,                              -- the script root of the note, used to join with the notes_scripts table
     nullifier TEXT NULL,                                    -- the nullifier of the note, used to query by nullifier; NULL until metadata is known
+    tag BLOB NULL,                                          -- the serialized note tag, used to join with the tags table; NULL if unknown
     state_discriminant UNSIGNED INT NOT NULL,               -- state discriminant of the note, used to query by state
     state BLOB NOT NULL,                                    -- serialized note state
     created_at UNSIGNED BIG INT NOT NULL,                   -- timestamp of the note creation/import
+    created_block_height INTEGER NULL,                      -- block height at which the note was created, from its inclusion proof; NULL if unknown
     consumed_block_height INTEGER NULL,                     -- block height at which the note was consumed; NULL for non-consumed notes
     consumed_tx_order INTEGER NULL,                         -- per-account position of the consuming tx in the account's execution chain within the block; NULL for external consumption or non-consumed notes
     consumer_account_id TEXT NULL,                          -- account ID that consumed this note; NULL for non-consumed or externally consumed notes
+    updated_at UNSIGNED BIG INT NOT NULL DEFAULT 0,         -- value of the input notes change sequence when the note was last inserted or updated
 
     PRIMARY KEY (details_commitment),
     FOREIGN KEY (script_root) REFERENCES notes_scripts(script_root)
 ) WITHOUT ROWID;
 CREATE INDEX idx_input_notes_state ON input_notes(state_discriminant);
 CREATE INDEX idx_input_notes_nullifier ON input_notes(nullifier);
+CREATE INDEX idx_input_notes_tag ON input_notes(tag);
 CREATE INDEX idx_input_notes_note_id ON input_notes(note_id);
+CREATE INDEX idx_input_notes_created ON input_notes(created_block_height);
 CREATE INDEX idx_input_notes_consumption ON input_notes(consumed_block_height, consumed_tx_order);
+CREATE INDEX idx_input_notes_updated_at ON input_notes(updated_at);
+
+-- Monotonic counter backing `input_notes.updated_at`. Input note writes advance it and store the
+-- new value in the notes they insert or update. It lives in its own table so that deleting the
+-- most recently changed notes never causes a sequence value to be handed out twice.
+CREATE TABLE input_notes_sequence (
+    id INTEGER NOT NULL CHECK (id = 0),     -- single row table
+    value UNSIGNED BIG INT NOT NULL,        -- the last sequence value assigned to an input note
+
+    PRIMARY KEY (id)
+);
+
+INSERT INTO input_notes_sequence (id, value) VALUES (0, 0);
+
+-- Log of the states each input note went through. Notes are referenced by their details
+-- commitment, as expected notes may not have a note ID yet.
+CREATE TABLE input_note_state_log (
+    id INTEGER PRIMARY KEY,                     -- insertion order of the transitions
+    details_commitment TEXT NOT NULL,           -- details commitment of the input note
+    state_discriminant UNSIGNED INT NOT NULL,   -- state discriminant the note transitioned to
+    state BLOB NOT NULL,                        -- serialized note state
+    block_num UNSIGNED BIG INT NOT NULL,        -- the client's sync height at the time of the transition
+    timestamp UNSIGNED BIG INT NOT NULL         -- UTC timestamp (seconds since Unix epoch) of the transition
+);
+CREATE INDEX idx_input_note_state_log_note ON input_note_state_log(details_commitment);
+
+-- Notes pinned by the user, which pruning must never remove. Pins are kept in their own table,
+-- referenced by details commitment, so that upserting a note doesn't clear them.
+CREATE TABLE pinned_notes (
+    details_commitment TEXT NOT NULL,           -- details commitment of the pinned input or output note
+
+    PRIMARY KEY (details_commitment)
+) WITHOUT ROWID;
 
 CREATE TABLE output_notes (
     details_commitment TEXT NOT NULL,                       -- commitment to the note details (recipient + assets); primary key
@@ -191,10 +242,14 @@ CREATE TABLE output_notes (
     state_discriminant UNSIGNED INT NOT NULL,               -- state discriminant of the note, used to query by state
     state BLOB NOT NULL,                                    -- serialized note state
     attachments BLOB NOT NULL,
+    created_block_height INTEGER NULL,                      -- block height at which the note was created, from its inclusion proof; NULL if unknown
+    consumed_block_height INTEGER NULL,                     -- block height at which the note was consumed; NULL for non-consumed notes
 
     PRIMARY KEY (details_commitment)
 ) WITHOUT ROWID;
 CREATE INDEX idx_output_notes_state ON output_notes(state_discriminant);
+CREATE INDEX idx_output_notes_created ON output_notes(created_block_height);
+CREATE INDEX idx_output_notes_consumed ON output_notes(consumed_block_height);
 CREATE INDEX idx_output_notes_nullifier ON output_notes(nullifier);
 CREATE INDEX idx_output_notes_note_id ON output_notes(note_id);
 
@@ -218,6 +273,21 @@ CREATE TABLE tags (
     source BLOB NOT NULL   -- the serialized tag source
 );
 
+CREATE TABLE sync_history (
+    id INTEGER PRIMARY KEY,                -- insertion order of the sync points
+    block_num UNSIGNED BIG INT NOT NULL,   -- the block number the sync reached
+    timestamp UNSIGNED BIG INT NOT NULL,   -- UTC timestamp (seconds since Unix epoch) of the sync
+    partial_blockchain_peaks BLOB NOT NULL -- serialized MMR peaks at the sync height, used to roll back to this sync point
+);
+
+-- Public account updates applied by each sync, used to roll the accounts back to a sync point.
+CREATE TABLE account_sync_updates (
+    block_num UNSIGNED BIG INT NOT NULL,   -- the block number the sync reached
+    account_id TEXT NOT NULL,              -- ID of the account updated by the sync
+    previous_nonce BIGINT NOT NULL,        -- nonce of the account before the sync
+    PRIMARY KEY (block_num, account_id)
+) WITHOUT ROWID;
+
 -- insert initial row into blockchain_checkpoint table
 INSERT OR IGNORE INTO blockchain_checkpoint (block_num, partial_blockchain_peaks)
 SELECT 0, X''
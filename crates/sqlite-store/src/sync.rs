@@ -7,7 +7,12 @@ use std::vec::Vec;
 use miden_client::Word;
 use miden_client::account::AccountId;
 use miden_client::note::{BlockNumber, NoteTag};
-use miden_client::store::{AccountSmtForest, AccountStorageFilter, StoreError};
+use miden_client::store::{
+    AccountSmtForest,
+    AccountStorageFilter,
+    NoteConflictStrategy,
+    StoreError,
+};
 use miden_client::sync::{
     NoteTagRecord,
     NoteTagSource,
@@ -15,14 +20,15 @@ use miden_client::sync::{
     PublicAccountUpdate,
     StateSyncUpdate,
 };
+use miden_client::transaction::{TransactionStatus, TransactionStatusVariant};
 use miden_client::utils::{Deserializable, Serializable};
-use rusqlite::{Connection, Transaction, params};
+use rusqlite::{Connection, OptionalExtension, Transaction, params};
 
 use super::SqliteStore;
-use crate::note::apply_note_updates_tx;
+use crate::note::{apply_note_updates_tx, rollback_notes_tx};
 use crate::sql_error::SqlResultExt;
 use crate::transaction::{upsert_transaction_record, with_forest_snapshot};
-use crate::{insert_sql, subst};
+use crate::{column_value_as_u64, current_timestamp_u64, insert_sql, subst, u64_to_value};
 
 impl SqliteStore {
     pub(crate) fn get_note_tags(conn: &mut Connection) -> Result<Vec<NoteTagRecord>, StoreError> {
@@ -44,6 +50,32 @@ impl SqliteStore {
             .collect::<Result<Vec<NoteTagRecord>, _>>()
     }
 
+    pub(crate) fn get_note_tags_with_note_counts(
+        conn: &mut Connection,
+    ) -> Result<Vec<(NoteTagRecord, usize)>, StoreError> {
+        // Input notes store their tag serialized like the `tags` table, so they can be joined
+        // directly. Grouping by `rowid` keeps a separate count for each source of the same tag.
+        const QUERY: &str = "SELECT tags.tag, tags.source, COUNT(input_notes.details_commitment) \
+            FROM tags LEFT JOIN input_notes ON input_notes.tag = tags.tag \
+            GROUP BY tags.rowid ORDER BY tags.rowid";
+
+        conn.prepare_cached(QUERY)
+            .into_store_error()?
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+            .expect("no binding parameters used in query")
+            .map(|result| {
+                let (tag, source, count): (Vec<u8>, Vec<u8>, i64) = result.into_store_error()?;
+                let record = NoteTagRecord {
+                    tag: NoteTag::read_from_bytes(&tag)
+                        .map_err(StoreError::DataDeserializationError)?,
+                    source: NoteTagSource::read_from_bytes(&source)
+                        .map_err(StoreError::DataDeserializationError)?,
+                };
+                Ok((record, usize::try_from(count)?))
+            })
+            .collect()
+    }
+
     pub(crate) fn get_unique_note_tags(
         conn: &mut Connection,
     ) -> Result<BTreeSet<NoteTag>, StoreError> {
@@ -88,6 +120,34 @@ impl SqliteStore {
         Ok(removed_tags)
     }
 
+    pub(super) fn replace_note_tag(
+        conn: &mut Connection,
+        old: NoteTagRecord,
+        new: NoteTagRecord,
+    ) -> Result<(), StoreError> {
+        let tx = conn.transaction().into_store_error()?;
+
+        // Adding and then removing the same record would leave it untracked.
+        if old == new {
+            return if note_tag_tracked_tx(&tx, &old)? {
+                Ok(())
+            } else {
+                Err(StoreError::NoteTagNotTracked(u64::from(old.tag.as_u32())))
+            };
+        }
+
+        add_untracked_note_tag_tx(&tx, &new)?;
+
+        // Dropping the transaction without committing rolls back the insertion above.
+        if remove_note_tag_tx(&tx, old)? == 0 {
+            return Err(StoreError::NoteTagNotTracked(u64::from(old.tag.as_u32())));
+        }
+
+        tx.commit().into_store_error()?;
+
+        Ok(())
+    }
+
     pub(super) fn get_sync_height(conn: &mut Connection) -> Result<BlockNumber, StoreError> {
         const QUERY: &str = "SELECT block_num FROM blockchain_checkpoint";
 
@@ -103,13 +163,121 @@ impl SqliteStore {
             .expect("state sync block number exists")
     }
 
+    pub(super) fn get_sync_history(
+        conn: &mut Connection,
+        limit: usize,
+    ) -> Result<Vec<(BlockNumber, u64)>, StoreError> {
+        const QUERY: &str =
+            "SELECT block_num, timestamp FROM sync_history ORDER BY id DESC LIMIT ?";
+
+        let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+        conn.prepare_cached(QUERY)
+            .into_store_error()?
+            .query_map(params![limit], |row| {
+                Ok((row.get::<_, u32>(0)?, column_value_as_u64(row, 1)?))
+            })
+            .into_store_error()?
+            .map(|result| {
+                let (block_num, timestamp) = result.into_store_error()?;
+                Ok((BlockNumber::from(block_num), timestamp))
+            })
+            .collect()
+    }
+
+    pub(super) fn rollback_to_block(
+        conn: &mut Connection,
+        block_num: BlockNumber,
+    ) -> Result<(), StoreError> {
+        const SYNC_POINT_QUERY: &str = "SELECT partial_blockchain_peaks FROM sync_history \
+            WHERE block_num = ? ORDER BY id DESC LIMIT 1";
+        const COMMITTED_TRANSACTIONS_QUERY: &str =
+            "SELECT id, status FROM transactions WHERE status_variant = ?";
+        const RESET_TRANSACTION_QUERY: &str =
+            "UPDATE transactions SET status_variant = ?, status = ? WHERE id = ?";
+
+        let tx = conn.transaction().into_store_error()?;
+        let block_num_value = i64::from(block_num.as_u32());
+
+        let peaks_bytes: Vec<u8> = tx
+            .query_row(SYNC_POINT_QUERY, params![block_num_value], |row| row.get(0))
+            .optional()
+            .into_store_error()?
+            .ok_or(StoreError::RollbackTargetNotFound(block_num))?;
+
+        let has_header = tx
+            .query_row(
+                "SELECT 1 FROM block_headers WHERE block_num = ?",
+                params![block_num_value],
+                |_| Ok(()),
+            )
+            .optional()
+            .into_store_error()?
+            .is_some();
+        if !has_header {
+            return Err(StoreError::BlockHeaderNotFound(block_num));
+        }
+
+        tx.execute(
+            "UPDATE blockchain_checkpoint SET block_num = ?, partial_blockchain_peaks = ?",
+            params![block_num_value, peaks_bytes],
+        )
+        .into_store_error()?;
+        tx.execute("DELETE FROM block_headers WHERE block_num > ?", params![block_num_value])
+            .into_store_error()?;
+        tx.execute("DELETE FROM sync_history WHERE block_num > ?", params![block_num_value])
+            .into_store_error()?;
+
+        // A node of the partial blockchain is only part of the MMR at the target block if its
+        // rightmost leaf is, i.e. if `id + lowest_set_bit(id) - 1` doesn't exceed the in-order
+        // index of the target block's leaf.
+        tx.execute(
+            "DELETE FROM partial_blockchain_nodes WHERE id + (id & -id) - 1 > ?",
+            params![2 * block_num_value + 1],
+        )
+        .into_store_error()?;
+
+        rollback_account_sync_updates_tx(&tx, block_num)?;
+        rollback_notes_tx(&tx, block_num)?;
+
+        let committed_transactions = tx
+            .prepare(COMMITTED_TRANSACTIONS_QUERY)
+            .into_store_error()?
+            .query_map(params![TransactionStatusVariant::Committed as u8], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, Vec<u8>>(1)?))
+            })
+            .into_store_error()?
+            .collect::<Result<Vec<_>, _>>()
+            .into_store_error()?;
+
+        for (id, status) in committed_transactions {
+            if let TransactionStatus::Committed { block_number, .. } =
+                TransactionStatus::read_from_bytes(&status)?
+                && block_number > block_num
+            {
+                tx.execute(
+                    RESET_TRANSACTION_QUERY,
+                    params![
+                        TransactionStatusVariant::Pending as u8,
+                        TransactionStatus::Pending.to_bytes(),
+                        id
+                    ],
+                )
+                .into_store_error()?;
+            }
+        }
+
+        tx.commit().into_store_error()
+    }
+
     pub(super) fn apply_state_sync(
         conn: &mut Connection,
         smt_forest: &Arc<RwLock<AccountSmtForest>>,
         state_sync_update: StateSyncUpdate,
+        max_sync_history_rows: usize,
     ) -> Result<(), StoreError> {
         let StateSyncUpdate {
             block_num,
+            chain_tip: _,
             partial_blockchain_updates,
             note_updates,
             transaction_updates,
@@ -142,8 +310,9 @@ impl SqliteStore {
                 partial_blockchain_updates.new_authentication_nodes(),
             )?;
 
-            // Update notes
-            apply_note_updates_tx(tx, &note_updates)?;
+            // Update notes. Sync updates may arrive out of order, so they never move a stored note
+            // backwards in its lifecycle.
+            apply_note_updates_tx(tx, &note_updates, NoteConflictStrategy::KeepNewerState)?;
 
             // Remove tags
             let tags_to_remove = note_updates
@@ -190,6 +359,8 @@ impl SqliteStore {
 
             // Update public accounts on the db that have been updated onchain
             for update in account_updates.updated_public_accounts() {
+                record_account_sync_update_tx(tx, block_num, update.id())?;
+
                 match update {
                     PublicAccountUpdate::Full(account) => {
                         Self::update_account_state(tx, smt_forest, account)?;
@@ -204,6 +375,8 @@ impl SqliteStore {
                 Self::lock_account_on_unexpected_commitment(tx, account_id, digest)?;
             }
 
+            insert_sync_history_tx(tx, block_num, &new_peaks_bytes, max_sync_history_rows)?;
+
             Ok(())
         })
     }
@@ -232,6 +405,101 @@ impl SqliteStore {
     }
 }
 
+/// Records that a sync reached `block_num` at the current time, and prunes the oldest entries so
+/// at most `max_rows` are kept.
+pub(crate) fn insert_sync_history_tx(
+    tx: &Transaction<'_>,
+    block_num: BlockNumber,
+    peaks_bytes: &[u8],
+    max_rows: usize,
+) -> Result<(), StoreError> {
+    const INSERT_QUERY: &str = insert_sql!(sync_history {
+        block_num,
+        timestamp,
+        partial_blockchain_peaks
+    });
+    const PRUNE_QUERY: &str = "DELETE FROM sync_history WHERE id NOT IN \
+        (SELECT id FROM sync_history ORDER BY id DESC LIMIT ?)";
+
+    // Account updates are only needed to roll back to a recorded sync point.
+    const PRUNE_ACCOUNT_UPDATES_QUERY: &str = "DELETE FROM account_sync_updates \
+        WHERE block_num <= (SELECT MIN(block_num) FROM sync_history)";
+
+    tx.execute(
+        INSERT_QUERY,
+        params![
+            i64::from(block_num.as_u32()),
+            u64_to_value(current_timestamp_u64()),
+            peaks_bytes
+        ],
+    )
+    .into_store_error()?;
+
+    let max_rows = i64::try_from(max_rows).unwrap_or(i64::MAX);
+    tx.execute(PRUNE_QUERY, params![max_rows]).into_store_error()?;
+    tx.execute(PRUNE_ACCOUNT_UPDATES_QUERY, []).into_store_error()?;
+
+    Ok(())
+}
+
+/// Records the nonce the account had before the sync reaching `block_num` updated it, so the
+/// update can be undone by a rollback.
+fn record_account_sync_update_tx(
+    tx: &Transaction<'_>,
+    block_num: BlockNumber,
+    account_id: AccountId,
+) -> Result<(), StoreError> {
+    const QUERY: &str =
+        insert_sql!(account_sync_updates { block_num, account_id, previous_nonce } | IGNORE);
+
+    let Some(previous_nonce) = SqliteStore::get_account_nonce(tx, account_id)? else {
+        return Ok(());
+    };
+
+    tx.execute(
+        QUERY,
+        params![
+            i64::from(block_num.as_u32()),
+            account_id.to_hex(),
+            u64_to_value(previous_nonce.as_canonical_u64())
+        ],
+    )
+    .into_store_error()?;
+
+    Ok(())
+}
+
+/// Undoes the public account updates applied by the syncs after `block_num`.
+fn rollback_account_sync_updates_tx(
+    tx: &Transaction<'_>,
+    block_num: BlockNumber,
+) -> Result<(), StoreError> {
+    // The earliest update after the target holds the account's nonce at the target block.
+    const QUERY: &str = "SELECT account_id, previous_nonce FROM account_sync_updates AS u \
+        WHERE block_num = (SELECT MIN(block_num) FROM account_sync_updates \
+            WHERE account_id = u.account_id AND block_num > ?1)";
+
+    let block_num_value = i64::from(block_num.as_u32());
+    let updates = tx
+        .prepare(QUERY)
+        .into_store_error()?
+        .query_map(params![block_num_value], |row| {
+            Ok((row.get::<_, String>(0)?, column_value_as_u64(row, 1)?))
+        })
+        .into_store_error()?
+        .collect::<Result<Vec<_>, _>>()
+        .into_store_error()?;
+
+    for (account_id_hex, previous_nonce) in updates {
+        SqliteStore::undo_account_states_after_nonce(tx, &account_id_hex, previous_nonce)?;
+    }
+
+    tx.execute("DELETE FROM account_sync_updates WHERE block_num > ?", params![block_num_value])
+        .into_store_error()?;
+
+    Ok(())
+}
+
 pub(super) fn add_note_tag_tx(tx: &Transaction<'_>, tag: &NoteTagRecord) -> Result<(), StoreError> {
     const QUERY: &str = insert_sql!(tags { tag, source });
     tx.execute(QUERY, params![tag.tag.to_bytes(), tag.source.to_bytes()])
@@ -240,13 +508,35 @@ pub(super) fn add_note_tag_tx(tx: &Transaction<'_>, tag: &NoteTagRecord) -> Resu
     Ok(())
 }
 
+/// Adds the tag unless an identical record is already tracked.
+///
+/// The check and the insertion run as a single statement, so they can't interleave with other
+/// writers.
+fn add_untracked_note_tag_tx(tx: &Transaction<'_>, tag: &NoteTagRecord) -> Result<(), StoreError> {
+    const QUERY: &str = "INSERT INTO tags (tag, source) SELECT ?1, ?2 \
+        WHERE NOT EXISTS (SELECT 1 FROM tags WHERE tag = ?1 AND source = ?2)";
+    tx.execute(QUERY, params![tag.tag.to_bytes(), tag.source.to_bytes()])
+        .into_store_error()?;
+
+    Ok(())
+}
+
+/// Returns whether an identical tag record is tracked.
+fn note_tag_tracked_tx(tx: &Transaction<'_>, tag: &NoteTagRecord) -> Result<bool, StoreError> {
+    const QUERY: &str = "SELECT EXISTS(SELECT 1 FROM tags WHERE tag = ? AND source = ?)";
+    tx.query_row(QUERY, params![tag.tag.to_bytes(), tag.source.to_bytes()], |row| row.get(0))
+        .into_store_error()
+}
+
 pub(super) fn remove_note_tag_tx(
     tx: &Transaction<'_>,
     tag: NoteTagRecord,
 ) -> Result<usize, StoreError> {
     const QUERY: &str = "DELETE FROM tags WHERE tag = ? AND source = ?";
     let removed_tags = tx
-        .execute(QUERY, params![tag.tag.to_bytes(), tag.source.to_bytes()])
+        .prepare_cached(QUERY)
+        .into_store_error()?
+        .execute(params![tag.tag.to_bytes(), tag.source.to_bytes()])
         .into_store_error()?;
 
     Ok(removed_tags)
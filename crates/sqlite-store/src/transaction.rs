@@ -7,10 +7,12 @@ use std::vec::Vec;
 
 use miden_client::Word;
 use miden_client::note::ToInputNoteCommitments;
-use miden_client::store::{AccountSmtForest, StoreError, TransactionFilter};
+use miden_client::store::{AccountSmtForest, NoteConflictStrategy, StoreError, TransactionFilter};
 use miden_client::transaction::{
+    ProvenTransaction,
     TransactionDetails,
     TransactionId,
+    TransactionInputs,
     TransactionRecord,
     TransactionScript,
     TransactionStatus,
@@ -18,7 +20,7 @@ use miden_client::transaction::{
 };
 use miden_client::utils::{Deserializable as _, Serializable as _};
 use rusqlite::types::Value;
-use rusqlite::{Connection, Transaction, params};
+use rusqlite::{Connection, OptionalExtension, Transaction, params};
 
 use super::SqliteStore;
 use super::note::apply_note_updates_tx;
@@ -26,16 +28,18 @@ use super::sync::add_note_tag_tx;
 use crate::sql_error::SqlResultExt;
 use crate::{insert_sql, subst};
 
-pub(crate) const UPSERT_TRANSACTION_QUERY: &str = insert_sql!(
-    transactions {
-        id,
-        details,
-        script_root,
-        block_num,
-        status_variant,
-        status
-    } | REPLACE
-);
+/// Inserts a transaction or updates an existing one. The local-only `category` column is left
+/// untouched on update, and a recorded cycle count is never cleared.
+pub(crate) const UPSERT_TRANSACTION_QUERY: &str = "INSERT INTO transactions \
+    (id, details, script_root, block_num, status_variant, status, execution_cycles) \
+    VALUES (?, ?, ?, ?, ?, ?, ?) \
+    ON CONFLICT(id) DO UPDATE SET \
+        details = excluded.details, \
+        script_root = excluded.script_root, \
+        block_num = excluded.block_num, \
+        status_variant = excluded.status_variant, \
+        status = excluded.status, \
+        execution_cycles = COALESCE(excluded.execution_cycles, transactions.execution_cycles)";
 
 pub(crate) const INSERT_TRANSACTION_SCRIPT_QUERY: &str =
     insert_sql!(transaction_scripts { script_root, script } | IGNORE);
@@ -58,6 +62,8 @@ struct SerializedTransactionData {
     status_variant: u8,
     /// Serialized transaction status
     status: Vec<u8>,
+    /// VM cycles taken to execute the transaction
+    execution_cycles: Option<u32>,
 }
 
 struct SerializedTransactionParts {
@@ -69,12 +75,14 @@ struct SerializedTransactionParts {
     details: Vec<u8>,
     /// Serialized transaction status
     status: Vec<u8>,
+    /// VM cycles taken to execute the transaction
+    execution_cycles: Option<u32>,
 }
 
 impl SqliteStore {
     /// Retrieves tracked transactions, filtered by [`TransactionFilter`].
     pub fn get_transactions(
-        conn: &mut Connection,
+        conn: &Connection,
         filter: &TransactionFilter,
     ) -> Result<Vec<TransactionRecord>, StoreError> {
         match filter {
@@ -91,6 +99,13 @@ impl SqliteStore {
                     .map(|result| Ok(result.into_store_error()?).and_then(parse_transaction))
                     .collect::<Result<Vec<TransactionRecord>, _>>()
             },
+            TransactionFilter::Category(category) => conn
+                .prepare(filter.to_query().as_ref())
+                .into_store_error()?
+                .query_map(params![category], parse_transaction_columns)
+                .into_store_error()?
+                .map(|result| Ok(result.into_store_error()?).and_then(parse_transaction))
+                .collect::<Result<Vec<TransactionRecord>, _>>(),
             _ => {
                 // For other filters, no parameters are needed
                 conn.prepare(filter.to_query().as_ref())
@@ -103,6 +118,107 @@ impl SqliteStore {
         }
     }
 
+    /// Inserts the record of a transaction that failed to execute locally.
+    pub fn insert_failed_transaction(
+        conn: &mut Connection,
+        transaction_record: &TransactionRecord,
+    ) -> Result<(), StoreError> {
+        let tx = conn.transaction().into_store_error()?;
+        upsert_transaction_record(&tx, transaction_record)?;
+        tx.commit().into_store_error()
+    }
+
+    /// Sets the local-only category of a tracked transaction. `None` clears the category.
+    pub fn set_transaction_category(
+        conn: &mut Connection,
+        transaction_id: TransactionId,
+        category: Option<&str>,
+    ) -> Result<(), StoreError> {
+        const QUERY: &str = "UPDATE transactions SET category = ? WHERE id = ?";
+        let updated_rows = conn
+            .execute(QUERY, params![category, transaction_id.to_hex()])
+            .into_store_error()?;
+
+        if updated_rows == 0 {
+            return Err(StoreError::TransactionNotFound(transaction_id));
+        }
+
+        Ok(())
+    }
+
+    /// Retrieves the local-only category of a tracked transaction.
+    pub fn get_transaction_category(
+        conn: &mut Connection,
+        transaction_id: TransactionId,
+    ) -> Result<Option<String>, StoreError> {
+        const QUERY: &str = "SELECT category FROM transactions WHERE id = ?";
+        conn.query_row(QUERY, params![transaction_id.to_hex()], |row| {
+            row.get::<_, Option<String>>(0)
+        })
+        .optional()
+        .into_store_error()?
+        .ok_or(StoreError::TransactionNotFound(transaction_id))
+    }
+
+    /// Inserts a proven transaction and its inputs, replacing any proof already stored for the
+    /// same ID.
+    pub fn insert_proven_transaction(
+        conn: &mut Connection,
+        proven_transaction: &ProvenTransaction,
+        transaction_inputs: &TransactionInputs,
+    ) -> Result<(), StoreError> {
+        const QUERY: &str = insert_sql!(
+            proven_transactions {
+                id,
+                proven_transaction,
+                transaction_inputs
+            } | REPLACE
+        );
+        conn.execute(
+            QUERY,
+            params![
+                proven_transaction.id().to_hex(),
+                proven_transaction.to_bytes(),
+                transaction_inputs.to_bytes()
+            ],
+        )
+        .into_store_error()?;
+
+        Ok(())
+    }
+
+    /// Retrieves a stored proven transaction by its ID.
+    pub fn get_proven_transaction(
+        conn: &mut Connection,
+        transaction_id: TransactionId,
+    ) -> Result<Option<ProvenTransaction>, StoreError> {
+        const QUERY: &str = "SELECT proven_transaction FROM proven_transactions WHERE id = ?";
+        conn.query_row(QUERY, params![transaction_id.to_hex()], |row| row.get::<_, Vec<u8>>(0))
+            .optional()
+            .into_store_error()?
+            .map(|bytes| {
+                ProvenTransaction::read_from_bytes(&bytes)
+                    .map_err(StoreError::DataDeserializationError)
+            })
+            .transpose()
+    }
+
+    /// Retrieves the inputs of a stored proven transaction by its ID.
+    pub fn get_proven_transaction_inputs(
+        conn: &mut Connection,
+        transaction_id: TransactionId,
+    ) -> Result<Option<TransactionInputs>, StoreError> {
+        const QUERY: &str = "SELECT transaction_inputs FROM proven_transactions WHERE id = ?";
+        conn.query_row(QUERY, params![transaction_id.to_hex()], |row| row.get::<_, Vec<u8>>(0))
+            .optional()
+            .into_store_error()?
+            .map(|bytes| {
+                TransactionInputs::read_from_bytes(&bytes)
+                    .map_err(StoreError::DataDeserializationError)
+            })
+            .transpose()
+    }
+
     /// Inserts a transaction and updates the current state based on the `tx_result` changes.
     ///
     /// SQL writes and `AccountSmtForest` mutations are committed atomically: on any error
@@ -183,6 +299,8 @@ impl SqliteStore {
             submission_height: tx_update.submission_height(),
             expiration_block_num: executed_transaction.expiration_block_num(),
             creation_timestamp: super::current_timestamp_u64(),
+            execution_cycles: u32::try_from(executed_transaction.measurements().total_cycles())
+                .ok(),
         };
 
         let transaction_record = TransactionRecord::new(
@@ -207,7 +325,7 @@ impl SqliteStore {
         )?;
 
         // Note Updates
-        apply_note_updates_tx(db_tx, tx_update.note_updates())?;
+        apply_note_updates_tx(db_tx, tx_update.note_updates(), NoteConflictStrategy::Replace)?;
 
         // Note tags
         for tag_record in tx_update.new_tags() {
@@ -260,18 +378,28 @@ pub(crate) fn upsert_transaction_record(
         block_num,
         status_variant,
         status,
+        execution_cycles,
     } = serialize_transaction_data(transaction);
 
     if let Some(root) = script_root.clone() {
-        tx.execute(INSERT_TRANSACTION_SCRIPT_QUERY, params![root, tx_script])
+        tx.prepare_cached(INSERT_TRANSACTION_SCRIPT_QUERY)
+            .into_store_error()?
+            .execute(params![root, tx_script])
             .into_store_error()?;
     }
 
-    tx.execute(
-        UPSERT_TRANSACTION_QUERY,
-        params![id, details, script_root, block_num, status_variant, status],
-    )
-    .into_store_error()?;
+    tx.prepare_cached(UPSERT_TRANSACTION_QUERY)
+        .into_store_error()?
+        .execute(params![
+            id,
+            details,
+            script_root,
+            block_num,
+            status_variant,
+            status,
+            execution_cycles
+        ])
+        .into_store_error()?;
 
     Ok(())
 }
@@ -291,6 +419,7 @@ fn serialize_transaction_data(transaction_record: &TransactionRecord) -> Seriali
         block_num: transaction_record.details.block_num.as_u32(),
         status_variant: transaction_record.status.variant() as u8,
         status: transaction_record.status.to_bytes(),
+        execution_cycles: transaction_record.details.execution_cycles,
     }
 }
 
@@ -301,15 +430,28 @@ fn parse_transaction_columns(
     let tx_script: Option<Vec<u8>> = row.get(1)?;
     let details: Vec<u8> = row.get(2)?;
     let status: Vec<u8> = row.get(3)?;
+    let execution_cycles: Option<u32> = row.get(4)?;
 
-    Ok(SerializedTransactionParts { id, tx_script, details, status })
+    Ok(SerializedTransactionParts {
+        id,
+        tx_script,
+        details,
+        status,
+        execution_cycles,
+    })
 }
 
 /// Parse a transaction from the provided parts.
 fn parse_transaction(
     serialized_transaction: SerializedTransactionParts,
 ) -> Result<TransactionRecord, StoreError> {
-    let SerializedTransactionParts { id, tx_script, details, status } = serialized_transaction;
+    let SerializedTransactionParts {
+        id,
+        tx_script,
+        details,
+        status,
+        execution_cycles,
+    } = serialized_transaction;
 
     let id: Word = id.as_str().try_into()?;
 
@@ -319,7 +461,10 @@ fn parse_transaction(
 
     Ok(TransactionRecord {
         id: TransactionId::from_raw(id),
-        details: TransactionDetails::read_from_bytes(&details)?,
+        details: TransactionDetails {
+            execution_cycles,
+            ..TransactionDetails::read_from_bytes(&details)?
+        },
         script,
         status: TransactionStatus::read_from_bytes(&status)?,
     })
@@ -1,4 +1,5 @@
 use core::future::Future;
+use core::num::NonZeroU32;
 use core::pin::Pin;
 use std::boxed::Box;
 use std::collections::{BTreeMap, BTreeSet};
@@ -6,7 +7,7 @@ use std::env::temp_dir;
 use std::println;
 use std::sync::Arc;
 
-use miden_client::account::{Address, AddressInterface};
+use miden_client::account::{Address, AddressInterface, FaucetBalance};
 use miden_client::address::RoutingParameters;
 use miden_client::assembly::CodeBuilder;
 use miden_client::auth::{
@@ -17,21 +18,32 @@ use miden_client::auth::{
     RPO_FALCON_SCHEME_ID,
 };
 use miden_client::builder::ClientBuilder;
-use miden_client::keystore::{FilesystemKeyStore, Keystore};
+use miden_client::keystore::{CompositeAuthenticator, FilesystemKeyStore, Keystore};
 use miden_client::note::{BlockNumber, NetworkAccountTarget, NoteExecutionHint};
 use miden_client::pswap::PswapLineageState;
 use miden_client::rpc::NodeRpcClient;
-use miden_client::store::input_note_states::ConsumedAuthenticatedLocalNoteState;
+use miden_client::settings::{
+    account_vault_sync_cursor_setting_key,
+    storage_map_sync_cursor_setting_key,
+};
+use miden_client::store::input_note_states::{
+    CommittedNoteState,
+    ConsumedAuthenticatedLocalNoteState,
+};
 use miden_client::store::{
     AccountStorageFilter,
     ClientAccountType,
+    INPUT_NOTE_RECORD_DTO_VERSION,
     InputNoteRecord,
+    InputNoteRecordDto,
     InputNoteState,
     NoteFilter,
     OutputNoteState,
+    Store,
+    StoreError,
     TransactionFilter,
 };
-use miden_client::sync::{NoteTagRecord, NoteTagSource};
+use miden_client::sync::{NoteTagRecord, NoteTagSource, PruningPolicy};
 use miden_client::testing::common::{
     ACCOUNT_ID_REGULAR,
     MINT_AMOUNT,
@@ -48,20 +60,22 @@ use miden_client::testing::common::{
     setup_two_wallets_and_faucet,
     setup_wallet_and_faucet,
 };
-use miden_client::testing::mock::{MockClient, MockRpcApi};
+use miden_client::testing::mock::{MockClient, MockRpcApi, MockRpcClientBuilder};
 use miden_client::transaction::{
     DiscardCause,
     PaymentNoteDescription,
     PswapTransactionData,
     SwapTransactionData,
     TransactionExecutorError,
+    TransactionId,
+    TransactionRecord,
     TransactionRequestBuilder,
     TransactionRequestError,
     TransactionStatus,
 };
 use miden_client::utils::{Deserializable, Serializable};
-use miden_client::{ClientError, DebugMode};
-use miden_client_sqlite_store::ClientBuilderSqliteExt;
+use miden_client::{ClientError, DebugMode, IdPrefixFetchError};
+use miden_client_sqlite_store::{ClientBuilderSqliteExt, SqliteStore};
 use miden_protocol::account::{
     Account,
     AccountBuilder,
@@ -91,6 +105,7 @@ use miden_protocol::note::{
     Note,
     NoteAssets,
     NoteAttachments,
+    NoteDetails,
     NoteFile,
     NoteRecipient,
     NoteStorage,
@@ -113,7 +128,7 @@ use miden_protocol::vm::AdviceInputs;
 use miden_protocol::{EMPTY_WORD, Felt, ONE, Word};
 use miden_standards::account::AccountBuilderSchemaCommitmentExt;
 use miden_standards::account::faucets::{FungibleFaucet, TokenName};
-use miden_standards::account::interface::AccountInterfaceError;
+use miden_standards::account::interface::{AccountComponentInterface, AccountInterfaceError};
 use miden_standards::account::policies::{
     BurnPolicyConfig,
     MintPolicyConfig,
@@ -227,6 +242,35 @@ async fn get_input_note() {
     assert_eq!(recorded_note.details_commitment(), retrieved_note.details_commitment());
 }
 
+#[tokio::test]
+async fn input_note_record_dto_round_trips_through_json() {
+    let (_client, rpc_api, _) = Box::pin(create_test_client()).await;
+    let note = rpc_api.get_available_notes()[0].note().unwrap().clone();
+    let record: InputNoteRecord = note.clone().into();
+
+    let dto = InputNoteRecordDto::from(&record);
+    assert_eq!(dto.version, INPUT_NOTE_RECORD_DTO_VERSION);
+    assert_eq!(dto.id, Some(note.id().to_hex()));
+    assert_eq!(
+        dto.metadata.as_ref().map(|metadata| metadata.tag),
+        Some(note.metadata().tag().as_u32())
+    );
+    assert_eq!(dto.assets.len(), note.assets().num_assets());
+    assert!(!dto.has_inclusion_proof);
+
+    let json = serde_json::to_string(&dto).unwrap();
+    let parsed: InputNoteRecordDto = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, dto);
+    assert_eq!(InputNoteRecord::try_from(&parsed).unwrap(), record);
+
+    // Records written with an unknown version of the format are rejected.
+    let future = InputNoteRecordDto {
+        version: INPUT_NOTE_RECORD_DTO_VERSION + 1,
+        ..parsed
+    };
+    assert!(InputNoteRecord::try_from(&future).is_err());
+}
+
 type InsertAccountFuture<'client> =
     Pin<Box<dyn Future<Output = Result<Account, ClientError>> + 'client>>;
 
@@ -431,6 +475,293 @@ async fn sync_state() {
     assert_eq!(client.get_sync_height().await.unwrap(), rpc_api.get_chain_tip_block_num());
 }
 
+#[tokio::test]
+async fn preview_sync_does_not_apply_update() {
+    let (mut client, rpc_api, _) = Box::pin(create_test_client()).await;
+
+    let expected_notes = rpc_api
+        .get_available_notes()
+        .into_iter()
+        .filter(|n| n.inclusion_proof().location().block_num() != BlockNumber::GENESIS)
+        .map(|n| n.note().unwrap().clone())
+        .collect::<Vec<Note>>();
+
+    for note in &expected_notes {
+        client
+            .import_notes(&[NoteFile::NoteDetails {
+                details: note.clone().into(),
+                after_block_num: 0.into(),
+                tag: Some(note.metadata().tag()),
+            }])
+            .await
+            .unwrap();
+    }
+
+    let preview = client.preview_sync().await.unwrap();
+    assert_eq!(preview.block_num, rpc_api.get_chain_tip_block_num());
+    assert_eq!(preview.consumed_notes.len(), 1);
+
+    // Nothing was applied, and previewing again yields the same summary.
+    assert_eq!(client.get_sync_height().await.unwrap(), BlockNumber::GENESIS);
+    assert_eq!(client.get_input_notes(NoteFilter::Committed).await.unwrap().len(), 0);
+    assert_eq!(client.get_input_notes(NoteFilter::Consumed).await.unwrap().len(), 0);
+    assert_eq!(client.preview_sync().await.unwrap(), preview);
+
+    // The actual sync reports what the preview announced.
+    assert_eq!(client.sync_state().await.unwrap(), preview);
+}
+
+#[tokio::test]
+async fn sync_lag_reports_blocks_behind_chain_tip() {
+    let (mut client, rpc_api, _) = Box::pin(create_test_client()).await;
+
+    // Nothing was synced yet, so the client is behind the whole chain.
+    let lag = client.sync_lag().await.unwrap();
+    assert_eq!(lag.sync_height, BlockNumber::GENESIS);
+    assert_eq!(lag.chain_tip, rpc_api.get_chain_tip_block_num());
+    assert_eq!(lag.blocks_behind(), rpc_api.get_chain_tip_block_num().as_u32());
+    assert!(!lag.is_synced());
+
+    client.sync_state().await.unwrap();
+    assert!(client.sync_lag().await.unwrap().is_synced());
+
+    // The lag grows as the node produces blocks.
+    rpc_api.advance_blocks(3);
+    let lag = client.sync_lag().await.unwrap();
+    assert_eq!(lag.blocks_behind(), 3);
+    assert_eq!(lag.sync_height.as_u32() + 3, lag.chain_tip.as_u32());
+}
+
+#[tokio::test]
+async fn sync_history_records_each_sync() {
+    let (mut client, rpc_api, _) = Box::pin(create_test_client()).await;
+    assert!(client.get_sync_history(10).await.unwrap().is_empty());
+
+    client.sync_state().await.unwrap();
+    rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+
+    let history = client.get_sync_history(10).await.unwrap();
+    assert!(history.len() >= 2);
+
+    // Entries are returned newest first, and the newest one matches the sync height.
+    assert_eq!(history[0].0, client.get_sync_height().await.unwrap());
+    for window in history.windows(2) {
+        assert!(window[0].0 >= window[1].0);
+        assert!(window[0].1 >= window[1].1);
+    }
+
+    assert_eq!(client.get_sync_history(1).await.unwrap(), history[..1]);
+}
+
+#[tokio::test]
+async fn rollback_to_block_reverts_chain_state() {
+    let (mut client, rpc_api, keystore) = Box::pin(create_test_client()).await;
+    let wallet = Box::pin(insert_new_wallet(&mut client, AccountType::Public, &keystore))
+        .await
+        .unwrap();
+
+    client.sync_state().await.unwrap();
+    let rollback_block = client.get_sync_height().await.unwrap();
+
+    let tx_request = TransactionRequestBuilder::new().build().unwrap();
+    let tx_id = Box::pin(client.submit_new_transaction(wallet.id(), tx_request)).await.unwrap();
+    rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+
+    let tx_status = async |client: &MockClient<FilesystemKeyStore>| -> TransactionStatus {
+        client
+            .get_transactions(TransactionFilter::Ids(vec![tx_id]))
+            .await
+            .unwrap()
+            .pop()
+            .unwrap()
+            .status
+    };
+    assert!(client.get_sync_height().await.unwrap() > rollback_block);
+    assert!(matches!(tx_status(&client).await, TransactionStatus::Committed { .. }));
+
+    client.rollback_to_block(rollback_block).await.unwrap();
+
+    assert_eq!(client.get_sync_height().await.unwrap(), rollback_block);
+    assert_eq!(client.get_sync_history(1).await.unwrap()[0].0, rollback_block);
+    assert!(matches!(tx_status(&client).await, TransactionStatus::Pending));
+
+    // Syncing again commits the transaction again.
+    client.sync_state().await.unwrap();
+    assert!(client.get_sync_height().await.unwrap() > rollback_block);
+    assert!(matches!(tx_status(&client).await, TransactionStatus::Committed { .. }));
+
+    // Only recorded sync points can be rolled back to.
+    let result = client.rollback_to_block(BlockNumber::from(u32::MAX)).await;
+    assert!(matches!(
+        result,
+        Err(ClientError::StoreError(StoreError::RollbackTargetNotFound(_)))
+    ));
+}
+
+#[tokio::test]
+async fn rollback_to_block_reverts_accounts_and_notes() {
+    let (mut client, rpc_api, keystore) = Box::pin(create_test_client()).await;
+    let (wallet, faucet) =
+        setup_wallet_and_faucet(&mut client, AccountType::Public, &keystore, RPO_FALCON_SCHEME_ID)
+            .await
+            .unwrap();
+
+    // The first mint deploys the faucet, so a second client can track it.
+    mint_note(&mut client, wallet.id(), faucet.id(), NoteType::Public).await;
+    rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+
+    let (mut observer, _) = create_pswap_test_client(&rpc_api).await;
+    observer.import_account_by_id(faucet.id()).await.unwrap();
+    observer.sync_state().await.unwrap();
+    let rollback_block = client.get_sync_height().await.unwrap();
+    assert_eq!(observer.get_sync_height().await.unwrap(), rollback_block);
+    let faucet_before = observer.try_get_account(faucet.id()).await.unwrap().to_commitment();
+
+    let (tx_id, note) = mint_note(&mut client, wallet.id(), faucet.id(), NoteType::Public).await;
+    observer
+        .import_notes(&[NoteFile::NoteDetails {
+            details: note.clone().into(),
+            after_block_num: rollback_block,
+            tag: Some(note.metadata().tag()),
+        }])
+        .await
+        .unwrap();
+    rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+    observer.sync_state().await.unwrap();
+
+    let tx_status = async |client: &MockClient<FilesystemKeyStore>| -> TransactionStatus {
+        client
+            .get_transactions(TransactionFilter::Ids(vec![tx_id]))
+            .await
+            .unwrap()
+            .pop()
+            .unwrap()
+            .status
+    };
+    let output_note_state = async |client: &MockClient<FilesystemKeyStore>| -> OutputNoteState {
+        client.get_output_note(note.id()).await.unwrap().unwrap().state().clone()
+    };
+    let faucet_after = observer.try_get_account(faucet.id()).await.unwrap().to_commitment();
+    assert_ne!(faucet_before, faucet_after);
+    assert!(matches!(tx_status(&client).await, TransactionStatus::Committed { .. }));
+    assert!(matches!(
+        output_note_state(&client).await,
+        OutputNoteState::CommittedFull { .. }
+    ));
+    assert_eq!(observer.get_input_notes(NoteFilter::Committed).await.unwrap().len(), 1);
+
+    client.rollback_to_block(rollback_block).await.unwrap();
+    observer.rollback_to_block(rollback_block).await.unwrap();
+
+    // The synced public account state and the note inclusions are reverted.
+    assert!(matches!(tx_status(&client).await, TransactionStatus::Pending));
+    assert!(matches!(output_note_state(&client).await, OutputNoteState::ExpectedFull { .. }));
+    let input_note = client.get_input_note(note.id()).await.unwrap().unwrap();
+    assert!(matches!(input_note.state(), InputNoteState::Expected(_)));
+    assert_eq!(
+        observer.try_get_account(faucet.id()).await.unwrap().to_commitment(),
+        faucet_before
+    );
+    assert!(observer.get_input_notes(NoteFilter::Committed).await.unwrap().is_empty());
+    assert_eq!(observer.get_input_notes(NoteFilter::Expected).await.unwrap().len(), 1);
+
+    // Syncing again fetches the reverted updates.
+    client.sync_state().await.unwrap();
+    observer.sync_state().await.unwrap();
+
+    assert!(matches!(tx_status(&client).await, TransactionStatus::Committed { .. }));
+    assert!(matches!(
+        output_note_state(&client).await,
+        OutputNoteState::CommittedFull { .. }
+    ));
+    assert_eq!(
+        observer.try_get_account(faucet.id()).await.unwrap().to_commitment(),
+        faucet_after
+    );
+    assert_eq!(observer.get_input_notes(NoteFilter::Committed).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn get_account_at_block_returns_historical_private_state() {
+    let (mut client, rpc_api, keystore) = Box::pin(create_test_client()).await;
+    let (wallet, faucet) =
+        setup_wallet_and_faucet(&mut client, AccountType::Private, &keystore, RPO_FALCON_SCHEME_ID)
+            .await
+            .unwrap();
+    client.sync_state().await.unwrap();
+    let before_first_mint = client.get_sync_height().await.unwrap();
+
+    // Each mint is committed in its own block and changes the faucet's state.
+    let mut states = vec![];
+    for _ in 0..2 {
+        mint_note(&mut client, wallet.id(), faucet.id(), NoteType::Private).await;
+        rpc_api.prove_block();
+        client.sync_state().await.unwrap();
+
+        let account = client.try_get_account(faucet.id()).await.unwrap();
+        states.push((client.get_sync_height().await.unwrap(), account.to_commitment()));
+    }
+    assert_ne!(states[0].1, states[1].1);
+
+    for (block_num, commitment) in &states {
+        let account = client.get_account_at_block(faucet.id(), *block_num).await.unwrap();
+        assert_eq!(account.to_commitment(), *commitment);
+    }
+
+    // Reading a historical state leaves the latest state untouched.
+    let latest = client.try_get_account(faucet.id()).await.unwrap();
+    assert_eq!(latest.to_commitment(), states[1].1);
+
+    // No state can be determined before the first committed transaction.
+    let result = client.get_account_at_block(faucet.id(), before_first_mint).await;
+    assert!(matches!(result, Err(ClientError::AccountStateNotAvailable { .. })));
+}
+
+#[tokio::test]
+async fn rpc_operation_timeouts_are_respected() {
+    use core::time::Duration;
+
+    use miden_client::rpc::{GrpcError, RpcEndpoint, RpcError};
+
+    let (mut client, rpc_api, _) = Box::pin(create_test_client()).await;
+    rpc_api.set_latency(RpcEndpoint::GetBlockHeaderByNumber, Duration::from_millis(200));
+    rpc_api.set_latency(RpcEndpoint::SyncChainMmr, Duration::from_millis(200));
+
+    // Slow calls succeed as long as no timeout is exceeded.
+    rpc_api.get_block_header_by_number(None, false).await.unwrap();
+
+    // An operation-specific timeout only applies to its endpoint.
+    client.with_operation_timeout(RpcEndpoint::GetBlockHeaderByNumber, Duration::from_millis(50));
+    let err = rpc_api.get_block_header_by_number(None, false).await.unwrap_err();
+    assert!(matches!(
+        err,
+        RpcError::RequestError {
+            endpoint: RpcEndpoint::GetBlockHeaderByNumber,
+            error_kind: GrpcError::DeadlineExceeded,
+            ..
+        }
+    ));
+    rpc_api.set_latency(RpcEndpoint::GetBlockHeaderByNumber, Duration::ZERO);
+    client.sync_state().await.unwrap();
+
+    // The default timeout applies to endpoints without a specific timeout.
+    client.set_rpc_timeout(Duration::from_millis(50));
+    assert!(matches!(
+        client.sync_state().await.unwrap_err(),
+        ClientError::RpcError(RpcError::RequestError {
+            error_kind: GrpcError::DeadlineExceeded,
+            ..
+        })
+    ));
+
+    client.with_operation_timeout(RpcEndpoint::SyncChainMmr, Duration::from_secs(1));
+    client.sync_state().await.unwrap();
+}
+
 #[tokio::test]
 async fn sync_state_mmr() {
     // generate test client with a random store name
@@ -504,6 +835,112 @@ async fn sync_state_mmr() {
     assert_eq!(client.test_store().get_tracked_block_headers().await.unwrap().len(), 1);
 }
 
+#[tokio::test]
+async fn block_with_peaks_verifies_note_inclusion() {
+    let (mut client, rpc_api, keystore) = Box::pin(create_test_client()).await;
+    insert_new_wallet(&mut client, AccountType::Private, &keystore).await.unwrap();
+
+    let notes = rpc_api
+        .get_public_available_notes()
+        .into_iter()
+        .filter_map(|n| n.note().cloned())
+        .collect::<Vec<Note>>();
+    for note in &notes {
+        client
+            .import_notes(&[NoteFile::NoteDetails {
+                details: note.clone().into(),
+                after_block_num: 0.into(),
+                tag: Some(note.metadata().tag()),
+            }])
+            .await
+            .unwrap();
+    }
+    client.sync_state().await.unwrap();
+
+    // Block 1 holds the only unspent public note, so it stays tracked.
+    let committed_note =
+        client.get_input_notes(NoteFilter::Committed).await.unwrap().pop().unwrap();
+    let inclusion_proof = committed_note.inclusion_proof().unwrap();
+    let block_num = inclusion_proof.location().block_num();
+
+    let (block_header, peaks) = client.get_block_with_peaks(block_num).await.unwrap();
+    assert_eq!(block_header.block_num(), block_num);
+    assert_eq!(peaks.num_leaves(), client.get_sync_height().await.unwrap().as_usize());
+
+    // The note is included in the block...
+    inclusion_proof
+        .note_path()
+        .verify(
+            inclusion_proof.location().block_note_tree_index().into(),
+            committed_note.id().unwrap().as_word(),
+            &block_header.note_root(),
+        )
+        .unwrap();
+
+    // ...and the block is included in the chain committed to by the peaks.
+    let partial_mmr = client.get_current_partial_mmr().await.unwrap();
+    let mmr_proof = partial_mmr.open(block_num.as_usize()).unwrap().unwrap();
+    peaks.verify(block_header.commitment(), mmr_proof).unwrap();
+
+    // Headers that aren't stored are reported as missing.
+    let missing_block = BlockNumber::from(client.get_sync_height().await.unwrap().as_u32() + 100);
+    assert!(matches!(
+        client.get_block_with_peaks(missing_block).await,
+        Err(ClientError::StoreError(StoreError::BlockHeaderNotFound(_)))
+    ));
+}
+
+#[tokio::test]
+async fn fetch_block_range_enables_offline_inclusion_verification() {
+    let (mut client, rpc_api, _) = Box::pin(create_test_client()).await;
+    client.sync_state().await.unwrap();
+    let sync_height = client.get_sync_height().await.unwrap();
+
+    // Pick a public note committed before the sync height. The client doesn't track it, so its
+    // block header isn't stored.
+    let (note, inclusion_proof) = rpc_api
+        .get_public_available_notes()
+        .into_iter()
+        .find_map(|fetched| {
+            let proof = fetched.inclusion_proof().clone();
+            let note = fetched.note().cloned()?;
+            (proof.location().block_num() < sync_height).then_some((note, proof))
+        })
+        .unwrap();
+    let block_num = inclusion_proof.location().block_num();
+    let from = BlockNumber::from(block_num.as_u32().saturating_sub(1));
+
+    client.fetch_block_range(from, block_num).await.unwrap();
+
+    // Everything needed for verification is now read from the store.
+    let (block_header, peaks) = client.get_block_with_peaks(block_num).await.unwrap();
+    inclusion_proof
+        .note_path()
+        .verify(
+            inclusion_proof.location().block_note_tree_index().into(),
+            note.id().as_word(),
+            &block_header.note_root(),
+        )
+        .unwrap();
+
+    let partial_mmr = client.get_current_partial_mmr().await.unwrap();
+    let mmr_proof = partial_mmr.open(block_num.as_usize()).unwrap().unwrap();
+    peaks.verify(block_header.commitment(), mmr_proof).unwrap();
+
+    // Fetching the same range again is a no-op.
+    client.fetch_block_range(from, block_num).await.unwrap();
+
+    // Ranges reaching the sync height or in reverse order are rejected.
+    assert!(matches!(
+        client.fetch_block_range(from, sync_height).await,
+        Err(ClientError::InvalidBlockRange { .. })
+    ));
+    assert!(matches!(
+        client.fetch_block_range(block_num, from).await,
+        Err(ClientError::InvalidBlockRange { .. })
+    ));
+}
+
 #[tokio::test]
 async fn sync_state_mmr_with_in_memory_cache() {
     let (builder, rpc_api, keystore) = Box::pin(create_test_client_builder()).await;
@@ -782,6 +1219,71 @@ async fn sync_state_tags() {
     assert_eq!(client.test_store().get_tracked_block_headers().await.unwrap().len(), 1);
 }
 
+#[tokio::test]
+async fn set_rpc_client_switches_nodes_of_the_same_chain() {
+    let (mut client, mock_rpc_api, _) = Box::pin(create_test_client()).await;
+    client.sync_state().await.unwrap();
+
+    // A node serving a different chain is rejected, and the previous RPC client is kept.
+    let err = client
+        .set_rpc_client(Arc::new(MockRpcApi::new(MockChain::new())))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ClientError::GenesisMismatch { .. }));
+
+    // A second node serving the same chain replaces it and is used by the following syncs.
+    let other_rpc_api: Arc<dyn NodeRpcClient> = Arc::new(mock_rpc_api.clone());
+    client.set_rpc_client(other_rpc_api.clone()).await.unwrap();
+    assert!(Arc::ptr_eq(client.test_rpc_api(), &other_rpc_api));
+
+    mock_rpc_api.prove_block();
+    let summary = client.sync_state().await.unwrap();
+    assert_eq!(summary.block_num, mock_rpc_api.get_chain_tip_block_num());
+}
+
+#[tokio::test]
+async fn genesis_mismatch_is_detected() {
+    let store_path = create_test_store_path();
+    let keystore = FilesystemKeyStore::new(temp_dir()).unwrap();
+
+    // Create a store for the prebuilt mock chain.
+    let rpc_api = MockRpcApi::new(Box::pin(create_prebuilt_mock_chain()).await);
+    let mut client = ClientBuilder::new()
+        .rpc(Arc::new(rpc_api))
+        .sqlite_store(store_path.clone())
+        .authenticator(Arc::new(keystore.clone()))
+        .build()
+        .await
+        .unwrap();
+    client.ensure_genesis_in_place().await.unwrap();
+    let (genesis, _) = client.get_block_header_by_num(BlockNumber::GENESIS).await.unwrap().unwrap();
+    let expected = genesis.commitment();
+    drop(client);
+
+    // Reopen the same store against a node serving a different chain.
+    let other_rpc_api = MockRpcApi::new(MockChain::new());
+    let actual = other_rpc_api.get_genesis_commitment().await.unwrap();
+    assert_ne!(actual, expected);
+    let mut client = ClientBuilder::new()
+        .rpc(Arc::new(other_rpc_api))
+        .sqlite_store(store_path)
+        .authenticator(Arc::new(keystore))
+        .build()
+        .await
+        .unwrap();
+
+    let err = client.sync_state().await.unwrap_err();
+    assert!(matches!(
+        err,
+        ClientError::GenesisMismatch { expected: e, actual: a } if e == expected && a == actual
+    ));
+
+    // The stored genesis block is left untouched.
+    let (stored_genesis, _) =
+        client.get_block_header_by_num(BlockNumber::GENESIS).await.unwrap().unwrap();
+    assert_eq!(stored_genesis.commitment(), expected);
+}
+
 #[tokio::test]
 async fn tags() {
     // generate test client with a random store name
@@ -820,28 +1322,167 @@ async fn tags() {
 }
 
 #[tokio::test]
-async fn mint_transaction() {
-    // generate test client with a random store name
-    let (mut client, _rpc_api, keystore) = Box::pin(create_test_client()).await;
+async fn replace_note_tag() {
+    let (mut client, _rpc_api, _) = Box::pin(create_test_client()).await;
 
-    // Faucet account generation
-    let faucet = insert_new_fungible_faucet(&mut client, AccountType::Private, &keystore)
-        .await
-        .unwrap();
+    let tag_1: NoteTag = 1.into();
+    let tag_2: NoteTag = 2.into();
+    let tag_3: NoteTag = 3.into();
+    client.add_note_tag(tag_1).await.unwrap();
 
-    client.sync_state().await.unwrap();
+    // The old tag is swapped for the new one in a single call.
+    client.replace_note_tag(tag_1, tag_2).await.unwrap();
+    assert_eq!(client.get_note_tags().await.unwrap(), vec![tag_2]);
 
-    // Test submitting a mint transaction
-    let transaction_request = TransactionRequestBuilder::new()
-        .build_mint_fungible_asset(
-            FungibleAsset::new(faucet.id(), 5u64).unwrap(),
-            AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_1).unwrap(),
-            miden_protocol::note::NoteType::Private,
-            client.rng(),
-        )
-        .unwrap();
+    // Replacing a tag that isn't tracked fails without adding the new tag.
+    let err = client.replace_note_tag(tag_1, tag_3).await.unwrap_err();
+    assert!(matches!(err, ClientError::StoreError(StoreError::NoteTagNotTracked(_))));
+    assert_eq!(client.get_note_tags().await.unwrap(), vec![tag_2]);
 
-    let transaction_result =
+    // Replacing a tag with itself keeps it tracked.
+    client.replace_note_tag(tag_2, tag_2).await.unwrap();
+    assert_eq!(client.get_note_tags().await.unwrap(), vec![tag_2]);
+
+    let err = client.replace_note_tag(tag_3, tag_3).await.unwrap_err();
+    assert!(matches!(err, ClientError::StoreError(StoreError::NoteTagNotTracked(_))));
+    assert_eq!(client.get_note_tags().await.unwrap(), vec![tag_2]);
+}
+
+#[tokio::test]
+async fn note_tags_with_note_counts() {
+    let (mut client, _rpc_api, _) = Box::pin(create_test_client()).await;
+
+    let watched_tag: NoteTag = 10.into();
+    let dead_tag: NoteTag = 11.into();
+    client.add_note_tag(watched_tag).await.unwrap();
+    client.add_note_tag(dead_tag).await.unwrap();
+
+    // Import two notes expected under the watched tag.
+    for serial_num in [1u32, 2] {
+        let recipient = NoteRecipient::new(
+            Word::from([serial_num, 0, 0, 0]),
+            StandardNote::SWAP.script(),
+            NoteStorage::new(vec![]).unwrap(),
+        );
+        let details = NoteDetails::new(NoteAssets::new(vec![]).unwrap(), recipient);
+        client
+            .import_notes(&[NoteFile::NoteDetails {
+                details,
+                after_block_num: 0.into(),
+                tag: Some(watched_tag),
+            }])
+            .await
+            .unwrap();
+    }
+
+    let user_tag_counts: Vec<(NoteTag, usize)> = client
+        .note_tags_with_note_counts()
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|(record, _)| record.source == NoteTagSource::User)
+        .map(|(record, count)| (record.tag, count))
+        .collect();
+    assert_eq!(user_tag_counts, vec![(watched_tag, 2), (dead_tag, 0)]);
+
+    // Tags added for the imported notes are counted as well.
+    let note_sourced = client
+        .note_tags_with_note_counts()
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|(record, _)| matches!(record.source, NoteTagSource::Note(_)))
+        .collect::<Vec<_>>();
+    assert_eq!(note_sourced.len(), 2);
+    assert!(
+        note_sourced
+            .iter()
+            .all(|(record, count)| record.tag == watched_tag && *count == 2)
+    );
+}
+
+#[tokio::test]
+async fn prune_unused_note_tags() {
+    let (mut client, _rpc_api, _) = Box::pin(create_test_client()).await;
+
+    let watched_tag: NoteTag = 20.into();
+    let user_tag: NoteTag = 21.into();
+    let stale_tag: NoteTag = 22.into();
+    client.add_note_tag(watched_tag).await.unwrap();
+    client.add_note_tag(user_tag).await.unwrap();
+
+    let note_details = |serial_num: u32| {
+        let recipient = NoteRecipient::new(
+            Word::from([serial_num, 0, 0, 0]),
+            StandardNote::SWAP.script(),
+            NoteStorage::new(vec![]).unwrap(),
+        );
+        NoteDetails::new(NoteAssets::new(vec![]).unwrap(), recipient)
+    };
+
+    // Import a note expected under the watched tag, which keeps the tag in use.
+    let details = note_details(1);
+    client
+        .import_notes(&[NoteFile::NoteDetails {
+            details: details.clone(),
+            after_block_num: 0.into(),
+            tag: Some(watched_tag),
+        }])
+        .await
+        .unwrap();
+
+    // A tag derived from a note the client doesn't track is stale.
+    client
+        .test_store()
+        .add_note_tag(NoteTagRecord::with_note_source(stale_tag, note_details(2).commitment()))
+        .await
+        .unwrap();
+
+    // Only the derived tag is removed; user tags are kept even if no note carries them.
+    assert_eq!(client.prune_unused_note_tags().await.unwrap(), 1);
+
+    let remaining = client.get_note_tags().await.unwrap();
+    assert_eq!(remaining.len(), 3);
+    assert!(remaining.iter().all(|record| record.tag != stale_tag));
+    assert!(remaining.contains(&NoteTagRecord {
+        tag: watched_tag,
+        source: NoteTagSource::User
+    }));
+    assert!(remaining.contains(&NoteTagRecord {
+        tag: user_tag,
+        source: NoteTagSource::User
+    }));
+    assert!(
+        remaining.contains(&NoteTagRecord::with_note_source(watched_tag, details.commitment()))
+    );
+
+    // Pruning again has nothing left to remove.
+    assert_eq!(client.prune_unused_note_tags().await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn mint_transaction() {
+    // generate test client with a random store name
+    let (mut client, _rpc_api, keystore) = Box::pin(create_test_client()).await;
+
+    // Faucet account generation
+    let faucet = insert_new_fungible_faucet(&mut client, AccountType::Private, &keystore)
+        .await
+        .unwrap();
+
+    client.sync_state().await.unwrap();
+
+    // Test submitting a mint transaction
+    let transaction_request = TransactionRequestBuilder::new()
+        .build_mint_fungible_asset(
+            FungibleAsset::new(faucet.id(), 5u64).unwrap(),
+            AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_1).unwrap(),
+            miden_protocol::note::NoteType::Private,
+            client.rng(),
+        )
+        .unwrap();
+
+    let transaction_result =
         Box::pin(client.execute_transaction(faucet.id(), transaction_request.clone()))
             .await
             .unwrap();
@@ -926,6 +1567,37 @@ async fn import_note_validation() {
     assert!(consumed_note.is_consumed());
 }
 
+#[tokio::test]
+async fn check_nullifiers_onchain() {
+    let (client, rpc_api, _) = Box::pin(create_test_client()).await;
+
+    let public_notes = rpc_api
+        .get_available_notes()
+        .into_iter()
+        .filter_map(|note| {
+            let block_num = note.inclusion_proof().location().block_num();
+            note.note().map(|note| (note.nullifier(), block_num))
+        })
+        .collect::<Vec<_>>();
+    let nullifiers = public_notes.iter().map(|(nullifier, _)| *nullifier).collect::<Vec<_>>();
+
+    let statuses = client.check_nullifiers_onchain(&nullifiers).await.unwrap();
+
+    assert_eq!(statuses.len(), nullifiers.len());
+    assert!(statuses.values().any(Option::is_some), "expected at least one spent nullifier");
+    assert!(
+        statuses.values().any(Option::is_none),
+        "expected at least one unspent nullifier"
+    );
+    for (nullifier, inclusion_block) in &public_notes {
+        if let Some(consumed_block) = statuses[nullifier] {
+            assert!(consumed_block >= *inclusion_block);
+        }
+    }
+
+    assert!(client.check_nullifiers_onchain(&[]).await.unwrap().is_empty());
+}
+
 #[tokio::test]
 async fn transaction_request_expiration() {
     let (mut client, _, keystore) = Box::pin(create_test_client()).await;
@@ -956,6 +1628,142 @@ async fn transaction_request_expiration() {
     assert_eq!(tx_outputs.expiration_block_num(), current_height + 5);
 }
 
+#[tokio::test]
+async fn account_interface_lists_account_components() {
+    let (mut client, _, keystore) = Box::pin(create_test_client()).await;
+
+    let wallet = insert_new_wallet(&mut client, AccountType::Private, &keystore).await.unwrap();
+    let faucet = insert_new_fungible_faucet(&mut client, AccountType::Private, &keystore)
+        .await
+        .unwrap();
+
+    let wallet_interface = client.account_interface(wallet.id()).await.unwrap();
+    assert!(wallet_interface.components().contains(&AccountComponentInterface::BasicWallet));
+
+    let faucet_interface = client.account_interface(faucet.id()).await.unwrap();
+    assert!(
+        faucet_interface
+            .components()
+            .contains(&AccountComponentInterface::FungibleFaucet)
+    );
+    assert!(!faucet_interface.components().contains(&AccountComponentInterface::BasicWallet));
+
+    let untracked = AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
+    assert!(client.account_interface(untracked).await.is_err());
+}
+
+#[tokio::test]
+async fn account_components_lists_wallet_and_auth_components() {
+    let (mut client, _, keystore) = Box::pin(create_test_client()).await;
+
+    let wallet = insert_new_wallet(&mut client, AccountType::Private, &keystore).await.unwrap();
+
+    // A basic wallet is made of the wallet component and its authentication component.
+    let components = client.account_components(wallet.id()).await.unwrap();
+    assert_eq!(components.len(), 2);
+    assert!(components.contains(&AccountComponentInterface::BasicWallet));
+    assert_eq!(
+        components,
+        client.account_interface(wallet.id()).await.unwrap().components().to_vec()
+    );
+
+    let untracked = AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
+    assert!(client.account_components(untracked).await.is_err());
+}
+
+#[tokio::test]
+async fn simulate_transaction_does_not_modify_state() {
+    let (mut client, _, keystore) = Box::pin(create_test_client()).await;
+    client.sync_state().await.unwrap();
+
+    let faucet = insert_new_fungible_faucet(&mut client, AccountType::Private, &keystore)
+        .await
+        .unwrap();
+    let initial_commitment = client.account_reader(faucet.id()).commitment().await.unwrap();
+
+    let transaction_request = TransactionRequestBuilder::new()
+        .build_mint_fungible_asset(
+            FungibleAsset::new(faucet.id(), 5u64).unwrap(),
+            AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap(),
+            miden_protocol::note::NoteType::Private,
+            client.rng(),
+        )
+        .unwrap();
+
+    let transaction_result =
+        Box::pin(client.simulate_transaction(faucet.id(), transaction_request))
+            .await
+            .unwrap();
+
+    assert_eq!(transaction_result.created_notes().num_notes(), 1);
+    assert_ne!(transaction_result.final_account_commitment(), initial_commitment);
+
+    // Nothing was persisted.
+    assert_eq!(
+        client.account_reader(faucet.id()).commitment().await.unwrap(),
+        initial_commitment
+    );
+    assert!(client.get_transactions(TransactionFilter::All).await.unwrap().is_empty());
+    assert!(client.get_output_notes(NoteFilter::All).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn batch_execute_returns_per_request_results() {
+    let (mut client, _, keystore) = Box::pin(create_test_client()).await;
+    client.sync_state().await.unwrap();
+
+    let wallet = insert_new_wallet(&mut client, AccountType::Private, &keystore).await.unwrap();
+    let faucet_a = insert_new_fungible_faucet(&mut client, AccountType::Private, &keystore)
+        .await
+        .unwrap();
+    let faucet_b = insert_new_fungible_faucet(&mut client, AccountType::Private, &keystore)
+        .await
+        .unwrap();
+    let untracked = AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
+
+    let mut mint = |faucet_id: AccountId| {
+        TransactionRequestBuilder::new()
+            .build_mint_fungible_asset(
+                FungibleAsset::new(faucet_id, 5u64).unwrap(),
+                wallet.id(),
+                miden_protocol::note::NoteType::Private,
+                client.rng(),
+            )
+            .unwrap()
+    };
+    let requests = vec![
+        (faucet_a.id(), mint(faucet_a.id())),
+        (faucet_b.id(), mint(faucet_b.id())),
+        (untracked, mint(faucet_a.id())),
+        (untracked, mint(faucet_a.id())),
+        (faucet_a.id(), mint(faucet_a.id())),
+    ];
+
+    let results = Box::pin(client.batch_execute(requests)).await.unwrap();
+    assert_eq!(results.len(), 5);
+
+    // The failure of the untracked account doesn't abort the other accounts' requests, and the
+    // second request of faucet A is executed on top of the first one.
+    assert!(results[0].is_ok());
+    assert!(results[1].is_ok());
+    assert!(!matches!(results[2], Ok(_) | Err(ClientError::PrecedingTransactionFailed(_))));
+    assert!(matches!(
+        results[3],
+        Err(ClientError::PrecedingTransactionFailed(account_id)) if account_id == untracked
+    ));
+    assert!(results[4].is_ok());
+
+    let faucet_a_transactions = client
+        .get_transactions(TransactionFilter::All)
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|tx| tx.details.account_id == faucet_a.id())
+        .count();
+    assert_eq!(faucet_a_transactions, 2);
+    assert_eq!(client.get_output_notes(NoteFilter::All).await.unwrap().len(), 3);
+}
+
 #[tokio::test]
 async fn import_processing_note_returns_error() {
     // generate test client with a random store name
@@ -1116,6 +1924,145 @@ async fn execute_program() {
     assert_eq!(output_stack, expected_stack);
 }
 
+#[tokio::test]
+async fn execute_program_with_account_state_does_not_require_tracked_account() {
+    let (mut client, ..) = Box::pin(create_test_client()).await;
+    let _ = client.sync_state().await.unwrap();
+
+    // The account is never added to the client, so only the provided state can be used.
+    let key_pair = AuthSecretKey::new_falcon512_poseidon2();
+    let account = AccountBuilder::new([7u8; 32])
+        .account_type(AccountType::Private)
+        .with_auth_component(AuthSingleSig::new(
+            key_pair.public_key().to_commitment(),
+            AuthSchemeId::Falcon512Poseidon2,
+        ))
+        .with_component(BasicWallet)
+        .build_with_schema_commitment()
+        .unwrap();
+
+    let tx_script = client
+        .code_builder()
+        .compile_tx_script(
+            "
+            use miden::core::sys
+
+            begin
+                push.1 push.2 add
+                exec.sys::truncate_stack
+            end
+            ",
+        )
+        .unwrap();
+
+    let output_stack = Box::pin(client.execute_program_with_account_state(
+        account.clone(),
+        tx_script,
+        AdviceInputs::default(),
+        BTreeMap::new(),
+    ))
+    .await
+    .unwrap();
+    assert_eq!(output_stack[0], Felt::from(3u32));
+
+    // Nothing is persisted.
+    assert!(client.get_account(account.id()).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn execute_program_with_account_state_prefers_provided_state_over_tracked_one() {
+    const VALUE_SLOT_NAME: &str = "miden::testing::read_value::value";
+    const READ_VALUE_CODE: &str = r#"
+        use miden::core::word
+
+        const VALUE_SLOT = word("miden::testing::read_value::value")
+
+        pub proc read_value
+            push.VALUE_SLOT[0..2]
+            exec.::miden::protocol::active_account::get_item
+            # => [VALUE, pad(16)]
+
+            swapw dropw
+            # => [VALUE, pad(12)]
+        end"#;
+
+    let (mut client, ..) = Box::pin(create_test_client()).await;
+    let _ = client.sync_state().await.unwrap();
+
+    // Builds an account whose value slot holds `value` in every element, so the output doesn't
+    // depend on the order of the word on the stack.
+    let build_account = |seed: u8, value: u32| {
+        let component_code = CodeBuilder::default()
+            .compile_component_code("miden::testing::read_value_component", READ_VALUE_CODE)
+            .unwrap();
+        let value_slot = StorageSlot::with_value(
+            StorageSlotName::new(VALUE_SLOT_NAME).unwrap(),
+            [Felt::from(value); 4].into(),
+        );
+        let component = AccountComponent::new(
+            component_code,
+            vec![value_slot],
+            AccountComponentMetadata::new("miden::testing::read_value_component"),
+        )
+        .unwrap();
+
+        AccountBuilder::new([seed; 32])
+            .account_type(AccountType::Private)
+            .with_auth_component(AuthSingleSig::new(
+                PublicKeyCommitment::from(EMPTY_WORD),
+                AuthSchemeId::Falcon512Poseidon2,
+            ))
+            .with_component(BasicWallet)
+            .with_component(component)
+            .build_existing()
+            .unwrap()
+    };
+
+    let tracked_account = build_account(1, 1);
+    client.add_account(&tracked_account, false).await.unwrap();
+
+    // Same account, but its file state holds a different value in the slot.
+    let (_, vault, storage, code, ..) = build_account(2, 7).into_parts();
+    let file_account =
+        Account::new_unchecked(tracked_account.id(), vault, storage, code, ONE, None);
+
+    let tx_script = CodeBuilder::new()
+        .with_linked_module("external_contract::read_value_contract", READ_VALUE_CODE)
+        .unwrap()
+        .compile_tx_script(
+            "
+            use miden::core::sys
+            use external_contract::read_value_contract
+
+            begin
+                call.read_value_contract::read_value
+                exec.sys::truncate_stack
+            end
+            ",
+        )
+        .unwrap();
+
+    let output_stack = Box::pin(client.execute_program_with_account_state(
+        file_account,
+        tx_script,
+        AdviceInputs::default(),
+        BTreeMap::new(),
+    ))
+    .await
+    .unwrap();
+    assert_eq!(output_stack[0], Felt::from(7u32));
+
+    // The tracked state is left untouched.
+    let stored_account: Account = client
+        .get_account(tracked_account.id())
+        .await
+        .unwrap()
+        .unwrap()
+        .try_into()
+        .unwrap();
+    assert_eq!(stored_account.to_commitment(), tracked_account.to_commitment());
+}
+
 #[tokio::test]
 async fn real_note_roundtrip() {
     let (mut client, mock_rpc_api, keystore) = Box::pin(create_test_client()).await;
@@ -1317,16 +2264,84 @@ async fn p2id_transfer() {
 }
 
 #[tokio::test]
-async fn input_note_reader_finds_externally_consumed_notes() {
-    let sender_id: AccountId = ACCOUNT_ID_PRIVATE_SENDER.try_into().unwrap();
-    let faucet_id: AccountId = ACCOUNT_ID_PRIVATE_FUNGIBLE_FAUCET.try_into().unwrap();
+async fn get_transaction_notes_links_sent_and_consumed_notes() {
+    let (mut client, mock_rpc_api, authenticator) = Box::pin(create_test_client()).await;
 
-    // Build a MockChain with a sender, consumer, and a P2ID note from sender to consumer.
-    let mut builder = MockChainBuilder::new();
-    let consumer = builder.add_existing_mock_account(miden_testing::Auth::IncrNonce).unwrap();
-    let consumer_id = consumer.id();
+    let (first_regular_account, second_regular_account, faucet_account_header) =
+        setup_two_wallets_and_faucet(
+            &mut client,
+            AccountType::Private,
+            &authenticator,
+            RPO_FALCON_SCHEME_ID,
+        )
+        .await
+        .unwrap();
 
-    let asset = Asset::Fungible(FungibleAsset::new(faucet_id, 100u64).unwrap());
+    let from_account_id = first_regular_account.id();
+    let to_account_id = second_regular_account.id();
+    let faucet_account_id = faucet_account_header.id();
+
+    mint_and_consume(&mut client, from_account_id, faucet_account_id, NoteType::Private).await;
+    mock_rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+
+    let asset = FungibleAsset::new(faucet_account_id, TRANSFER_AMOUNT).unwrap();
+    let tx_request = TransactionRequestBuilder::new()
+        .build_pay_to_id(
+            PaymentNoteDescription::new(
+                vec![Asset::Fungible(asset)],
+                from_account_id,
+                to_account_id,
+            ),
+            NoteType::Private,
+            client.rng(),
+        )
+        .unwrap();
+    let sent_note = tx_request.expected_output_own_notes().pop().unwrap();
+    let send_tx_id = Box::pin(client.submit_new_transaction(from_account_id, tx_request))
+        .await
+        .unwrap();
+    mock_rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+
+    // The send transaction consumed nothing and created the P2ID note.
+    let (input_notes, output_notes) = client.get_transaction_notes(send_tx_id).await.unwrap();
+    assert!(input_notes.is_empty());
+    assert_eq!(output_notes.len(), 1);
+    assert_eq!(output_notes[0].id(), sent_note.id());
+
+    let tx_request = TransactionRequestBuilder::new()
+        .build_consume_notes(vec![sent_note.clone()])
+        .unwrap();
+    let consume_tx_id = Box::pin(client.submit_new_transaction(to_account_id, tx_request))
+        .await
+        .unwrap();
+
+    // The consume transaction is linked to the same note, now as an input note.
+    let (input_notes, output_notes) = client.get_transaction_notes(consume_tx_id).await.unwrap();
+    assert!(output_notes.is_empty());
+    assert_eq!(input_notes.len(), 1);
+    assert_eq!(input_notes[0].id(), Some(sent_note.id()));
+    assert!(input_notes[0].is_consumed() || input_notes[0].is_processing());
+
+    let missing_tx_id = TransactionId::from_raw(Word::default());
+    assert!(matches!(
+        client.get_transaction_notes(missing_tx_id).await,
+        Err(ClientError::StoreError(StoreError::TransactionNotFound(id))) if id == missing_tx_id
+    ));
+}
+
+#[tokio::test]
+async fn input_note_reader_finds_externally_consumed_notes() {
+    let sender_id: AccountId = ACCOUNT_ID_PRIVATE_SENDER.try_into().unwrap();
+    let faucet_id: AccountId = ACCOUNT_ID_PRIVATE_FUNGIBLE_FAUCET.try_into().unwrap();
+
+    // Build a MockChain with a sender, consumer, and a P2ID note from sender to consumer.
+    let mut builder = MockChainBuilder::new();
+    let consumer = builder.add_existing_mock_account(miden_testing::Auth::IncrNonce).unwrap();
+    let consumer_id = consumer.id();
+
+    let asset = Asset::Fungible(FungibleAsset::new(faucet_id, 100u64).unwrap());
     let p2id_note = builder
         .add_p2id_note(sender_id, consumer_id, &[asset], NoteType::Public)
         .unwrap();
@@ -1427,10 +2442,11 @@ async fn input_note_reader_finds_externally_consumed_notes() {
 }
 
 /// Builds a chain with two blocks relevant to a tracked account (blocks 1 and 4) and a client
-/// synced up to block 4 with `prune_interval` configured. Returns the consuming pieces needed to
-/// make block 4 irrelevant on demand.
+/// synced up to block 4 with `prune_interval` and the optional `pruning_policy` configured.
+/// Returns the consuming pieces needed to make block 4 irrelevant on demand.
 async fn setup_prunable_block_scenario(
     prune_interval: Option<u32>,
+    pruning_policy: Option<PruningPolicy>,
 ) -> (MockClient<FilesystemKeyStore>, MockRpcApi, AccountId, Note) {
     let mut builder = MockChainBuilder::new();
     let mock_account = builder.add_existing_mock_account(miden_testing::Auth::IncrNonce).unwrap();
@@ -1497,17 +2513,18 @@ async fn setup_prunable_block_scenario(
     let keystore = FilesystemKeyStore::new(std::env::temp_dir()).unwrap();
     let mock_rpc = MockRpcApi::new(chain);
 
-    let mut client = ClientBuilder::new()
+    let mut client_builder = ClientBuilder::new()
         .rpc(Arc::new(mock_rpc.clone()))
         .rng(Box::new(rng))
         .sqlite_store(create_test_store_path())
         .authenticator(Arc::new(keystore))
         .in_debug_mode(DebugMode::Enabled)
         .tx_discard_delta(None)
-        .irrelevant_block_prune_interval(prune_interval)
-        .build()
-        .await
-        .unwrap();
+        .irrelevant_block_prune_interval(prune_interval);
+    if let Some(policy) = pruning_policy {
+        client_builder = client_builder.pruning_policy(policy);
+    }
+    let mut client = client_builder.build().await.unwrap();
     client.ensure_genesis_in_place().await.unwrap();
     client.add_note_tag(NoteTag::new(0)).await.unwrap();
 
@@ -1541,7 +2558,7 @@ async fn consume_note_and_prove(mock_rpc: &MockRpcApi, account_id: AccountId, no
 #[tokio::test]
 async fn irrelevant_block_pruning_respects_sync_interval() {
     let (mut client, mock_rpc, account_id, note_second) =
-        setup_prunable_block_scenario(Some(2)).await;
+        setup_prunable_block_scenario(Some(2), None).await;
 
     consume_note_and_prove(&mock_rpc, account_id, note_second).await;
 
@@ -1561,22 +2578,664 @@ async fn irrelevant_block_pruning_respects_sync_interval() {
     );
 }
 
-#[tokio::test]
-async fn irrelevant_block_pruning_disabled_when_interval_is_none() {
-    let (mut client, mock_rpc, account_id, note_second) = setup_prunable_block_scenario(None).await;
+#[tokio::test]
+async fn irrelevant_block_pruning_disabled_when_interval_is_none() {
+    let (mut client, mock_rpc, account_id, note_second) =
+        setup_prunable_block_scenario(None, None).await;
+
+    consume_note_and_prove(&mock_rpc, account_id, note_second).await;
+
+    // With pruning disabled, both tracked blocks must remain across repeated syncs.
+    for _ in 0..5 {
+        mock_rpc.prove_block();
+        client.sync_state().await.unwrap();
+        assert_eq!(
+            client.test_store().get_tracked_block_headers().await.unwrap().len(),
+            2,
+            "no pruning should occur when the prune interval is None",
+        );
+    }
+}
+
+#[tokio::test]
+async fn pruning_policy_prunes_after_interval() {
+    let policy = PruningPolicy::new(NonZeroU32::new(2).unwrap())
+        .with_consumed_notes_retention(0)
+        .with_irrelevant_block_pruning();
+    let (mut client, mock_rpc, account_id, note_second) =
+        setup_prunable_block_scenario(None, Some(policy)).await;
+
+    consume_note_and_prove(&mock_rpc, account_id, note_second).await;
+
+    client.sync_state().await.unwrap();
+    assert_eq!(client.get_input_notes(NoteFilter::Consumed).await.unwrap().len(), 1);
+    assert_eq!(
+        client.test_store().get_tracked_block_headers().await.unwrap().len(),
+        2,
+        "pruning should be deferred until the policy interval elapses",
+    );
+
+    mock_rpc.prove_block();
+    client.sync_state().await.unwrap();
+    assert!(
+        client.get_input_notes(NoteFilter::Consumed).await.unwrap().is_empty(),
+        "the consumed note should be pruned once the policy interval is reached",
+    );
+    assert_eq!(
+        client.test_store().get_tracked_block_headers().await.unwrap().len(),
+        1,
+        "the irrelevant block should be pruned once the policy interval is reached",
+    );
+}
+
+#[tokio::test]
+async fn verify_note_inclusion_checks_stored_chain_data() {
+    let (mut client, mock_rpc, account_id, note_second) =
+        setup_prunable_block_scenario(Some(1), None).await;
+
+    let committed_notes = client.get_input_notes(NoteFilter::Committed).await.unwrap();
+    let first_record = committed_notes
+        .iter()
+        .find(|record| record.id() != Some(note_second.id()))
+        .unwrap()
+        .clone();
+    let first_id = first_record.id().unwrap();
+    let second_proof = committed_notes
+        .iter()
+        .find(|record| record.id() == Some(note_second.id()))
+        .and_then(|record| record.inclusion_proof().cloned())
+        .unwrap();
+
+    assert!(client.verify_note_inclusion(first_id).await.unwrap());
+    assert!(client.verify_note_inclusion(note_second.id()).await.unwrap());
+
+    // Attaching the second note's proof to the first note yields a path that doesn't lead to it.
+    let with_proof = |inclusion_proof| {
+        InputNoteRecord::new(
+            first_record.details().clone(),
+            first_record.attachments().clone(),
+            first_record.created_at(),
+            CommittedNoteState {
+                metadata: *first_record.metadata().unwrap(),
+                inclusion_proof,
+                block_note_root: Word::default(),
+            }
+            .into(),
+        )
+    };
+    client
+        .test_store()
+        .upsert_input_notes(&[with_proof(second_proof.clone())])
+        .await
+        .unwrap();
+    assert!(!client.verify_note_inclusion(first_id).await.unwrap());
+
+    // Once the second note is consumed, its block is pruned and the proof can't be checked.
+    client.test_store().upsert_input_notes(&[first_record.clone()]).await.unwrap();
+    consume_note_and_prove(&mock_rpc, account_id, note_second).await;
+    client.sync_state().await.unwrap();
+    assert_eq!(client.test_store().get_tracked_block_headers().await.unwrap().len(), 1);
+
+    client
+        .test_store()
+        .upsert_input_notes(&[with_proof(second_proof)])
+        .await
+        .unwrap();
+    assert!(matches!(
+        client.verify_note_inclusion(first_id).await,
+        Err(ClientError::NoteInclusionDataMissing { note_id, .. }) if note_id == first_id
+    ));
+}
+
+/// Reproduces a note being committed in a block the client hasn't observed yet: the first sync is
+/// scripted to stop before the note's block, so the note must be picked up by the second sync.
+#[tokio::test]
+async fn note_committed_between_syncs_is_received() {
+    let mut builder = MockChainBuilder::new();
+    let mock_account = builder.add_existing_mock_account(miden_testing::Auth::IncrNonce).unwrap();
+
+    let note = NoteBuilder::new(
+        mock_account.id(),
+        RandomCoin::new([0, 0, 0, 0].map(Felt::new_unchecked).into()),
+    )
+    .note_type(NoteType::Public)
+    .tag(NoteTag::new(0).into())
+    .build()
+    .unwrap();
+    let spawn_note = builder.add_spawn_note(std::slice::from_ref(&note)).unwrap();
+
+    let mut chain = builder.build().unwrap();
+
+    // Blocks 1 and 2: advance the chain without creating notes.
+    chain.prove_next_block().unwrap();
+    chain.prove_next_block().unwrap();
+
+    // Block 3: create the note.
+    let tx = Box::pin(
+        chain
+            .build_tx_context(TxContextInput::AccountId(mock_account.id()), &[], &[spawn_note])
+            .unwrap()
+            .extend_expected_output_notes(vec![RawOutputNote::Full(note.clone())])
+            .build()
+            .unwrap()
+            .execute(),
+    )
+    .await
+    .unwrap();
+    chain.add_pending_executed_transaction(&tx).unwrap();
+    chain.prove_next_block().unwrap();
+
+    // The first sync only observes the chain up to block 2, before the note was committed.
+    let mock_rpc = MockRpcClientBuilder::new(chain).with_sync_tip(2.into()).build();
+
+    let rng =
+        RandomCoin::new(rand::random::<[u64; 4]>().map(|v| Felt::new_unchecked(v >> 1)).into());
+    let keystore = FilesystemKeyStore::new(std::env::temp_dir()).unwrap();
+    let mut client = ClientBuilder::new()
+        .rpc(Arc::new(mock_rpc))
+        .rng(Box::new(rng))
+        .sqlite_store(create_test_store_path())
+        .authenticator(Arc::new(keystore))
+        .in_debug_mode(DebugMode::Enabled)
+        .tx_discard_delta(None)
+        .build()
+        .await
+        .unwrap();
+    client.ensure_genesis_in_place().await.unwrap();
+    client.add_note_tag(NoteTag::new(0)).await.unwrap();
+
+    let summary = client.sync_state().await.unwrap();
+    assert_eq!(summary.block_num, 2.into());
+    assert!(client.get_input_notes(NoteFilter::All).await.unwrap().is_empty());
+
+    let summary = client.sync_state().await.unwrap();
+    assert_eq!(summary.block_num, 3.into());
+    let input_notes = client.get_input_notes(NoteFilter::Committed).await.unwrap();
+    assert_eq!(input_notes.len(), 1);
+    assert_eq!(input_notes[0].id(), Some(note.id()));
+}
+
+#[tokio::test]
+async fn mock_rpc_client_builder_serves_scripted_blocks_notes_and_nullifiers() {
+    use miden_client::rpc::domain::note::CommittedNote;
+
+    // Take a block header, a committed note and its nullifier from one chain, and script them
+    // into a mock node backed by another chain that has none of them.
+    let mut builder = MockChainBuilder::new();
+    let mock_account = builder.add_existing_mock_account(miden_testing::Auth::IncrNonce).unwrap();
+    let note = NoteBuilder::new(
+        mock_account.id(),
+        RandomCoin::new([0, 0, 0, 0].map(Felt::new_unchecked).into()),
+    )
+    .note_type(NoteType::Public)
+    .tag(NoteTag::new(0).into())
+    .build()
+    .unwrap();
+    let spawn_note = builder.add_spawn_note(std::slice::from_ref(&note)).unwrap();
+    let source_chain = builder.build().unwrap();
+
+    let chain_note = source_chain.committed_notes().get(&spawn_note.id()).unwrap().clone();
+    let note_block = chain_note.inclusion_proof().location().block_num();
+    let header = source_chain.block_header(note_block.as_usize());
+    let committed_note = CommittedNote::new(
+        chain_note.id(),
+        *chain_note.metadata(),
+        chain_note.inclusion_proof().clone(),
+    );
+
+    let mut chain = MockChain::new();
+    chain.prove_next_block().unwrap();
+    let mock_rpc = MockRpcClientBuilder::new(chain)
+        .with_block(header.clone())
+        .with_note(committed_note)
+        .with_nullifier(spawn_note.nullifier(), note_block)
+        .build();
+
+    let (served_header, _) =
+        mock_rpc.get_block_header_by_number(Some(note_block), false).await.unwrap();
+    assert_eq!(served_header, header);
+
+    let tags = BTreeSet::from([spawn_note.metadata().tag()]);
+    let note_blocks = mock_rpc.sync_notes(note_block, note_block, &tags).await.unwrap();
+    assert_eq!(note_blocks.len(), 1);
+    assert_eq!(note_blocks[0].block_header, header);
+    assert!(note_blocks[0].notes.contains_key(&spawn_note.id()));
+
+    let nullifiers = mock_rpc
+        .sync_nullifiers(&[spawn_note.nullifier().prefix()], note_block, note_block)
+        .await
+        .unwrap();
+    assert_eq!(nullifiers.len(), 1);
+    assert_eq!(nullifiers[0].nullifier, spawn_note.nullifier());
+    assert_eq!(nullifiers[0].block_num, note_block);
+}
+
+/// Builds a client whose mock node serves `sync_chain_mmr` in pages of at most `page_size` blocks,
+/// over a chain where a public note tagged with `NoteTag::new(0)` is committed in block 3.
+async fn client_with_paged_sync(page_size: u32) -> (TestClient, Note) {
+    let mut builder = MockChainBuilder::new();
+    let mock_account = builder.add_existing_mock_account(miden_testing::Auth::IncrNonce).unwrap();
+
+    let note = NoteBuilder::new(
+        mock_account.id(),
+        RandomCoin::new([0, 0, 0, 0].map(Felt::new_unchecked).into()),
+    )
+    .note_type(NoteType::Public)
+    .tag(NoteTag::new(0).into())
+    .build()
+    .unwrap();
+    let spawn_note = builder.add_spawn_note(std::slice::from_ref(&note)).unwrap();
+
+    let mut chain = builder.build().unwrap();
+    chain.prove_next_block().unwrap();
+    chain.prove_next_block().unwrap();
+
+    let tx = Box::pin(
+        chain
+            .build_tx_context(TxContextInput::AccountId(mock_account.id()), &[], &[spawn_note])
+            .unwrap()
+            .extend_expected_output_notes(vec![RawOutputNote::Full(note.clone())])
+            .build()
+            .unwrap()
+            .execute(),
+    )
+    .await
+    .unwrap();
+    chain.add_pending_executed_transaction(&tx).unwrap();
+    chain.prove_next_block().unwrap();
+
+    let mock_rpc = MockRpcClientBuilder::new(chain).with_sync_page_size(page_size).build();
+
+    let rng =
+        RandomCoin::new(rand::random::<[u64; 4]>().map(|v| Felt::new_unchecked(v >> 1)).into());
+    let keystore = FilesystemKeyStore::new(std::env::temp_dir()).unwrap();
+    let mut client = ClientBuilder::new()
+        .rpc(Arc::new(mock_rpc))
+        .rng(Box::new(rng))
+        .sqlite_store(create_test_store_path())
+        .authenticator(Arc::new(keystore))
+        .in_debug_mode(DebugMode::Enabled)
+        .tx_discard_delta(None)
+        .build()
+        .await
+        .unwrap();
+    client.ensure_genesis_in_place().await.unwrap();
+    client.add_note_tag(NoteTag::new(0)).await.unwrap();
+
+    (client, note)
+}
+
+#[tokio::test]
+async fn sync_state_applies_paged_responses_until_chain_tip() {
+    let (mut client, note) = client_with_paged_sync(1).await;
+
+    // A single sync walks the three one-block pages up to the chain tip.
+    let summary = client.sync_state().await.unwrap();
+    assert_eq!(summary.block_num, 3.into());
+    assert_eq!(summary.new_public_notes, vec![note.id()]);
+    assert_eq!(client.get_sync_height().await.unwrap(), 3.into());
+
+    let input_notes = client.get_input_notes(NoteFilter::Committed).await.unwrap();
+    assert_eq!(input_notes.len(), 1);
+    assert_eq!(input_notes[0].id(), Some(note.id()));
+}
+
+#[tokio::test]
+async fn sync_state_with_progress_reports_each_step() {
+    let (mut client, _) = client_with_paged_sync(1).await;
+
+    let mut progress = Vec::new();
+    let summary = client
+        .sync_state_with_progress(|block_num, chain_tip| progress.push((block_num, chain_tip)))
+        .await
+        .unwrap();
+
+    // Every one-block page is reported, with increasing block numbers up to the chain tip.
+    assert_eq!(progress.len(), 3);
+    assert!(progress.windows(2).all(|steps| steps[0].0 < steps[1].0));
+    assert!(progress.iter().all(|(_, chain_tip)| *chain_tip == 3.into()));
+    assert_eq!(progress.last(), Some(&(summary.block_num, 3.into())));
+}
+
+#[tokio::test]
+async fn sync_state_with_progress_reports_multi_block_steps() {
+    let (mut client, note) = client_with_paged_sync(2).await;
+
+    let mut progress = Vec::new();
+    let summary = client
+        .sync_state_with_progress(|block_num, chain_tip| progress.push((block_num, chain_tip)))
+        .await
+        .unwrap();
+
+    // Two-block pages take two steps to reach the chain tip, and the first one is short of it.
+    assert_eq!(progress, vec![(2.into(), 3.into()), (3.into(), 3.into())]);
+    assert_eq!(summary.block_num, 3.into());
+    assert_eq!(summary.new_public_notes, vec![note.id()]);
+}
+
+#[tokio::test]
+async fn sync_state_fails_when_the_node_makes_no_progress() {
+    let (mut client, _) = client_with_paged_sync(0).await;
+
+    let err = client.sync_state().await.unwrap_err();
+    assert!(matches!(
+        err,
+        ClientError::SyncStalled { block_num, chain_tip }
+            if block_num == BlockNumber::GENESIS && chain_tip == 3.into()
+    ));
+    assert_eq!(client.get_sync_height().await.unwrap(), BlockNumber::GENESIS);
+}
+
+#[tokio::test]
+async fn wait_for_note_commitment_syncs_until_committed() {
+    use core::time::Duration;
+
+    let mut builder = MockChainBuilder::new();
+    let mock_account = builder.add_existing_mock_account(miden_testing::Auth::IncrNonce).unwrap();
+
+    let note = NoteBuilder::new(
+        mock_account.id(),
+        RandomCoin::new([0, 0, 0, 0].map(Felt::new_unchecked).into()),
+    )
+    .note_type(NoteType::Public)
+    .tag(NoteTag::new(0).into())
+    .build()
+    .unwrap();
+    let spawn_note = builder.add_spawn_note(std::slice::from_ref(&note)).unwrap();
+
+    let mut chain = builder.build().unwrap();
+    chain.prove_next_block().unwrap();
+
+    // Block 2: create the note.
+    let tx = Box::pin(
+        chain
+            .build_tx_context(TxContextInput::AccountId(mock_account.id()), &[], &[spawn_note])
+            .unwrap()
+            .extend_expected_output_notes(vec![RawOutputNote::Full(note.clone())])
+            .build()
+            .unwrap()
+            .execute(),
+    )
+    .await
+    .unwrap();
+    chain.add_pending_executed_transaction(&tx).unwrap();
+    chain.prove_next_block().unwrap();
+
+    // The first sync stops before the note is committed, so at least two syncs are needed.
+    let mock_rpc = MockRpcClientBuilder::new(chain).with_sync_tip(1.into()).build();
+
+    let rng =
+        RandomCoin::new(rand::random::<[u64; 4]>().map(|v| Felt::new_unchecked(v >> 1)).into());
+    let keystore = FilesystemKeyStore::new(std::env::temp_dir()).unwrap();
+    let mut client = ClientBuilder::new()
+        .rpc(Arc::new(mock_rpc))
+        .rng(Box::new(rng))
+        .sqlite_store(create_test_store_path())
+        .authenticator(Arc::new(keystore))
+        .in_debug_mode(DebugMode::Enabled)
+        .tx_discard_delta(None)
+        .build()
+        .await
+        .unwrap();
+    client.ensure_genesis_in_place().await.unwrap();
+    client.add_note_tag(NoteTag::new(0)).await.unwrap();
+
+    // A note that is never tracked times out without syncing.
+    let unknown_note_id = miden_protocol::note::NoteId::from_raw(Word::from([1u32, 2, 3, 4]));
+    let err = client
+        .wait_for_note_commitment(unknown_note_id, Duration::ZERO)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ClientError::Timeout(_)));
+    assert_eq!(client.get_sync_height().await.unwrap(), 0.into());
+
+    client
+        .wait_for_note_commitment(note.id(), Duration::from_secs(10))
+        .await
+        .unwrap();
+    assert_eq!(client.get_sync_height().await.unwrap(), 2.into());
+
+    // Once committed, the call returns without syncing again.
+    client.wait_for_note_commitment(note.id(), Duration::ZERO).await.unwrap();
+}
+
+#[tokio::test]
+async fn note_screener_policy_tracks_otherwise_ignored_note() {
+    use miden_client::async_trait;
+    use miden_client::note::{NoteScreener, ScreenerPolicy, ScreeningDecision};
+    use miden_client::rpc::domain::note::CommittedNote;
+    use miden_client::sync::{NoteUpdateAction, OnNoteReceived};
+    use miden_testing::MockChainNote;
+
+    // A policy that tracks every note carrying the watched tag.
+    struct WatchedTagPolicy(NoteTag);
+
+    #[async_trait(?Send)]
+    impl ScreenerPolicy for WatchedTagPolicy {
+        async fn screen(
+            &self,
+            committed_note: &CommittedNote,
+            _public_note: Option<&InputNoteRecord>,
+        ) -> Result<ScreeningDecision, ClientError> {
+            if committed_note.metadata().tag() == self.0 {
+                Ok(ScreeningDecision::Track)
+            } else {
+                Ok(ScreeningDecision::UseDefault)
+            }
+        }
+    }
+
+    let (builder, rpc_api, _) = Box::pin(create_test_client_builder()).await;
+    let mut client = builder
+        .note_screener_policy(Arc::new(WatchedTagPolicy(NoteTag::new(0))))
+        .build()
+        .await
+        .unwrap();
+
+    let MockChainNote::Public(note, inclusion_proof) =
+        rpc_api.get_public_available_notes().pop().unwrap()
+    else {
+        panic!("expected a public note");
+    };
+    assert_eq!(note.metadata().tag(), NoteTag::new(0));
+    let committed_note = CommittedNote::new(note.id(), *note.metadata(), inclusion_proof);
+    let public_note = InputNoteRecord::from(note);
+
+    // The client neither tracks the note's tag nor has accounts that could consume it, so the
+    // default policy discards the note.
+    let default_action = NoteScreener::new(client.test_store().clone(), Arc::new(rpc_api.clone()))
+        .on_note_received(committed_note.clone(), Some(public_note.clone()))
+        .await
+        .unwrap();
+    assert!(matches!(default_action, NoteUpdateAction::Discard));
+
+    // The client's screener uses the configured policy, which tracks the note.
+    let action = client
+        .note_screener()
+        .on_note_received(committed_note, Some(public_note))
+        .await
+        .unwrap();
+    assert!(matches!(action, NoteUpdateAction::Insert(_)));
+}
+
+/// Creates `count` public P2ID notes from `sender` for each of the `targets`, with varying
+/// amounts of `faucet_id`'s asset.
+fn p2id_notes(
+    client: &mut MockClient<FilesystemKeyStore>,
+    sender: AccountId,
+    faucet_id: AccountId,
+    targets: &[AccountId],
+    count: u64,
+) -> Vec<Note> {
+    let mut notes = Vec::new();
+    for target in targets {
+        for amount in 1..=count {
+            let asset = FungibleAsset::new(faucet_id, amount).unwrap();
+            let note = P2idNote::create(
+                sender,
+                *target,
+                vec![asset.into()],
+                NoteType::Public,
+                NoteAttachments::empty(),
+                client.rng(),
+            )
+            .unwrap();
+            notes.push(note);
+        }
+    }
+    notes
+}
+
+#[tokio::test]
+async fn note_screener_cache_matches_uncached_results() {
+    use miden_client::note::{NoteConsumability, NoteScreener};
+    use miden_protocol::note::NoteId;
+
+    let (mut client, rpc_api, keystore) = Box::pin(create_test_client()).await;
+    let (wallet, faucet) =
+        setup_wallet_and_faucet(&mut client, AccountType::Private, &keystore, RPO_FALCON_SCHEME_ID)
+            .await
+            .unwrap();
+    let other_wallet =
+        insert_new_wallet(&mut client, AccountType::Private, &keystore).await.unwrap();
+    let untracked = AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
+
+    // Notes with the same shape for each target, so that the cache is hit for repeated shapes.
+    let notes = p2id_notes(
+        &mut client,
+        faucet.id(),
+        faucet.id(),
+        &[wallet.id(), other_wallet.id(), untracked],
+        3,
+    );
+
+    let screener = NoteScreener::new(client.test_store().clone(), Arc::new(rpc_api.clone()));
+    let cached = screener.clone().can_consume_batch(&notes).await.unwrap();
+    let uncached = screener.with_relevance_cache(false).can_consume_batch(&notes).await.unwrap();
+
+    let consumers =
+        |relevance: &BTreeMap<NoteId, Vec<NoteConsumability>>, note: &Note| -> Vec<AccountId> {
+            relevance
+                .get(&note.id())
+                .map(|consumers| consumers.iter().map(|(account_id, _)| *account_id).collect())
+                .unwrap_or_default()
+        };
+
+    for note in &notes {
+        assert_eq!(consumers(&cached, note), consumers(&uncached, note));
+    }
+
+    // Cached decisions are never reused across accounts.
+    for note in &notes[..3] {
+        assert_eq!(consumers(&cached, note), vec![wallet.id()]);
+    }
+    for note in &notes[3..6] {
+        assert_eq!(consumers(&cached, note), vec![other_wallet.id()]);
+    }
+    for note in &notes[6..] {
+        assert!(consumers(&cached, note).is_empty());
+    }
+}
+
+/// Compares the time taken to screen 1000 P2ID notes with and without the relevance cache.
+#[tokio::test]
+#[ignore = "benchmark, run manually with `--ignored --nocapture`"]
+async fn note_screener_cache_benchmark() {
+    use std::time::Instant;
+
+    use miden_client::note::NoteScreener;
+
+    let (mut client, rpc_api, keystore) = Box::pin(create_test_client()).await;
+    let (wallet, faucet) =
+        setup_wallet_and_faucet(&mut client, AccountType::Private, &keystore, RPO_FALCON_SCHEME_ID)
+            .await
+            .unwrap();
+    let notes = p2id_notes(&mut client, faucet.id(), faucet.id(), &[wallet.id()], 1000);
+
+    let screener = NoteScreener::new(client.test_store().clone(), Arc::new(rpc_api.clone()));
+
+    let start = Instant::now();
+    let uncached = screener.clone().with_relevance_cache(false).can_consume_batch(&notes).await;
+    let uncached_elapsed = start.elapsed();
+
+    let start = Instant::now();
+    let cached = screener.can_consume_batch(&notes).await;
+    let cached_elapsed = start.elapsed();
+
+    assert_eq!(uncached.unwrap().len(), notes.len());
+    assert_eq!(cached.unwrap().len(), notes.len());
+    println!(
+        "screened {} P2ID notes: {uncached_elapsed:?} without cache, {cached_elapsed:?} with cache",
+        notes.len()
+    );
+}
+
+#[tokio::test]
+async fn block_verifier_rejects_forked_header() {
+    use miden_client::async_trait;
+    use miden_client::sync::BlockVerifier;
+    use miden_protocol::block::BlockHeader;
+
+    // A verifier that only accepts the given commitment for a checkpointed block.
+    struct CheckpointVerifier {
+        block_num: BlockNumber,
+        commitment: Word,
+    }
+
+    #[async_trait(?Send)]
+    impl BlockVerifier for CheckpointVerifier {
+        async fn verify(
+            &self,
+            block_header: &BlockHeader,
+            _previous_header: Option<&BlockHeader>,
+        ) -> Result<(), ClientError> {
+            if block_header.block_num() == self.block_num
+                && block_header.commitment() != self.commitment
+            {
+                return Err(ClientError::BlockVerificationFailed {
+                    block_num: block_header.block_num(),
+                    reason: "header doesn't match the checkpoint".to_string(),
+                });
+            }
+            Ok(())
+        }
+    }
+
+    let (builder, rpc_api, _) = Box::pin(create_test_client_builder()).await;
+    let chain_tip = rpc_api.get_chain_tip_block_num();
+    // The checkpoint commits to a header of a different chain, so the node's header is a fork.
+    let forked_header = MockChain::new().block_header(0);
+    let mut client = builder
+        .block_verifier(Arc::new(CheckpointVerifier {
+            block_num: chain_tip,
+            commitment: forked_header.commitment(),
+        }))
+        .build()
+        .await
+        .unwrap();
 
-    consume_note_and_prove(&mock_rpc, account_id, note_second).await;
+    let err = client.sync_state().await.unwrap_err();
+    assert!(matches!(
+        err,
+        ClientError::BlockVerificationFailed { block_num, .. } if block_num == chain_tip
+    ));
+    assert_eq!(client.get_sync_height().await.unwrap(), BlockNumber::GENESIS);
+    assert!(client.get_block_header_by_num(chain_tip).await.unwrap().is_none());
+
+    // With the node's header as the checkpoint, the sync goes through.
+    let (builder, rpc_api, _) = Box::pin(create_test_client_builder()).await;
+    let (tip_header, _) = rpc_api.get_block_header_by_number(None, false).await.unwrap();
+    let mut client = builder
+        .block_verifier(Arc::new(CheckpointVerifier {
+            block_num: chain_tip,
+            commitment: tip_header.commitment(),
+        }))
+        .build()
+        .await
+        .unwrap();
 
-    // With pruning disabled, both tracked blocks must remain across repeated syncs.
-    for _ in 0..5 {
-        mock_rpc.prove_block();
-        client.sync_state().await.unwrap();
-        assert_eq!(
-            client.test_store().get_tracked_block_headers().await.unwrap().len(),
-            2,
-            "no pruning should occur when the prune interval is None",
-        );
-    }
+    client.sync_state().await.unwrap();
+    assert_eq!(client.get_sync_height().await.unwrap(), chain_tip);
 }
 
 #[tokio::test]
@@ -1630,6 +3289,129 @@ async fn p2id_transfer_failing_not_enough_balance() {
     .await;
 }
 
+#[tokio::test]
+async fn export_and_import_note_set() {
+    use miden_client::note::NoteFileSet;
+    use miden_client::store::NoteExportType;
+
+    let (mut client, mock_rpc_api, authenticator) = Box::pin(create_test_client()).await;
+    let (wallet, faucet) = setup_wallet_and_faucet(
+        &mut client,
+        AccountType::Private,
+        &authenticator,
+        RPO_FALCON_SCHEME_ID,
+    )
+    .await
+    .unwrap();
+
+    let (_, first_note) = mint_note(&mut client, wallet.id(), faucet.id(), NoteType::Private).await;
+    mock_rpc_api.prove_block();
+    let (_, second_note) =
+        mint_note(&mut client, wallet.id(), faucet.id(), NoteType::Private).await;
+    mock_rpc_api.prove_block();
+
+    let note_ids = [first_note.id(), second_note.id()];
+    let bytes = client.export_notes(&note_ids, &NoteExportType::NoteDetails).await.unwrap();
+
+    let note_set = NoteFileSet::read_from_bytes(&bytes).unwrap();
+    assert_eq!(note_set.note_files().len(), 2);
+
+    // Exporting a note that isn't tracked fails instead of silently skipping it.
+    let missing_note_id = miden_protocol::note::NoteId::from_raw(Word::from([1u32, 2, 3, 4]));
+    let err = client
+        .export_notes(&[first_note.id(), missing_note_id], &NoteExportType::NoteDetails)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, ClientError::StoreError(StoreError::NoteNotFound(id)) if id == missing_note_id)
+    );
+
+    // Import the whole set into a fresh client.
+    let (mut other_client, ..) = Box::pin(create_test_client()).await;
+    let commitments = other_client.import_note_set(&bytes).await.unwrap();
+    assert_eq!(commitments.len(), 2);
+    for note in [&first_note, &second_note] {
+        assert!(commitments.contains(&note.details_commitment()));
+    }
+
+    // Data that isn't a note set is rejected before anything is imported.
+    let single_note_file = NoteFile::NoteDetails {
+        details: first_note.into(),
+        after_block_num: 0.into(),
+        tag: None,
+    };
+    let err = other_client.import_note_set(&single_note_file.to_bytes()).await.unwrap_err();
+    assert!(matches!(err, ClientError::DataDeserializationError(_)));
+}
+
+#[tokio::test]
+async fn recallable_notes_lists_unconsumed_p2ide_notes() {
+    let (mut client, mock_rpc_api, authenticator) = Box::pin(create_test_client()).await;
+    let (sender, recipient, faucet) = setup_two_wallets_and_faucet(
+        &mut client,
+        AccountType::Private,
+        &authenticator,
+        RPO_FALCON_SCHEME_ID,
+    )
+    .await
+    .unwrap();
+
+    let note = mint_note(&mut client, sender.id(), faucet.id(), NoteType::Private).await.1;
+    mock_rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+    consume_notes(&mut client, sender.id(), core::slice::from_ref(&note)).await;
+    mock_rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+
+    // Send a P2IDE note with a reclaim height and a P2ID note, which can't be recalled.
+    let reclaim_height = client.get_sync_height().await.unwrap() + RECALL_HEIGHT_DELTA;
+    let p2ide_request = TransactionRequestBuilder::new()
+        .build_pay_to_id(
+            PaymentNoteDescription::new(
+                vec![Asset::Fungible(FungibleAsset::new(faucet.id(), TRANSFER_AMOUNT).unwrap())],
+                sender.id(),
+                recipient.id(),
+            )
+            .with_reclaim_height(reclaim_height),
+            NoteType::Private,
+            client.rng(),
+        )
+        .unwrap();
+    let p2ide_note = p2ide_request.expected_output_own_notes().pop().unwrap();
+    Box::pin(client.submit_new_transaction(sender.id(), p2ide_request))
+        .await
+        .unwrap();
+
+    let p2id_request = TransactionRequestBuilder::new()
+        .build_pay_to_id(
+            PaymentNoteDescription::new(
+                vec![Asset::Fungible(FungibleAsset::new(faucet.id(), TRANSFER_AMOUNT).unwrap())],
+                sender.id(),
+                recipient.id(),
+            ),
+            NoteType::Private,
+            client.rng(),
+        )
+        .unwrap();
+    Box::pin(client.submit_new_transaction(sender.id(), p2id_request))
+        .await
+        .unwrap();
+    mock_rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+
+    let recallable = client.recallable_notes(sender.id()).await.unwrap();
+    assert_eq!(recallable.len(), 1);
+    assert_eq!(recallable[0].id(), p2ide_note.id());
+    assert_eq!(recallable[0].reclaim_height(), Some(reclaim_height));
+    assert!(client.recallable_notes(recipient.id()).await.unwrap().is_empty());
+
+    // Once the recipient consumes the note, it can't be recalled anymore.
+    consume_notes(&mut client, recipient.id(), &[p2ide_note]).await;
+    mock_rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+    assert!(client.recallable_notes(sender.id()).await.unwrap().is_empty());
+}
+
 #[tokio::test]
 #[allow(clippy::too_many_lines)]
 async fn p2ide_transfer_consumed_by_target() {
@@ -1958,6 +3740,169 @@ async fn p2ide_timelocked() {
     assert_eq!(target_balance, TRANSFER_AMOUNT);
 }
 
+#[tokio::test]
+async fn consume_all_consumable_notes() {
+    let (mut client, mock_rpc_api, authenticator) = Box::pin(create_test_client()).await;
+
+    let (first_regular_account, second_regular_account, faucet_account_header) =
+        setup_two_wallets_and_faucet(
+            &mut client,
+            AccountType::Private,
+            &authenticator,
+            RPO_FALCON_SCHEME_ID,
+        )
+        .await
+        .unwrap();
+
+    let account_id = first_regular_account.id();
+    let faucet_account_id = faucet_account_header.id();
+
+    // Nothing to consume yet.
+    assert!(client.consume_all_consumable_notes(account_id, None).await.unwrap().is_empty());
+
+    mint_note(&mut client, account_id, faucet_account_id, NoteType::Private).await;
+    mint_note(&mut client, account_id, faucet_account_id, NoteType::Private).await;
+    mock_rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+
+    // No note carries an asset from an unrelated account.
+    assert!(
+        client
+            .consume_all_consumable_notes(account_id, Some(second_regular_account.id()))
+            .await
+            .unwrap()
+            .is_empty()
+    );
+
+    // Both notes fit in a single transaction.
+    let transaction_ids = client
+        .consume_all_consumable_notes(account_id, Some(faucet_account_id))
+        .await
+        .unwrap();
+    assert_eq!(transaction_ids.len(), 1);
+    mock_rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+
+    assert!(
+        Box::pin(client.get_consumable_notes(Some(account_id)))
+            .await
+            .unwrap()
+            .is_empty()
+    );
+    let balance = client.account_reader(account_id).get_balance(faucet_account_id).await.unwrap();
+    assert_eq!(balance, 2 * MINT_AMOUNT);
+}
+
+#[tokio::test]
+async fn coalesce_dust_consumes_the_smallest_notes_first() {
+    let (mut client, mock_rpc_api, authenticator) = Box::pin(create_test_client()).await;
+
+    let (regular_account, _, faucet_account_header) = setup_two_wallets_and_faucet(
+        &mut client,
+        AccountType::Private,
+        &authenticator,
+        RPO_FALCON_SCHEME_ID,
+    )
+    .await
+    .unwrap();
+
+    let account_id = regular_account.id();
+    let faucet_account_id = faucet_account_header.id();
+
+    // Nothing to coalesce yet.
+    assert!(matches!(
+        client.coalesce_dust(account_id, faucet_account_id, 2).await,
+        Err(ClientError::NoConsumableNoteForAccount(id)) if id == account_id
+    ));
+
+    let mut largest_note_id = None;
+    for amount in [400u64, 100, 200] {
+        let tx_request = TransactionRequestBuilder::new()
+            .build_mint_fungible_asset(
+                FungibleAsset::new(faucet_account_id, amount).unwrap(),
+                account_id,
+                NoteType::Private,
+                client.rng(),
+            )
+            .unwrap();
+        if amount == 400 {
+            largest_note_id = Some(tx_request.expected_output_own_notes().pop().unwrap().id());
+        }
+        Box::pin(client.submit_new_transaction(faucet_account_id, tx_request))
+            .await
+            .unwrap();
+    }
+    mock_rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+
+    client.coalesce_dust(account_id, faucet_account_id, 2).await.unwrap();
+    mock_rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+
+    // The two smallest notes were consumed and the largest one is left untouched.
+    let balance = client.account_reader(account_id).get_balance(faucet_account_id).await.unwrap();
+    assert_eq!(balance, 300);
+    let remaining_notes = Box::pin(client.get_consumable_notes(Some(account_id))).await.unwrap();
+    assert_eq!(remaining_notes.len(), 1);
+    assert_eq!(remaining_notes[0].0.id(), largest_note_id);
+}
+
+#[tokio::test]
+async fn clients_with_the_same_rng_seed_draw_the_same_values() {
+    const SEED: [u8; 32] = [7; 32];
+
+    let (client, ..) = Box::pin(create_test_client()).await;
+    // The test client is built with a custom RNG, whose seed is unknown.
+    assert_eq!(client.rng_seed(), None);
+
+    let mut draws = Vec::new();
+    for _ in 0..2 {
+        let (builder, ..) = Box::pin(create_test_client_builder()).await;
+        let mut client = builder.with_rng_seed(SEED).build().await.unwrap();
+        assert_eq!(client.rng_seed(), Some(SEED));
+        draws.push((client.rng().draw_word(), client.rng().draw_word()));
+    }
+    assert_eq!(draws[0], draws[1]);
+}
+
+#[tokio::test]
+async fn transaction_records_execution_cycles() {
+    let (mut client, mock_rpc_api, authenticator) = Box::pin(create_test_client()).await;
+
+    let (regular_account, _, faucet_account_header) = setup_two_wallets_and_faucet(
+        &mut client,
+        AccountType::Private,
+        &authenticator,
+        RPO_FALCON_SCHEME_ID,
+    )
+    .await
+    .unwrap();
+
+    let (tx_id, _) =
+        mint_note(&mut client, regular_account.id(), faucet_account_header.id(), NoteType::Private)
+            .await;
+
+    let execution_cycles = |transactions: Vec<TransactionRecord>| {
+        transactions
+            .into_iter()
+            .find(|tx| tx.id == tx_id)
+            .unwrap()
+            .details
+            .execution_cycles
+    };
+
+    let cycles = execution_cycles(client.get_transactions(TransactionFilter::All).await.unwrap());
+    assert!(cycles.is_some_and(|cycles| cycles > 0));
+
+    // Status updates made by the sync keep the recorded cycles.
+    mock_rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+    assert_eq!(
+        execution_cycles(client.get_transactions(TransactionFilter::All).await.unwrap()),
+        cycles
+    );
+}
+
 #[tokio::test]
 async fn get_consumable_notes() {
     let (mut client, mock_rpc_api, authenticator) = Box::pin(create_test_client()).await;
@@ -2076,6 +4021,61 @@ async fn get_consumable_notes() {
     }
 }
 
+#[tokio::test]
+async fn network_id_is_cached_and_persisted() {
+    use miden_client::address::NetworkId;
+
+    let (mut client, ..) = Box::pin(create_test_client()).await;
+    assert!(client.test_store().get_network_id().await.unwrap().is_none());
+
+    // The mock RPC API reports testnet.
+    assert_eq!(client.network_id().await.unwrap(), NetworkId::Testnet);
+    assert_eq!(client.test_store().get_network_id().await.unwrap(), Some(NetworkId::Testnet));
+
+    // Once cached, the network ID is served from memory without querying the RPC client.
+    client.test_store().set_network_id(NetworkId::Devnet).await.unwrap();
+    assert_eq!(client.network_id().await.unwrap(), NetworkId::Testnet);
+
+    // Resetting drops both the in-memory and the persisted network ID.
+    client.reset_network_id().await.unwrap();
+    assert!(client.test_store().get_network_id().await.unwrap().is_none());
+    assert_eq!(client.network_id().await.unwrap(), NetworkId::Testnet);
+}
+
+#[tokio::test]
+async fn get_output_note_by_recipient() {
+    let (mut client, _, authenticator) = Box::pin(create_test_client()).await;
+    let (wallet, faucet) = setup_wallet_and_faucet(
+        &mut client,
+        AccountType::Private,
+        &authenticator,
+        RPO_FALCON_SCHEME_ID,
+    )
+    .await
+    .unwrap();
+
+    let note = mint_note(&mut client, wallet.id(), faucet.id(), NoteType::Private).await.1;
+    let recipient_digest = note.recipient().digest();
+
+    let output_notes =
+        client.get_output_notes(NoteFilter::Recipient(recipient_digest)).await.unwrap();
+    assert_eq!(output_notes.len(), 1);
+    assert_eq!(output_notes[0].id(), note.id());
+
+    let output_note = client.get_output_note_by_recipient(recipient_digest).await.unwrap().unwrap();
+    assert_eq!(output_note.recipient_digest(), recipient_digest);
+
+    // The filter doesn't apply to input notes.
+    assert!(
+        client
+            .get_input_notes(NoteFilter::Recipient(recipient_digest))
+            .await
+            .unwrap()
+            .is_empty()
+    );
+    assert!(client.get_output_note_by_recipient(Word::default()).await.unwrap().is_none());
+}
+
 #[tokio::test]
 async fn get_output_notes() {
     let (mut client, mock_rpc_api, authenticator) = Box::pin(create_test_client()).await;
@@ -2129,20 +4129,103 @@ async fn get_output_notes() {
         )
         .unwrap();
 
-    let output_note_id = tx_request.expected_output_own_notes().pop().unwrap().id();
+    let output_note_id = tx_request.expected_output_own_notes().pop().unwrap().id();
+
+    // Before executing, the output note is not found
+    assert!(client.get_output_note(output_note_id).await.unwrap().is_none());
+
+    Box::pin(client.submit_new_transaction(from_account_id, tx_request))
+        .await
+        .unwrap();
+    mock_rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+
+    // After executing, the note is only found in output notes
+    assert!(client.get_output_note(output_note_id).await.unwrap().is_some());
+    assert!(client.get_input_note(output_note_id).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn get_unconsumed_output_notes() {
+    let (mut client, mock_rpc_api, authenticator) = Box::pin(create_test_client()).await;
+    let _ = client.sync_state().await.unwrap();
+    let (regular_account, faucet_account_header) = setup_wallet_and_faucet(
+        &mut client,
+        AccountType::Private,
+        &authenticator,
+        RPO_FALCON_SCHEME_ID,
+    )
+    .await
+    .unwrap();
+
+    let account_id = regular_account.id();
+    let faucet_account_id = faucet_account_header.id();
+
+    assert!(client.get_unconsumed_output_notes().await.unwrap().is_empty());
+
+    let note = mint_note(&mut client, account_id, faucet_account_id, NoteType::Private).await.1;
+    mock_rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+
+    // The mint note was created by the faucet and nobody has consumed it yet
+    let unconsumed = client.get_unconsumed_output_notes().await.unwrap();
+    assert_eq!(unconsumed.len(), 1);
+    assert_eq!(unconsumed[0].id(), note.id());
 
-    // Before executing, the output note is not found
-    assert!(client.get_output_note(output_note_id).await.unwrap().is_none());
+    consume_notes(&mut client, account_id, &[note]).await;
+    mock_rpc_api.prove_block();
+    client.sync_state().await.unwrap();
 
-    Box::pin(client.submit_new_transaction(from_account_id, tx_request))
+    // Once the nullifier is synced, the note is no longer pending
+    assert!(client.get_unconsumed_output_notes().await.unwrap().is_empty());
+    assert_eq!(client.get_output_notes(NoteFilter::Consumed).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn transaction_categories() {
+    let (mut client, mock_rpc_api, authenticator) = Box::pin(create_test_client()).await;
+    let _ = client.sync_state().await.unwrap();
+    let (regular_account, faucet_account_header) = setup_wallet_and_faucet(
+        &mut client,
+        AccountType::Private,
+        &authenticator,
+        RPO_FALCON_SCHEME_ID,
+    )
+    .await
+    .unwrap();
+
+    let (tx_id, _) =
+        mint_note(&mut client, regular_account.id(), faucet_account_header.id(), NoteType::Private)
+            .await;
+    assert_eq!(client.get_transaction_category(tx_id).await.unwrap(), None);
+
+    client
+        .set_transaction_category(tx_id, Some("salary".to_string()))
         .await
         .unwrap();
+    assert_eq!(client.get_transaction_category(tx_id).await.unwrap().as_deref(), Some("salary"));
+
+    // The category survives status updates applied during sync
     mock_rpc_api.prove_block();
     client.sync_state().await.unwrap();
 
-    // After executing, the note is only found in output notes
-    assert!(client.get_output_note(output_note_id).await.unwrap().is_some());
-    assert!(client.get_input_note(output_note_id).await.unwrap().is_none());
+    let salary_txs = client
+        .get_transactions(TransactionFilter::Category("salary".to_string()))
+        .await
+        .unwrap();
+    assert_eq!(salary_txs.len(), 1);
+    assert_eq!(salary_txs[0].id, tx_id);
+    assert!(matches!(salary_txs[0].status, TransactionStatus::Committed { .. }));
+    assert!(
+        client
+            .get_transactions(TransactionFilter::Category("refund".to_string()))
+            .await
+            .unwrap()
+            .is_empty()
+    );
+
+    client.set_transaction_category(tx_id, None).await.unwrap();
+    assert_eq!(client.get_transaction_category(tx_id).await.unwrap(), None);
 }
 
 #[tokio::test]
@@ -2615,6 +4698,69 @@ async fn swap_chain_test() {
     assert_eq!(last_wallet_balance, 1);
 }
 
+#[tokio::test]
+async fn get_swap_notes_decodes_swap_terms() {
+    let (mut client, mock_rpc_api, keystore) = Box::pin(create_test_client()).await;
+    client.sync_state().await.unwrap();
+
+    let (wallet_a, faucet_a) =
+        setup_wallet_and_faucet(&mut client, AccountType::Private, &keystore, RPO_FALCON_SCHEME_ID)
+            .await
+            .unwrap();
+    let (wallet_b, faucet_b) =
+        setup_wallet_and_faucet(&mut client, AccountType::Private, &keystore, RPO_FALCON_SCHEME_ID)
+            .await
+            .unwrap();
+
+    mint_and_consume(&mut client, wallet_a.id(), faucet_a.id(), NoteType::Private).await;
+    mock_rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+
+    // Wallet A offers 10 units of faucet A in exchange for 25 units of faucet B.
+    let offered_asset = Asset::Fungible(FungibleAsset::new(faucet_a.id(), 10).unwrap());
+    let requested_asset = Asset::Fungible(FungibleAsset::new(faucet_b.id(), 25).unwrap());
+    let swap_tx_request = TransactionRequestBuilder::new()
+        .build_swap(
+            &SwapTransactionData::new(wallet_a.id(), offered_asset, requested_asset),
+            NoteType::Private,
+            NoteType::Private,
+            client.rng(),
+        )
+        .unwrap();
+    let swap_note = swap_tx_request.expected_output_own_notes()[0].clone();
+
+    Box::pin(client.submit_new_transaction(wallet_a.id(), swap_tx_request))
+        .await
+        .unwrap();
+    mock_rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+
+    // The private SWAP note reaches the counterparty as an imported input note.
+    let inclusion_proof = client
+        .get_output_note(swap_note.id())
+        .await
+        .unwrap()
+        .unwrap()
+        .inclusion_proof()
+        .cloned()
+        .unwrap();
+    client
+        .import_notes(&[NoteFile::NoteWithProof(swap_note.clone(), inclusion_proof)])
+        .await
+        .unwrap();
+
+    let swap_notes = client.get_swap_notes(wallet_b.id()).await.unwrap();
+    assert_eq!(swap_notes.len(), 1);
+    let (note, swap_data) = &swap_notes[0];
+    assert_eq!(note.id(), Some(swap_note.id()));
+    assert_eq!(swap_data.account_id(), wallet_a.id());
+    assert_eq!(swap_data.offered_asset(), offered_asset);
+    assert_eq!(swap_data.requested_asset(), requested_asset);
+
+    // The creator of the swap can't fill it.
+    assert!(client.get_swap_notes(wallet_a.id()).await.unwrap().is_empty());
+}
+
 /// Tests that partial output notes (created when a SWAP note is consumed) are correctly included in
 /// `NoteFilter::Unspent` and receive inclusion proofs during sync, transitioning from
 /// `ExpectedPartial` to `CommittedPartial` state.
@@ -3713,8 +5859,282 @@ async fn storage_and_vault_proofs() {
         )
         .unwrap();
 
-    let key_pair = AuthSecretKey::new_falcon512_poseidon2();
-    let pub_key = key_pair.public_key();
+    let key_pair = AuthSecretKey::new_falcon512_poseidon2();
+    let pub_key = key_pair.public_key();
+
+    let mut init_seed = [0u8; 32];
+    client.rng().fill_bytes(&mut init_seed);
+
+    let account = AccountBuilder::new(init_seed)
+        .account_type(AccountType::Public)
+        .with_auth_component(AuthSingleSig::new(
+            pub_key.to_commitment(),
+            AuthSchemeId::Falcon512Poseidon2,
+        ))
+        .with_component(BasicWallet)
+        .with_component(bump_item_component)
+        .build_with_schema_commitment()
+        .unwrap();
+
+    keystore.add_key(&key_pair, account.id()).await.unwrap();
+
+    client.add_account(&account, false).await.unwrap();
+
+    let account_id = account.id();
+
+    // Add assets and modify storage map multiple times
+    for _ in 0..5 {
+        let faucet_account =
+            insert_new_fungible_faucet(&mut client, AccountType::Public, &keystore)
+                .await
+                .unwrap();
+
+        let faucet_account_id = faucet_account.id();
+
+        mint_and_consume(&mut client, account_id, faucet_account_id, NoteType::Private).await;
+        mock_rpc_api.prove_block();
+        client.sync_state().await.unwrap();
+
+        let tx_request = TransactionRequestBuilder::new()
+            .custom_script(tx_script.clone())
+            .build()
+            .unwrap();
+        Box::pin(client.submit_new_transaction(account_id, tx_request)).await.unwrap();
+        mock_rpc_api.prove_block();
+        client.sync_state().await.unwrap();
+
+        // Check that retrieved vault and storage match with the account.
+        let account_reader = client.account_reader(account_id);
+        let account_storage_commitment = account_reader.storage_commitment().await.unwrap();
+        let account_vault_root = account_reader.vault_root().await.unwrap();
+
+        let storage = client
+            .test_store()
+            .get_account_storage(account_id, AccountStorageFilter::All)
+            .await
+            .unwrap();
+        let vault = client.test_store().get_account_vault(account_id).await.unwrap();
+
+        assert_eq!(account_storage_commitment, storage.to_commitment());
+        assert_eq!(account_vault_root, vault.root());
+
+        // Check that specific asset proof matches the one in the vault
+        let vault_key = AssetVaultKey::new_fungible(faucet_account_id, AssetCallbackFlag::Disabled);
+        let (asset, witness) = client
+            .test_store()
+            .get_account_asset(account_id, vault_key)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let expected_witness = AssetWitness::new(vault.open(asset.vault_key()).into()).unwrap();
+        assert_eq!(witness, expected_witness);
+
+        // Check that specific map item proof matches the one in the storage
+        let (value, proof) = client
+            .test_store()
+            .get_account_map_item(
+                account_id,
+                bump_map_slot_name.clone(),
+                StorageMapKey::new(MAP_KEY.into()),
+            )
+            .await
+            .unwrap();
+
+        let map_slot = storage
+            .slots()
+            .iter()
+            .find(|slot| slot.name() == &bump_map_slot_name)
+            .expect("storage should contain bump map slot");
+        let StorageSlotContent::Map(map) = map_slot.content() else {
+            panic!("Expected bump map slot content to be a map");
+        };
+
+        assert_eq!(value, map.get(&StorageMapKey::new(MAP_KEY.into())));
+        assert_eq!(proof, map.open(&StorageMapKey::new(MAP_KEY.into())));
+    }
+}
+
+#[tokio::test]
+async fn faucet_metadata_registration_and_lookup() {
+    let (mut client, ..) = Box::pin(create_test_client()).await;
+
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_1).unwrap();
+    let other_faucet_id = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_2).unwrap();
+    let asset = FungibleAsset::new(faucet_id, 1_500_000).unwrap();
+
+    // Without metadata, amounts fall back to base units and symbols don't resolve.
+    assert!(client.get_faucet_metadata(faucet_id).await.unwrap().is_none());
+    assert_eq!(
+        client.format_asset_amount(&asset).await.unwrap(),
+        format!("1500000::{}", faucet_id.to_hex())
+    );
+    assert!(client.resolve_faucet("TKN").await.unwrap().is_none());
+
+    client.register_faucet_metadata(faucet_id, "TKN".to_string(), 6).await.unwrap();
+    client
+        .register_faucet_metadata(other_faucet_id, "OTH".to_string(), 2)
+        .await
+        .unwrap();
+
+    assert_eq!(client.resolve_faucet("TKN").await.unwrap(), Some(faucet_id));
+    assert_eq!(client.resolve_faucet("OTH").await.unwrap(), Some(other_faucet_id));
+    assert!(client.resolve_faucet("MISSING").await.unwrap().is_none());
+    assert_eq!(client.format_asset_amount(&asset).await.unwrap(), "1.500000 TKN");
+
+    // Registering again overwrites the stored metadata.
+    client.register_faucet_metadata(faucet_id, "NEW".to_string(), 3).await.unwrap();
+    assert!(client.resolve_faucet("TKN").await.unwrap().is_none());
+    assert_eq!(client.format_asset_amount(&asset).await.unwrap(), "1500.000 NEW");
+}
+
+#[tokio::test]
+async fn known_faucets_are_discovered_from_received_notes() {
+    let (mut client, ..) = Box::pin(create_test_client()).await;
+    assert!(client.known_faucets().await.unwrap().is_empty());
+
+    let faucet_ids = [
+        AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_1).unwrap(),
+        AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_2).unwrap(),
+    ];
+
+    // Receive a note carrying an asset from each faucet.
+    for (serial_num, faucet_id) in (1u32..).zip(faucet_ids) {
+        let recipient = NoteRecipient::new(
+            Word::from([serial_num, 0, 0, 0]),
+            StandardNote::SWAP.script(),
+            NoteStorage::new(vec![]).unwrap(),
+        );
+        let assets =
+            NoteAssets::new(vec![FungibleAsset::new(faucet_id, 10).unwrap().into()]).unwrap();
+        client
+            .import_notes(&[NoteFile::NoteDetails {
+                details: NoteDetails::new(assets, recipient),
+                after_block_num: 0.into(),
+                tag: None,
+            }])
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(client.known_faucets().await.unwrap(), BTreeSet::from(faucet_ids));
+}
+
+#[tokio::test]
+async fn account_summary_reports_balances_and_pending_state() {
+    let (mut client, mock_rpc_api, keystore) = Box::pin(create_test_client()).await;
+    let wallet = insert_new_wallet(&mut client, AccountType::Private, &keystore).await.unwrap();
+    let faucet = insert_new_fungible_faucet(&mut client, AccountType::Private, &keystore)
+        .await
+        .unwrap();
+
+    mock_rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+
+    // Mint two notes for the wallet.
+    let mut note_ids = Vec::new();
+    for _ in 0..2 {
+        let transaction_request = TransactionRequestBuilder::new()
+            .build_mint_fungible_asset(
+                FungibleAsset::new(faucet.id(), 5u64).unwrap(),
+                wallet.id(),
+                NoteType::Public,
+                client.rng(),
+            )
+            .unwrap();
+        note_ids.push(transaction_request.expected_output_own_notes().pop().unwrap().id());
+        Box::pin(client.submit_new_transaction(faucet.id(), transaction_request))
+            .await
+            .unwrap();
+        mock_rpc_api.prove_block();
+        client.sync_state().await.unwrap();
+    }
+
+    let summary = client.account_summary(wallet.id()).await.unwrap();
+    assert_eq!(summary.account_id, wallet.id());
+    assert!(summary.balances.is_empty());
+    assert_eq!(summary.pending_transactions, 0);
+    assert_eq!(summary.consumable_notes, 2);
+
+    // Consume the first note and let the transaction be committed.
+    let note = client.get_input_note(note_ids[0]).await.unwrap().unwrap();
+    let transaction_request = TransactionRequestBuilder::new()
+        .build_consume_notes(vec![note.try_into().unwrap()])
+        .unwrap();
+    Box::pin(client.submit_new_transaction(wallet.id(), transaction_request))
+        .await
+        .unwrap();
+    mock_rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+
+    // Consume the second note, leaving the transaction pending.
+    let note = client.get_input_note(note_ids[1]).await.unwrap().unwrap();
+    let transaction_request = TransactionRequestBuilder::new()
+        .build_consume_notes(vec![note.try_into().unwrap()])
+        .unwrap();
+    Box::pin(client.submit_new_transaction(wallet.id(), transaction_request))
+        .await
+        .unwrap();
+
+    let summary = client.account_summary(wallet.id()).await.unwrap();
+    assert_eq!(summary.nonce, client.account_reader(wallet.id()).nonce().await.unwrap());
+    assert_eq!(
+        summary.balances,
+        BTreeMap::from([(faucet.id(), FaucetBalance { committed: 5, available: 10 })])
+    );
+    assert_eq!(summary.pending_transactions, 1);
+    assert_eq!(summary.consumable_notes, 0);
+
+    // Once the transaction is committed, both balances match.
+    mock_rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+
+    let summary = client.account_summary(wallet.id()).await.unwrap();
+    assert_eq!(
+        summary.balances,
+        BTreeMap::from([(faucet.id(), FaucetBalance { committed: 10, available: 10 })])
+    );
+    assert_eq!(summary.pending_transactions, 0);
+}
+
+#[tokio::test]
+async fn account_code_source_registration_and_lookup() {
+    let (mut client, ..) = Box::pin(create_test_client()).await;
+
+    let account = Account::mock(
+        ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_2,
+        AuthSingleSig::new(PublicKeyCommitment::from(EMPTY_WORD), AuthSchemeId::Falcon512Poseidon2),
+    );
+
+    // Untracked accounts have no source.
+    assert!(client.get_account_code_source(account.id()).await.unwrap().is_none());
+
+    client.add_account(&account, false).await.unwrap();
+    assert!(client.get_account_code_source(account.id()).await.unwrap().is_none());
+
+    let source = "pub proc foo\n    push.1 drop\nend\n".to_string();
+    client
+        .register_account_code_source(account.code().commitment(), source.clone())
+        .await
+        .unwrap();
+    assert_eq!(client.get_account_code_source(account.id()).await.unwrap(), Some(source));
+}
+
+#[tokio::test]
+async fn account_code_source_is_captured_by_the_client_code_builder() {
+    let (mut client, ..) = Box::pin(create_test_client()).await;
+
+    let source = "pub proc get_answer\n    push.42\nend\n";
+    let component_code = client
+        .code_builder()
+        .compile_component_code("miden::testing::answer_component", source)
+        .unwrap();
+    let component = AccountComponent::new(
+        component_code,
+        vec![],
+        AccountComponentMetadata::new("miden::testing::answer_component"),
+    )
+    .unwrap();
 
     let mut init_seed = [0u8; 32];
     client.rng().fill_bytes(&mut init_seed);
@@ -3722,91 +6142,19 @@ async fn storage_and_vault_proofs() {
     let account = AccountBuilder::new(init_seed)
         .account_type(AccountType::Public)
         .with_auth_component(AuthSingleSig::new(
-            pub_key.to_commitment(),
+            PublicKeyCommitment::from(EMPTY_WORD),
             AuthSchemeId::Falcon512Poseidon2,
         ))
-        .with_component(BasicWallet)
-        .with_component(bump_item_component)
+        .with_component(component)
         .build_with_schema_commitment()
         .unwrap();
 
-    keystore.add_key(&key_pair, account.id()).await.unwrap();
-
     client.add_account(&account, false).await.unwrap();
 
-    let account_id = account.id();
-
-    // Add assets and modify storage map multiple times
-    for _ in 0..5 {
-        let faucet_account =
-            insert_new_fungible_faucet(&mut client, AccountType::Public, &keystore)
-                .await
-                .unwrap();
-
-        let faucet_account_id = faucet_account.id();
-
-        mint_and_consume(&mut client, account_id, faucet_account_id, NoteType::Private).await;
-        mock_rpc_api.prove_block();
-        client.sync_state().await.unwrap();
-
-        let tx_request = TransactionRequestBuilder::new()
-            .custom_script(tx_script.clone())
-            .build()
-            .unwrap();
-        Box::pin(client.submit_new_transaction(account_id, tx_request)).await.unwrap();
-        mock_rpc_api.prove_block();
-        client.sync_state().await.unwrap();
-
-        // Check that retrieved vault and storage match with the account.
-        let account_reader = client.account_reader(account_id);
-        let account_storage_commitment = account_reader.storage_commitment().await.unwrap();
-        let account_vault_root = account_reader.vault_root().await.unwrap();
-
-        let storage = client
-            .test_store()
-            .get_account_storage(account_id, AccountStorageFilter::All)
-            .await
-            .unwrap();
-        let vault = client.test_store().get_account_vault(account_id).await.unwrap();
-
-        assert_eq!(account_storage_commitment, storage.to_commitment());
-        assert_eq!(account_vault_root, vault.root());
-
-        // Check that specific asset proof matches the one in the vault
-        let vault_key = AssetVaultKey::new_fungible(faucet_account_id, AssetCallbackFlag::Disabled);
-        let (asset, witness) = client
-            .test_store()
-            .get_account_asset(account_id, vault_key)
-            .await
-            .unwrap()
-            .unwrap();
-
-        let expected_witness = AssetWitness::new(vault.open(asset.vault_key()).into()).unwrap();
-        assert_eq!(witness, expected_witness);
-
-        // Check that specific map item proof matches the one in the storage
-        let (value, proof) = client
-            .test_store()
-            .get_account_map_item(
-                account_id,
-                bump_map_slot_name.clone(),
-                StorageMapKey::new(MAP_KEY.into()),
-            )
-            .await
-            .unwrap();
-
-        let map_slot = storage
-            .slots()
-            .iter()
-            .find(|slot| slot.name() == &bump_map_slot_name)
-            .expect("storage should contain bump map slot");
-        let StorageSlotContent::Map(map) = map_slot.content() else {
-            panic!("Expected bump map slot content to be a map");
-        };
-
-        assert_eq!(value, map.get(&StorageMapKey::new(MAP_KEY.into())));
-        assert_eq!(proof, map.open(&StorageMapKey::new(MAP_KEY.into())));
-    }
+    assert_eq!(
+        client.get_account_code_source(account.id()).await.unwrap(),
+        Some(source.to_string())
+    );
 }
 
 #[tokio::test]
@@ -3891,6 +6239,45 @@ async fn account_add_address_after_creation() {
     assert!(note_tags.contains(&note_tag_record));
 }
 
+#[tokio::test]
+async fn new_address_validates_interface_and_tracks_tag() {
+    let (mut client, _rpc_api, _) = Box::pin(create_test_client()).await;
+
+    // `Account::mock` includes the basic wallet component.
+    let wallet = Account::mock(
+        ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_2,
+        AuthSingleSig::new(PublicKeyCommitment::from(EMPTY_WORD), AuthSchemeId::Falcon512Poseidon2),
+    );
+    let faucet = Account::mock_non_fungible_faucet(ACCOUNT_ID_PUBLIC_NON_FUNGIBLE_FAUCET);
+    client.add_account(&wallet, false).await.unwrap();
+    client.add_account(&faucet, false).await.unwrap();
+
+    let routing_params = RoutingParameters::new(AddressInterface::BasicWallet)
+        .with_note_tag_len(NoteTag::MAX_ACCOUNT_TARGET_TAG_LENGTH)
+        .unwrap();
+    let address = client.new_address(wallet.id(), routing_params.clone()).await.unwrap();
+
+    assert_eq!(
+        address,
+        Address::new(wallet.id()).with_routing_parameters(routing_params.clone())
+    );
+    assert!(client.account_reader(wallet.id()).addresses().await.unwrap().contains(&address));
+    let note_tag_record = NoteTagRecord::with_account_source(address.to_note_tag(), wallet.id());
+    assert!(client.get_note_tags().await.unwrap().contains(&note_tag_record));
+
+    // Creating the same address twice fails.
+    let err = client.new_address(wallet.id(), routing_params.clone()).await.unwrap_err();
+    assert!(matches!(err, ClientError::AddressAlreadyTracked(_)));
+
+    // The faucet doesn't expose the basic wallet interface.
+    let err = client.new_address(faucet.id(), routing_params).await.unwrap_err();
+    assert!(matches!(
+        err,
+        ClientError::IncompatibleAddressInterface { account_id, .. } if account_id == faucet.id()
+    ));
+    assert_eq!(client.account_reader(faucet.id()).addresses().await.unwrap().len(), 1);
+}
+
 #[tokio::test]
 async fn import_watched_account_by_id_rejects_already_tracked_native_account() {
     let mut mock_chain_builder = MockChainBuilder::new();
@@ -4333,73 +6720,262 @@ async fn sync_large_public_account() {
     )
     .await
     .unwrap();
-    mock_chain.add_pending_executed_transaction(&tx).unwrap();
-    mock_chain.prove_next_block().unwrap();
-
-    // 3. Create MockRpcApi with a low oversize threshold so both the storage map
-    // and vault trigger the `too_many_entries` / `too_many_assets` flags.
-    let rpc_api = MockRpcApi::new(mock_chain).with_oversize_threshold(OVERSIZE_THRESHOLD);
-    let arc_rpc_api = Arc::new(rpc_api.clone());
+    mock_chain.add_pending_executed_transaction(&tx).unwrap();
+    mock_chain.prove_next_block().unwrap();
+
+    // 3. Create MockRpcApi with a low oversize threshold so both the storage map
+    // and vault trigger the `too_many_entries` / `too_many_assets` flags.
+    let rpc_api = MockRpcApi::new(mock_chain).with_oversize_threshold(OVERSIZE_THRESHOLD);
+    let arc_rpc_api = Arc::new(rpc_api.clone());
+
+    // 4. Build a client and add the ORIGINAL (pre-tx) account.
+    // The pre-tx commitment differs from on-chain, which triggers sync.
+    let mut rng = rand::rng();
+    let coin_seed: [u64; 4] = rng.random();
+    let rng = RandomCoin::new(coin_seed.map(|v| Felt::new_unchecked(v >> 1)).into());
+
+    let keystore_path = temp_dir();
+    let keystore = FilesystemKeyStore::new(keystore_path).unwrap();
+
+    let mut client = ClientBuilder::new()
+        .rpc(arc_rpc_api)
+        .rng(Box::new(rng))
+        .sqlite_store(create_test_store_path())
+        .authenticator(Arc::new(keystore))
+        .in_debug_mode(DebugMode::Enabled)
+        .build()
+        .await
+        .unwrap();
+    client.ensure_genesis_in_place().await.unwrap();
+    client.add_account(&original_account, false).await.unwrap();
+
+    // 5. Sync — the client detects a commitment mismatch, fetches full account state.
+    client.sync_state().await.unwrap();
+
+    // 6. Verify the synced account matches the on-chain state.
+    let synced_account: Account = client.get_account(mock_account.id()).await.unwrap().unwrap();
+    let on_chain_account =
+        rpc_api.mock_chain.read().committed_account(mock_account.id()).unwrap().clone();
+
+    assert_eq!(
+        synced_account.to_commitment(),
+        on_chain_account.to_commitment(),
+        "client should have the updated account state after sync"
+    );
+
+    // Verify the storage map entries are preserved.
+    let map_name = StorageSlotName::new("test::large_map").unwrap();
+    let map_slot = synced_account
+        .storage()
+        .slots()
+        .iter()
+        .find(|s| *s.name() == map_name)
+        .expect("large map slot should exist after sync");
+    let StorageSlotContent::Map(map) = map_slot.content() else {
+        panic!("expected map slot content");
+    };
+    assert_eq!(
+        map.entries().count(),
+        usize::try_from(NUM_STORAGE_MAP_ENTRIES_LARGE_ACCOUNT).unwrap(),
+        "all map entries should be preserved after sync"
+    );
+
+    // Verify the vault assets are preserved.
+    let synced_assets: Vec<Asset> = synced_account.vault().assets().collect();
+    assert_eq!(
+        synced_assets.len(),
+        usize::try_from(NUM_FAUCETS_LARGE_ACCOUNT).unwrap(),
+        "all vault assets should be preserved after sync"
+    );
+}
+
+/// Tests that `sync_account_vault_incremental` applies only the vault changes committed since the
+/// account was last synced and advances the account's vault sync cursor.
+#[tokio::test]
+async fn sync_account_vault_incremental_applies_vault_changes() {
+    let sender_id: AccountId = ACCOUNT_ID_PRIVATE_SENDER.try_into().unwrap();
+    let faucet_id: AccountId = ACCOUNT_ID_PRIVATE_FUNGIBLE_FAUCET.try_into().unwrap();
+
+    let mut builder = MockChainBuilder::new();
+    let account = builder.add_existing_mock_account(miden_testing::Auth::IncrNonce).unwrap();
+    let account_id = account.id();
+
+    let asset = Asset::Fungible(FungibleAsset::new(faucet_id, 100u64).unwrap());
+    let p2id_note = builder
+        .add_p2id_note(sender_id, account_id, &[asset], NoteType::Public)
+        .unwrap();
+
+    let mut chain = builder.build().unwrap();
+    chain.prove_next_block().unwrap();
+
+    // The transaction that changes the account's vault is only committed once the client synced.
+    let tx = Box::pin(
+        chain
+            .build_tx_context(
+                TxContextInput::Account(account.clone()),
+                &[],
+                core::slice::from_ref(&p2id_note),
+            )
+            .unwrap()
+            .build()
+            .unwrap()
+            .execute(),
+    )
+    .await
+    .unwrap();
+
+    let rpc_api = MockRpcApi::new(chain);
+    let rng =
+        RandomCoin::new(rand::random::<[u64; 4]>().map(|v| Felt::new_unchecked(v >> 1)).into());
+    let keystore = FilesystemKeyStore::new(temp_dir()).unwrap();
+    let mut client = ClientBuilder::new()
+        .rpc(Arc::new(rpc_api.clone()))
+        .rng(Box::new(rng))
+        .sqlite_store(create_test_store_path())
+        .authenticator(Arc::new(keystore))
+        .in_debug_mode(DebugMode::Enabled)
+        .build()
+        .await
+        .unwrap();
+    client.ensure_genesis_in_place().await.unwrap();
+    client.add_account(&account, false).await.unwrap();
+    client.sync_state().await.unwrap();
+
+    // Nothing changed on chain since the sync.
+    let vault_delta = client.sync_account_vault_incremental(account_id).await.unwrap();
+    assert!(vault_delta.is_empty());
+
+    rpc_api.mock_chain.write().add_pending_executed_transaction(&tx).unwrap();
+    rpc_api.prove_block();
+
+    let vault_delta = client.sync_account_vault_incremental(account_id).await.unwrap();
+    assert_eq!(vault_delta.added_assets().collect::<Vec<_>>(), vec![asset]);
+
+    let synced_account = client.try_get_account(account_id).await.unwrap();
+    let on_chain_account = rpc_api.mock_chain.read().committed_account(account_id).unwrap().clone();
+    assert_eq!(synced_account.to_commitment(), on_chain_account.to_commitment());
+    assert_eq!(client.account_reader(account_id).get_balance(faucet_id).await.unwrap(), 100);
+
+    let cursor: Option<u32> = client
+        .get_setting(account_vault_sync_cursor_setting_key(account_id))
+        .await
+        .unwrap();
+    assert_eq!(cursor, Some(rpc_api.get_chain_tip_block_num().as_u32()));
+
+    // The vault is already up to date with the cursor.
+    let vault_delta = client.sync_account_vault_incremental(account_id).await.unwrap();
+    assert!(vault_delta.is_empty());
+}
+
+/// Tests that after `sync_storage_map_incremental` the stored storage map equals the one fetched
+/// in full from the node.
+#[tokio::test]
+async fn sync_storage_map_incremental_matches_full_refetch() {
+    let (mut client, rpc_api, keystore) = create_test_client().await;
+
+    let mut storage_map = StorageMap::new();
+    storage_map
+        .insert(
+            StorageMapKey::new(MAP_KEY.into()),
+            [Felt::from(0u32), Felt::from(0u32), Felt::from(0u32), Felt::from(1u32)].into(),
+        )
+        .unwrap();
+    let bump_map_code = BUMP_MAP_CODE.replace("{map_key}", &Word::from(MAP_KEY).to_hex());
+    let bump_map_slot_name = StorageSlotName::new(BUMP_MAP_SLOT_NAME).unwrap();
+    let bump_item_component = AccountComponent::new(
+        CodeBuilder::default()
+            .compile_component_code("miden::testing::bump_map_component", bump_map_code.clone())
+            .unwrap(),
+        vec![StorageSlot::with_map(bump_map_slot_name.clone(), storage_map)],
+        AccountComponentMetadata::new("miden::testing::bump_map_component"),
+    )
+    .unwrap();
+    let tx_script = CodeBuilder::new()
+        .with_linked_module("external_contract::bump_item_contract", bump_map_code)
+        .unwrap()
+        .compile_tx_script(
+            "use external_contract::bump_item_contract
+            begin
+                call.bump_item_contract::bump_map_item
+            end",
+        )
+        .unwrap();
 
-    // 4. Build a client and add the ORIGINAL (pre-tx) account.
-    // The pre-tx commitment differs from on-chain, which triggers sync.
-    let mut rng = rand::rng();
-    let coin_seed: [u64; 4] = rng.random();
-    let rng = RandomCoin::new(coin_seed.map(|v| Felt::new_unchecked(v >> 1)).into());
+    let key_pair = AuthSecretKey::new_falcon512_poseidon2();
+    let mut init_seed = [0u8; 32];
+    client.rng().fill_bytes(&mut init_seed);
+    let account = AccountBuilder::new(init_seed)
+        .account_type(AccountType::Public)
+        .with_auth_component(AuthSingleSig::new(
+            key_pair.public_key().to_commitment(),
+            AuthSchemeId::Falcon512Poseidon2,
+        ))
+        .with_component(BasicWallet)
+        .with_component(bump_item_component)
+        .build_with_schema_commitment()
+        .unwrap();
+    let account_id = account.id();
+    keystore.add_key(&key_pair, account_id).await.unwrap();
+    client.add_account(&account, false).await.unwrap();
 
-    let keystore_path = temp_dir();
-    let keystore = FilesystemKeyStore::new(keystore_path).unwrap();
+    let bump_map = async |client: &mut MockClient<FilesystemKeyStore>| {
+        let tx_request = TransactionRequestBuilder::new()
+            .custom_script(tx_script.clone())
+            .build()
+            .unwrap();
+        Box::pin(client.submit_new_transaction(account_id, tx_request)).await.unwrap();
+        rpc_api.prove_block();
+    };
+    bump_map(&mut client).await;
 
-    let mut client = ClientBuilder::new()
-        .rpc(arc_rpc_api)
+    // A second client watches the account and is brought up to date with a regular sync.
+    let rng =
+        RandomCoin::new(rand::random::<[u64; 4]>().map(|v| Felt::new_unchecked(v >> 1)).into());
+    let mut watcher = ClientBuilder::new()
+        .rpc(Arc::new(rpc_api.clone()))
         .rng(Box::new(rng))
         .sqlite_store(create_test_store_path())
-        .authenticator(Arc::new(keystore))
+        .authenticator(Arc::new(FilesystemKeyStore::new(temp_dir()).unwrap()))
         .in_debug_mode(DebugMode::Enabled)
         .build()
         .await
         .unwrap();
-    client.ensure_genesis_in_place().await.unwrap();
-    client.add_account(&original_account, false).await.unwrap();
-
-    // 5. Sync — the client detects a commitment mismatch, fetches full account state.
-    client.sync_state().await.unwrap();
+    watcher.ensure_genesis_in_place().await.unwrap();
+    watcher.import_watched_account_by_id(account_id).await.unwrap();
+    watcher.sync_state().await.unwrap();
 
-    // 6. Verify the synced account matches the on-chain state.
-    let synced_account: Account = client.get_account(mock_account.id()).await.unwrap().unwrap();
-    let on_chain_account =
-        rpc_api.mock_chain.read().committed_account(mock_account.id()).unwrap().clone();
+    // The map changes on chain after the watcher's sync.
+    bump_map(&mut client).await;
 
-    assert_eq!(
-        synced_account.to_commitment(),
-        on_chain_account.to_commitment(),
-        "client should have the updated account state after sync"
-    );
+    let storage_delta = watcher
+        .sync_storage_map_incremental(account_id, bump_map_slot_name.clone())
+        .await
+        .unwrap();
+    assert!(!storage_delta.is_empty());
 
-    // Verify the storage map entries are preserved.
-    let map_name = StorageSlotName::new("test::large_map").unwrap();
-    let map_slot = synced_account
-        .storage()
-        .slots()
-        .iter()
-        .find(|s| *s.name() == map_name)
-        .expect("large map slot should exist after sync");
-    let StorageSlotContent::Map(map) = map_slot.content() else {
-        panic!("expected map slot content");
+    let map_entries = |account: &Account| -> BTreeMap<StorageMapKey, Word> {
+        let map_slot = account
+            .storage()
+            .slots()
+            .iter()
+            .find(|slot| slot.name() == &bump_map_slot_name)
+            .expect("account should contain the bump map slot");
+        let StorageSlotContent::Map(map) = map_slot.content() else {
+            panic!("expected bump map slot content to be a map");
+        };
+        map.entries().map(|(key, value)| (*key, *value)).collect()
     };
-    assert_eq!(
-        map.entries().count(),
-        usize::try_from(NUM_STORAGE_MAP_ENTRIES_LARGE_ACCOUNT).unwrap(),
-        "all map entries should be preserved after sync"
-    );
 
-    // Verify the vault assets are preserved.
-    let synced_assets: Vec<Asset> = synced_account.vault().assets().collect();
-    assert_eq!(
-        synced_assets.len(),
-        usize::try_from(NUM_FAUCETS_LARGE_ACCOUNT).unwrap(),
-        "all vault assets should be preserved after sync"
-    );
+    let synced_account = watcher.try_get_account(account_id).await.unwrap();
+    let refetched_account = rpc_api.get_account_details(account_id).await.unwrap().unwrap();
+    assert_eq!(map_entries(&synced_account), map_entries(&refetched_account));
+    assert_eq!(synced_account.to_commitment(), refetched_account.to_commitment());
+
+    let cursor: Option<u32> = watcher
+        .get_setting(storage_map_sync_cursor_setting_key(account_id, &bump_map_slot_name))
+        .await
+        .unwrap();
+    assert_eq!(cursor, Some(rpc_api.get_chain_tip_block_num().as_u32()));
 }
 
 #[tokio::test]
@@ -4445,6 +7021,104 @@ async fn prepare_offline_bootstrap_inserts_mock_chain_genesis() {
     assert_eq!(stored_genesis.tx_kernel_commitment(), TransactionKernel.to_commitment());
 }
 
+// FEE PARAMETER TESTS
+// ================================================================================================
+
+#[tokio::test]
+async fn fee_parameters_are_cached_until_refresh_interval_elapses() {
+    let (builder, rpc_api, _) = Box::pin(create_test_client_builder()).await;
+    let mut client = builder.fee_parameters_refresh_interval(2).build().await.unwrap();
+    client.sync_state().await.unwrap();
+
+    let expected = rpc_api.get_fee_parameters().await.unwrap();
+    let calls_before = rpc_api.get_fee_parameters_call_count();
+
+    // The first call fetches the parameters from the node, later ones reuse the cached value.
+    assert_eq!(client.get_fee_parameters().await.unwrap(), expected);
+    assert_eq!(client.get_fee_parameters().await.unwrap(), expected);
+    assert_eq!(rpc_api.get_fee_parameters_call_count() - calls_before, 1);
+
+    // Syncing fewer blocks than the refresh interval keeps the cached value.
+    rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+    client.get_fee_parameters().await.unwrap();
+    assert_eq!(rpc_api.get_fee_parameters_call_count() - calls_before, 1);
+
+    // Once the interval elapses the parameters are fetched again.
+    rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+    assert_eq!(client.get_fee_parameters().await.unwrap(), expected);
+    assert_eq!(rpc_api.get_fee_parameters_call_count() - calls_before, 2);
+}
+
+// RPC LOGGING TESTS
+// ================================================================================================
+
+/// Writer that collects everything written to it, to capture the output of a subscriber.
+#[derive(Clone, Default)]
+struct CapturedLogs(Arc<std::sync::Mutex<Vec<u8>>>);
+
+impl std::io::Write for CapturedLogs {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn rpc_logging_logs_each_call() {
+    let logs = CapturedLogs::default();
+    let writer = logs.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::DEBUG)
+        .with_ansi(false)
+        .with_writer(move || writer.clone())
+        .finish();
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let (builder, ..) = Box::pin(create_test_client_builder()).await;
+    let client = builder.with_rpc_logging().build().await.unwrap();
+    client.get_fee_parameters().await.unwrap();
+
+    let logs = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+    let line = logs
+        .lines()
+        .find(|line| line.contains("get_fee_parameters"))
+        .expect("the call should be logged");
+    assert!(line.contains("RPC call succeeded"));
+    assert!(line.contains("latency="));
+}
+
+// CUSTOM STORE TESTS
+// ================================================================================================
+
+#[tokio::test]
+async fn builder_uses_provided_store() {
+    let store: Arc<dyn Store> = Arc::new(SqliteStore::new(create_test_store_path()).await.unwrap());
+    let rpc_api = MockRpcApi::new(Box::pin(create_prebuilt_mock_chain()).await);
+    let keystore = FilesystemKeyStore::new(temp_dir()).unwrap();
+
+    let mut client = ClientBuilder::new()
+        .rpc(Arc::new(rpc_api))
+        .store(store.clone())
+        .authenticator(Arc::new(keystore))
+        .build()
+        .await
+        .unwrap();
+
+    // Writes made through the client land in the provided store.
+    let tag: NoteTag = 42.into();
+    client.add_note_tag(tag).await.unwrap();
+    assert_eq!(
+        store.get_note_tags().await.unwrap(),
+        vec![NoteTagRecord { tag, source: NoteTagSource::User }]
+    );
+}
+
 // HELPERS
 // ================================================================================================
 
@@ -4925,3 +7599,241 @@ async fn execute_transaction_fails_for_watched_account() {
         other => panic!("expected AccountIsWatched, got {other:?}"),
     }
 }
+
+#[tokio::test]
+async fn execute_transaction_fails_for_watch_only_account() {
+    let (mut client, _rpc_api, keystore) = Box::pin(create_test_client()).await;
+    let wallet = Box::pin(insert_new_wallet(&mut client, AccountType::Private, &keystore))
+        .await
+        .unwrap();
+
+    client.set_account_watch_only(wallet.id(), true).await.unwrap();
+
+    // The sender is rejected before the request is prepared or the executor is built.
+    let tx_request = TransactionRequestBuilder::new().build().unwrap();
+    let result = Box::pin(client.execute_transaction(wallet.id(), tx_request.clone())).await;
+    match result {
+        Err(ClientError::WatchOnlyAccount(id)) => assert_eq!(id, wallet.id()),
+        other => panic!("expected WatchOnlyAccount, got {other:?}"),
+    }
+
+    // Clearing the mark makes the account usable as a sender again.
+    client.set_account_watch_only(wallet.id(), false).await.unwrap();
+    Box::pin(client.execute_transaction(wallet.id(), tx_request)).await.unwrap();
+}
+
+#[tokio::test]
+async fn encrypted_filesystem_keystore() {
+    let keys_directory = temp_dir().join(format!("keystore-{}", rand::random::<u64>()));
+    let account_id = AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
+
+    // Start with a plaintext keystore holding one key.
+    let plaintext = FilesystemKeyStore::new(keys_directory.clone()).unwrap();
+    let plaintext_key = AuthSecretKey::new_falcon512_poseidon2();
+    let plaintext_commitment = plaintext_key.public_key().to_commitment();
+    plaintext.add_key(&plaintext_key, account_id).await.unwrap();
+    assert!(!FilesystemKeyStore::is_encrypted(&keys_directory));
+
+    // Migrate it to an encrypted keystore.
+    let encrypted =
+        FilesystemKeyStore::encrypt_keystore(keys_directory.clone(), "passphrase").unwrap();
+    assert!(FilesystemKeyStore::is_encrypted(&keys_directory));
+
+    let key_file = keys_directory.join(Word::from(plaintext_commitment).to_hex());
+    assert_ne!(std::fs::read(&key_file).unwrap(), plaintext_key.to_bytes());
+    assert_eq!(
+        encrypted.get_key(plaintext_commitment).await.unwrap().unwrap().to_bytes(),
+        plaintext_key.to_bytes()
+    );
+
+    // New keys are encrypted as well.
+    let new_key = AuthSecretKey::new_falcon512_poseidon2();
+    let new_commitment = new_key.public_key().to_commitment();
+    encrypted.add_key(&new_key, account_id).await.unwrap();
+
+    let reopened = FilesystemKeyStore::new_encrypted(keys_directory.clone(), "passphrase").unwrap();
+    assert_eq!(
+        reopened.get_key(new_commitment).await.unwrap().unwrap().to_bytes(),
+        new_key.to_bytes()
+    );
+    assert_eq!(reopened.get_account_key_commitments(&account_id).await.unwrap().len(), 2);
+
+    // A plaintext key file written behind the keystore's back is rejected.
+    let planted_key = AuthSecretKey::new_falcon512_poseidon2();
+    let planted_commitment = planted_key.public_key().to_commitment();
+    std::fs::write(
+        keys_directory.join(Word::from(planted_commitment).to_hex()),
+        planted_key.to_bytes(),
+    )
+    .unwrap();
+    assert!(reopened.get_key(planted_commitment).await.is_err());
+
+    // The keystore can't be opened without the right passphrase.
+    assert!(FilesystemKeyStore::new(keys_directory.clone()).is_err());
+    assert!(FilesystemKeyStore::new_encrypted(keys_directory.clone(), "wrong").is_err());
+    assert!(FilesystemKeyStore::encrypt_keystore(keys_directory.clone(), "passphrase").is_err());
+
+    std::fs::remove_dir_all(keys_directory).unwrap();
+}
+
+#[tokio::test]
+async fn composite_authenticator_routes_signatures_to_the_backend_holding_the_key() {
+    let primary = FilesystemKeyStore::new(
+        temp_dir().join(format!("composite-primary-{}", rand::random::<u64>())),
+    )
+    .unwrap();
+    let secondary = FilesystemKeyStore::new(
+        temp_dir().join(format!("composite-secondary-{}", rand::random::<u64>())),
+    )
+    .unwrap();
+
+    let rpc_api = MockRpcApi::new(Box::pin(create_prebuilt_mock_chain()).await);
+    let mut client = ClientBuilder::new()
+        .rpc(Arc::new(rpc_api))
+        .sqlite_store(create_test_store_path())
+        .authenticator(Arc::new(CompositeAuthenticator::new(primary.clone())))
+        .add_authenticator(Arc::new(secondary.clone()))
+        .unwrap()
+        .in_debug_mode(DebugMode::Enabled)
+        .tx_discard_delta(None)
+        .build()
+        .await
+        .unwrap();
+    client.sync_state().await.unwrap();
+
+    // Each wallet's key lives in a single backend; the third one's key isn't held by any.
+    let mut wallet_ids = Vec::new();
+    for (seed, keystore) in [(1u8, Some(&primary)), (2, Some(&secondary)), (3, None)] {
+        let key_pair = AuthSecretKey::new_falcon512_poseidon2();
+        let account = AccountBuilder::new([seed; 32])
+            .account_type(AccountType::Private)
+            .with_auth_component(AuthSingleSig::new(
+                key_pair.public_key().to_commitment(),
+                AuthSchemeId::Falcon512Poseidon2,
+            ))
+            .with_component(BasicWallet)
+            .build_with_schema_commitment()
+            .unwrap();
+        if let Some(keystore) = keystore {
+            keystore.add_key(&key_pair, account.id()).await.unwrap();
+        }
+        client.add_account(&account, false).await.unwrap();
+        wallet_ids.push(account.id());
+    }
+
+    let tx_script = client.code_builder().compile_tx_script("begin push.1 drop end").unwrap();
+    let request = || {
+        TransactionRequestBuilder::new()
+            .custom_script(tx_script.clone())
+            .build()
+            .unwrap()
+    };
+
+    Box::pin(client.execute_transaction(wallet_ids[0], request())).await.unwrap();
+    Box::pin(client.execute_transaction(wallet_ids[1], request())).await.unwrap();
+    assert!(Box::pin(client.execute_transaction(wallet_ids[2], request())).await.is_err());
+}
+
+#[tokio::test]
+async fn resolve_by_prefix_handles_unique_ambiguous_and_missing_prefixes() {
+    let (mut client, mock_rpc_api, authenticator) = Box::pin(create_test_client()).await;
+    let (wallet, _, faucet) = setup_two_wallets_and_faucet(
+        &mut client,
+        AccountType::Private,
+        &authenticator,
+        RPO_FALCON_SCHEME_ID,
+    )
+    .await
+    .unwrap();
+
+    // No notes are tracked yet.
+    assert!(matches!(
+        client.resolve_note_by_prefix("0x").await,
+        Err(IdPrefixFetchError::NoMatch(_))
+    ));
+
+    let first_note = mint_note(&mut client, wallet.id(), faucet.id(), NoteType::Private).await.1;
+    let second_note = mint_note(&mut client, wallet.id(), faucet.id(), NoteType::Private).await.1;
+    mock_rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+
+    // Minted notes are tracked both as output and input notes, and still resolve uniquely.
+    assert_eq!(
+        client.resolve_note_by_prefix(&first_note.id().to_hex()).await.unwrap(),
+        first_note.id()
+    );
+    assert_eq!(
+        client.resolve_note_by_prefix(&second_note.id().to_hex()).await.unwrap(),
+        second_note.id()
+    );
+    assert!(matches!(
+        client.resolve_note_by_prefix("0x").await,
+        Err(IdPrefixFetchError::MultipleMatches(_))
+    ));
+
+    assert_eq!(
+        client.resolve_account_by_prefix(&wallet.id().to_hex()).await.unwrap(),
+        wallet.id()
+    );
+    assert!(matches!(
+        client.resolve_account_by_prefix("0x").await,
+        Err(IdPrefixFetchError::MultipleMatches(_))
+    ));
+
+    let untracked_account = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_2).unwrap();
+    assert!(matches!(
+        client.resolve_account_by_prefix(&untracked_account.to_hex()).await,
+        Err(IdPrefixFetchError::NoMatch(_))
+    ));
+}
+
+// NOTE CONSUMPTION REQUIREMENTS TESTS
+// ================================================================================================
+
+#[tokio::test]
+async fn note_consumption_requirements_of_p2ide_note() {
+    let (mut client, mock_rpc_api, authenticator) = Box::pin(create_test_client()).await;
+    let (sender, recipient, faucet) = setup_two_wallets_and_faucet(
+        &mut client,
+        AccountType::Private,
+        &authenticator,
+        RPO_FALCON_SCHEME_ID,
+    )
+    .await
+    .unwrap();
+
+    mint_and_consume(&mut client, sender.id(), faucet.id(), NoteType::Public).await;
+    mock_rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+
+    let current_block_num = client.get_sync_height().await.unwrap();
+    let timelock_height = current_block_num + RECALL_HEIGHT_DELTA;
+    let tx_request = TransactionRequestBuilder::new()
+        .build_pay_to_id(
+            PaymentNoteDescription::new(
+                vec![Asset::Fungible(FungibleAsset::new(faucet.id(), TRANSFER_AMOUNT).unwrap())],
+                sender.id(),
+                recipient.id(),
+            )
+            .with_timelock_height(timelock_height)
+            .with_reclaim_height(current_block_num),
+            NoteType::Public,
+            client.rng(),
+        )
+        .unwrap();
+    let note = tx_request.expected_output_own_notes().pop().unwrap();
+    Box::pin(client.submit_new_transaction(sender.id(), tx_request)).await.unwrap();
+    mock_rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+
+    let requirements = client.note_consumption_requirements(note.id()).await.unwrap();
+    assert_eq!(requirements.requires_note_args(), Some(false));
+    assert_eq!(requirements.timelock_height(), Some(timelock_height));
+    assert_eq!(requirements.reclaim_height(), Some(current_block_num));
+    assert!(
+        requirements
+            .consumability()
+            .iter()
+            .any(|(account_id, _)| *account_id == recipient.id())
+    );
+}
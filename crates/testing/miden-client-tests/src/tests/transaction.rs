@@ -1,18 +1,24 @@
 use alloc::boxed::Box;
 use alloc::sync::Arc;
+use core::sync::atomic::{AtomicUsize, Ordering};
 
 use miden_client::assembly::CodeBuilder;
 use miden_client::auth::{AuthSchemeId, AuthSecretKey, AuthSingleSig, RPO_FALCON_SCHEME_ID};
 use miden_client::keystore::Keystore;
 use miden_client::note::{NoteAttachments, P2idNote};
-use miden_client::store::NoteFilter;
+use miden_client::store::{NoteFilter, TransactionFilter};
+use miden_client::testing::common::{mint_note, setup_two_wallets_and_faucet};
 use miden_client::transaction::{
+    ForeignAccount,
+    LocalTransactionProver,
+    MAX_FAILURE_REASON_LEN,
     ProvenTransaction,
     TransactionExecutorError,
     TransactionInputs,
     TransactionProver,
     TransactionProverError,
     TransactionRequestBuilder,
+    TransactionStatus,
 };
 use miden_client::{ClientError, async_trait};
 use miden_protocol::account::{
@@ -39,7 +45,7 @@ use miden_standards::account::AccountBuilderSchemaCommitmentExt;
 use miden_standards::account::wallets::BasicWallet;
 
 use super::PaymentNoteDescription;
-use crate::tests::{create_test_client, setup_wallet_and_faucet};
+use crate::tests::{create_test_client, create_test_client_builder, setup_wallet_and_faucet};
 
 #[tokio::test]
 async fn transaction_creates_two_notes() {
@@ -239,6 +245,39 @@ impl TransactionProver for AlwaysFailingProver {
     }
 }
 
+/// A prover that delegates to [`LocalTransactionProver`] while it has proofs left, and fails
+/// afterwards. Starts with an unlimited number of proofs.
+struct LimitedProver {
+    inner: LocalTransactionProver,
+    remaining_proofs: AtomicUsize,
+}
+
+impl LimitedProver {
+    fn new() -> Self {
+        Self {
+            inner: LocalTransactionProver::default(),
+            remaining_proofs: AtomicUsize::new(usize::MAX),
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionProver for LimitedProver {
+    async fn prove(
+        &self,
+        inputs: TransactionInputs,
+    ) -> Result<ProvenTransaction, TransactionProverError> {
+        if self
+            .remaining_proofs
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |remaining| remaining.checked_sub(1))
+            .is_err()
+        {
+            return Err(TransactionProverError::other("simulated prover failure"));
+        }
+        self.inner.prove(inputs).await
+    }
+}
+
 // PROVER FALLBACK TESTS
 // ================================================================================================
 
@@ -279,6 +318,60 @@ async fn prover_fallback_pattern_allows_retry_with_different_prover() {
     assert!(tx_id.is_ok(), "fallback to default prover should succeed");
 }
 
+// NOTE CONSUMPTION TESTS
+// ================================================================================================
+
+/// Tests that when a later consume transaction fails, the IDs of the transactions already
+/// submitted are returned along with the failure.
+#[tokio::test]
+async fn consume_all_consumable_notes_reports_submitted_transactions_on_failure() {
+    let (builder, rpc_api, keystore) = Box::pin(create_test_client_builder()).await;
+    let prover = Arc::new(LimitedProver::new());
+    let mut client = builder.prover(prover.clone()).build().await.unwrap();
+    client.ensure_genesis_in_place().await.unwrap();
+
+    let (wallet, _, faucet) = setup_two_wallets_and_faucet(
+        &mut client,
+        AccountType::Private,
+        &keystore,
+        RPO_FALCON_SCHEME_ID,
+    )
+    .await
+    .unwrap();
+    mint_note(&mut client, wallet.id(), faucet.id(), NoteType::Private).await;
+    mint_note(&mut client, wallet.id(), faucet.id(), NoteType::Private).await;
+    rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+
+    // Consume one note per transaction, and only let the first transaction be proven.
+    prover.remaining_proofs.store(1, Ordering::SeqCst);
+    let result = Box::pin(client.test_consume_all_consumable_notes_in_batches(
+        wallet.id(),
+        Some(faucet.id()),
+        1,
+    ))
+    .await;
+
+    let (account_id, submitted_transactions, source) = match result {
+        Err(ClientError::NoteConsumptionIncomplete {
+            account_id,
+            submitted_transactions,
+            source,
+        }) => (account_id, submitted_transactions, source),
+        other => panic!("expected a partial note consumption failure, got {other:?}"),
+    };
+    assert_eq!(account_id, wallet.id());
+    assert_eq!(submitted_transactions.len(), 1);
+    assert!(matches!(*source, ClientError::TransactionProvingError(_)));
+
+    // The reported transaction was submitted and recorded.
+    let transactions = client
+        .get_transactions(TransactionFilter::Ids(submitted_transactions))
+        .await
+        .unwrap();
+    assert_eq!(transactions.len(), 1);
+}
+
 // LAZY FOREIGN ACCOUNT LOADING TESTS
 // ================================================================================================
 
@@ -402,3 +495,274 @@ async fn lazy_foreign_account_loading() {
         .unwrap();
     assert_eq!(cached.len(), 1, "foreign account code should be cached after lazy loading");
 }
+
+#[tokio::test]
+async fn foreign_account_inputs_are_cached_per_block() {
+    let (mut client, rpc_api, keystore) = Box::pin(create_test_client()).await;
+
+    // Deploy a public account to be used as the foreign account.
+    let foreign_account = super::insert_new_wallet(&mut client, AccountType::Public, &keystore)
+        .await
+        .unwrap();
+    let deploy_request = TransactionRequestBuilder::new().build().unwrap();
+    Box::pin(client.submit_new_transaction(foreign_account.id(), deploy_request))
+        .await
+        .unwrap();
+    rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+
+    let local_wallet = super::insert_new_wallet(&mut client, AccountType::Public, &keystore)
+        .await
+        .unwrap();
+    let fpi_request = || {
+        TransactionRequestBuilder::new()
+            .foreign_accounts([
+                ForeignAccount::public(foreign_account.id(), Default::default()).unwrap()
+            ])
+            .build()
+            .unwrap()
+    };
+
+    // The first execution fetches the foreign account from the node.
+    let calls_before = rpc_api.get_account_call_count();
+    Box::pin(client.execute_transaction(local_wallet.id(), fpi_request()))
+        .await
+        .unwrap();
+    let first_calls = rpc_api.get_account_call_count() - calls_before;
+    assert!(first_calls > 0);
+
+    // A second execution at the same sync height reuses the cached inputs.
+    let calls_before = rpc_api.get_account_call_count();
+    Box::pin(client.execute_transaction(local_wallet.id(), fpi_request()))
+        .await
+        .unwrap();
+    let cached_calls = rpc_api.get_account_call_count() - calls_before;
+    assert!(cached_calls < first_calls);
+
+    // Once the sync height advances the cached proof is stale and is fetched again.
+    rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+    let calls_before = rpc_api.get_account_call_count();
+    Box::pin(client.execute_transaction(local_wallet.id(), fpi_request()))
+        .await
+        .unwrap();
+    assert_eq!(rpc_api.get_account_call_count() - calls_before, first_calls);
+}
+
+#[tokio::test]
+async fn foreign_account_cache_can_be_disabled() {
+    let (builder, rpc_api, keystore) = Box::pin(create_test_client_builder()).await;
+    let mut client = builder.cache_foreign_account_inputs(false).build().await.unwrap();
+    client.ensure_genesis_in_place().await.unwrap();
+
+    let foreign_account = super::insert_new_wallet(&mut client, AccountType::Public, &keystore)
+        .await
+        .unwrap();
+    let deploy_request = TransactionRequestBuilder::new().build().unwrap();
+    Box::pin(client.submit_new_transaction(foreign_account.id(), deploy_request))
+        .await
+        .unwrap();
+    rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+
+    let local_wallet = super::insert_new_wallet(&mut client, AccountType::Public, &keystore)
+        .await
+        .unwrap();
+
+    let mut calls_per_execution = Vec::new();
+    for _ in 0..2 {
+        let fpi_request = TransactionRequestBuilder::new()
+            .foreign_accounts([
+                ForeignAccount::public(foreign_account.id(), Default::default()).unwrap()
+            ])
+            .build()
+            .unwrap();
+        let calls_before = rpc_api.get_account_call_count();
+        Box::pin(client.execute_transaction(local_wallet.id(), fpi_request))
+            .await
+            .unwrap();
+        calls_per_execution.push(rpc_api.get_account_call_count() - calls_before);
+    }
+
+    assert_eq!(calls_per_execution[0], calls_per_execution[1]);
+}
+
+#[tokio::test]
+async fn retained_transaction_proofs() {
+    let (builder, rpc_api, keystore) = Box::pin(create_test_client_builder()).await;
+    let mut client = builder.retain_proofs(true).build().await.unwrap();
+    client.ensure_genesis_in_place().await.unwrap();
+
+    let wallet = super::insert_new_wallet(&mut client, AccountType::Public, &keystore)
+        .await
+        .unwrap();
+    let tx_request = TransactionRequestBuilder::new().build().unwrap();
+    let tx_id = Box::pin(client.submit_new_transaction(wallet.id(), tx_request)).await.unwrap();
+    rpc_api.prove_block();
+
+    let proven_transaction = client.get_transaction_proof(tx_id).await.unwrap().unwrap();
+    assert_eq!(proven_transaction.id(), tx_id);
+
+    // Proofs are not retained by default.
+    let (mut client, _, keystore) = Box::pin(create_test_client()).await;
+    let wallet = super::insert_new_wallet(&mut client, AccountType::Public, &keystore)
+        .await
+        .unwrap();
+    let tx_request = TransactionRequestBuilder::new().build().unwrap();
+    let tx_id = Box::pin(client.submit_new_transaction(wallet.id(), tx_request)).await.unwrap();
+
+    assert!(client.get_transaction_proof(tx_id).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn resubmit_transaction_requires_pending_retained_proof() {
+    let (builder, rpc_api, keystore) = Box::pin(create_test_client_builder()).await;
+    let mut client = builder.retain_proofs(true).build().await.unwrap();
+    client.ensure_genesis_in_place().await.unwrap();
+
+    let wallet = super::insert_new_wallet(&mut client, AccountType::Public, &keystore)
+        .await
+        .unwrap();
+
+    // Committed transactions can't be resubmitted.
+    let tx_request = TransactionRequestBuilder::new().build().unwrap();
+    let committed_tx_id =
+        Box::pin(client.submit_new_transaction(wallet.id(), tx_request)).await.unwrap();
+    rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+
+    assert!(matches!(
+        client.resubmit_transaction(committed_tx_id).await,
+        Err(ClientError::TransactionNotResubmittable { transaction_id, .. })
+            if transaction_id == committed_tx_id
+    ));
+
+    // Pending transactions are resubmitted with their retained proof.
+    let tx_request = TransactionRequestBuilder::new().build().unwrap();
+    let pending_tx_id =
+        Box::pin(client.submit_new_transaction(wallet.id(), tx_request)).await.unwrap();
+
+    let block_num = client.resubmit_transaction(pending_tx_id).await.unwrap();
+    assert_eq!(block_num, rpc_api.get_chain_tip_block_num());
+
+    // Without retained proofs, pending transactions can't be resubmitted either.
+    let (mut client, _, keystore) = Box::pin(create_test_client()).await;
+    let wallet = super::insert_new_wallet(&mut client, AccountType::Public, &keystore)
+        .await
+        .unwrap();
+    let tx_request = TransactionRequestBuilder::new().build().unwrap();
+    let tx_id = Box::pin(client.submit_new_transaction(wallet.id(), tx_request)).await.unwrap();
+
+    assert!(matches!(
+        client.resubmit_transaction(tx_id).await,
+        Err(ClientError::TransactionNotResubmittable { .. })
+    ));
+}
+
+#[tokio::test]
+async fn failed_transactions_are_recorded() {
+    let (builder, _, keystore) = Box::pin(create_test_client_builder()).await;
+    let mut client = builder.record_failed_transactions(true).build().await.unwrap();
+    client.ensure_genesis_in_place().await.unwrap();
+
+    let wallet = super::insert_new_wallet(&mut client, AccountType::Private, &keystore)
+        .await
+        .unwrap();
+    let failing_script = client
+        .code_builder()
+        .compile_tx_script("begin push.0 push.2 assert_eq end")
+        .unwrap();
+    let tx_request =
+        TransactionRequestBuilder::new().custom_script(failing_script).build().unwrap();
+
+    Box::pin(client.execute_transaction(wallet.id(), tx_request))
+        .await
+        .expect_err("transaction should fail for assertion");
+
+    let failed = client.get_transactions(TransactionFilter::Failed).await.unwrap();
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].details.account_id, wallet.id());
+    match &failed[0].status {
+        TransactionStatus::Failed { reason } => {
+            assert!(!reason.is_empty());
+            assert!(reason.chars().count() <= MAX_FAILURE_REASON_LEN);
+        },
+        status => panic!("unexpected transaction status: {status}"),
+    }
+
+    // Failed transactions are not pending, so they are never picked up by sync.
+    assert!(
+        client
+            .get_transactions(TransactionFilter::Uncommitted)
+            .await
+            .unwrap()
+            .is_empty()
+    );
+
+    // Failures are not recorded by default.
+    let (mut client, _, keystore) = Box::pin(create_test_client()).await;
+    let wallet = super::insert_new_wallet(&mut client, AccountType::Private, &keystore)
+        .await
+        .unwrap();
+    let failing_script = client
+        .code_builder()
+        .compile_tx_script("begin push.0 push.2 assert_eq end")
+        .unwrap();
+    let tx_request =
+        TransactionRequestBuilder::new().custom_script(failing_script).build().unwrap();
+
+    Box::pin(client.execute_transaction(wallet.id(), tx_request))
+        .await
+        .expect_err("transaction should fail for assertion");
+
+    assert!(client.get_transactions(TransactionFilter::All).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn failed_simulations_are_not_recorded() {
+    let (builder, _, keystore) = Box::pin(create_test_client_builder()).await;
+    let mut client = builder.record_failed_transactions(true).build().await.unwrap();
+    client.ensure_genesis_in_place().await.unwrap();
+
+    let wallet = super::insert_new_wallet(&mut client, AccountType::Private, &keystore)
+        .await
+        .unwrap();
+    let failing_script = client
+        .code_builder()
+        .compile_tx_script("begin push.0 push.2 assert_eq end")
+        .unwrap();
+    let tx_request =
+        TransactionRequestBuilder::new().custom_script(failing_script).build().unwrap();
+
+    Box::pin(client.simulate_transaction(wallet.id(), tx_request))
+        .await
+        .expect_err("transaction should fail for assertion");
+
+    assert!(client.get_transactions(TransactionFilter::All).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn pending_transactions_are_returned_per_account() {
+    let (mut client, rpc_api, keystore) = Box::pin(create_test_client()).await;
+    let (wallet, faucet) =
+        setup_wallet_and_faucet(&mut client, AccountType::Private, &keystore, RPO_FALCON_SCHEME_ID)
+            .await
+            .unwrap();
+
+    let fungible_asset = FungibleAsset::new(faucet.id(), 100).unwrap();
+    let tx_request = TransactionRequestBuilder::new()
+        .build_mint_fungible_asset(fungible_asset, wallet.id(), NoteType::Private, client.rng())
+        .unwrap();
+    let tx_id = Box::pin(client.submit_new_transaction(faucet.id(), tx_request)).await.unwrap();
+
+    let pending = client.pending_transactions(faucet.id()).await.unwrap();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].id, tx_id);
+    assert!(client.pending_transactions(wallet.id()).await.unwrap().is_empty());
+
+    // Once the transaction is committed it is no longer pending.
+    rpc_api.prove_block();
+    client.sync_state().await.unwrap();
+
+    assert!(client.pending_transactions(faucet.id()).await.unwrap().is_empty());
+}
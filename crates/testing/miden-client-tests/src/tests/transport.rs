@@ -7,10 +7,10 @@ use miden_client::address::{Address, AddressInterface, RoutingParameters};
 use miden_client::builder::ClientBuilder;
 use miden_client::keystore::FilesystemKeyStore;
 use miden_client::note::{Note, NoteAttachments, NoteDetails, NoteTag, NoteType};
-use miden_client::note_transport::NoteTransportClient;
+use miden_client::note_transport::{NoteInfo, NoteTransportClient, NoteTransportCursor};
 use miden_client::store::NoteFilter;
 use miden_client::testing::common::create_test_store_path;
-use miden_client::testing::mock::{MockClient, MockRpcApi};
+use miden_client::testing::mock::{MockClient, MockRpcApi, MockRpcClientBuilder};
 use miden_client::testing::note_transport::{
     FaultyNoteTransportApi,
     MockNoteTransportApi,
@@ -26,7 +26,7 @@ use miden_protocol::transaction::RawOutputNote;
 use miden_protocol::utils::serde::Serializable;
 use miden_standards::note::P2idNote;
 use miden_standards::testing::note::NoteBuilder;
-use miden_testing::{MockChainBuilder, TxContextInput};
+use miden_testing::{MockChain, MockChainBuilder, TxContextInput};
 use rand::Rng;
 
 use crate::tests::{create_test_client_builder, insert_new_wallet};
@@ -637,6 +637,72 @@ async fn fetch_private_notes_without_floor_falls_back_to_lookback_window() {
     );
 }
 
+/// Regression test for private notes that stay `Expected` forever: the recipient syncs past the
+/// note's commitment block before the sender's relay reaches the NTL, so the note is imported
+/// after its block was already synced. It must still end up `Committed`.
+#[tokio::test]
+async fn private_note_delivered_after_commitment_sync_is_committed() {
+    let (mock_chain, private_note) = committed_private_note_chain(2).await;
+
+    // The first sync only sees the note's commitment block, the later ones the actual tip.
+    let rpc_api = MockRpcClientBuilder::new(mock_chain).with_sync_tip(1.into()).build();
+
+    // The note only reaches the NTL right before the second fetch, i.e. the second sync.
+    let details_bytes = NoteDetails::from(private_note.clone()).to_bytes();
+    let transport = MockNoteTransportApi::builder(Arc::default())
+        .deliver_on_fetch(2, *private_note.header(), details_bytes)
+        .build();
+
+    let mut client =
+        create_test_client_with_rpc_and_transport(rpc_api, Arc::new(transport.clone())).await;
+
+    client.sync_state().await.unwrap();
+    assert_eq!(client.get_sync_height().await.unwrap(), BlockNumber::from(1));
+    assert!(client.get_input_notes(NoteFilter::All).await.unwrap().is_empty());
+
+    client.sync_state().await.unwrap();
+    client.sync_state().await.unwrap();
+    assert_eq!(transport.fetch_count(), 3);
+
+    let committed_notes = client.get_input_notes(NoteFilter::Committed).await.unwrap();
+    assert!(
+        committed_notes.iter().any(|n| n.id() == Some(private_note.id())),
+        "note delivered after its commitment block was synced should be committed"
+    );
+}
+
+/// Scripted fetch responses are served in order, then fetches fall back to the node.
+#[tokio::test]
+async fn mock_transport_serves_scripted_fetches() {
+    let (_, private_note) = committed_private_note_chain(0).await;
+    let details_bytes = NoteDetails::from(private_note.clone()).to_bytes();
+    let info = NoteInfo {
+        header: *private_note.header(),
+        details_bytes: details_bytes.clone(),
+        block_hint: None,
+    };
+
+    let transport = MockNoteTransportApi::builder(Arc::default())
+        .with_fetch_response(vec![])
+        .with_fetch_response(vec![info])
+        .deliver_on_fetch(3, *private_note.header(), details_bytes)
+        .build();
+
+    let tags = [NoteTag::new(0)];
+    let cursor = NoteTransportCursor::init();
+    let (notes, rcursor) = transport.fetch_notes(&tags, cursor);
+    assert!(notes.is_empty());
+    assert_eq!(rcursor, cursor);
+
+    let (notes, _) = transport.fetch_notes(&tags, cursor);
+    assert_eq!(notes.len(), 1);
+
+    let (notes, rcursor) = transport.fetch_notes(&tags, cursor);
+    assert_eq!(notes.len(), 1);
+    assert!(rcursor > cursor);
+    assert_eq!(transport.fetch_count(), 3);
+}
+
 // HELPERS
 // ================================================================================================
 
@@ -678,13 +744,9 @@ pub async fn create_test_user_with_transport(
     (client, account)
 }
 
-/// Build a chain with a private note (tag 0) committed at block 1, advance
-/// `blocks_past_commitment` blocks beyond it, then create a recipient client synced to the tip
-/// with an (initially empty) note transport. Returns the client, the committed note, and the
-/// shared mock transport node so a test can deliver the note over the NTL afterwards.
-async fn committed_private_note_recipient(
-    blocks_past_commitment: u32,
-) -> (MockClient<FilesystemKeyStore>, Note, Arc<RwLock<MockNoteTransportNode>>) {
+/// Build a chain with a private note (tag 0) committed at block 1 and advance
+/// `blocks_past_commitment` blocks beyond it. Returns the chain and the committed note.
+async fn committed_private_note_chain(blocks_past_commitment: u32) -> (MockChain, Note) {
     let mut mock_chain_builder = MockChainBuilder::new();
     let mock_account = mock_chain_builder
         .add_existing_mock_account(miden_testing::Auth::IncrNonce)
@@ -723,11 +785,14 @@ async fn committed_private_note_recipient(
         mock_chain.prove_next_block().unwrap();
     }
 
-    let mock_transport_node = Arc::new(RwLock::new(MockNoteTransportNode::new()));
-    let rpc_api = MockRpcApi::new(mock_chain);
-    let arc_rpc_api = Arc::new(rpc_api);
-    let transport_client = MockNoteTransportApi::new(mock_transport_node.clone());
+    (mock_chain, private_note)
+}
 
+/// Create a client backed by the provided RPC and note transport mocks, tracking tag 0.
+async fn create_test_client_with_rpc_and_transport(
+    rpc_api: MockRpcApi,
+    transport: Arc<dyn NoteTransportClient>,
+) -> MockClient<FilesystemKeyStore> {
     let mut rng = rand::rng();
     let coin_seed: [u64; 4] = rng.random();
     let rng = RandomCoin::new(coin_seed.map(|v| Felt::new_unchecked(v >> 1)).into());
@@ -736,20 +801,41 @@ async fn committed_private_note_recipient(
     let keystore = FilesystemKeyStore::new(keystore_path.clone()).unwrap();
 
     let builder: ClientBuilder<FilesystemKeyStore> = ClientBuilder::new()
-        .rpc(arc_rpc_api)
+        .rpc(Arc::new(rpc_api))
         .rng(Box::new(rng))
         .sqlite_store(create_test_store_path())
         .authenticator(Arc::new(keystore))
         .in_debug_mode(DebugMode::Enabled)
         .tx_discard_delta(None)
-        .note_transport(Arc::new(transport_client));
+        .note_transport(transport);
 
     let mut client = builder.build().await.unwrap();
     client.ensure_genesis_in_place().await.unwrap();
 
-    // Register tag 0 so chain sync sees the note's block, then sync to the tip. The NTL is empty,
-    // so no transport notes are imported yet.
+    // Register tag 0 so chain sync sees the note's block.
     client.add_note_tag(NoteTag::new(0)).await.unwrap();
+
+    client
+}
+
+/// Build a chain with a private note (tag 0) committed at block 1, advance
+/// `blocks_past_commitment` blocks beyond it, then create a recipient client synced to the tip
+/// with an (initially empty) note transport. Returns the client, the committed note, and the
+/// shared mock transport node so a test can deliver the note over the NTL afterwards.
+async fn committed_private_note_recipient(
+    blocks_past_commitment: u32,
+) -> (MockClient<FilesystemKeyStore>, Note, Arc<RwLock<MockNoteTransportNode>>) {
+    let (mock_chain, private_note) = committed_private_note_chain(blocks_past_commitment).await;
+
+    let mock_transport_node = Arc::new(RwLock::new(MockNoteTransportNode::new()));
+    let transport_client = MockNoteTransportApi::new(mock_transport_node.clone());
+    let mut client = create_test_client_with_rpc_and_transport(
+        MockRpcApi::new(mock_chain),
+        Arc::new(transport_client),
+    )
+    .await;
+
+    // Sync to the tip. The NTL is empty, so no transport notes are imported yet.
     client.sync_state().await.unwrap();
 
     (client, private_note, mock_transport_node)